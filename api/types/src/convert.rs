@@ -5,8 +5,9 @@
 use crate::{
     transaction::{
         DecodedTableData, DeleteModule, DeleteResource, DeleteTableItem, DeletedTableData,
-        MultisigPayload, MultisigTransactionPayload, StateCheckpointTransaction,
-        UserTransactionRequestInner, WriteModule, WriteResource, WriteTableItem,
+        MultiSignerScriptWriteSet, MultisigPayload, MultisigTransactionPayload,
+        StateCheckpointTransaction, UserTransactionRequestInner, WriteModule, WriteResource,
+        WriteTableItem,
     },
     view::{ViewFunction, ViewRequest},
     Address, Bytecode, DirectWriteSet, EntryFunctionId, EntryFunctionPayload, Event,
@@ -328,6 +329,12 @@ impl<'a, S: StateView> MoveConverter<'a, S> {
                     script: script.try_into()?,
                 }),
             },
+            MultiSignerScript { signers, script } => WriteSetPayload {
+                write_set: WriteSet::MultiSignerScriptWriteSet(MultiSignerScriptWriteSet {
+                    signers: signers.into_iter().map(Address::from).collect(),
+                    script: script.try_into()?,
+                }),
+            },
             Direct(d) => {
                 let (write_set, events) = d.into_inner();
                 let nested_writeset_changes: Vec<Vec<WriteSetChange>> = write_set
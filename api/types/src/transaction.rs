@@ -788,6 +788,7 @@ pub struct WriteSetPayload {
 pub enum WriteSet {
     ScriptWriteSet(ScriptWriteSet),
     DirectWriteSet(DirectWriteSet),
+    MultiSignerScriptWriteSet(MultiSignerScriptWriteSet),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
@@ -796,6 +797,13 @@ pub struct ScriptWriteSet {
     pub script: ScriptPayload,
 }
 
+/// A writeset generated by a script which takes the signers in order
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct MultiSignerScriptWriteSet {
+    pub signers: Vec<Address>,
+    pub script: ScriptPayload,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
 pub struct DirectWriteSet {
     pub changes: Vec<WriteSetChange>,
@@ -2,10 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use anyhow::{bail, format_err, Result};
+use aptos_block_executor::txn_commit_hook::NoOpTransactionCommitHook;
 use aptos_gas_profiling::{GasProfiler, TransactionGasLog};
+use aptos_gas_schedule::LATEST_GAS_FEATURE_VERSION;
 use aptos_rest_client::Client;
 use aptos_types::{
     account_address::AccountAddress,
+    block_executor::config::BlockExecutorConfig,
+    on_chain_config::{Features, OnChainConfig},
     state_store::TStateView,
     transaction::{
         signature_verified_transaction::SignatureVerifiedTransaction, SignedTransaction,
@@ -16,18 +20,41 @@ use aptos_types::{
 use aptos_validator_interface::{
     AptosValidatorInterface, DBDebuggerInterface, DebuggerStateView, RestDebuggerInterface,
 };
-use aptos_vm::{data_cache::AsMoveResolver, AptosVM, VMExecutor};
+use aptos_vm::{
+    aptos_vm::RAYON_EXEC_POOL,
+    block_executor::{AptosTransactionOutput, BlockAptosVM},
+    data_cache::AsMoveResolver,
+    AptosVM,
+};
 use aptos_vm_logging::log_schema::AdapterLogSchema;
 use aptos_vm_types::output::VMOutput;
 use std::{path::Path, sync::Arc};
 
 pub struct AptosDebugger {
     debugger: Arc<dyn AptosValidatorInterface + Send>,
+    skip_prologue_resession: bool,
+    max_gas_feature_version: u64,
 }
 
 impl AptosDebugger {
     pub fn new(debugger: Arc<dyn AptosValidatorInterface + Send>) -> Self {
-        Self { debugger }
+        Self {
+            debugger,
+            skip_prologue_resession: false,
+            max_gas_feature_version: LATEST_GAS_FEATURE_VERSION,
+        }
+    }
+
+    /// Sets whether past transactions are re-executed without flushing the data cache into a new
+    /// session after the prologue, see `AptosVM::set_skip_prologue_resession`.
+    pub fn set_skip_prologue_resession(&mut self, enable: bool) {
+        self.skip_prologue_resession = enable;
+    }
+
+    /// Sets the newest gas feature version past transactions may be re-executed with. Defaults to
+    /// the latest version known to this binary, see `AptosVM::check_gas_feature_version_for_replay`.
+    pub fn set_max_gas_feature_version(&mut self, max_gas_feature_version: u64) {
+        self.max_gas_feature_version = max_gas_feature_version;
     }
 
     pub fn rest_client(rest_client: Client) -> Result<Self> {
@@ -49,13 +76,14 @@ impl AptosDebugger {
         let sig_verified_txns: Vec<SignatureVerifiedTransaction> =
             txns.into_iter().map(|x| x.into()).collect::<Vec<_>>();
         let state_view = DebuggerStateView::new(self.debugger.clone(), version);
+        AptosVM::new(&state_view.as_move_resolver(), None)
+            .check_gas_feature_version_for_replay(self.max_gas_feature_version)
+            .map_err(|err| format_err!("Cannot replay at version {}: {:?}", version, err))?;
 
-        let result = AptosVM::execute_block_no_limit(&sig_verified_txns, &state_view)
-            .map_err(|err| format_err!("Unexpected VM Error: {:?}", err))?;
+        let result = self.execute_block(&sig_verified_txns, &state_view)?;
 
         for i in 1..repeat_execution_times {
-            let repeat_result = AptosVM::execute_block_no_limit(&sig_verified_txns, &state_view)
-                .map_err(|err| format_err!("Unexpected VM Error: {:?}", err))?;
+            let repeat_result = self.execute_block(&sig_verified_txns, &state_view)?;
             println!(
                 "Finished execution round {}/{} with {} transactions",
                 i,
@@ -73,6 +101,27 @@ impl AptosDebugger {
         Ok(result)
     }
 
+    fn execute_block(
+        &self,
+        txns: &[SignatureVerifiedTransaction],
+        state_view: &DebuggerStateView,
+    ) -> Result<Vec<TransactionOutput>> {
+        let mut config = BlockExecutorConfig::new_no_block_limit(AptosVM::get_concurrency_level());
+        config.local.skip_prologue_resession = self.skip_prologue_resession;
+        BlockAptosVM::execute_block::<
+            _,
+            NoOpTransactionCommitHook<AptosTransactionOutput, VMStatus>,
+        >(
+            Arc::clone(&RAYON_EXEC_POOL),
+            txns,
+            state_view,
+            config,
+            None,
+        )
+        .map(|output| output.into_transaction_outputs_forced())
+        .map_err(|err| format_err!("Unexpected VM Error: {:?}", err))
+    }
+
     pub fn execute_transaction_at_version_with_gas_profiler(
         &self,
         version: Version,
@@ -86,10 +135,14 @@ impl AptosDebugger {
 
         // TODO(Gas): revisit this.
         let resolver = state_view.as_move_resolver();
-        let vm = AptosVM::new(
-            &resolver,
-            /*override_is_delayed_field_optimization_capable=*/ Some(false),
-        );
+        // Replay with the feature flags of the transaction's version, instead of silently falling
+        // back to the default ones if they can't be read.
+        let features = Features::fetch_config(&resolver)
+            .ok_or_else(|| format_err!("Failed to fetch feature flags at version {}", version))?;
+        let mut vm = AptosVM::with_feature_snapshot(&resolver, features);
+        vm.check_gas_feature_version_for_replay(self.max_gas_feature_version)
+            .map_err(|err| format_err!("Cannot replay at version {}: {:?}", version, err))?;
+        vm.set_record_call_trace(true);
 
         // Module bundle is deprecated!
         if let TransactionPayload::ModuleBundle(_) = txn.payload() {
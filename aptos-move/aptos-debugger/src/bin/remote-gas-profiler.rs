@@ -57,6 +57,13 @@ async fn main() -> Result<()> {
     let (_status, output, gas_log) =
         debugger.execute_transaction_at_version_with_gas_profiler(version, txn)?;
 
+    if let Some(call_trace) = output.call_trace() {
+        println!("Call trace:");
+        for call in call_trace {
+            println!("  {}", call);
+        }
+    }
+
     let txn_output =
         output.try_materialize_into_transaction_output(&debugger.state_view_at_version(version))?;
 
@@ -24,19 +24,25 @@ pub struct Command {
 
     #[clap(long)]
     repeat_execution_times: Option<u64>,
+
+    /// Skip flushing the data cache into a new session after the prologue. Speeds up
+    /// re-execution, but gas usage may differ from what was charged on chain.
+    #[clap(long)]
+    skip_prologue_resession: bool,
 }
 
 impl Command {
     pub async fn run(self) -> Result<()> {
         AptosVM::set_concurrency_level_once(self.opts.concurrency_level);
 
-        let debugger = if let Some(rest_endpoint) = self.opts.target.rest_endpoint {
+        let mut debugger = if let Some(rest_endpoint) = self.opts.target.rest_endpoint {
             AptosDebugger::rest_client(Client::new(Url::parse(&rest_endpoint)?))?
         } else if let Some(db_path) = self.opts.target.db_path {
             AptosDebugger::db(db_path)?
         } else {
             unreachable!("Must provide one target.");
         };
+        debugger.set_skip_prologue_resession(self.skip_prologue_resession);
 
         let result = debugger
             .execute_past_transactions(
@@ -47,6 +47,16 @@ pub enum Module {}
 
 pub type NumModules = GasQuantity<Module>;
 
+/// Unit of event
+pub enum Event {}
+
+pub type NumEvents = GasQuantity<Event>;
+
+/// Unit of dynamic dispatch
+pub enum Dispatch {}
+
+pub type NumDispatches = GasQuantity<Dispatch>;
+
 /***************************************************************************************************
  * Unit Conversion
  *
@@ -5,7 +5,7 @@
 
 use crate::{
     gas_schedule::NativeGasParameters,
-    ver::gas_feature_versions::{RELEASE_V1_12, RELEASE_V1_13},
+    ver::gas_feature_versions::{RELEASE_V1_12, RELEASE_V1_13, RELEASE_V1_14},
 };
 use aptos_gas_algebra::{
     InternalGas, InternalGasPerAbstractValueUnit, InternalGasPerArg, InternalGasPerByte,
@@ -26,6 +26,7 @@ crate::gas_schedule::macros::define_gas_parameters!(
         [algebra_ark_bn254_fq12_deser: InternalGas, { 12.. => "algebra.ark_bn254_fq12_deser" }, 23721],
         [algebra_ark_bn254_fq12_div: InternalGas, { 12.. => "algebra.ark_bn254_fq12_div" }, 517140],
         [algebra_ark_bn254_fq12_eq: InternalGas, { 12.. => "algebra.ark_bn254_fq12_eq" }, 2231],
+        [algebra_ark_bn254_fq12_frobenius_map: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bn254_fq12_frobenius_map" }, 10722],
         [algebra_ark_bn254_fq12_from_u64: InternalGas, { 12.. => "algebra.ark_bn254_fq12_from_u64" }, 2658],
         [algebra_ark_bn254_fq12_inv: InternalGas, { 12.. => "algebra.ark_bn254_fq12_inv" }, 398555],
         [algebra_ark_bn254_fq12_mul: InternalGas, { 12.. => "algebra.ark_bn254_fq12_mul" }, 118351],
@@ -43,29 +44,38 @@ crate::gas_schedule::macros::define_gas_parameters!(
         [algebra_ark_bn254_fq_eq: InternalGas, { 12.. => "algebra.ark_bn254_fq_eq" }, 803],
         [algebra_ark_bn254_fq_from_u64: InternalGas, { 12.. => "algebra.ark_bn254_fq_from_u64" }, 2598],
         [algebra_ark_bn254_fq_inv: InternalGas, { 12.. => "algebra.ark_bn254_fq_inv" }, 208902],
+        [algebra_ark_bn254_fq_is_square: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bn254_fq_is_square" }, 701860],
         [algebra_ark_bn254_fq_mul: InternalGas, { 12.. => "algebra.ark_bn254_fq_mul" }, 1847],
         [algebra_ark_bn254_fq_neg: InternalGas, { 12.. => "algebra.ark_bn254_fq_neg" }, 792],
         [algebra_ark_bn254_fq_one: InternalGas, { 12.. => "algebra.ark_bn254_fq_one" }, 38],
         [algebra_ark_bn254_fq_pow_u256: InternalGas, { 12.. => "algebra.ark_bn254_fq_pow_u256" }, 382570],
         [algebra_ark_bn254_fq_serialize: InternalGas, { 12.. => "algebra.ark_bn254_fq_serialize" }, 4767],
+        [algebra_ark_bn254_fq_sqrt: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bn254_fq_sqrt" }, 1403720],
         [algebra_ark_bn254_fq_square: InternalGas, { 12.. => "algebra.ark_bn254_fq_square" }, 792],
         [algebra_ark_bn254_fq_sub: InternalGas, { 12.. => "algebra.ark_bn254_fq_sub" }, 1130],
         [algebra_ark_bn254_fq_zero: InternalGas, { 12.. => "algebra.ark_bn254_fq_zero" }, 38],
         [algebra_ark_bn254_fr_add: InternalGas, { 12.. => "algebra.ark_bn254_fr_add" }, 804],
+        [algebra_ark_bn254_fr_bit: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bn254_fr_bit" }, 1300],
         [algebra_ark_bn254_fr_deser: InternalGas, { 12.. => "algebra.ark_bn254_fr_deser" }, 3073],
         [algebra_ark_bn254_fr_div: InternalGas, { 12.. => "algebra.ark_bn254_fr_div" }, 223857],
         [algebra_ark_bn254_fr_eq: InternalGas, { 12.. => "algebra.ark_bn254_fr_eq" }, 807],
         [algebra_ark_bn254_fr_from_u64: InternalGas, { 12.. => "algebra.ark_bn254_fr_from_u64" }, 2478],
+        [algebra_ark_bn254_fr_from_u256: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bn254_fr_from_u256" }, 3073],
         [algebra_ark_bn254_fr_inv: InternalGas, { 12.. => "algebra.ark_bn254_fr_inv" }, 222216],
+        [algebra_ark_bn254_fr_is_square: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bn254_fr_is_square" }, 688940],
         [algebra_ark_bn254_fr_mul: InternalGas, { 12.. => "algebra.ark_bn254_fr_mul" }, 1813],
         [algebra_ark_bn254_fr_neg: InternalGas, { 12.. => "algebra.ark_bn254_fr_neg" }, 792],
         [algebra_ark_bn254_fr_one: InternalGas, { 12.. => "algebra.ark_bn254_fr_one" }, 0],
         [algebra_ark_bn254_fr_serialize: InternalGas, { 12.. => "algebra.ark_bn254_fr_serialize" }, 4732],
+        [algebra_ark_bn254_fr_sqrt: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bn254_fr_sqrt" }, 2103080],
         [algebra_ark_bn254_fr_square: InternalGas, { 12.. => "algebra.ark_bn254_fr_square" }, 792],
         [algebra_ark_bn254_fr_sub: InternalGas, { 12.. => "algebra.ark_bn254_fr_sub" }, 1906],
         [algebra_ark_bn254_fr_zero: InternalGas, { 12.. => "algebra.ark_bn254_fr_zero" }, 38],
+        [algebra_ark_bn254_g1_affine_clear_cofactor: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bn254_g1_affine_clear_cofactor" }, 1165],
         [algebra_ark_bn254_g1_affine_deser_comp: InternalGas, { 12.. => "algebra.ark_bn254_g1_affine_deser_comp" }, 4318809],
+        [algebra_ark_bn254_g1_affine_deser_comp_unchecked: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bn254_g1_affine_deser_comp_unchecked" }, 400981],
         [algebra_ark_bn254_g1_affine_deser_uncomp: InternalGas, { 12.. => "algebra.ark_bn254_g1_affine_deser_uncomp" }, 3956976],
+        [algebra_ark_bn254_g1_affine_deser_uncomp_unchecked: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bn254_g1_affine_deser_uncomp_unchecked" }, 39148],
         [algebra_ark_bn254_g1_affine_serialize_comp: InternalGas, { 12.. => "algebra.ark_bn254_g1_affine_serialize_comp" }, 8257],
         [algebra_ark_bn254_g1_affine_serialize_uncomp: InternalGas, { 12.. => "algebra.ark_bn254_g1_affine_serialize_uncomp" }, 10811],
         [algebra_ark_bn254_g1_proj_add: InternalGas, { 12.. => "algebra.ark_bn254_g1_proj_add" }, 19574],
@@ -74,11 +84,16 @@ crate::gas_schedule::macros::define_gas_parameters!(
         [algebra_ark_bn254_g1_proj_generator: InternalGas, { 12.. => "algebra.ark_bn254_g1_proj_generator" }, 38],
         [algebra_ark_bn254_g1_proj_infinity: InternalGas, { 12.. => "algebra.ark_bn254_g1_proj_infinity" }, 38],
         [algebra_ark_bn254_g1_proj_neg: InternalGas, { 12.. => "algebra.ark_bn254_g1_proj_neg" }, 38],
+        [algebra_ark_bn254_g1_proj_normalize_batch_base: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bn254_g1_proj_normalize_batch_base" }, 208902],
+        [algebra_ark_bn254_g1_proj_normalize_batch_per_point: InternalGasPerArg, { RELEASE_V1_14.. => "algebra.ark_bn254_g1_proj_normalize_batch_per_point" }, 11874],
         [algebra_ark_bn254_g1_proj_scalar_mul: InternalGas, { 12.. => "algebra.ark_bn254_g1_proj_scalar_mul" }, 4862683],
         [algebra_ark_bn254_g1_proj_sub: InternalGas, { 12.. => "algebra.ark_bn254_g1_proj_sub" }, 19648],
         [algebra_ark_bn254_g1_proj_to_affine: InternalGas, { 12.. => "algebra.ark_bn254_g1_proj_to_affine" }, 1165],
+        [algebra_ark_bn254_g2_affine_clear_cofactor: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bn254_g2_affine_clear_cofactor" }, 14041548],
         [algebra_ark_bn254_g2_affine_deser_comp: InternalGas, { 12.. => "algebra.ark_bn254_g2_affine_deser_comp" }, 12445138],
+        [algebra_ark_bn254_g2_affine_deser_comp_unchecked: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bn254_g2_affine_deser_comp_unchecked" }, 1409579],
         [algebra_ark_bn254_g2_affine_deser_uncomp: InternalGas, { 12.. => "algebra.ark_bn254_g2_affine_deser_uncomp" }, 11152541],
+        [algebra_ark_bn254_g2_affine_deser_uncomp_unchecked: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bn254_g2_affine_deser_uncomp_unchecked" }, 116982],
         [algebra_ark_bn254_g2_affine_serialize_comp: InternalGas, { 12.. => "algebra.ark_bn254_g2_affine_serialize_comp" }, 12721],
         [algebra_ark_bn254_g2_affine_serialize_uncomp: InternalGas, { 12.. => "algebra.ark_bn254_g2_affine_serialize_uncomp" }, 18105],
         [algebra_ark_bn254_g2_proj_add: InternalGas, { 12.. => "algebra.ark_bn254_g2_proj_add" }, 58491],
@@ -87,6 +102,8 @@ crate::gas_schedule::macros::define_gas_parameters!(
         [algebra_ark_bn254_g2_proj_generator: InternalGas, { 12.. => "algebra.ark_bn254_g2_proj_generator" }, 38],
         [algebra_ark_bn254_g2_proj_infinity: InternalGas, { 12.. => "algebra.ark_bn254_g2_proj_infinity" }, 38],
         [algebra_ark_bn254_g2_proj_neg: InternalGas, { 12.. => "algebra.ark_bn254_g2_proj_neg" }, 38],
+        [algebra_ark_bn254_g2_proj_normalize_batch_base: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bn254_g2_proj_normalize_batch_base" }, 230100],
+        [algebra_ark_bn254_g2_proj_normalize_batch_per_point: InternalGasPerArg, { RELEASE_V1_14.. => "algebra.ark_bn254_g2_proj_normalize_batch_per_point" }, 36940],
         [algebra_ark_bn254_g2_proj_scalar_mul: InternalGas, { 12.. => "algebra.ark_bn254_g2_proj_scalar_mul" }, 14041548],
         [algebra_ark_bn254_g2_proj_sub: InternalGas, { 12.. => "algebra.ark_bn254_g2_proj_sub" }, 59133],
         [algebra_ark_bn254_g2_proj_to_affine: InternalGas, { 12.. => "algebra.ark_bn254_g2_proj_to_affine" }, 230100],
@@ -102,6 +119,7 @@ crate::gas_schedule::macros::define_gas_parameters!(
         [algebra_ark_bls12_381_fq12_deser: InternalGas, { 8.. => "algebra.ark_bls12_381_fq12_deser" }, 41097],
         [algebra_ark_bls12_381_fq12_div: InternalGas, { 8.. => "algebra.ark_bls12_381_fq12_div" }, 921988],
         [algebra_ark_bls12_381_fq12_eq: InternalGas, { 8.. => "algebra.ark_bls12_381_fq12_eq" }, 2668],
+        [algebra_ark_bls12_381_fq12_frobenius_map: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq12_frobenius_map" }, 16582],
         [algebra_ark_bls12_381_fq12_from_u64: InternalGas, { 8.. => "algebra.ark_bls12_381_fq12_from_u64" }, 3312],
         [algebra_ark_bls12_381_fq12_inv: InternalGas, { 8.. => "algebra.ark_bls12_381_fq12_inv" }, 737122],
         [algebra_ark_bls12_381_fq12_mul: InternalGas, { 8.. => "algebra.ark_bls12_381_fq12_mul" }, 183380],
@@ -113,20 +131,27 @@ crate::gas_schedule::macros::define_gas_parameters!(
         [algebra_ark_bls12_381_fq12_sub: InternalGas, { 8.. => "algebra.ark_bls12_381_fq12_sub" }, 6462],
         [algebra_ark_bls12_381_fq12_zero: InternalGas, { 8.. => "algebra.ark_bls12_381_fq12_zero" }, 775],
         [algebra_ark_bls12_381_fr_add: InternalGas, { 8.. => "algebra.ark_bls12_381_fr_add" }, 775],
+        [algebra_ark_bls12_381_fr_bit: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fr_bit" }, 1310],
         [algebra_ark_bls12_381_fr_deser: InternalGas, { 8.. => "algebra.ark_bls12_381_fr_deser" }, 2764],
         [algebra_ark_bls12_381_fr_div: InternalGas, { 8.. => "algebra.ark_bls12_381_fr_div" }, 218501],
         [algebra_ark_bls12_381_fr_eq: InternalGas, { 8.. => "algebra.ark_bls12_381_fr_eq" }, 779],
         [algebra_ark_bls12_381_fr_from_u64: InternalGas, { 8.. => "algebra.ark_bls12_381_fr_from_u64" }, 1815],
+        [algebra_ark_bls12_381_fr_from_u256: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fr_from_u256" }, 2764],
         [algebra_ark_bls12_381_fr_inv: InternalGas, { 8.. => "algebra.ark_bls12_381_fr_inv" }, 215450],
+        [algebra_ark_bls12_381_fr_is_square: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fr_is_square" }, 701100],
         [algebra_ark_bls12_381_fr_mul: InternalGas, { 8.. => "algebra.ark_bls12_381_fr_mul" }, 1845],
         [algebra_ark_bls12_381_fr_neg: InternalGas, { 8.. => "algebra.ark_bls12_381_fr_neg" }, 782],
         [algebra_ark_bls12_381_fr_one: InternalGas, { 8.. => "algebra.ark_bls12_381_fr_one" }, 775],
         [algebra_ark_bls12_381_fr_serialize: InternalGas, { 8.. => "algebra.ark_bls12_381_fr_serialize" }, 4054],
+        [algebra_ark_bls12_381_fr_sqrt: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fr_sqrt" }, 2324700],
         [algebra_ark_bls12_381_fr_square: InternalGas, { 8.. => "algebra.ark_bls12_381_fr_square" }, 1746],
         [algebra_ark_bls12_381_fr_sub: InternalGas, { 8.. => "algebra.ark_bls12_381_fr_sub" }, 1066],
         [algebra_ark_bls12_381_fr_zero: InternalGas, { 8.. => "algebra.ark_bls12_381_fr_zero" }, 775],
+        [algebra_ark_bls12_381_g1_affine_clear_cofactor: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_g1_affine_clear_cofactor" }, 1437010],
         [algebra_ark_bls12_381_g1_affine_deser_comp: InternalGas, { 8.. => "algebra.ark_bls12_381_g1_affine_deser_comp" }, 3784805],
+        [algebra_ark_bls12_381_g1_affine_deser_comp_unchecked: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_g1_affine_deser_comp_unchecked" }, 1215184],
         [algebra_ark_bls12_381_g1_affine_deser_uncomp: InternalGas, { 8.. => "algebra.ark_bls12_381_g1_affine_deser_uncomp" }, 2649065],
+        [algebra_ark_bls12_381_g1_affine_deser_uncomp_unchecked: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_g1_affine_deser_uncomp_unchecked" }, 79444],
         [algebra_ark_bls12_381_g1_affine_serialize_comp: InternalGas, { 8.. => "algebra.ark_bls12_381_g1_affine_serialize_comp" }, 7403],
         [algebra_ark_bls12_381_g1_affine_serialize_uncomp: InternalGas, { 8.. => "algebra.ark_bls12_381_g1_affine_serialize_uncomp" }, 8943],
         [algebra_ark_bls12_381_g1_proj_add: InternalGas, { 8.. => "algebra.ark_bls12_381_g1_proj_add" }, 39722],
@@ -135,11 +160,17 @@ crate::gas_schedule::macros::define_gas_parameters!(
         [algebra_ark_bls12_381_g1_proj_generator: InternalGas, { 8.. => "algebra.ark_bls12_381_g1_proj_generator" }, 40],
         [algebra_ark_bls12_381_g1_proj_infinity: InternalGas, { 8.. => "algebra.ark_bls12_381_g1_proj_infinity" }, 40],
         [algebra_ark_bls12_381_g1_proj_neg: InternalGas, { 8.. => "algebra.ark_bls12_381_g1_proj_neg" }, 40],
+        [algebra_ark_bls12_381_g1_proj_normalize_batch_base: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_g1_proj_normalize_batch_base" }, 444924],
+        [algebra_ark_bls12_381_g1_proj_normalize_batch_per_point: InternalGasPerArg, { RELEASE_V1_14.. => "algebra.ark_bls12_381_g1_proj_normalize_batch_per_point" }, 9675],
+        [algebra_ark_bls12_381_g1_proj_glv_scalar_mul: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_g1_proj_glv_scalar_mul" }, 6290112],
         [algebra_ark_bls12_381_g1_proj_scalar_mul: InternalGas, { 8.. => "algebra.ark_bls12_381_g1_proj_scalar_mul" }, 9276463],
         [algebra_ark_bls12_381_g1_proj_sub: InternalGas, { 8.. => "algebra.ark_bls12_381_g1_proj_sub" }, 40976],
         [algebra_ark_bls12_381_g1_proj_to_affine: InternalGas, { 8.. => "algebra.ark_bls12_381_g1_proj_to_affine" }, 444924],
+        [algebra_ark_bls12_381_g2_affine_clear_cofactor: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_g2_affine_clear_cofactor" }, 8887840],
         [algebra_ark_bls12_381_g2_affine_deser_comp: InternalGas, { 8.. => "algebra.ark_bls12_381_g2_affine_deser_comp" }, 7572809],
+        [algebra_ark_bls12_381_g2_affine_deser_comp_unchecked: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_g2_affine_deser_comp_unchecked" }, 4068931],
         [algebra_ark_bls12_381_g2_affine_deser_uncomp: InternalGas, { 8.. => "algebra.ark_bls12_381_g2_affine_deser_uncomp" }, 3742090],
+        [algebra_ark_bls12_381_g2_affine_deser_uncomp_unchecked: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_g2_affine_deser_uncomp_unchecked" }, 238212],
         [algebra_ark_bls12_381_g2_affine_serialize_comp: InternalGas, { 8.. => "algebra.ark_bls12_381_g2_affine_serialize_comp" }, 12417],
         [algebra_ark_bls12_381_g2_affine_serialize_uncomp: InternalGas, { 8.. => "algebra.ark_bls12_381_g2_affine_serialize_uncomp" }, 15501],
         [algebra_ark_bls12_381_g2_proj_add: InternalGas, { 8.. => "algebra.ark_bls12_381_g2_proj_add" }, 119106],
@@ -148,6 +179,8 @@ crate::gas_schedule::macros::define_gas_parameters!(
         [algebra_ark_bls12_381_g2_proj_generator: InternalGas, { 8.. => "algebra.ark_bls12_381_g2_proj_generator" }, 40],
         [algebra_ark_bls12_381_g2_proj_infinity: InternalGas, { 8.. => "algebra.ark_bls12_381_g2_proj_infinity" }, 40],
         [algebra_ark_bls12_381_g2_proj_neg: InternalGas, { 8.. => "algebra.ark_bls12_381_g2_proj_neg" }, 40],
+        [algebra_ark_bls12_381_g2_proj_normalize_batch_base: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_g2_proj_normalize_batch_base" }, 473678],
+        [algebra_ark_bls12_381_g2_proj_normalize_batch_per_point: InternalGasPerArg, { RELEASE_V1_14.. => "algebra.ark_bls12_381_g2_proj_normalize_batch_per_point" }, 27854],
         [algebra_ark_bls12_381_g2_proj_scalar_mul: InternalGas, { 8.. => "algebra.ark_bls12_381_g2_proj_scalar_mul" }, 27667443],
         [algebra_ark_bls12_381_g2_proj_sub: InternalGas, { 8.. => "algebra.ark_bls12_381_g2_proj_sub" }, 120826],
         [algebra_ark_bls12_381_g2_proj_to_affine: InternalGas, { 8.. => "algebra.ark_bls12_381_g2_proj_to_affine" }, 473678],
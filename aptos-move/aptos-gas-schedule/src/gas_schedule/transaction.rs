@@ -6,11 +6,11 @@
 
 use crate::{
     gas_schedule::VMGasParameters,
-    ver::gas_feature_versions::{RELEASE_V1_11, RELEASE_V1_12, RELEASE_V1_13},
+    ver::gas_feature_versions::{RELEASE_V1_11, RELEASE_V1_12, RELEASE_V1_13, RELEASE_V1_14},
 };
 use aptos_gas_algebra::{
     AbstractValueSize, Fee, FeePerByte, FeePerGasUnit, FeePerSlot, Gas, GasExpression,
-    GasScalingFactor, GasUnit, NumModules, NumSlots,
+    GasScalingFactor, GasUnit, NumDispatches, NumEvents, NumModules, NumSlots,
 };
 use move_core_types::gas_algebra::{
     InternalGas, InternalGasPerArg, InternalGasPerByte, InternalGasUnit, NumBytes, ToUnitWithParams,
@@ -172,6 +172,34 @@ crate::gas_schedule::macros::define_gas_parameters!(
             { 11.. => "max_write_ops_per_transaction" },
             8192,
         ],
+        [
+            max_events_per_transaction: NumEvents,
+            { RELEASE_V1_14.. => "max_events_per_transaction" },
+            10_000,
+        ],
+        [
+            max_bytes_per_resource_group: NumBytes,
+            { RELEASE_V1_14.. => "max_bytes_per_resource_group" },
+            1 << 20, // a single resource group is 1MB max
+        ],
+        [
+            max_resource_creations_per_txn: NumSlots,
+            { RELEASE_V1_14.. => "max_resource_creations_per_txn" },
+            4096,
+        ],
+        [
+            max_dynamic_dispatches: NumDispatches,
+            { RELEASE_V1_14.. => "max_dynamic_dispatches" },
+            10_000,
+        ],
+        // Bounds the total size of the arguments of an entry function or script, so that their
+        // deserialization stays cheap. Must allow for the arguments of code publishing through
+        // governance, which can be as large as `max_transaction_size_in_bytes_gov`.
+        [
+            max_txn_args_bytes: NumBytes,
+            { RELEASE_V1_14.. => "max_txn_args_bytes" },
+            1024 * 1024,
+        ],
         [
             legacy_storage_fee_per_state_slot_create: FeePerSlot,
             { 7..=13 => "storage_fee_per_state_slot_create", 14.. => "legacy_storage_fee_per_state_slot_create" },
@@ -258,7 +286,15 @@ crate::gas_schedule::macros::define_gas_parameters!(
             keyless_base_cost: InternalGas,
             { RELEASE_V1_12.. => "keyless.base" },
             414_000_000,
-        ]
+        ],
+        // The most gas the implicit account creation of a sponsored transaction may consume
+        // before the transaction is aborted. Only covers execution and IO gas: the storage fee
+        // for the new account is charged with the rest of the transaction's change set.
+        [
+            max_sponsored_account_creation_gas: Gas,
+            { RELEASE_V1_14.. => "max_sponsored_account_creation_gas" },
+            1_000,
+        ],
     ]
 );
 
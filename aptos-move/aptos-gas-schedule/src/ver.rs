@@ -8,6 +8,13 @@
 ///   - Changing how gas is calculated in any way
 ///
 /// Change log:
+/// - V19
+///   - Limit on the number of events per transaction
+///   - Limit on the size of resource groups
+///   - Limit on the total size of transaction arguments
+///   - Limit on the number of state slots created per transaction
+///   - Limit on the number of dynamic dispatches per transaction
+///   - Limit on the gas used by the implicit account creation of sponsored transactions
 /// - V18
 ///   - Separate limits for governance scripts
 ///   - Function info & dispatchable token gas params
@@ -59,11 +66,12 @@
 ///       global operations.
 /// - V1
 ///   - TBA
-pub const LATEST_GAS_FEATURE_VERSION: u64 = 18;
+pub const LATEST_GAS_FEATURE_VERSION: u64 = 19;
 
 #[allow(dead_code)]
 pub mod gas_feature_versions {
     pub const RELEASE_V1_11: u64 = 16;
     pub const RELEASE_V1_12: u64 = 17;
     pub const RELEASE_V1_13: u64 = 18;
+    pub const RELEASE_V1_14: u64 = 19;
 }
@@ -114,6 +114,8 @@ pub enum FeatureFlag {
     PrimaryAPTFungibleStoreAtUserAddress,
     ObjectNativeDerivedAddress,
     DispatchableFungibleAsset,
+    ScriptEventEmission,
+    MultiSignerWriteSetScript,
 }
 
 fn generate_features_blob(writer: &CodeWriter, data: &[u64]) {
@@ -292,6 +294,10 @@ impl From<FeatureFlag> for AptosFeatureFlag {
                 AptosFeatureFlag::OBJECT_NATIVE_DERIVED_ADDRESS
             },
             FeatureFlag::DispatchableFungibleAsset => AptosFeatureFlag::DISPATCHABLE_FUNGIBLE_ASSET,
+            FeatureFlag::ScriptEventEmission => AptosFeatureFlag::SCRIPT_EVENT_EMISSION,
+            FeatureFlag::MultiSignerWriteSetScript => {
+                AptosFeatureFlag::MULTI_SIGNER_WRITE_SET_SCRIPT
+            },
         }
     }
 }
@@ -399,6 +405,10 @@ impl From<AptosFeatureFlag> for FeatureFlag {
                 FeatureFlag::ObjectNativeDerivedAddress
             },
             AptosFeatureFlag::DISPATCHABLE_FUNGIBLE_ASSET => FeatureFlag::DispatchableFungibleAsset,
+            AptosFeatureFlag::SCRIPT_EVENT_EMISSION => FeatureFlag::ScriptEventEmission,
+            AptosFeatureFlag::MULTI_SIGNER_WRITE_SET_SCRIPT => {
+                FeatureFlag::MultiSignerWriteSetScript
+            },
         }
     }
 }
@@ -19,9 +19,12 @@ use aptos_logger::{prelude::*, Level};
 use aptos_speculative_state_helper::{SpeculativeEvent, SpeculativeEvents};
 use arc_swap::ArcSwapOption;
 use once_cell::sync::Lazy;
-use std::sync::{
-    atomic::{AtomicBool, Ordering},
-    Arc,
+use std::{
+    cell::RefCell,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
 };
 
 struct VMLogEntry {
@@ -58,6 +61,33 @@ impl SpeculativeEvent for VMLogEntry {
     }
 }
 
+/// A speculative log entry captured by `capture_speculative_logs`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CapturedLogEntry {
+    pub level: Level,
+    pub message: String,
+}
+
+thread_local! {
+    static CAPTURED_LOG_ENTRIES: RefCell<Option<Vec<CapturedLogEntry>>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` and returns, together with its result, all speculative log entries emitted by it on
+/// the current thread, regardless of the enabled log levels. The entries are still logged (or
+/// recorded speculatively) as usual. Meant for debugging the execution of a single transaction.
+pub fn capture_speculative_logs<T>(f: impl FnOnce() -> T) -> (T, Vec<CapturedLogEntry>) {
+    let outer = CAPTURED_LOG_ENTRIES.with(|entries| entries.borrow_mut().replace(vec![]));
+    let ret = f();
+    let captured = CAPTURED_LOG_ENTRIES
+        .with(|entries| std::mem::replace(&mut *entries.borrow_mut(), outer).unwrap_or_default());
+    (ret, captured)
+}
+
+/// Returns true if speculative log entries are being captured on the current thread.
+pub fn speculative_log_capture_active() -> bool {
+    CAPTURED_LOG_ENTRIES.with(|entries| entries.borrow().is_some())
+}
+
 static BUFFERED_LOG_EVENTS: Lazy<ArcSwapOption<SpeculativeEvents<VMLogEntry>>> =
     Lazy::new(|| ArcSwapOption::from(None));
 
@@ -92,6 +122,15 @@ pub fn init_speculative_logs(num_txns: usize) {
 pub fn speculative_log(level: Level, context: &AdapterLogSchema, message: String) {
     let txn_idx = context.get_txn_idx();
 
+    CAPTURED_LOG_ENTRIES.with(|entries| {
+        if let Some(entries) = entries.borrow_mut().as_mut() {
+            entries.push(CapturedLogEntry {
+                level,
+                message: message.clone(),
+            });
+        }
+    });
+
     if !context.speculation_supported() || speculation_disabled() {
         // Speculation isn't supported in the current mode, or disabled globally.
         // log the entry directly.
@@ -178,7 +217,7 @@ macro_rules! speculative_alert {
 #[macro_export]
 macro_rules! speculative_error {
     ($($args:tt)+) => {
-        if enabled!(Level::Error) {
+        if enabled!(Level::Error) || $crate::speculative_log_capture_active() {
             speculative_log(Level::Error, $($args)+);
         }
     };
@@ -187,7 +226,7 @@ macro_rules! speculative_error {
 #[macro_export]
 macro_rules! speculative_warn {
     ($($args:tt)+) => {
-        if enabled!(Level::Warn) {
+        if enabled!(Level::Warn) || $crate::speculative_log_capture_active() {
             speculative_log(Level::Warn, $($args)+);
         }
     };
@@ -196,7 +235,7 @@ macro_rules! speculative_warn {
 #[macro_export]
 macro_rules! speculative_info {
     ($($args:tt)+) => {
-        if enabled!(Level::Info) {
+        if enabled!(Level::Info) || $crate::speculative_log_capture_active() {
             speculative_log(Level::Info, $($args)+);
         }
     };
@@ -205,7 +244,7 @@ macro_rules! speculative_info {
 #[macro_export]
 macro_rules! speculative_debug {
     ($($args:tt)+) => {
-        if enabled!(Level::Debug) {
+        if enabled!(Level::Debug) || $crate::speculative_log_capture_active() {
             speculative_log(Level::Debug, $($args)+);
         }
     };
@@ -214,7 +253,7 @@ macro_rules! speculative_debug {
 #[macro_export]
 macro_rules! speculative_trace {
     ($($args:tt)+) => {
-        if enabled!(Level::Trace) {
+        if enabled!(Level::Trace) || $crate::speculative_log_capture_active() {
             speculative_log(Level::Trace, $($args)+);
         }
     };
@@ -0,0 +1,38 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use move_core_types::{identifier::Identifier, language_storage::ModuleId};
+use std::fmt;
+
+/// A call into Move made by the VM while executing a transaction.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TracedCall {
+    /// Execution of the script payload of the transaction.
+    Script,
+    /// Execution of an entry function, respecting its visibility.
+    EntryFunction {
+        module: ModuleId,
+        function: Identifier,
+    },
+    /// Execution of a function bypassing its visibility, e.g., `init_module` or the multisig
+    /// payload and cleanup functions.
+    Function {
+        module: ModuleId,
+        function: Identifier,
+    },
+}
+
+impl fmt::Display for TracedCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TracedCall::Script => write!(f, "script"),
+            TracedCall::EntryFunction { module, function } => {
+                write!(f, "entry {}::{}", module, function)
+            },
+            TracedCall::Function { module, function } => write!(f, "{}::{}", module, function),
+        }
+    }
+}
+
+/// All calls made by the VM while executing a transaction, in order.
+pub type CallTrace = Vec<TracedCall>;
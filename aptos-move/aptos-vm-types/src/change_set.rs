@@ -7,6 +7,7 @@ use crate::{
         ResourceGroupInPlaceDelayedFieldChangeOp, WriteWithDelayedFieldsOp,
     },
     check_change_set::CheckChangeSet,
+    output::VMOutputWrite,
     resolver::ExecutorView,
 };
 use aptos_aggregator::{
@@ -23,7 +24,7 @@ use aptos_types::{
         state_value::StateValueMetadata,
     },
     transaction::ChangeSet as StorageChangeSet,
-    write_set::{TransactionWrite, WriteOp, WriteOpSize, WriteSetMut},
+    write_set::{TransactionWrite, WriteOp, WriteOpSize, WriteSet, WriteSetMut},
 };
 use move_binary_format::errors::{Location, PartialVMError, PartialVMResult, VMResult};
 use move_core_types::{
@@ -32,7 +33,7 @@ use move_core_types::{
     identifier::IdentStr,
     language_storage::{ModuleId, CORE_CODE_ADDRESS},
     value::MoveTypeLayout,
-    vm_status::StatusCode,
+    vm_status::{StatusCode, VMStatus},
 };
 use move_vm_types::delayed_values::delayed_field_id::DelayedFieldID;
 use rand::Rng;
@@ -358,6 +359,32 @@ impl VMChangeSet {
             )
     }
 
+    /// Returns all writes (including aggregator v1 deltas) keyed by the state key they write to.
+    pub(crate) fn normalized_writes(&self) -> BTreeMap<StateKey, VMOutputWrite> {
+        let resources = self
+            .resource_write_set
+            .iter()
+            .map(|(k, v)| (k.clone(), VMOutputWrite::Resource(v.clone())));
+        let modules = self
+            .module_write_set
+            .iter()
+            .map(|(k, v)| (k.clone(), VMOutputWrite::Module(v.clone())));
+        let v1_aggregators = self
+            .aggregator_v1_write_set
+            .iter()
+            .map(|(k, v)| (k.clone(), VMOutputWrite::AggregatorV1(v.clone())));
+        let v1_deltas = self
+            .aggregator_v1_delta_set
+            .iter()
+            .map(|(k, v)| (k.clone(), VMOutputWrite::AggregatorV1Delta(*v)));
+
+        resources
+            .chain(modules)
+            .chain(v1_aggregators)
+            .chain(v1_deltas)
+            .collect()
+    }
+
     pub fn num_write_ops(&self) -> usize {
         self.resource_write_set().len()
             + self.module_write_set().len()
@@ -467,6 +494,10 @@ impl VMChangeSet {
         std::mem::take(&mut self.aggregator_v1_delta_set)
     }
 
+    pub(crate) fn drain_events(&mut self) -> Vec<(ContractEvent, Option<MoveTypeLayout>)> {
+        std::mem::take(&mut self.events)
+    }
+
     pub fn aggregator_v1_write_set(&self) -> &BTreeMap<StateKey, WriteOp> {
         &self.aggregator_v1_write_set
     }
@@ -521,6 +552,27 @@ impl VMChangeSet {
         Ok(())
     }
 
+    /// Materializes aggregator v1 deltas using the given resolver and converts this change set
+    /// into the final storage `WriteSet`, dropping the events. Intended for tools which work
+    /// outside of the block executor (e.g., genesis builders or migration scripts). Delayed field
+    /// changes can only be materialized by the block executor, so they result in an error.
+    pub fn into_write_set(
+        mut self,
+        resolver: &impl AggregatorV1Resolver,
+    ) -> Result<WriteSet, VMStatus> {
+        self.try_materialize_aggregator_v1_delta_set(resolver)?;
+        let (write_set, _events) = self
+            .try_into_storage_change_set()
+            .map_err(|e| {
+                VMStatus::error(
+                    StatusCode::DELAYED_MATERIALIZATION_CODE_INVARIANT_ERROR,
+                    Some(e.to_string()),
+                )
+            })?
+            .into_inner();
+        Ok(write_set)
+    }
+
     fn squash_additional_aggregator_v1_changes(
         aggregator_v1_write_set: &mut BTreeMap<StateKey, WriteOp>,
         aggregator_v1_delta_set: &mut BTreeMap<StateKey, DeltaOp>,
@@ -919,6 +971,14 @@ impl VMChangeSet {
         self.write_set_size_iter()
             .any(|(_key, op_size)| matches!(op_size, WriteOpSize::Creation { .. }))
     }
+
+    /// Returns the number of new state slots created by this change set. A resource group
+    /// counts as a single slot.
+    pub fn num_creations(&self) -> usize {
+        self.write_set_size_iter()
+            .filter(|(_key, op_size)| matches!(op_size, WriteOpSize::Creation { .. }))
+            .count()
+    }
 }
 
 pub struct WriteOpInfo<'a> {
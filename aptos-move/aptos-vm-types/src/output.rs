@@ -1,8 +1,12 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::change_set::VMChangeSet;
-use aptos_aggregator::{resolver::AggregatorV1Resolver, types::code_invariant_error};
+use crate::{
+    abstract_write_op::AbstractResourceWriteOp, call_trace::CallTrace, change_set::VMChangeSet,
+};
+use aptos_aggregator::{
+    delta_change_set::DeltaOp, resolver::AggregatorV1Resolver, types::code_invariant_error,
+};
 use aptos_types::fee_statement::FeeStatement;
 use aptos_types::{
     contract_event::ContractEvent, //contract_event::ContractEvent,
@@ -12,17 +16,31 @@ use aptos_types::{
     write_set::WriteOp,
 };
 use move_core_types::vm_status::{StatusCode, VMStatus};
+use std::collections::{BTreeMap, BTreeSet};
 
 /// Output produced by the VM after executing a transaction.
 ///
 /// **WARNING**: This type should only be used inside the VM. For storage backends,
 /// use `TransactionOutput`.
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq)]
 pub struct VMOutput {
     change_set: VMChangeSet,
     fee_statement: FeeStatement,
     status: TransactionStatus,
     auxiliary_data: TransactionAuxiliaryData,
+    /// Calls made while executing the transaction, only recorded if enabled on the VM.
+    call_trace: Option<CallTrace>,
+}
+
+// The call trace is diagnostic only, so outputs which only differ in whether calls were traced
+// are equal.
+impl PartialEq for VMOutput {
+    fn eq(&self, other: &Self) -> bool {
+        self.change_set == other.change_set
+            && self.fee_statement == other.fee_statement
+            && self.status == other.status
+            && self.auxiliary_data == other.auxiliary_data
+    }
 }
 
 impl VMOutput {
@@ -37,6 +55,7 @@ impl VMOutput {
             fee_statement,
             status,
             auxiliary_data,
+            call_trace: None,
         }
     }
 
@@ -46,9 +65,19 @@ impl VMOutput {
             fee_statement: FeeStatement::zero(),
             status,
             auxiliary_data: TransactionAuxiliaryData::default(),
+            call_trace: None,
         }
     }
 
+    pub fn with_call_trace(mut self, call_trace: Option<CallTrace>) -> Self {
+        self.call_trace = call_trace;
+        self
+    }
+
+    pub fn call_trace(&self) -> Option<&CallTrace> {
+        self.call_trace.as_ref()
+    }
+
     pub fn unpack(
         self,
     ) -> (
@@ -105,6 +134,82 @@ impl VMOutput {
         &self.auxiliary_data
     }
 
+    /// Returns the aggregator v1 deltas produced by the transaction, e.g. for analysing how it
+    /// changed the supply or other counters. The deltas are only available before the output is
+    /// materialized: afterwards they are folded into the aggregator v1 write set.
+    pub fn aggregator_v1_delta_set(&self) -> &BTreeMap<StateKey, DeltaOp> {
+        self.change_set.aggregator_v1_delta_set()
+    }
+
+    /// Returns the keys of the resource groups modified by the transaction, including groups where
+    /// only delayed fields changed, e.g. for analysing conflicts on shared groups. Individual
+    /// resources written outside of groups are not included.
+    pub fn modified_resource_groups(&self) -> BTreeSet<StateKey> {
+        self.change_set
+            .resource_write_set()
+            .iter()
+            .filter_map(|(key, write)| match write {
+                AbstractResourceWriteOp::WriteResourceGroup(_)
+                | AbstractResourceWriteOp::ResourceGroupInPlaceDelayedFieldChange(_) => {
+                    Some(key.clone())
+                },
+                AbstractResourceWriteOp::Write(_)
+                | AbstractResourceWriteOp::WriteWithDelayedFields(_)
+                | AbstractResourceWriteOp::InPlaceDelayedFieldChange(_) => None,
+            })
+            .collect()
+    }
+
+    /// Compares this output with `other`, e.g. the output of the same transaction produced by a
+    /// different VM version. Write sets are compared as maps from state keys to writes, and events
+    /// are compared regardless of the order they were emitted in. All reported differences are
+    /// from the point of view of `self`, i.e. "added" means only present in `other`.
+    pub fn diff(&self, other: &VMOutput) -> VMOutputDiff {
+        let self_writes = self.change_set.normalized_writes();
+        let mut other_writes = other.change_set.normalized_writes();
+
+        let mut diff = VMOutputDiff::default();
+        for (key, write) in self_writes {
+            match other_writes.remove(&key) {
+                None => {
+                    diff.removed_writes.insert(key, write);
+                },
+                Some(other_write) if other_write != write => {
+                    diff.changed_writes.insert(key, (write, other_write));
+                },
+                Some(_) => (),
+            }
+        }
+        diff.added_writes = other_writes;
+
+        let mut other_events = other
+            .change_set
+            .events()
+            .iter()
+            .map(|(event, _)| event)
+            .collect::<Vec<_>>();
+        for (event, _) in self.change_set.events() {
+            match other_events.iter().position(|e| *e == event) {
+                Some(idx) => {
+                    other_events.swap_remove(idx);
+                },
+                None => diff.removed_events.push(event.clone()),
+            }
+        }
+        diff.added_events = other_events.into_iter().cloned().collect();
+
+        if self.fee_statement != other.fee_statement {
+            diff.fee_statement_delta = Some(FeeStatementDelta::new(
+                &self.fee_statement,
+                &other.fee_statement,
+            ));
+        }
+        if self.status != other.status {
+            diff.status = Some((self.status.clone(), other.status.clone()));
+        }
+        diff
+    }
+
     /// Materializes delta sets.
     /// Guarantees that if deltas are materialized successfully, the output
     /// has an empty delta set.
@@ -134,16 +239,23 @@ impl VMOutput {
 
     /// Same as `try_materialize` but also constructs `TransactionOutput`.
     pub fn try_materialize_into_transaction_output(
-        mut self,
+        self,
         resolver: &impl AggregatorV1Resolver,
     ) -> anyhow::Result<TransactionOutput, VMStatus> {
-        self.try_materialize(resolver)?;
-        Self::convert_to_transaction_output(self).map_err(|e| {
-            VMStatus::error(
-                StatusCode::DELAYED_MATERIALIZATION_CODE_INVARIANT_ERROR,
-                Some(e.to_string()),
-            )
-        })
+        let (mut change_set, gas_used, status, auxiliary_data) = self.unpack();
+        let events = change_set
+            .drain_events()
+            .into_iter()
+            .map(|(event, _)| event)
+            .collect();
+        let write_set = change_set.into_write_set(resolver)?;
+        Ok(TransactionOutput::new(
+            write_set,
+            events,
+            gas_used,
+            status,
+            auxiliary_data,
+        ))
     }
 
     /// Constructs `TransactionOutput`, without doing `try_materialize`
@@ -217,3 +329,60 @@ impl VMOutput {
         Self::convert_to_transaction_output(self)
     }
 }
+
+/// A single write to a state key in a `VMOutput`, regardless of which write set it belongs to.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum VMOutputWrite {
+    Resource(AbstractResourceWriteOp),
+    Module(WriteOp),
+    AggregatorV1(WriteOp),
+    AggregatorV1Delta(DeltaOp),
+}
+
+/// Difference in every fee component between two fee statements, computed as `other - self`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct FeeStatementDelta {
+    pub total_charge_gas_units: i128,
+    pub execution_gas_units: i128,
+    pub io_gas_units: i128,
+    pub storage_fee_octas: i128,
+    pub storage_fee_refund_octas: i128,
+}
+
+impl FeeStatementDelta {
+    fn new(this: &FeeStatement, other: &FeeStatement) -> Self {
+        let delta = |a: u64, b: u64| b as i128 - a as i128;
+        Self {
+            total_charge_gas_units: delta(this.gas_used(), other.gas_used()),
+            execution_gas_units: delta(this.execution_gas_used(), other.execution_gas_used()),
+            io_gas_units: delta(this.io_gas_used(), other.io_gas_used()),
+            storage_fee_octas: delta(this.storage_fee_used(), other.storage_fee_used()),
+            storage_fee_refund_octas: delta(this.storage_fee_refund(), other.storage_fee_refund()),
+        }
+    }
+}
+
+/// Semantic difference between two `VMOutput`s, see `VMOutput::diff`.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct VMOutputDiff {
+    /// Writes to keys that only the other output writes to.
+    pub added_writes: BTreeMap<StateKey, VMOutputWrite>,
+    /// Writes to keys that only this output writes to.
+    pub removed_writes: BTreeMap<StateKey, VMOutputWrite>,
+    /// Keys written by both outputs, but differently (this output's write first).
+    pub changed_writes: BTreeMap<StateKey, (VMOutputWrite, VMOutputWrite)>,
+    /// Events only emitted by the other output.
+    pub added_events: Vec<ContractEvent>,
+    /// Events only emitted by this output.
+    pub removed_events: Vec<ContractEvent>,
+    /// Set if the fee statements differ.
+    pub fee_statement_delta: Option<FeeStatementDelta>,
+    /// Set if the statuses differ (this output's status first).
+    pub status: Option<(TransactionStatus, TransactionStatus)>,
+}
+
+impl VMOutputDiff {
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
@@ -138,6 +138,14 @@ pub trait TResourceGroupView {
     fn release_group_cache(
         &self,
     ) -> Option<HashMap<Self::GroupKey, BTreeMap<Self::ResourceTag, Bytes>>>;
+
+    /// Same as `release_group_cache`, but only releases the cached entry of a single group,
+    /// keeping other groups cached. Returns the released contents if `release_group_cache`
+    /// would have returned them, and the group was cached.
+    fn release_group_cache_for(
+        &self,
+        group_key: &Self::GroupKey,
+    ) -> Option<BTreeMap<Self::ResourceTag, Bytes>>;
 }
 
 /// Allows to query modules from the state.
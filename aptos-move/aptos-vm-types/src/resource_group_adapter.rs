@@ -271,6 +271,18 @@ impl TResourceGroupView for ResourceGroupAdapter<'_> {
             )
         }
     }
+
+    fn release_group_cache_for(
+        &self,
+        group_key: &Self::GroupKey,
+    ) -> Option<BTreeMap<Self::ResourceTag, Bytes>> {
+        let released = self.group_cache.borrow_mut().remove(group_key);
+        if self.group_size_kind == GroupSizeKind::AsSum {
+            None
+        } else {
+            released.map(|v| v.0)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -281,7 +293,7 @@ mod tests {
         errors::StateviewError, state_storage_usage::StateStorageUsage, state_value::StateValue,
         TStateView,
     };
-    use claims::{assert_gt, assert_none, assert_ok_eq, assert_some, assert_some_eq};
+    use claims::{assert_gt, assert_none, assert_ok, assert_ok_eq, assert_some, assert_some_eq};
     use std::cmp::max;
     use test_case::test_case;
 
@@ -409,6 +421,13 @@ mod tests {
         ) -> Option<HashMap<Self::GroupKey, BTreeMap<Self::ResourceTag, Bytes>>> {
             unimplemented!("Currently resolved by ResourceGroupAdapter");
         }
+
+        fn release_group_cache_for(
+            &self,
+            _group_key: &Self::GroupKey,
+        ) -> Option<BTreeMap<Self::ResourceTag, Bytes>> {
+            unimplemented!("Currently resolved by ResourceGroupAdapter");
+        }
     }
 
     #[test]
@@ -552,6 +571,37 @@ mod tests {
         assert_some!(cache.get(&key_2));
     }
 
+    #[test]
+    fn release_single_group_cache() {
+        let state_view = MockStateView::new();
+        let adapter = ResourceGroupAdapter::new(None, &state_view, 12, false);
+        assert_eq!(adapter.group_size_kind, GroupSizeKind::AsBlob);
+
+        let key_0 = StateKey::raw(&[0]);
+        let key_1 = StateKey::raw(&[1]);
+        let key_2 = StateKey::raw(&[2]);
+
+        assert_ok!(adapter.resource_group_size(&key_0));
+        assert_ok!(adapter.resource_group_size(&key_1));
+
+        let key_1_blob = &state_view.group.get(&key_1).unwrap().blob;
+        let key_1_contents = adapter.release_group_cache_for(&key_1).unwrap();
+        assert_eq!(bcs::to_bytes(&key_1_contents).unwrap(), *key_1_blob);
+        assert_none!(adapter.release_group_cache_for(&key_1));
+        assert_none!(adapter.release_group_cache_for(&key_2));
+
+        // Other groups stay cached.
+        let cache = adapter.release_group_cache().unwrap();
+        assert_eq!(cache.len(), 1);
+        assert_some!(cache.get(&key_0));
+
+        // With sizes computed as sums, the released contents are never returned.
+        let adapter = ResourceGroupAdapter::new(Some(&state_view), &state_view, 12, true);
+        assert_eq!(adapter.group_size_kind, GroupSizeKind::AsSum);
+        assert_ok!(adapter.resource_group_size(&key_1));
+        assert_none!(adapter.release_group_cache_for(&key_1));
+    }
+
     #[test]
     fn set_group_view_forwarding() {
         let state_view = MockStateView::new();
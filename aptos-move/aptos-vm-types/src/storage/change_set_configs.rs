@@ -1,7 +1,10 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{change_set::VMChangeSet, check_change_set::CheckChangeSet};
+use crate::{
+    abstract_write_op::AbstractResourceWriteOp, change_set::VMChangeSet,
+    check_change_set::CheckChangeSet,
+};
 use aptos_gas_schedule::AptosGasParameters;
 use move_binary_format::errors::{PartialVMError, PartialVMResult};
 use move_core_types::vm_status::StatusCode;
@@ -14,6 +17,9 @@ pub struct ChangeSetConfigs {
     max_bytes_per_event: u64,
     max_bytes_all_events_per_transaction: u64,
     max_write_ops_per_transaction: u64,
+    max_events_per_transaction: u64,
+    max_bytes_per_resource_group: u64,
+    max_resource_creations_per_txn: u64,
 }
 
 impl ChangeSetConfigs {
@@ -25,6 +31,9 @@ impl ChangeSetConfigs {
             u64::MAX,
             u64::MAX,
             u64::MAX,
+            u64::MAX,
+            u64::MAX,
+            u64::MAX,
         )
     }
 
@@ -45,6 +54,9 @@ impl ChangeSetConfigs {
         max_bytes_per_event: u64,
         max_bytes_all_events_per_transaction: u64,
         max_write_ops_per_transaction: u64,
+        max_events_per_transaction: u64,
+        max_bytes_per_resource_group: u64,
+        max_resource_creations_per_txn: u64,
     ) -> Self {
         Self {
             gas_feature_version,
@@ -53,6 +65,9 @@ impl ChangeSetConfigs {
             max_bytes_per_event,
             max_bytes_all_events_per_transaction,
             max_write_ops_per_transaction,
+            max_events_per_transaction,
+            max_bytes_per_resource_group,
+            max_resource_creations_per_txn,
         }
     }
 
@@ -68,7 +83,17 @@ impl ChangeSetConfigs {
     fn for_feature_version_3() -> Self {
         const MB: u64 = 1 << 20;
 
-        Self::new_impl(3, MB, u64::MAX, MB, 10 * MB, u64::MAX)
+        Self::new_impl(
+            3,
+            MB,
+            u64::MAX,
+            MB,
+            10 * MB,
+            u64::MAX,
+            u64::MAX,
+            u64::MAX,
+            u64::MAX,
+        )
     }
 
     fn from_gas_params(gas_feature_version: u64, gas_params: &AptosGasParameters) -> Self {
@@ -80,6 +105,9 @@ impl ChangeSetConfigs {
             params.max_bytes_per_event.into(),
             params.max_bytes_all_events_per_transaction.into(),
             params.max_write_ops_per_transaction.into(),
+            params.max_events_per_transaction.into(),
+            params.max_bytes_per_resource_group.into(),
+            params.max_resource_creations_per_txn.into(),
         )
     }
 }
@@ -93,6 +121,36 @@ impl CheckChangeSet for ChangeSetConfigs {
                 .with_message("Too many write ops.".to_string()));
         }
 
+        if self.max_resource_creations_per_txn != 0
+            && change_set.num_creations() as u64 > self.max_resource_creations_per_txn
+        {
+            return Err(
+                PartialVMError::new(StatusCode::RESOURCE_CREATION_LIMIT_REACHED)
+                    .with_message("Too many new state slots.".to_string()),
+            );
+        }
+
+        // Checked before the write op sizes below, which also cover group writes, so that an
+        // oversized group is reported as such. Only growing a group past the limit is rejected, so
+        // that oversized groups can shrink.
+        if self.max_bytes_per_resource_group != 0 {
+            for write in change_set.resource_write_set().values() {
+                if let AbstractResourceWriteOp::WriteResourceGroup(group_write) = write {
+                    let group_size = group_write
+                        .maybe_group_op_size()
+                        .map_or(0, |size| size.get());
+                    if group_size > self.max_bytes_per_resource_group
+                        && group_size > group_write.prev_group_size()
+                    {
+                        return Err(PartialVMError::new(
+                            StatusCode::RESOURCE_GROUP_SIZE_LIMIT_REACHED,
+                        )
+                        .with_message("Resource group is too large.".to_string()));
+                    }
+                }
+            }
+        }
+
         let mut write_set_size = 0;
         for (key, op_size) in change_set.write_set_size_iter() {
             if let Some(len) = op_size.write_len() {
@@ -107,6 +165,13 @@ impl CheckChangeSet for ChangeSetConfigs {
             }
         }
 
+        if self.max_events_per_transaction != 0
+            && change_set.events().len() as u64 > self.max_events_per_transaction
+        {
+            return Err(PartialVMError::new(StatusCode::TOO_MANY_EVENTS)
+                .with_message("Too many events.".to_string()));
+        }
+
         let mut total_event_size = 0;
         for (event, _) in change_set.events() {
             let size = event.event_data().len() as u64;
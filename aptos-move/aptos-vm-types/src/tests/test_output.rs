@@ -2,17 +2,25 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    output::VMOutput,
-    tests::utils::{as_state_key, build_vm_output, mock_add, mock_create_with_layout, mock_modify},
+    abstract_write_op::{AbstractResourceWriteOp, GroupWrite},
+    call_trace::TracedCall,
+    output::{FeeStatementDelta, VMOutput},
+    resolver::ResourceGroupSize,
+    tests::utils::{
+        as_bytes, as_state_key, build_vm_output, mock_add, mock_create_with_layout, mock_modify,
+        mock_tag_0,
+    },
 };
 use aptos_aggregator::delta_change_set::serialize;
 use aptos_language_e2e_tests::data_store::FakeDataStore;
 use aptos_types::{
-    state_store::state_key::StateKey, transaction::TransactionOutput, write_set::WriteOp,
+    fee_statement::FeeStatement, state_store::state_key::StateKey, transaction::TransactionOutput,
+    write_set::WriteOp,
 };
-use claims::{assert_err, assert_matches, assert_ok};
+use bytes::Bytes;
+use claims::{assert_err, assert_matches, assert_none, assert_ok, assert_some_eq};
 use move_core_types::vm_status::{AbortLocation, VMStatus};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 fn assert_eq_outputs(vm_output: &VMOutput, txn_output: TransactionOutput) {
     let vm_output_writes = &vm_output
@@ -80,9 +88,14 @@ fn test_ok_output_equality_with_deltas() {
         vec![mock_modify("2", 2)],
         vec![mock_add(delta_key, 300)],
     );
+    assert_eq!(
+        vm_output.aggregator_v1_delta_set(),
+        &BTreeMap::from([mock_add(delta_key, 300)])
+    );
 
     let mut materialized_vm_output = vm_output.clone();
     assert_ok!(materialized_vm_output.try_materialize(&state_view));
+    assert!(materialized_vm_output.aggregator_v1_delta_set().is_empty());
     let txn_output_1 = assert_ok!(vm_output
         .clone()
         .try_materialize_into_transaction_output(&state_view));
@@ -93,6 +106,8 @@ fn test_ok_output_equality_with_deltas() {
             vec![],
             vec![],
         );
+    let write_set = assert_ok!(vm_output.change_set().clone().into_write_set(&state_view));
+    assert_eq!(&write_set, txn_output_1.write_set());
 
     let expected_aggregator_write_set =
         BTreeMap::from([mock_modify("2", 2), mock_modify("3", 400)]);
@@ -134,10 +149,14 @@ fn test_err_output_equality_with_deltas() {
     )]);
 
     let vm_status_1 = assert_err!(vm_output.clone().try_materialize(&state_view));
-    let vm_status_2 = assert_err!(vm_output.try_materialize_into_transaction_output(&state_view));
+    let vm_status_2 = assert_err!(vm_output
+        .clone()
+        .try_materialize_into_transaction_output(&state_view));
+    let vm_status_3 = assert_err!(vm_output.change_set().clone().into_write_set(&state_view));
 
     // Error should be consistent.
     assert_eq!(vm_status_1, vm_status_2);
+    assert_eq!(vm_status_1, vm_status_3);
 
     // Aggregator errors lead to aborts. Because an overflow happens,
     // the code must be 131073.
@@ -146,3 +165,101 @@ fn test_err_output_equality_with_deltas() {
         VMStatus::MoveAbort(AbortLocation::Module(_), 131073)
     );
 }
+
+#[test]
+fn test_output_diff() {
+    let vm_output = build_vm_output(
+        vec![mock_create_with_layout("0", 0, None)],
+        vec![mock_modify("1", 1)],
+        vec![],
+        vec![mock_modify("2", 2)],
+        vec![mock_add("3", 3)],
+    );
+    assert!(vm_output.diff(&vm_output.clone()).is_empty());
+
+    let other_vm_output = build_vm_output(
+        vec![mock_create_with_layout("0", 10, None)],
+        vec![],
+        vec![],
+        vec![mock_modify("2", 2)],
+        vec![mock_add("3", 3), mock_add("4", 4)],
+    );
+    let diff = vm_output.diff(&other_vm_output);
+    assert_eq!(diff.added_writes.keys().collect::<Vec<_>>(), vec![
+        &as_state_key!("4")
+    ]);
+    assert_eq!(diff.removed_writes.keys().collect::<Vec<_>>(), vec![
+        &as_state_key!("1")
+    ]);
+    assert_eq!(diff.changed_writes.keys().collect::<Vec<_>>(), vec![
+        &as_state_key!("0")
+    ]);
+    assert!(diff.added_events.is_empty());
+    assert!(diff.removed_events.is_empty());
+    assert_none!(diff.fee_statement_delta);
+    assert_none!(diff.status);
+
+    let (change_set, _, status, auxiliary_data) = vm_output.clone().unpack_with_fee_statement();
+    let more_expensive_vm_output = VMOutput::new(
+        change_set,
+        FeeStatement::new(105, 100, 5, 0, 0),
+        status,
+        auxiliary_data,
+    );
+    let diff = vm_output.diff(&more_expensive_vm_output);
+    assert_some_eq!(diff.fee_statement_delta, FeeStatementDelta {
+        total_charge_gas_units: 5,
+        execution_gas_units: 0,
+        io_gas_units: 5,
+        storage_fee_octas: 0,
+        storage_fee_refund_octas: 0,
+    });
+    assert!(diff.added_writes.is_empty() && diff.changed_writes.is_empty());
+}
+
+#[test]
+fn test_output_equality_ignores_call_trace() {
+    let vm_output = build_vm_output(vec![], vec![mock_modify("0", 0)], vec![], vec![], vec![]);
+    let traced_vm_output = vm_output
+        .clone()
+        .with_call_trace(Some(vec![TracedCall::Script]));
+    assert_eq!(vm_output, traced_vm_output);
+
+    let other_vm_output =
+        build_vm_output(vec![], vec![mock_modify("0", 1)], vec![], vec![], vec![])
+            .with_call_trace(Some(vec![TracedCall::Script]));
+    assert_ne!(traced_vm_output, other_vm_output);
+}
+
+#[test]
+fn test_modified_resource_groups() {
+    let group_write = GroupWrite::new(
+        WriteOp::legacy_modification(Bytes::new()),
+        BTreeMap::from([(
+            mock_tag_0(),
+            (WriteOp::legacy_modification(as_bytes!(5).into()), None),
+        )]),
+        ResourceGroupSize::Combined {
+            num_tagged_resources: 1,
+            all_tagged_resources_size: 100,
+        },
+        100,
+    );
+    let vm_output = build_vm_output(
+        vec![
+            mock_create_with_layout("0", 0, None),
+            (
+                as_state_key!("1"),
+                AbstractResourceWriteOp::WriteResourceGroup(group_write),
+            ),
+        ],
+        vec![mock_modify("2", 2)],
+        vec![],
+        vec![],
+        vec![],
+    );
+    assert_eq!(
+        vm_output.modified_resource_groups(),
+        BTreeSet::from([as_state_key!("1")])
+    );
+}
@@ -3,17 +3,23 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    block_executor::{AptosTransactionOutput, BlockAptosVM},
+    block_executor::{estimate_parallel_speedup, AptosTransactionOutput, BlockAptosVM},
+    call_trace,
     counters::*,
     data_cache::{AsMoveResolver, StorageAdapter},
     errors::{discarded_output, expect_only_successful_execution},
-    gas::{check_gas, get_gas_parameters, make_prod_gas_meter, ProdGasMeter},
+    gas::{
+        check_gas, estimate_gas_split, gas_used, get_gas_parameters, make_prod_gas_meter,
+        ProdGasMeter,
+    },
     keyless_validation,
     move_vm_ext::{
-        get_max_binary_format_version, get_max_identifier_size,
-        session::user_transaction_sessions::{
-            abort_hook::AbortHookSession, epilogue::EpilogueSession, prologue::PrologueSession,
-            user::UserSession,
+        session::{
+            user_transaction_sessions::{
+                abort_hook::AbortHookSession, epilogue::EpilogueSession, prologue::PrologueSession,
+                user::UserSession,
+            },
+            view_with_change_set::ExecutorViewWithChangeSet,
         },
         AptosMoveResolver, MoveVmExt, SessionExt, SessionId, UserTransactionContext,
     },
@@ -29,12 +35,15 @@ use anyhow::anyhow;
 use aptos_block_executor::txn_commit_hook::NoOpTransactionCommitHook;
 use aptos_crypto::HashValue;
 use aptos_framework::{
-    natives::{code::PublishRequest, randomness::RandomnessContext},
+    natives::{code::PublishRequest, cryptography::algebra, randomness::RandomnessContext},
     RuntimeModuleMetadataV1,
 };
 use aptos_gas_algebra::{Gas, GasQuantity, NumBytes, Octa};
 use aptos_gas_meter::{AptosGasMeter, GasAlgebra};
-use aptos_gas_schedule::{AptosGasParameters, TransactionGasParameters, VMGasParameters};
+use aptos_gas_schedule::{
+    gas_feature_versions::RELEASE_V1_14, AptosGasParameters, TransactionGasParameters,
+    VMGasParameters,
+};
 use aptos_logger::{enabled, prelude::*, Level};
 use aptos_metrics_core::TimerHelper;
 #[cfg(any(test, feature = "testing"))]
@@ -57,18 +66,26 @@ use aptos_types::{
         TimedFeaturesBuilder,
     },
     randomness::Randomness,
-    state_store::{StateView, TStateView},
+    state_store::{
+        read_recording_state_view::ReadRecordingStateView, state_key::StateKey, StateView,
+        TStateView,
+    },
     transaction::{
         authenticator::AnySignature, signature_verified_transaction::SignatureVerifiedTransaction,
-        BlockOutput, EntryFunction, ExecutionError, ExecutionStatus, ModuleBundle, Multisig,
-        MultisigTransactionPayload, Script, SignedTransaction, Transaction,
+        AbortInfo, BlockOutput, EntryFunction, ExecutionError, ExecutionStatus, ModuleBundle,
+        Multisig, MultisigTransactionPayload, Script, SignedTransaction, Transaction,
         TransactionAuxiliaryData, TransactionOutput, TransactionPayload, TransactionStatus,
         VMValidatorResult, ViewFunctionOutput, WriteSetPayload,
     },
+    validator_set::ValidatorSet,
     vm_status::{AbortLocation, StatusCode, VMStatus},
+    write_set::WriteOp,
 };
 use aptos_utils::{aptos_try, return_on_failure};
-use aptos_vm_logging::{log_schema::AdapterLogSchema, speculative_error, speculative_log};
+use aptos_vm_logging::{
+    capture_speculative_logs, log_schema::AdapterLogSchema, speculative_error, speculative_log,
+    speculative_warn, CapturedLogEntry,
+};
 use aptos_vm_types::{
     abstract_write_op::AbstractResourceWriteOp,
     change_set::VMChangeSet,
@@ -81,11 +98,11 @@ use ark_groth16::PreparedVerifyingKey;
 use claims::assert_err;
 use fail::fail_point;
 use move_binary_format::{
-    access::ModuleAccess,
-    compatibility::Compatibility,
+    access::{ModuleAccess, ScriptAccess},
+    compatibility::{Compatibility, Incompatibility},
     deserializer::DeserializerConfig,
     errors::{Location, PartialVMError, PartialVMResult, VMError, VMResult},
-    CompiledModule,
+    normalized, CompiledModule, CompiledScript,
 };
 use move_core_types::{
     account_address::AccountAddress,
@@ -93,6 +110,7 @@ use move_core_types::{
     identifier::Identifier,
     language_storage::{ModuleId, TypeTag},
     move_resource::MoveStructType,
+    resolver::ModuleResolver,
     transaction_argument::convert_txn_args,
     value::{serialize_values, MoveValue},
     vm_status::StatusType,
@@ -100,15 +118,19 @@ use move_core_types::{
 use move_vm_runtime::{
     logging::expect_no_verification_errors,
     module_traversal::{TraversalContext, TraversalStorage},
+    CALL_STACK_SIZE_LIMIT, OPERAND_STACK_SIZE_LIMIT,
 };
 use move_vm_types::gas::{GasMeter, UnmeteredGasMeter};
 use num_cpus;
 use once_cell::sync::{Lazy, OnceCell};
+use serde::{de::DeserializeOwned, Serialize};
 use std::{
     cmp::{max, min},
     collections::{BTreeMap, BTreeSet},
     marker::Sync,
-    sync::Arc,
+    str::FromStr,
+    sync::{atomic::AtomicBool, Arc},
+    time::Instant,
 };
 
 static EXECUTION_CONCURRENCY_LEVEL: OnceCell<usize> = OnceCell::new();
@@ -116,6 +138,7 @@ static NUM_EXECUTION_SHARD: OnceCell<usize> = OnceCell::new();
 static NUM_PROOF_READING_THREADS: OnceCell<usize> = OnceCell::new();
 static PARANOID_TYPE_CHECKS: OnceCell<bool> = OnceCell::new();
 static DISCARD_FAILED_BLOCKS: OnceCell<bool> = OnceCell::new();
+static ABORT_RATE_ALERT_THRESHOLD_PCT: OnceCell<u64> = OnceCell::new();
 static PROCESSED_TRANSACTIONS_DETAILED_COUNTERS: OnceCell<bool> = OnceCell::new();
 static TIMED_FEATURE_OVERRIDE: OnceCell<TimedFeatureOverride> = OnceCell::new();
 
@@ -217,6 +240,32 @@ fn is_approved_gov_script(
     }
 }
 
+/// Returns the feature flags which are enabled in exactly one of `snapshot` and `stored`, each
+/// described by its name (or index, if unknown to this binary) and its state in the snapshot.
+fn mismatched_feature_flags(snapshot: &Features, stored: &Features) -> Vec<String> {
+    let num_flags = 8 * snapshot.features.len().max(stored.features.len());
+    (0..num_flags)
+        .filter_map(|idx| {
+            let enabled_in = |features: &Features| {
+                features
+                    .features
+                    .get(idx / 8)
+                    .map_or(false, |byte| byte & (1 << (idx % 8)) != 0)
+            };
+            let enabled = enabled_in(snapshot);
+            (enabled != enabled_in(stored)).then(|| {
+                let name = FeatureFlag::from_repr(idx)
+                    .map_or_else(|| format!("#{}", idx), |flag| format!("{:?}", flag));
+                format!(
+                    "{} ({})",
+                    name,
+                    if enabled { "enabled" } else { "disabled" }
+                )
+            })
+        })
+        .collect()
+}
+
 pub struct AptosVM {
     is_simulation: bool,
     move_vm: MoveVmExt,
@@ -227,6 +276,12 @@ pub struct AptosVM {
     /// For a new chain, or even mainnet, the VK might not necessarily be set.
     pvk: Option<PreparedVerifyingKey<Bn254>>,
     randomness_config: AptosVMRandomnessConfig,
+    /// Whether the calls made while executing user transactions are recorded into a trace
+    /// attached to their outputs.
+    record_call_trace: bool,
+    /// Whether user transactions continue in the prologue session instead of flushing the data
+    /// cache into a new session after the prologue.
+    skip_prologue_resession: bool,
 }
 
 impl AptosVM {
@@ -236,6 +291,36 @@ impl AptosVM {
     ) -> Self {
         let _timer = TIMER.timer_with(&["AptosVM::new"]);
         let features = Features::fetch_config(resolver).unwrap_or_default();
+        Self::new_with_features(
+            resolver,
+            features,
+            override_is_delayed_field_optimization_capable,
+        )
+    }
+
+    /// Creates a VM which uses the given snapshot of feature flags instead of the ones stored in
+    /// the resolver, e.g., the flags recorded at the version of the transactions being replayed.
+    /// If the snapshot differs from the flags the resolver reports, a warning listing the
+    /// mismatched flags is logged, as this usually means the replay environment is set up
+    /// against the wrong version and execution may silently diverge.
+    pub fn with_feature_snapshot(resolver: &impl AptosMoveResolver, features: Features) -> Self {
+        let _timer = TIMER.timer_with(&["AptosVM::with_feature_snapshot"]);
+        let stored_features = Features::fetch_config(resolver).unwrap_or_default();
+        let mismatched_flags = mismatched_feature_flags(&features, &stored_features);
+        if !mismatched_flags.is_empty() {
+            warn!(
+                "[VM] Feature flag snapshot differs from the features in storage: {}",
+                mismatched_flags.join(", ")
+            );
+        }
+        Self::new_with_features(resolver, features, None)
+    }
+
+    fn new_with_features(
+        resolver: &impl AptosMoveResolver,
+        features: Features,
+        override_is_delayed_field_optimization_capable: Option<bool>,
+    ) -> Self {
         let randomness_config = AptosVMRandomnessConfig::fetch(resolver);
         let (
             gas_params,
@@ -266,6 +351,13 @@ impl AptosVM {
         let aggregator_v2_type_tagging = override_is_delayed_field_optimization_capable
             && features.is_aggregator_v2_delayed_fields_enabled();
 
+        // Bounds the number of dynamic dispatches a single payload can perform.
+        let max_dynamic_dispatches = gas_params
+            .as_ref()
+            .ok()
+            .filter(|_| gas_feature_version >= RELEASE_V1_14)
+            .map(|gas_params| gas_params.vm.txn.max_dynamic_dispatches.into());
+
         let move_vm = MoveVmExt::new(
             native_gas_params,
             misc_gas_params,
@@ -275,6 +367,7 @@ impl AptosVM {
             timed_features.clone(),
             resolver,
             aggregator_v2_type_tagging,
+            max_dynamic_dispatches,
         )
         .expect("should be able to create Move VM; check if there are duplicated natives");
 
@@ -296,9 +389,26 @@ impl AptosVM {
             timed_features,
             pvk,
             randomness_config,
+            record_call_trace: false,
+            skip_prologue_resession: false,
         }
     }
 
+    /// Sets whether the calls made while executing user transactions are recorded into a trace
+    /// attached to their outputs, see `VMOutput::call_trace`. Meant for debugging tools.
+    pub fn set_record_call_trace(&mut self, enable: bool) {
+        self.record_call_trace = enable;
+    }
+
+    /// Sets whether user transactions continue in the prologue session instead of flushing the
+    /// data cache into a new session after the prologue. Only meant for trusted re-execution that
+    /// does not need to reproduce the gas charged on chain: without the flush, loading resources
+    /// cached by the prologue (including resource groups) is not charged again, so gas usage and
+    /// fees may differ from normal execution. The write set is otherwise the same.
+    pub fn set_skip_prologue_resession(&mut self, enable: bool) {
+        self.skip_prologue_resession = enable;
+    }
+
     pub fn new_session<'r, S: AptosMoveResolver>(
         &self,
         resolver: &'r S,
@@ -314,6 +424,31 @@ impl AptosVM {
         self.move_vm.features()
     }
 
+    /// Returns the gas feature version of the gas schedule this VM was created with.
+    pub fn gas_feature_version(&self) -> u64 {
+        self.gas_feature_version
+    }
+
+    /// Checks that the gas schedule this VM resolved is not newer than the one expected by the
+    /// transactions being replayed, i.e., that its gas feature version is at most
+    /// `max_gas_feature_version`. Replaying with a newer gas schedule would charge gas differently
+    /// from the original execution without any other visible sign.
+    pub fn check_gas_feature_version_for_replay(
+        &self,
+        max_gas_feature_version: u64,
+    ) -> Result<(), VMStatus> {
+        if self.gas_feature_version > max_gas_feature_version {
+            return Err(VMStatus::error(
+                StatusCode::VM_STARTUP_FAILURE,
+                Some(format!(
+                    "Gas feature version {} resolved from storage is newer than version {} expected by the replayed transactions",
+                    self.gas_feature_version, max_gas_feature_version
+                )),
+            ));
+        }
+        Ok(())
+    }
+
     /// Sets execution concurrency level when invoked the first time.
     pub fn set_concurrency_level_once(mut concurrency_level: usize) {
         concurrency_level = min(concurrency_level, num_cpus::get());
@@ -373,6 +508,18 @@ impl AptosVM {
         }
     }
 
+    /// Sets the percentage of speculative aborts per committed transaction above which parallel
+    /// execution reports a block as contended, when invoked the first time.
+    pub fn set_abort_rate_alert_threshold_pct(threshold_pct: u64) {
+        // Only the first call succeeds, due to OnceCell semantics.
+        ABORT_RATE_ALERT_THRESHOLD_PCT.set(threshold_pct).ok();
+    }
+
+    /// Get the abort rate alert threshold if already set, otherwise return default (None).
+    pub fn get_abort_rate_alert_threshold_pct() -> Option<u64> {
+        ABORT_RATE_ALERT_THRESHOLD_PCT.get().copied()
+    }
+
     // Set the override profile for timed features.
     pub fn set_timed_feature_override(profile: TimedFeatureOverride) {
         TIMED_FEATURE_OVERRIDE.set(profile).ok();
@@ -399,6 +546,12 @@ impl AptosVM {
         }
     }
 
+    /// Sets the # of threads for large multi-scalar multiplications and multi-pairings in the
+    /// algebra natives, when invoked the first time.
+    pub fn set_num_algebra_native_threads_once(num_threads: usize) {
+        algebra::set_num_threads_once(min(num_threads, num_cpus::get()));
+    }
+
     /// Sets additional details in counters when invoked the first time.
     pub fn set_processed_transactions_detailed_counters() {
         // Only the first call succeeds, due to OnceCell semantics.
@@ -413,6 +566,18 @@ impl AptosVM {
         }
     }
 
+    /// Returns the maximum depth of the Move call stack. Transactions recursing deeper fail with
+    /// `CALL_STACK_OVERFLOW`.
+    pub fn get_max_call_stack_depth() -> usize {
+        CALL_STACK_SIZE_LIMIT
+    }
+
+    /// Returns the maximum number of values on the Move operand stack. Transactions exceeding it
+    /// fail with `EXECUTION_STACK_OVERFLOW`.
+    pub fn get_max_operand_stack_size() -> usize {
+        OPERAND_STACK_SIZE_LIMIT
+    }
+
     /// Returns the internal gas schedule if it has been loaded, or an error if it hasn't.
     #[cfg(any(test, feature = "testing"))]
     pub fn gas_params(&self) -> Result<&AptosGasParameters, VMStatus> {
@@ -448,15 +613,15 @@ impl AptosVM {
         txn_data: &TransactionMetadata,
         gas_meter: &impl AptosGasMeter,
         storage_fee_refund: u64,
-    ) -> FeeStatement {
-        let gas_used = Self::gas_used(txn_data.max_gas_amount(), gas_meter);
-        FeeStatement::new(
+    ) -> Result<FeeStatement, VMStatus> {
+        let gas_used = Self::gas_used(txn_data.max_gas_amount(), gas_meter)?;
+        Ok(FeeStatement::new(
             gas_used,
             u64::from(gas_meter.execution_gas_used()),
             u64::from(gas_meter.io_gas_used()),
             u64::from(gas_meter.storage_fee_used()),
             storage_fee_refund,
-        )
+        ))
     }
 
     pub(crate) fn failed_transaction_cleanup(
@@ -541,9 +706,7 @@ impl AptosVM {
                 code,
                 ..
             } => {
-                let info = self
-                    .extract_module_metadata(&module)
-                    .and_then(|m| m.extract_abort_info(code));
+                let info = self.resolve_abort_info(&module, code);
                 ExecutionStatus::MoveAbort {
                     location: AbortLocation::Module(module),
                     code,
@@ -614,7 +777,7 @@ impl AptosVM {
             };
 
             let fee_statement =
-                AptosVM::fee_statement_from_gas_meter(txn_data, gas_meter, ZERO_STORAGE_REFUND);
+                AptosVM::fee_statement_from_gas_meter(txn_data, gas_meter, ZERO_STORAGE_REFUND)?;
 
             // Verify we charged sufficiently for creating an account slot
             let gas_params = get_or_vm_startup_failure(&self.gas_params, log_context)?;
@@ -676,7 +839,7 @@ impl AptosVM {
             let status = self.inject_abort_info_if_available(status);
 
             let fee_statement =
-                AptosVM::fee_statement_from_gas_meter(txn_data, gas_meter, ZERO_STORAGE_REFUND);
+                AptosVM::fee_statement_from_gas_meter(txn_data, gas_meter, ZERO_STORAGE_REFUND)?;
             epilogue_session.execute(|session| {
                 transaction_validation::run_failure_epilogue(
                     session,
@@ -722,7 +885,7 @@ impl AptosVM {
             txn_data,
             gas_meter,
             u64::from(epilogue_session.get_storage_fee_refund()),
-        );
+        )?;
         epilogue_session.execute(|session| {
             transaction_validation::run_success_epilogue(
                 session,
@@ -769,10 +932,21 @@ impl AptosVM {
         let loaded_func = session.load_script(script.code(), script.ty_args().to_vec())?;
 
         // TODO(Gerardo): consolidate the extended validation to verifier.
-        verifier::event_validation::verify_no_event_emission_in_script(
-            script.code(),
-            &session.get_vm_config().deserializer_config,
-        )?;
+        if self
+            .features()
+            .is_enabled(FeatureFlag::SCRIPT_EVENT_EMISSION)
+        {
+            verifier::event_validation::validate_script_emit_calls(
+                session,
+                script.code(),
+                &senders,
+            )?;
+        } else {
+            verifier::event_validation::verify_no_event_emission_in_script(
+                script.code(),
+                &session.get_vm_config().deserializer_config,
+            )?;
+        }
 
         let args = verifier::transaction_arg_validation::validate_combine_signer_and_txn_args(
             session,
@@ -780,8 +954,10 @@ impl AptosVM {
             convert_txn_args(script.args()),
             &loaded_func,
             self.features().is_enabled(FeatureFlag::STRUCT_CONSTRUCTORS),
+            self.max_txn_args_bytes(gas_meter),
         )?;
 
+        call_trace::record_script();
         session.execute_script(
             script.code(),
             script.ty_args().to_vec(),
@@ -792,6 +968,13 @@ impl AptosVM {
         Ok(())
     }
 
+    /// Returns the bound on the total size of transaction arguments, if it applies at the current
+    /// gas feature version.
+    fn max_txn_args_bytes(&self, gas_meter: &impl AptosGasMeter) -> Option<NumBytes> {
+        (self.gas_feature_version >= RELEASE_V1_14)
+            .then(|| gas_meter.vm_gas_params().txn.max_txn_args_bytes)
+    }
+
     fn validate_and_execute_entry_function(
         &self,
         resolver: &impl AptosMoveResolver,
@@ -838,7 +1021,9 @@ impl AptosVM {
             entry_fn.args().to_vec(),
             &function,
             struct_constructors_enabled,
+            self.max_txn_args_bytes(gas_meter),
         )?;
+        call_trace::record_entry_function(entry_fn.module(), entry_fn.function());
         session.execute_entry_function(
             entry_fn.module(),
             entry_fn.function(),
@@ -1081,6 +1266,7 @@ impl AptosVM {
         // Failures here will be propagated back.
         let payload_bytes: Vec<Vec<u8>> = session
             .execute(|session| {
+                call_trace::record_function(&MULTISIG_ACCOUNT_MODULE, GET_NEXT_TRANSACTION_PAYLOAD);
                 session.execute_function_bypass_visibility(
                     &MULTISIG_ACCOUNT_MODULE,
                     GET_NEXT_TRANSACTION_PAYLOAD,
@@ -1114,9 +1300,8 @@ impl AptosVM {
         let deserialization_error = PartialVMError::new(StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT)
             .finish(Location::Undefined);
         let payload_bytes =
-            bcs::from_bytes::<Vec<u8>>(payload_bytes).map_err(|_| deserialization_error.clone())?;
-        let payload = bcs::from_bytes::<MultisigTransactionPayload>(&payload_bytes)
-            .map_err(|_| deserialization_error)?;
+            bcs::from_bytes::<Vec<u8>>(payload_bytes).map_err(|_| deserialization_error)?;
+        let payload = deserialize_canonical_bcs::<MultisigTransactionPayload>(&payload_bytes)?;
 
         // Step 2: Execute the target payload. Transaction failure here is tolerated. In case of any
         // failures, we'll discard the session and start a new one. This ensures that any data
@@ -1284,6 +1469,10 @@ impl AptosVM {
             txn_data,
         )?;
         epilogue_session.execute(|session| {
+            call_trace::record_function(
+                &MULTISIG_ACCOUNT_MODULE,
+                SUCCESSFUL_TRANSACTION_EXECUTION_CLEANUP,
+            );
             session
                 .execute_function_bypass_visibility(
                     &MULTISIG_ACCOUNT_MODULE,
@@ -1325,6 +1514,10 @@ impl AptosVM {
                 .finish(Location::Undefined)
         })?);
         epilogue_session.execute(|session| {
+            call_trace::record_function(
+                &MULTISIG_ACCOUNT_MODULE,
+                FAILED_TRANSACTION_EXECUTION_CLEANUP,
+            );
             session
                 .execute_function_bypass_visibility(
                     &MULTISIG_ACCOUNT_MODULE,
@@ -1369,6 +1562,7 @@ impl AptosVM {
                         .iter()
                         .map(|s| MoveValue::Signer(*s).simple_serialize().unwrap())
                         .collect();
+                    call_trace::record_function(&module.self_id(), init_func_name);
                     session.execute_function_bypass_visibility(
                         &module.self_id(),
                         init_func_name,
@@ -1386,14 +1580,20 @@ impl AptosVM {
         Ok(())
     }
 
+    /// The deserializer config of the VM. Modules deserialized outside of the VM must use it, so
+    /// that they are subject to the same limits as the modules the VM loads itself.
+    fn deserializer_config(&self) -> &DeserializerConfig {
+        &self.move_vm.get_vm_config().deserializer_config
+    }
+
     /// Deserialize a module bundle.
     fn deserialize_module_bundle(&self, modules: &ModuleBundle) -> VMResult<Vec<CompiledModule>> {
-        let max_version = get_max_binary_format_version(self.features(), None);
-        let max_identifier_size = get_max_identifier_size(self.features());
-        let config = DeserializerConfig::new(max_version, max_identifier_size);
         let mut result = vec![];
         for module_blob in modules.iter() {
-            match CompiledModule::deserialize_with_config(module_blob.code(), &config) {
+            match CompiledModule::deserialize_with_config(
+                module_blob.code(),
+                self.deserializer_config(),
+            ) {
                 Ok(module) => {
                     result.push(module);
                 },
@@ -1406,6 +1606,121 @@ impl AptosVM {
         Ok(result)
     }
 
+    /// The compatibility checks applied when a module is republished.
+    fn module_upgrade_compatibility(&self) -> Compatibility {
+        Compatibility::new(
+            true,
+            true,
+            !self
+                .features()
+                .is_enabled(FeatureFlag::TREAT_FRIEND_AS_PRIVATE),
+        )
+    }
+
+    /// Checks whether publishing `bundle` would be a backward compatible upgrade of the modules
+    /// which are already on chain, without publishing anything. Returns, for every module in the
+    /// bundle, all the incompatibilities found with its on-chain version. Modules which are not on
+    /// chain yet, as well as compatible upgrades, have no incompatibilities.
+    pub fn check_upgrade_compatibility(
+        &self,
+        resolver: &impl AptosMoveResolver,
+        bundle: &ModuleBundle,
+    ) -> VMResult<Vec<(ModuleId, Vec<Incompatibility>)>> {
+        let compat = self.module_upgrade_compatibility();
+
+        let mut result = vec![];
+        for new_module in self.deserialize_module_bundle(bundle)? {
+            let module_id = new_module.self_id();
+            let incompatibilities = match resolver
+                .get_module(&module_id)
+                .map_err(|e| e.finish(Location::Undefined))?
+            {
+                Some(old_module_bytes) => {
+                    let old_module = CompiledModule::deserialize_with_config(
+                        &old_module_bytes,
+                        self.deserializer_config(),
+                    )
+                    .map_err(|e| e.finish(Location::Module(module_id.clone())))?;
+                    compat.incompatibilities(
+                        &normalized::Module::new(&old_module),
+                        &normalized::Module::new(&new_module),
+                    )
+                },
+                None => vec![],
+            };
+            result.push((module_id, incompatibilities));
+        }
+        Ok(result)
+    }
+
+    /// Returns the modules `payload` links against, each with the SHA3-256 hash of its bytecode in
+    /// `resolver`: the modules it calls or instantiates types from, and, transitively, their
+    /// dependencies and friends. This pins the code a transaction runs to exact module versions.
+    /// Modules which do not exist are skipped, and so are the framework modules only called by the
+    /// prologue and epilogue. Multisig payloads stored on chain are not resolved.
+    pub fn module_manifest(
+        &self,
+        resolver: &impl AptosMoveResolver,
+        payload: &TransactionPayload,
+    ) -> VMResult<Vec<(ModuleId, HashValue)>> {
+        let config = self.deserializer_config();
+
+        let mut pending = vec![];
+        let ty_args: &[TypeTag] = match payload {
+            TransactionPayload::Script(script) => {
+                let compiled_script =
+                    CompiledScript::deserialize_with_config(script.code(), config)
+                        .map_err(|e| e.finish(Location::Script))?;
+                pending.extend(compiled_script.immediate_dependencies());
+                script.ty_args()
+            },
+            TransactionPayload::EntryFunction(entry_function)
+            | TransactionPayload::Multisig(Multisig {
+                transaction_payload: Some(MultisigTransactionPayload::EntryFunction(entry_function)),
+                ..
+            }) => {
+                pending.push(entry_function.module().clone());
+                entry_function.ty_args()
+            },
+            TransactionPayload::Multisig(_) | TransactionPayload::ModuleBundle(_) => &[],
+        };
+        let mut ty_args = ty_args.iter().collect::<Vec<_>>();
+        while let Some(ty_arg) = ty_args.pop() {
+            match ty_arg {
+                TypeTag::Struct(struct_tag) => {
+                    pending.push(struct_tag.module_id());
+                    ty_args.extend(struct_tag.type_args.iter());
+                },
+                TypeTag::Vector(elem_ty) => ty_args.push(elem_ty.as_ref()),
+                _ => (),
+            }
+        }
+
+        let mut manifest = BTreeMap::new();
+        let mut visited = BTreeSet::new();
+        while let Some(module_id) = pending.pop() {
+            if !visited.insert(module_id.clone()) {
+                continue;
+            }
+            let Some(bytes) = resolver
+                .get_module(&module_id)
+                .map_err(|e| e.finish(Location::Undefined))?
+            else {
+                continue;
+            };
+            let module = CompiledModule::deserialize_with_config(&bytes, config)
+                .map_err(|e| e.finish(Location::Module(module_id.clone())))?;
+            pending.extend(
+                module
+                    .immediate_dependencies()
+                    .into_iter()
+                    .chain(module.immediate_friends()),
+            );
+            manifest.insert(module_id, HashValue::sha3_256_of(&bytes));
+        }
+        Ok(manifest.into_iter().collect())
+    }
+
     /// Resolve a pending code publish request registered via the NativeCodeContext.
     fn resolve_pending_code_publish(
         &self,
@@ -1422,10 +1737,10 @@ impl AptosVM {
             check_compat: _,
         }) = session.extract_publish_request()
         {
-            // TODO: unfortunately we need to deserialize the entire bundle here to handle
-            // `init_module` and verify some deployment conditions, while the VM need to do
-            // the deserialization again. Consider adding an API to MoveVM which allows to
-            // directly pass CompiledModule.
+            // The bundle is deserialized once here to handle `init_module` and verify some
+            // deployment conditions, and the result is handed to the VM when publishing so it
+            // does not need to deserialize the bundle again. This must use the VM's own
+            // deserializer config, otherwise the VM could publish modules it would have rejected.
             let modules = self.deserialize_module_bundle(&bundle)?;
             let modules: &Vec<CompiledModule> =
                 traversal_context.referenced_module_bundles.alloc(modules);
@@ -1496,17 +1811,12 @@ impl AptosVM {
             // Publish the bundle and execute initializers
             // publish_module_bundle doesn't actually load the published module into
             // the loader cache. It only puts the module data in the data cache.
-            return_on_failure!(session.publish_module_bundle_with_compat_config(
+            return_on_failure!(session.publish_compiled_module_bundle_with_compat_config(
                 bundle.into_inner(),
+                modules,
                 destination,
                 gas_meter,
-                Compatibility::new(
-                    true,
-                    true,
-                    !self
-                        .features()
-                        .is_enabled(FeatureFlag::TREAT_FRIEND_AS_PRIVATE),
-                ),
+                self.module_upgrade_compatibility(),
             ));
 
             self.execute_module_initialization(
@@ -1681,6 +1991,14 @@ impl AptosVM {
             self.move_vm.mark_loader_cache_as_invalid();
         };
 
+        match err.status_code() {
+            StatusCode::TOO_MANY_DYNAMIC_DISPATCHES => TXN_DYNAMIC_DISPATCH_LIMIT_REACHED.inc(),
+            StatusCode::RESOURCE_CREATION_LIMIT_REACHED => {
+                TXN_RESOURCE_CREATION_LIMIT_REACHED.inc()
+            },
+            _ => (),
+        }
+
         self.failed_transaction_cleanup(
             prologue_change_set,
             err,
@@ -1739,12 +2057,14 @@ impl AptosVM {
                 resolver,
                 self.gas_feature_version,
                 change_set_configs,
+                self.skip_prologue_resession,
             ));
 
         let is_account_init_for_sponsored_transaction = unwrap_or_discard!(
             is_account_init_for_sponsored_transaction(&txn_data, self.features(), resolver)
         );
         if is_account_init_for_sponsored_transaction {
+            let balance_before_account_creation = gas_meter.balance();
             unwrap_or_discard!(
                 user_session.execute(|session| create_account_if_does_not_exist(
                     session,
@@ -1753,6 +2073,40 @@ impl AptosVM {
                     &mut traversal_context,
                 ))
             );
+
+            // Bound how much of the fee payer's gas the implicit account creation may use. This
+            // covers execution and IO gas only, as storage fees are charged later for the whole
+            // change set. The account is still created by the abort hook when the transaction
+            // fails.
+            if self.gas_feature_version >= RELEASE_V1_14 {
+                let max_account_creation_gas = gas_meter
+                    .vm_gas_params()
+                    .txn
+                    .max_sponsored_account_creation_gas;
+                let account_creation_gas = balance_before_account_creation
+                    .checked_sub(gas_meter.balance())
+                    .unwrap_or_else(|| 0.into());
+                if account_creation_gas > max_account_creation_gas {
+                    speculative_warn!(
+                        log_context,
+                        format!(
+                            "[VM] Sponsored account creation used {} gas, above the maximum of {}",
+                            account_creation_gas, max_account_creation_gas,
+                        ),
+                    );
+                    return self.on_user_transaction_execution_failure(
+                        prologue_change_set,
+                        VMStatus::error(StatusCode::EXECUTION_LIMIT_REACHED, None),
+                        resolver,
+                        &txn_data,
+                        log_context,
+                        gas_meter,
+                        change_set_configs,
+                        false,
+                        &mut traversal_context,
+                    );
+                }
+            }
         }
 
         // We keep track of whether any newly published modules are loaded into the Vm's loader
@@ -1793,11 +2147,11 @@ impl AptosVM {
             },
         };
 
-        let gas_usage = txn_data
-            .max_gas_amount()
-            .checked_sub(gas_meter.balance())
-            .expect("Balance should always be less than or equal to max gas amount set");
-        TXN_GAS_USAGE.observe(u64::from(gas_usage) as f64);
+        // An inconsistent balance is reported as an invariant violation when computing the fee
+        // statement, so it is only skipped here.
+        if let Ok(gas_usage) = gas_used(txn_data.max_gas_amount(), gas_meter.balance()) {
+            TXN_GAS_USAGE.observe(u64::from(gas_usage) as f64);
+        }
 
         result.unwrap_or_else(|err| {
             self.on_user_transaction_execution_failure(
@@ -1841,6 +2195,9 @@ impl AptosVM {
             is_approved_gov_script,
             balance,
         );
+        if self.record_call_trace {
+            call_trace::start();
+        }
         let (status, output) = self.execute_user_transaction_impl(
             resolver,
             txn,
@@ -1849,6 +2206,7 @@ impl AptosVM {
             &mut gas_meter,
             log_context,
         );
+        let output = output.with_call_trace(call_trace::finish());
 
         Ok((status, output, gas_meter))
     }
@@ -1910,6 +2268,28 @@ impl AptosVM {
         }
     }
 
+    /// Executes a user transaction as if `overlay` had been applied on top of the state behind
+    /// `resolver`, using the production gas meter.
+    ///
+    /// This is meant for what-if analysis, e.g. to see how a transaction behaves if some resource
+    /// had a different value, without having to construct a custom state view. The returned output
+    /// only contains the changes made by the transaction itself, not the overlay.
+    pub fn execute_user_transaction_with_overlay(
+        &self,
+        resolver: &impl AptosMoveResolver,
+        overlay: VMChangeSet,
+        txn: &SignedTransaction,
+        log_context: &AdapterLogSchema,
+    ) -> (VMStatus, VMOutput) {
+        let executor_view = ExecutorViewWithChangeSet::new(
+            resolver.as_executor_view(),
+            resolver.as_resource_group_view(),
+            overlay,
+        );
+        let overlaid_resolver = self.as_move_resolver_with_group_view(&executor_view);
+        self.execute_user_transaction(&overlaid_resolver, txn, log_context)
+    }
+
     fn execute_write_set(
         &self,
         resolver: &impl AptosMoveResolver,
@@ -1945,27 +2325,63 @@ impl AptosVM {
                 Ok(change)
             },
             WriteSetPayload::Script { script, execute_as } => {
-                let mut tmp_session = self.new_session(resolver, session_id, None);
                 let senders = match txn_sender {
                     None => vec![*execute_as],
                     Some(sender) => vec![sender, *execute_as],
                 };
-
-                let traversal_storage = TraversalStorage::new();
-                let mut traversal_context = TraversalContext::new(&traversal_storage);
-
-                self.validate_and_execute_script(
-                    &mut tmp_session,
-                    &mut UnmeteredGasMeter,
-                    &mut traversal_context,
+                self.execute_write_set_script(
+                    resolver,
+                    session_id,
+                    &change_set_configs,
                     senders,
                     script,
-                )?;
-                Ok(tmp_session.finish(&change_set_configs)?)
+                )
+            },
+            WriteSetPayload::MultiSignerScript { signers, script } => {
+                if !self
+                    .features()
+                    .is_enabled(FeatureFlag::MULTI_SIGNER_WRITE_SET_SCRIPT)
+                {
+                    return Err(VMStatus::error(
+                        StatusCode::FEATURE_UNDER_GATING,
+                        Some("Multi-signer write set scripts are not enabled".to_string()),
+                    ));
+                }
+                self.execute_write_set_script(
+                    resolver,
+                    session_id,
+                    &change_set_configs,
+                    signers.clone(),
+                    script,
+                )
             },
         }
     }
 
+    /// Runs a write set script with the given ordered list of signers. The number of signers must
+    /// match the number of signer parameters of the script.
+    fn execute_write_set_script(
+        &self,
+        resolver: &impl AptosMoveResolver,
+        session_id: SessionId,
+        change_set_configs: &ChangeSetConfigs,
+        senders: Vec<AccountAddress>,
+        script: &Script,
+    ) -> Result<VMChangeSet, VMStatus> {
+        let mut tmp_session = self.new_session(resolver, session_id, None);
+        let traversal_storage = TraversalStorage::new();
+        let mut traversal_context = TraversalContext::new(&traversal_storage);
+
+        self.validate_and_execute_script(
+            &mut tmp_session,
+            &mut UnmeteredGasMeter,
+            &mut traversal_context,
+            senders,
+            script,
+        )?;
+        Ok(tmp_session.finish(change_set_configs)?)
+    }
+
     fn read_change_set(
         &self,
         executor_view: &dyn ExecutorView,
@@ -2055,7 +2471,40 @@ impl AptosVM {
         Ok((VMStatus::Executed, output))
     }
 
-    fn process_block_prologue(
+    /// Same as `process_waypoint_change_set`, but additionally returns the validator set that the
+    /// write set installs (if any), so that callers do not need to read it from storage.
+    pub fn process_waypoint_change_set_with_validator_set(
+        &self,
+        resolver: &impl AptosMoveResolver,
+        write_set_payload: WriteSetPayload,
+        log_context: &AdapterLogSchema,
+    ) -> Result<(VMStatus, VMOutput, Option<ValidatorSet>), VMStatus> {
+        let (vm_status, output) =
+            self.process_waypoint_change_set(resolver, write_set_payload, log_context)?;
+        let validator_set = Self::validator_set_from_change_set(output.change_set())?;
+        Ok((vm_status, output, validator_set))
+    }
+
+    fn validator_set_from_change_set(
+        change_set: &VMChangeSet,
+    ) -> Result<Option<ValidatorSet>, VMStatus> {
+        let state_key = StateKey::on_chain_config::<ValidatorSet>().map_err(|e| {
+            VMStatus::error(
+                StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR,
+                Some(e.to_string()),
+            )
+        })?;
+        change_set
+            .resource_write_set()
+            .get(&state_key)
+            .and_then(AbstractResourceWriteOp::try_as_concrete_write)
+            .and_then(WriteOp::bytes)
+            .map(|bytes| ValidatorSet::deserialize_into_config(bytes))
+            .transpose()
+            .map_err(|e| VMStatus::error(StatusCode::INVALID_WRITE_SET, Some(e.to_string())))
+    }
+
+    pub(crate) fn process_block_prologue(
         &self,
         resolver: &impl AptosMoveResolver,
         block_metadata: BlockMetadata,
@@ -2181,6 +2630,14 @@ impl AptosVM {
         Ok((VMStatus::Executed, output))
     }
 
+    /// Translates the abort `code` of `module` into a human-readable reason, using the error
+    /// map from the module's metadata. Returns `None` if the module is not loaded by this VM or
+    /// if its metadata does not describe the code.
+    pub fn resolve_abort_info(&self, module: &ModuleId, code: u64) -> Option<AbortInfo> {
+        self.extract_module_metadata(module)
+            .and_then(|metadata| metadata.extract_abort_info(code))
+    }
+
     fn extract_module_metadata(&self, module: &ModuleId) -> Option<Arc<RuntimeModuleMetadataV1>> {
         if self.features().is_enabled(FeatureFlag::VM_BINARY_FORMAT_V6) {
             aptos_framework::get_vm_metadata(&self.move_vm, module)
@@ -2196,6 +2653,63 @@ impl AptosVM {
         type_args: Vec<TypeTag>,
         arguments: Vec<Vec<u8>>,
         max_gas_amount: u64,
+    ) -> ViewFunctionOutput {
+        Self::execute_view_function_impl(
+            state_view,
+            module_id,
+            func_name,
+            |_| Ok(type_args),
+            arguments,
+            max_gas_amount,
+        )
+    }
+
+    /// Executes a view function like `execute_view_function`, but takes the type arguments as
+    /// strings (e.g., `0x1::aptos_coin::AptosCoin`). Each type argument is parsed and resolved
+    /// with the session's type loader before execution, so that a type which cannot be parsed or
+    /// does not exist on chain results in an error naming the offending argument.
+    pub fn execute_view_function_with_type_arg_strs(
+        state_view: &impl StateView,
+        module_id: ModuleId,
+        func_name: Identifier,
+        type_args: Vec<String>,
+        arguments: Vec<Vec<u8>>,
+        max_gas_amount: u64,
+    ) -> ViewFunctionOutput {
+        Self::execute_view_function_impl(
+            state_view,
+            module_id,
+            func_name,
+            |session| Self::resolve_type_arg_strs(session, &type_args),
+            arguments,
+            max_gas_amount,
+        )
+    }
+
+    fn resolve_type_arg_strs(
+        session: &mut SessionExt,
+        type_args: &[String],
+    ) -> anyhow::Result<Vec<TypeTag>> {
+        type_args
+            .iter()
+            .map(|type_arg| {
+                let tag = TypeTag::from_str(type_arg)
+                    .map_err(|err| anyhow!("Invalid type argument {}: {}", type_arg, err))?;
+                session.load_type(&tag).map_err(|err| {
+                    anyhow!("Failed to resolve type argument {}: {:?}", type_arg, err)
+                })?;
+                Ok(tag)
+            })
+            .collect()
+    }
+
+    fn execute_view_function_impl(
+        state_view: &impl StateView,
+        module_id: ModuleId,
+        func_name: Identifier,
+        resolve_type_args: impl FnOnce(&mut SessionExt) -> anyhow::Result<Vec<TypeTag>>,
+        arguments: Vec<Vec<u8>>,
+        max_gas_amount: u64,
     ) -> ViewFunctionOutput {
         let resolver = state_view.as_move_resolver();
         let vm = AptosVM::new(
@@ -2204,11 +2718,13 @@ impl AptosVM {
         );
 
         let log_context = AdapterLogSchema::new(state_view.id(), 0);
+        let gas_feature_version = vm.gas_feature_version;
 
         let vm_gas_params = match get_or_vm_startup_failure(&vm.gas_params, &log_context) {
             Ok(gas_params) => gas_params.vm.clone(),
             Err(err) => {
                 return ViewFunctionOutput::new(Err(anyhow::Error::msg(format!("{}", err))), 0)
+                    .with_gas_feature_version(gas_feature_version)
             },
         };
         let storage_gas_params =
@@ -2216,6 +2732,7 @@ impl AptosVM {
                 Ok(gas_params) => gas_params.clone(),
                 Err(err) => {
                     return ViewFunctionOutput::new(Err(anyhow::Error::msg(format!("{}", err))), 0)
+                        .with_gas_feature_version(gas_feature_version)
                 },
             };
 
@@ -2228,27 +2745,59 @@ impl AptosVM {
         );
 
         let mut session = vm.new_session(&resolver, SessionId::Void, None);
-        let execution_result = Self::execute_view_function_in_vm(
-            &mut session,
-            &vm,
+        let execution_result = resolve_type_args(&mut session).and_then(|type_args| {
+            Self::execute_view_function_in_vm(
+                &mut session,
+                &vm,
+                module_id,
+                func_name,
+                type_args,
+                arguments,
+                &mut gas_meter,
+            )
+        });
+        let gas_used = match Self::gas_used(max_gas_amount.into(), &gas_meter) {
+            Ok(gas_used) => gas_used,
+            Err(err) => {
+                return ViewFunctionOutput::new(Err(anyhow::Error::msg(format!("{}", err))), 0)
+                    .with_gas_feature_version(gas_feature_version)
+            },
+        };
+        match execution_result {
+            Ok(result) => ViewFunctionOutput::new(Ok(result), gas_used),
+            Err(e) => ViewFunctionOutput::new(Err(e), gas_used),
+        }
+        .with_gas_feature_version(gas_feature_version)
+    }
+
+    /// Executes a view function like `execute_view_function`, and additionally records the state
+    /// keys read during the execution in the output's `read_set`, so that callers caching the
+    /// results know when they need to be invalidated. Note that modules served from the VM's code
+    /// cache are not read from the state view, and may be missing from the recorded keys.
+    pub fn execute_view_function_with_read_set(
+        state_view: &impl StateView,
+        module_id: ModuleId,
+        func_name: Identifier,
+        type_args: Vec<TypeTag>,
+        arguments: Vec<Vec<u8>>,
+        max_gas_amount: u64,
+    ) -> ViewFunctionOutput {
+        let recording_view = ReadRecordingStateView::new(state_view);
+        let output = Self::execute_view_function(
+            &recording_view,
             module_id,
             func_name,
             type_args,
             arguments,
-            &mut gas_meter,
+            max_gas_amount,
         );
-        let gas_used = Self::gas_used(max_gas_amount.into(), &gas_meter);
-        match execution_result {
-            Ok(result) => ViewFunctionOutput::new(Ok(result), gas_used),
-            Err(e) => ViewFunctionOutput::new(Err(e), gas_used),
-        }
+        output.with_read_set(recording_view.into_read_set())
     }
 
-    fn gas_used(max_gas_amount: Gas, gas_meter: &impl AptosGasMeter) -> u64 {
-        max_gas_amount
-            .checked_sub(gas_meter.balance())
-            .expect("Balance should always be less than or equal to max gas amount")
-            .into()
+    fn gas_used(max_gas_amount: Gas, gas_meter: &impl AptosGasMeter) -> Result<u64, VMStatus> {
+        gas_used(max_gas_amount, gas_meter.balance())
+            .map(u64::from)
+            .map_err(|err| err.finish(Location::Undefined).into_vm_status())
     }
 
     fn execute_view_function_in_vm(
@@ -2470,6 +3019,11 @@ impl AptosVM {
                 if let Some(label) = counter_label {
                     USER_TRANSACTIONS_EXECUTED.with_label_values(&[label]).inc();
                 }
+                if let TransactionStatus::Discard(status_code) = output.status() {
+                    DISCARDED_TRANSACTIONS_BY_CODE
+                        .with_label_values(&[&format!("{:?}", status_code)])
+                        .inc();
+                }
                 (vm_status, output)
             },
             Transaction::StateCheckpoint(_) => {
@@ -2485,6 +3039,21 @@ impl AptosVM {
         })
     }
 
+    /// Same as `execute_single_transaction`, but also returns all speculative log entries emitted
+    /// while executing `txn`, so that a failing transaction can be debugged with its full logging
+    /// context. Entries are captured regardless of the enabled log levels.
+    pub fn execute_single_transaction_with_captured_logs(
+        &self,
+        txn: &SignatureVerifiedTransaction,
+        resolver: &impl AptosMoveResolver,
+        log_context: &AdapterLogSchema,
+    ) -> (
+        Result<(VMStatus, VMOutput), VMStatus>,
+        Vec<CapturedLogEntry>,
+    ) {
+        capture_speculative_logs(|| self.execute_single_transaction(txn, resolver, log_context))
+    }
+
     #[allow(clippy::manual_filter)]
     pub fn get_required_deposit(
         &self,
@@ -2532,17 +3101,13 @@ impl AptosVM {
     }
 }
 
-// Executor external API
-impl VMExecutor for AptosVM {
-    /// Execute a block of `transactions`. The output vector will have the exact same length as the
-    /// input vector. The discarded transactions will be marked as `TransactionStatus::Discard` and
-    /// have an empty `WriteSet`. Also `state_view` is immutable, and does not have interior
-    /// mutability. Writes to be applied to the data view are encoded in the write set part of a
-    /// transaction output.
-    fn execute_block(
+impl AptosVM {
+    fn execute_block_impl(
         transactions: &[SignatureVerifiedTransaction],
         state_view: &(impl StateView + Sync),
         onchain_config: BlockExecutorConfigFromOnchain,
+        deadline: Option<Instant>,
+        cancelled: Option<Arc<AtomicBool>>,
     ) -> Result<BlockOutput<TransactionOutput>, VMStatus> {
         fail_point!("move_adapter::execute_block", |_| {
             Err(VMStatus::error(
@@ -2570,6 +3135,11 @@ impl VMExecutor for AptosVM {
                     concurrency_level: Self::get_concurrency_level(),
                     allow_fallback: true,
                     discard_failed_blocks: Self::get_discard_failed_blocks(),
+                    deadline,
+                    cancelled,
+                    record_read_sets: false,
+                    abort_rate_alert_threshold_pct: Self::get_abort_rate_alert_threshold_pct(),
+                    skip_prologue_resession: false,
                 },
                 onchain: onchain_config,
             },
@@ -2582,6 +3152,153 @@ impl VMExecutor for AptosVM {
         ret
     }
 
+    /// Executes `transactions` sequentially and returns their raw `VMOutput`s, i.e. the outputs
+    /// before the materialization done by the block executor (aggregator v1 deltas are not turned
+    /// into writes). Every transaction observes the changes of the previous ones, and like in the
+    /// block executor, transactions after a reconfiguration are not executed and get `Retry`.
+    ///
+    /// Delayed field optimization is disabled, so aggregator v2 values are written directly. This
+    /// is meant for analysis and research tools, not for production execution.
+    pub fn execute_block_raw(
+        transactions: &[SignatureVerifiedTransaction],
+        state_view: &impl StateView,
+    ) -> Result<Vec<VMOutput>, VMStatus> {
+        let resolver = state_view.as_move_resolver();
+        let vm = AptosVM::new(
+            &resolver,
+            /*override_is_delayed_field_optimization_capable=*/ Some(false),
+        );
+        let change_set_configs =
+            ChangeSetConfigs::unlimited_at_gas_feature_version(vm.gas_feature_version);
+
+        let mut block_change_set = VMChangeSet::empty();
+        let mut outputs = Vec::with_capacity(transactions.len());
+        for (idx, txn) in transactions.iter().enumerate() {
+            let log_context = AdapterLogSchema::new(state_view.id(), idx);
+            let executor_view = ExecutorViewWithChangeSet::new(
+                resolver.as_executor_view(),
+                resolver.as_resource_group_view(),
+                block_change_set,
+            );
+            let (_, output) = vm.execute_single_transaction(
+                txn,
+                &vm.as_move_resolver_with_group_view(&executor_view),
+                &log_context,
+            )?;
+            block_change_set = executor_view.change_set;
+
+            let restart = Self::should_restart_execution(output.change_set());
+            if matches!(output.status(), TransactionStatus::Keep(_)) {
+                block_change_set
+                    .squash_additional_change_set(output.change_set().clone(), &change_set_configs)
+                    .map_err(|e| e.finish(Location::Undefined).into_vm_status())?;
+            }
+            outputs.push(output);
+            if restart {
+                break;
+            }
+        }
+        outputs.resize_with(transactions.len(), || {
+            VMOutput::empty_with_status(TransactionStatus::Retry)
+        });
+        Ok(outputs)
+    }
+
+    /// Estimates how much faster parallel execution of `transactions` would be compared to
+    /// sequential execution, before committing to the block composition. The block is dry-run
+    /// sequentially to record what each transaction reads and writes, and the speedup is derived
+    /// from the longest chain of read-after-write dependencies, weighted by gas used.
+    pub fn estimate_parallelism(
+        transactions: &[SignatureVerifiedTransaction],
+        state_view: &(impl StateView + Sync),
+    ) -> Result<f64, VMStatus> {
+        let mut block_output = BlockAptosVM::execute_block::<
+            _,
+            NoOpTransactionCommitHook<AptosTransactionOutput, VMStatus>,
+        >(
+            Arc::clone(&RAYON_EXEC_POOL),
+            transactions,
+            state_view,
+            BlockExecutorConfig {
+                local: BlockExecutorLocalConfig {
+                    concurrency_level: 1,
+                    allow_fallback: false,
+                    discard_failed_blocks: false,
+                    deadline: None,
+                    cancelled: None,
+                    record_read_sets: true,
+                    abort_rate_alert_threshold_pct: None,
+                    skip_prologue_resession: false,
+                },
+                onchain: BlockExecutorConfigFromOnchain::new_no_block_limit(),
+            },
+            None,
+        )?;
+
+        let read_sets = block_output.take_read_sets().unwrap_or_default();
+        let outputs = block_output.into_inner();
+        let read_sets: Vec<Vec<&StateKey>> = read_sets
+            .iter()
+            .map(|read_set| read_set.iter().collect())
+            .collect();
+        let write_sets: Vec<Vec<&StateKey>> = outputs
+            .iter()
+            .map(|output| output.write_set().iter().map(|(key, _)| key).collect())
+            .collect();
+        // Every transaction takes some time to execute, even if it did not charge gas.
+        let costs: Vec<u64> = outputs
+            .iter()
+            .map(|output| output.gas_used().max(1))
+            .collect();
+        Ok(estimate_parallel_speedup(&read_sets, &write_sets, &costs))
+    }
+}
+
+// Executor external API
+impl VMExecutor for AptosVM {
+    /// Execute a block of `transactions`. The output vector will have the exact same length as the
+    /// input vector. The discarded transactions will be marked as `TransactionStatus::Discard` and
+    /// have an empty `WriteSet`. Also `state_view` is immutable, and does not have interior
+    /// mutability. Writes to be applied to the data view are encoded in the write set part of a
+    /// transaction output.
+    fn execute_block(
+        transactions: &[SignatureVerifiedTransaction],
+        state_view: &(impl StateView + Sync),
+        onchain_config: BlockExecutorConfigFromOnchain,
+    ) -> Result<BlockOutput<TransactionOutput>, VMStatus> {
+        Self::execute_block_impl(transactions, state_view, onchain_config, None, None)
+    }
+
+    fn execute_block_with_deadline(
+        transactions: &[SignatureVerifiedTransaction],
+        state_view: &(impl StateView + Sync),
+        onchain_config: BlockExecutorConfigFromOnchain,
+        deadline: Instant,
+    ) -> Result<BlockOutput<TransactionOutput>, VMStatus> {
+        Self::execute_block_impl(
+            transactions,
+            state_view,
+            onchain_config,
+            Some(deadline),
+            None,
+        )
+    }
+
+    fn execute_block_with_cancellation(
+        transactions: &[SignatureVerifiedTransaction],
+        state_view: &(impl StateView + Sync),
+        onchain_config: BlockExecutorConfigFromOnchain,
+        cancelled: Arc<AtomicBool>,
+    ) -> Result<BlockOutput<TransactionOutput>, VMStatus> {
+        Self::execute_block_impl(
+            transactions,
+            state_view,
+            onchain_config,
+            None,
+            Some(cancelled),
+        )
+    }
+
     fn execute_block_sharded<S: StateView + Sync + Send + 'static, C: ExecutorClient<S>>(
         sharded_block_executor: &ShardedBlockExecutor<S, C>,
         transactions: PartitionedTransactions,
@@ -2707,10 +3424,21 @@ impl VMValidator for AptosVM {
                 "failure",
                 VMValidatorResult::new(Some(err.status_code()), 0),
             ),
-            _ => (
-                "success",
-                VMValidatorResult::new(None, txn.gas_unit_price()),
-            ),
+            _ => {
+                let mut result = VMValidatorResult::new(None, txn.gas_unit_price());
+                if let Ok(gas_params) = &self.gas_params {
+                    if let Ok((execution_gas, storage_gas)) = estimate_gas_split(
+                        gas_params,
+                        self.gas_feature_version,
+                        &resolver,
+                        &txn_data,
+                        self.features(),
+                    ) {
+                        result = result.with_gas_estimate(execution_gas.into(), storage_gas.into());
+                    }
+                }
+                ("success", result)
+            },
         };
 
         TRANSACTIONS_VALIDATED
@@ -2749,22 +3477,75 @@ impl AptosSimulationVM {
         let resolver = state_view.as_move_resolver();
         let vm = Self::new(&resolver);
         let log_context = AdapterLogSchema::new(state_view.id(), 0);
+        vm.simulate_signed_transaction(&resolver, transaction, &log_context)
+    }
+
+    /// Same as `create_vm_and_simulate_signed_transaction`, but also returns the manifest of the
+    /// modules the transaction links against (see [`AptosVM::module_manifest`]), so that the
+    /// simulation can be reproduced against the exact same module versions.
+    /// *Precondition:* the transaction must **not** have a valid signature.
+    pub fn create_vm_and_simulate_signed_transaction_with_module_manifest(
+        transaction: &SignedTransaction,
+        state_view: &impl StateView,
+    ) -> (
+        VMStatus,
+        TransactionOutput,
+        VMResult<Vec<(ModuleId, HashValue)>>,
+    ) {
+        assert_err!(
+            transaction.verify_signature(),
+            "Simulated transaction should not have a valid signature"
+        );
+
+        let resolver = state_view.as_move_resolver();
+        let vm = Self::new(&resolver);
+        let log_context = AdapterLogSchema::new(state_view.id(), 0);
+        let manifest = vm.0.module_manifest(&resolver, transaction.payload());
+        let (vm_status, txn_output) =
+            vm.simulate_signed_transaction(&resolver, transaction, &log_context);
+        (vm_status, txn_output, manifest)
+    }
 
+    fn simulate_signed_transaction(
+        &self,
+        resolver: &impl AptosMoveResolver,
+        transaction: &SignedTransaction,
+        log_context: &AdapterLogSchema,
+    ) -> (VMStatus, TransactionOutput) {
         let (vm_status, vm_output) =
-            vm.0.execute_user_transaction(&resolver, transaction, &log_context);
+            self.0
+                .execute_user_transaction(resolver, transaction, log_context);
         let txn_output = vm_output
-            .try_materialize_into_transaction_output(&resolver)
+            .try_materialize_into_transaction_output(resolver)
             .expect("Materializing aggregator V1 deltas should never fail");
         (vm_status, txn_output)
     }
 }
 
+/// Deserializes BCS bytes, rejecting bytes that are not the canonical encoding of the result. Used
+/// for multisig transaction payloads: approvals refer to the payload hash, so a payload must have
+/// exactly one encoding.
+fn deserialize_canonical_bcs<T: Serialize + DeserializeOwned>(bytes: &[u8]) -> VMResult<T> {
+    let value = bcs::from_bytes::<T>(bytes).map_err(|_| {
+        PartialVMError::new(StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT).finish(Location::Undefined)
+    })?;
+    if bcs::to_bytes(&value).ok().as_deref() != Some(bytes) {
+        return Err(
+            PartialVMError::new(StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT)
+                .with_message("Bytes are not canonical BCS".to_string())
+                .finish(Location::Undefined),
+        );
+    }
+    Ok(value)
+}
+
 fn create_account_if_does_not_exist(
     session: &mut SessionExt,
     gas_meter: &mut impl GasMeter,
     account: AccountAddress,
     traversal_context: &mut TraversalContext,
 ) -> VMResult<()> {
+    call_trace::record_function(&ACCOUNT_MODULE, CREATE_ACCOUNT_IF_DOES_NOT_EXIST);
     session
         .execute_function_bypass_visibility(
             &ACCOUNT_MODULE,
@@ -2813,3 +3594,84 @@ fn vm_thread_safe() {
     assert_send::<MoveVmExt>();
     assert_sync::<MoveVmExt>();
 }
+
+#[test]
+fn multisig_payload_must_be_canonical_bcs() {
+    let payload = MultisigTransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(AccountAddress::ONE, ident_str!("test").to_owned()),
+        ident_str!("run").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&42u64).unwrap()],
+    ));
+    let payload_bytes = bcs::to_bytes(&payload).unwrap();
+    assert_eq!(
+        deserialize_canonical_bcs::<MultisigTransactionPayload>(&payload_bytes).unwrap(),
+        payload
+    );
+
+    // The variant index 0 encoded as a two-byte ULEB128.
+    let mut non_canonical_bytes = vec![0x80, 0x00];
+    non_canonical_bytes.extend_from_slice(&payload_bytes[1..]);
+    // The payload followed by trailing bytes.
+    let mut trailing_bytes = payload_bytes.clone();
+    trailing_bytes.push(0);
+
+    for bytes in [non_canonical_bytes, trailing_bytes] {
+        let err = deserialize_canonical_bcs::<MultisigTransactionPayload>(&bytes).unwrap_err();
+        assert_eq!(
+            err.major_status(),
+            StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT
+        );
+    }
+
+    // A set is decoded from its elements in any order, but always encoded in sorted order, so
+    // these bytes decode successfully without being canonical.
+    let unsorted_set_bytes = vec![2, 2, 1];
+    assert_eq!(
+        bcs::from_bytes::<BTreeSet<u8>>(&unsorted_set_bytes).unwrap(),
+        BTreeSet::from([1, 2])
+    );
+    let err = deserialize_canonical_bcs::<BTreeSet<u8>>(&unsorted_set_bytes).unwrap_err();
+    assert_eq!(
+        err.major_status(),
+        StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT
+    );
+    assert_eq!(
+        deserialize_canonical_bcs::<BTreeSet<u8>>(&[2, 1, 2]).unwrap(),
+        BTreeSet::from([1, 2])
+    );
+}
+
+#[test]
+fn mismatched_feature_flags_of_different_lengths() {
+    let mut snapshot = Features { features: vec![] };
+    snapshot.enable(FeatureFlag::MULTI_SIGNER_WRITE_SET_SCRIPT);
+    let mut stored = Features { features: vec![] };
+    stored.enable(FeatureFlag::CODE_DEPENDENCY_CHECK);
+    // The snapshot has more bytes than the stored features, whose missing bits are disabled.
+    assert!(snapshot.features.len() > stored.features.len());
+
+    assert_eq!(mismatched_feature_flags(&snapshot, &stored), vec![
+        "CODE_DEPENDENCY_CHECK (disabled)",
+        "MULTI_SIGNER_WRITE_SET_SCRIPT (enabled)",
+    ]);
+    assert_eq!(mismatched_feature_flags(&stored, &snapshot), vec![
+        "CODE_DEPENDENCY_CHECK (enabled)",
+        "MULTI_SIGNER_WRITE_SET_SCRIPT (disabled)",
+    ]);
+    assert!(mismatched_feature_flags(&snapshot, &snapshot).is_empty());
+}
+
+#[test]
+fn mismatched_feature_flags_unknown_to_binary() {
+    // Flag 255 is not defined in this binary.
+    assert!(FeatureFlag::from_repr(255).is_none());
+    let mut features = vec![0; 32];
+    features[31] = 1 << 7;
+    let snapshot = Features { features };
+
+    assert_eq!(
+        mismatched_feature_flags(&snapshot, &Features { features: vec![] }),
+        vec!["#255 (enabled)"]
+    );
+}
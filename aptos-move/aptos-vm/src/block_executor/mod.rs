@@ -5,8 +5,9 @@
 pub(crate) mod vm_wrapper;
 
 use crate::{
-    block_executor::vm_wrapper::AptosExecutorTask,
+    block_executor::vm_wrapper::{AptosExecutorTask, AptosExecutorTaskArgs},
     counters::{BLOCK_EXECUTOR_CONCURRENCY, BLOCK_EXECUTOR_EXECUTE_BLOCK_SECONDS},
+    gas::get_gas_parameters,
 };
 use aptos_aggregator::{
     delayed_change::DelayedChange, delta_change_set::DeltaOp, resolver::TAggregatorV1View,
@@ -16,6 +17,7 @@ use aptos_block_executor::{
     task::TransactionOutput as BlockExecutorTransactionOutput,
     txn_commit_hook::TransactionCommitHook, types::InputOutputKey,
 };
+use aptos_gas_schedule::{AptosGasParameters, InitialGasSchedule};
 use aptos_infallible::Mutex;
 use aptos_types::{
     block_executor::config::BlockExecutorConfig,
@@ -23,10 +25,12 @@ use aptos_types::{
     delayed_fields::PanicError,
     executable::ExecutableTestType,
     fee_statement::FeeStatement,
+    on_chain_config::{Features, OnChainConfig},
     state_store::{state_key::StateKey, state_value::StateValueMetadata, StateView, StateViewId},
     transaction::{
-        signature_verified_transaction::SignatureVerifiedTransaction, BlockOutput,
-        TransactionOutput, TransactionStatus,
+        analyzed_transaction::{AnalyzedTransaction, StorageLocation},
+        signature_verified_transaction::SignatureVerifiedTransaction,
+        BlockOutput, Transaction, TransactionOutput, TransactionStatus,
     },
     write_set::WriteOp,
 };
@@ -41,7 +45,8 @@ use move_vm_types::delayed_values::delayed_field_id::DelayedFieldID;
 use once_cell::sync::OnceCell;
 use rayon::ThreadPool;
 use std::{
-    collections::{BTreeMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
+    hash::Hash,
     sync::Arc,
 };
 
@@ -391,6 +396,10 @@ impl BlockExecutorTransactionOutput for AptosTransactionOutput {
     }
 }
 
+/// Allowance for the bytes a user transaction reads besides the keys hinted by the entry-function
+/// analysis, such as the on-chain configs and the coin info read by the prologue and epilogue.
+const UNHINTED_READ_BYTES_PER_TRANSACTION: usize = 16 * 1024;
+
 pub struct BlockAptosVM();
 
 impl BlockAptosVM {
@@ -413,6 +422,10 @@ impl BlockAptosVM {
         }
 
         BLOCK_EXECUTOR_CONCURRENCY.set(config.local.concurrency_level as i64);
+        let executor_task_args = AptosExecutorTaskArgs {
+            state_view,
+            skip_prologue_resession: config.local.skip_prologue_resession,
+        };
         let executor = BlockExecutor::<
             SignatureVerifiedTransaction,
             AptosExecutorTask<S>,
@@ -421,9 +434,11 @@ impl BlockAptosVM {
             ExecutableTestType,
         >::new(config, executor_thread_pool, transaction_commit_listener);
 
-        let ret = executor.execute_block(state_view, signature_verified_block, state_view);
+        let ret = executor.execute_block(executor_task_args, signature_verified_block, state_view);
         match ret {
-            Ok(block_output) => {
+            Ok(mut block_output) => {
+                let read_sets = block_output.take_read_sets();
+                let block_end_reason = block_output.block_end_reason();
                 let transaction_outputs = block_output.into_inner();
                 let output_vec: Vec<_> = transaction_outputs
                     .into_iter()
@@ -439,7 +454,9 @@ impl BlockAptosVM {
                     flush_speculative_logs(pos);
                 }
 
-                Ok(BlockOutput::new(output_vec))
+                Ok(BlockOutput::new(output_vec)
+                    .with_read_sets(read_sets)
+                    .with_block_end_reason(block_end_reason))
             },
             Err(BlockExecutionError::FatalBlockExecutorError(PanicError::CodeInvariantError(
                 err_msg,
@@ -449,6 +466,285 @@ impl BlockAptosVM {
                 message: Some(err_msg),
             }),
             Err(BlockExecutionError::FatalVMError(err)) => Err(err),
+            Err(BlockExecutionError::DeadlineExceeded) => Err(VMStatus::error(
+                StatusCode::EXECUTION_LIMIT_REACHED,
+                Some("Block execution deadline exceeded".to_string()),
+            )),
+            Err(BlockExecutionError::Cancelled) => Err(VMStatus::error(
+                StatusCode::EXECUTION_LIMIT_REACHED,
+                Some("Block execution cancelled".to_string()),
+            )),
+        }
+    }
+
+    /// Estimates a conservative upper bound on the memory (in bytes) needed to hold the
+    /// transactions of a block together with the data they read and write, without executing
+    /// them. Module code is not accounted for, as it is cached and shared by the whole block.
+    ///
+    /// For user transactions supported by the entry-function analysis (see
+    /// [`AnalyzedTransaction`]), reads are accounted for by the current size of the hinted keys
+    /// in `state_view`, plus a fixed allowance for the resources read besides them (e.g. by the
+    /// prologue and epilogue), and every hinted write by the maximum size of a write op. All other
+    /// user transactions are assumed to read as many bytes as their max gas amount can pay for.
+    /// Writes of any transaction are bounded by the limit on the size of its write set.
+    pub fn estimate_block_memory(
+        txns: &[SignatureVerifiedTransaction],
+        state_view: &impl StateView,
+    ) -> usize {
+        let features = Features::fetch_config(state_view).unwrap_or_default();
+        let (gas_params, _, _, _, _) = get_gas_parameters(&features, state_view);
+        let gas_params = gas_params.unwrap_or_else(|_| AptosGasParameters::initial());
+        let txn_gas_params = &gas_params.vm.txn;
+        let max_bytes_per_write_op = u64::from(txn_gas_params.max_bytes_per_write_op) as usize;
+        let max_bytes_all_write_ops =
+            u64::from(txn_gas_params.max_bytes_all_write_ops_per_transaction) as usize;
+
+        let stored_size = |key: &StateKey| {
+            let value_size = state_view
+                .get_state_value(key)
+                .ok()
+                .flatten()
+                .map_or(0, |value| value.size());
+            key.size() + value_size
+        };
+
+        txns.iter()
+            .map(|txn| {
+                let signed_txn = match txn {
+                    SignatureVerifiedTransaction::Valid(Transaction::UserTransaction(
+                        signed_txn,
+                    )) => signed_txn,
+                    SignatureVerifiedTransaction::Valid(_) => return max_bytes_all_write_ops,
+                    SignatureVerifiedTransaction::Invalid(_) => return 0,
+                };
+
+                let (read_bytes, write_bytes) = match AnalyzedTransaction::try_read_write_hints(txn)
+                {
+                    Some((reads, writes))
+                        if reads
+                            .iter()
+                            .chain(&writes)
+                            .all(|loc| matches!(loc, StorageLocation::Specific(_))) =>
+                    {
+                        // Read hints exclude written keys, but those are read as well.
+                        let read_bytes = reads
+                            .iter()
+                            .chain(&writes)
+                            .map(|loc| stored_size(loc.state_key()))
+                            .fold(UNHINTED_READ_BYTES_PER_TRANSACTION, usize::saturating_add);
+                        let write_bytes = writes
+                            .len()
+                            .saturating_mul(max_bytes_per_write_op)
+                            .min(max_bytes_all_write_ops);
+                        (read_bytes, write_bytes)
+                    },
+                    _ => {
+                        let max_internal_gas = signed_txn
+                            .max_gas_amount()
+                            .saturating_mul(u64::from(txn_gas_params.gas_unit_scaling_factor));
+                        let read_bytes = max_internal_gas
+                            .checked_div(u64::from(txn_gas_params.storage_io_per_state_byte_read))
+                            .unwrap_or(u64::MAX);
+                        (
+                            usize::try_from(read_bytes).unwrap_or(usize::MAX),
+                            max_bytes_all_write_ops,
+                        )
+                    },
+                };
+
+                signed_txn
+                    .txn_bytes_len()
+                    .saturating_add(read_bytes)
+                    .saturating_add(write_bytes)
+            })
+            .fold(0, usize::saturating_add)
+    }
+}
+
+/// Estimates the speedup of executing a block in parallel, given the keys read and written by
+/// each transaction (in block order) and its cost. A transaction is assumed to depend on every
+/// earlier transaction that wrote a key it reads, and the estimate is the total cost of the block
+/// divided by the cost of the most expensive dependency chain, i.e. the speedup achievable with
+/// unbounded threads and no re-execution overhead.
+pub(crate) fn estimate_parallel_speedup<K: Hash + Eq>(
+    read_sets: &[Vec<K>],
+    write_sets: &[Vec<K>],
+    costs: &[u64],
+) -> f64 {
+    // For every key, the cost of the longest chain ending at its latest writer.
+    let mut key_chain_costs: HashMap<&K, u64> = HashMap::new();
+    let mut total_cost = 0u64;
+    let mut critical_path_cost = 0u64;
+    for ((reads, writes), cost) in read_sets.iter().zip(write_sets).zip(costs) {
+        let chain_cost = reads
+            .iter()
+            .filter_map(|key| key_chain_costs.get(key))
+            .max()
+            .copied()
+            .unwrap_or(0)
+            .saturating_add(*cost);
+        for key in writes {
+            let entry = key_chain_costs.entry(key).or_insert(0);
+            *entry = (*entry).max(chain_cost);
         }
+        total_cost = total_cost.saturating_add(*cost);
+        critical_path_cost = critical_path_cost.max(chain_cost);
+    }
+
+    if critical_path_cost == 0 {
+        1.0
+    } else {
+        total_cost as f64 / critical_path_cost as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_block_executor::txn_commit_hook::NoOpTransactionCommitHook;
+    use aptos_language_e2e_tests::{
+        account::Account, data_store::FakeDataStore, executor::FakeExecutor,
+    };
+    use aptos_types::{
+        state_store::{state_key::inner::StateKeyInner, TStateView},
+        transaction::{EntryFunction, ExecutionStatus},
+    };
+    use move_core_types::{
+        account_address::AccountAddress, ident_str, identifier::Identifier,
+        language_storage::ModuleId,
+    };
+
+    fn aptos_account_entry_function(function: &str, receiver: AccountAddress) -> EntryFunction {
+        EntryFunction::new(
+            ModuleId::new(AccountAddress::ONE, ident_str!("aptos_account").to_owned()),
+            Identifier::new(function).unwrap(),
+            vec![],
+            vec![
+                bcs::to_bytes(&receiver).unwrap(),
+                bcs::to_bytes(&100u64).unwrap(),
+            ],
+        )
+    }
+
+    #[test]
+    fn estimate_block_memory_should_bound_analyzed_transactions_tighter() {
+        let state_view = FakeDataStore::default();
+        let sender = Account::new();
+        let receiver = Account::new();
+        let txn = |function: &str| {
+            let signed_txn = sender
+                .transaction()
+                .entry_function(aptos_account_entry_function(function, *receiver.address()))
+                .sequence_number(0)
+                .sign();
+            SignatureVerifiedTransaction::Valid(Transaction::UserTransaction(signed_txn))
+        };
+
+        let analyzed = BlockAptosVM::estimate_block_memory(&[txn("transfer")], &state_view);
+        let not_analyzed =
+            BlockAptosVM::estimate_block_memory(&[txn("transfer_coins")], &state_view);
+        assert!(analyzed > 0);
+        assert!(analyzed < not_analyzed);
+        assert_eq!(
+            BlockAptosVM::estimate_block_memory(&[txn("transfer"), txn("transfer")], &state_view),
+            2 * analyzed
+        );
+        assert_eq!(BlockAptosVM::estimate_block_memory(&[], &state_view), 0);
+    }
+
+    #[test]
+    fn estimate_block_memory_should_bound_executed_transfer() {
+        let mut executor = FakeExecutor::from_head_genesis();
+        let sender = executor.create_raw_account_data(1_000_000_000, 0);
+        let receiver = executor.create_raw_account_data(1_000_000_000, 0);
+        executor.add_account_data(&sender);
+        executor.add_account_data(&receiver);
+        let signed_txn = sender
+            .account()
+            .transaction()
+            .entry_function(aptos_account_entry_function(
+                "transfer",
+                *receiver.address(),
+            ))
+            .sequence_number(0)
+            .sign();
+        let txns = [SignatureVerifiedTransaction::Valid(
+            Transaction::UserTransaction(signed_txn.clone()),
+        )];
+        let state_view = executor.get_state_view();
+        let estimate = BlockAptosVM::estimate_block_memory(&txns, state_view);
+
+        let mut config = BlockExecutorConfig::new_no_block_limit(1);
+        config.local.record_read_sets = true;
+        let mut block_output = BlockAptosVM::execute_block::<
+            _,
+            NoOpTransactionCommitHook<AptosTransactionOutput, VMStatus>,
+        >(
+            Arc::new(rayon::ThreadPoolBuilder::new().build().unwrap()),
+            &txns,
+            state_view,
+            config,
+            None,
+        )
+        .unwrap();
+        let read_set = block_output.take_read_sets().unwrap().remove(0);
+        let output = block_output.into_transaction_outputs_forced().remove(0);
+        assert_eq!(
+            output.status(),
+            &TransactionStatus::Keep(ExecutionStatus::Success)
+        );
+
+        // Module code is shared by the block, so it is not part of the estimate.
+        let is_code = |key: &StateKey| match key.inner() {
+            StateKeyInner::AccessPath(path) => path.is_code(),
+            _ => false,
+        };
+        let read_bytes: usize = read_set
+            .iter()
+            .filter(|key| !is_code(key))
+            .map(|key| {
+                let value = state_view.get_state_value(key).unwrap();
+                key.size() + value.map_or(0, |value| value.size())
+            })
+            .sum();
+        let write_bytes: usize = output
+            .write_set()
+            .iter()
+            .map(|(key, op)| key.size() + op.size())
+            .sum();
+        assert!(read_bytes > 0);
+        assert!(estimate >= signed_txn.txn_bytes_len() + read_bytes + write_bytes);
+    }
+
+    #[test]
+    fn estimate_parallel_speedup_should_follow_read_after_write_dependencies() {
+        // Independent transactions can all run in parallel.
+        assert_eq!(
+            estimate_parallel_speedup(
+                &[vec![1], vec![2], vec![3]],
+                &[vec![1], vec![2], vec![3]],
+                &[1, 1, 1]
+            ),
+            3.0
+        );
+        // A chain of read-after-write dependencies is sequential.
+        assert_eq!(
+            estimate_parallel_speedup(
+                &[vec![0], vec![1], vec![2]],
+                &[vec![1], vec![2], vec![3]],
+                &[1, 1, 1]
+            ),
+            1.0
+        );
+        // Two independent chains, the longer one (by cost) bounds the speedup.
+        assert_eq!(
+            estimate_parallel_speedup(
+                &[vec![], vec![], vec![1], vec![2]],
+                &[vec![1], vec![2], vec![3], vec![4]],
+                &[1, 3, 1, 3]
+            ),
+            8.0 / 6.0
+        );
+        assert_eq!(estimate_parallel_speedup::<u32>(&[], &[], &[]), 1.0);
     }
 }
@@ -24,22 +24,39 @@ pub(crate) struct AptosExecutorTask<'a, S> {
     base_view: &'a S,
 }
 
+/// Arguments every worker uses to initialize its [AptosExecutorTask].
+pub(crate) struct AptosExecutorTaskArgs<'a, S> {
+    pub(crate) state_view: &'a S,
+    /// See `AptosVM::set_skip_prologue_resession`.
+    pub(crate) skip_prologue_resession: bool,
+}
+
+// Implemented manually, as deriving would require the state view itself to be `Copy`.
+impl<'a, S> Clone for AptosExecutorTaskArgs<'a, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, S> Copy for AptosExecutorTaskArgs<'a, S> {}
+
 impl<'a, S: 'a + StateView + Sync> ExecutorTask for AptosExecutorTask<'a, S> {
-    type Argument = &'a S;
+    type Argument = AptosExecutorTaskArgs<'a, S>;
     type Error = VMStatus;
     type Output = AptosTransactionOutput;
     type Txn = SignatureVerifiedTransaction;
 
-    fn init(argument: &'a S) -> Self {
+    fn init(argument: AptosExecutorTaskArgs<'a, S>) -> Self {
         // AptosVM has to be initialized using configs from storage.
-        let vm = AptosVM::new(
-            &argument.as_move_resolver(),
+        let mut vm = AptosVM::new(
+            &argument.state_view.as_move_resolver(),
             /*override_is_delayed_field_optimization_capable=*/ Some(true),
         );
+        vm.set_skip_prologue_resession(argument.skip_prologue_resession);
 
         Self {
             vm,
-            base_view: argument,
+            base_view: argument.state_view,
         }
     }
 
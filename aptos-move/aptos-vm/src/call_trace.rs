@@ -0,0 +1,84 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Records the calls into Move made while executing a single transaction. A transaction is
+//! executed on a single thread, so the trace is kept in a thread local and does not need to be
+//! threaded through the sessions. Recording is only started for VMs on which
+//! `AptosVM::set_record_call_trace` was enabled.
+
+use aptos_vm_types::call_trace::{CallTrace, TracedCall};
+use move_core_types::{identifier::IdentStr, language_storage::ModuleId};
+use std::cell::RefCell;
+
+thread_local! {
+    static CALL_TRACE: RefCell<Option<CallTrace>> = RefCell::new(None);
+}
+
+/// Starts recording calls on the current thread, dropping any unfinished trace.
+pub(crate) fn start() {
+    CALL_TRACE.with(|trace| *trace.borrow_mut() = Some(vec![]));
+}
+
+/// Stops recording calls on the current thread, returning the recorded trace if any.
+pub(crate) fn finish() -> Option<CallTrace> {
+    CALL_TRACE.with(|trace| trace.borrow_mut().take())
+}
+
+fn record(call: impl FnOnce() -> TracedCall) {
+    CALL_TRACE.with(|trace| {
+        if let Some(trace) = trace.borrow_mut().as_mut() {
+            trace.push(call());
+        }
+    });
+}
+
+pub(crate) fn record_script() {
+    record(|| TracedCall::Script);
+}
+
+pub(crate) fn record_entry_function(module: &ModuleId, function: &IdentStr) {
+    record(|| TracedCall::EntryFunction {
+        module: module.clone(),
+        function: function.to_owned(),
+    });
+}
+
+pub(crate) fn record_function(module: &ModuleId, function: &IdentStr) {
+    record(|| TracedCall::Function {
+        module: module.clone(),
+        function: function.to_owned(),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_core_types::{account_address::AccountAddress, ident_str};
+
+    #[test]
+    fn test_call_trace_recording() {
+        let module = ModuleId::new(AccountAddress::ONE, ident_str!("coin").to_owned());
+
+        // Nothing is recorded unless recording was started.
+        record_script();
+        assert_eq!(finish(), None);
+
+        start();
+        record_script();
+        record_entry_function(&module, ident_str!("transfer"));
+        record_function(&module, ident_str!("initialize"));
+        let trace = finish().unwrap();
+        assert_eq!(
+            trace
+                .iter()
+                .map(|call| call.to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                "script".to_string(),
+                format!("entry {}::transfer", module),
+                format!("{}::initialize", module),
+            ]
+        );
+        assert_eq!(finish(), None);
+    }
+}
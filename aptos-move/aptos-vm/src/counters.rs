@@ -3,9 +3,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use aptos_metrics_core::{
-    exponential_buckets, register_histogram, register_histogram_vec, register_int_counter,
-    register_int_counter_vec, register_int_gauge, Histogram, HistogramVec, IntCounter,
-    IntCounterVec, IntGauge,
+    exponential_buckets, op_counters::OpMetrics, register_histogram, register_histogram_vec,
+    register_int_counter, register_int_counter_vec, register_int_gauge, Histogram, HistogramVec,
+    IntCounter, IntCounterVec, IntGauge,
 };
 use once_cell::sync::Lazy;
 
@@ -74,6 +74,46 @@ pub static USER_TRANSACTIONS_EXECUTED: Lazy<IntCounterVec> = Lazy::new(|| {
     .unwrap()
 });
 
+/// Count the number of discarded user transactions, with a "status_code" label
+/// to distinguish the reasons for which they were discarded.
+pub static DISCARDED_TRANSACTIONS_BY_CODE: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "aptos_vm_discarded_transactions_by_code",
+        "Number of user transactions discarded, by status code",
+        &["status_code"]
+    )
+    .unwrap()
+});
+
+/// Count the number of transactions whose arguments come close to or exceed the size limit, with
+/// a "status" label to distinguish the two cases.
+pub static TXN_ARGS_SIZE_LIMIT: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "aptos_vm_txn_args_size_limit",
+        "Number of transactions with arguments close to or above the size limit",
+        &["status"]
+    )
+    .unwrap()
+});
+
+/// Count the number of user transactions aborted for exceeding the dynamic dispatch limit.
+pub static TXN_DYNAMIC_DISPATCH_LIMIT_REACHED: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "aptos_vm_txn_dynamic_dispatch_limit_reached",
+        "Number of user transactions that exceeded the dynamic dispatch limit"
+    )
+    .unwrap()
+});
+
+/// Count the number of user transactions aborted for creating too many new state slots.
+pub static TXN_RESOURCE_CREATION_LIMIT_REACHED: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "aptos_vm_txn_resource_creation_limit_reached",
+        "Number of user transactions that exceeded the resource creation limit"
+    )
+    .unwrap()
+});
+
 /// Count the number of system transactions executed.
 pub static SYSTEM_TRANSACTIONS_EXECUTED: Lazy<IntCounter> = Lazy::new(|| {
     register_int_counter!(
@@ -148,3 +188,8 @@ pub static TIMER: Lazy<HistogramVec> = Lazy::new(|| {
     )
     .unwrap()
 });
+
+/// Number of sessions respawned on top of a previous session's change set (e.g. for the
+/// epilogue), and the sizes of these change sets.
+pub static RESPAWNED_SESSIONS: Lazy<OpMetrics> =
+    Lazy::new(|| OpMetrics::new_and_registered("aptos_vm_respawned_sessions"));
@@ -155,6 +155,13 @@ impl<'e, E: ExecutorView> ResourceGroupResolver for StorageAdapter<'e, E> {
         self.resource_group_view.release_group_cache()
     }
 
+    fn release_resource_group_cache_for(
+        &self,
+        group_key: &StateKey,
+    ) -> Option<BTreeMap<StructTag, Bytes>> {
+        self.resource_group_view.release_group_cache_for(group_key)
+    }
+
     fn resource_group_size(&self, group_key: &StateKey) -> PartialVMResult<ResourceGroupSize> {
         self.resource_group_view.resource_group_size(group_key)
     }
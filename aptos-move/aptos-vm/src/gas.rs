@@ -17,6 +17,7 @@ use aptos_vm_logging::{log_schema::AdapterLogSchema, speculative_log, speculativ
 use aptos_vm_types::storage::{
     io_pricing::IoPricing, space_pricing::DiskSpacePricing, StorageGasParameters,
 };
+use move_binary_format::errors::{PartialVMError, PartialVMResult};
 use move_core_types::{
     gas_algebra::NumArgs,
     vm_status::{StatusCode, VMStatus},
@@ -25,6 +26,18 @@ use move_core_types::{
 /// This is used until gas version 18, which introduces a configurable entry for this.
 const MAXIMUM_APPROVED_TRANSACTION_SIZE_LEGACY: u64 = 1024 * 1024;
 
+/// Returns the gas used by a transaction, i.e., its max gas amount minus the balance left in its
+/// gas meter. The balance can never exceed the max gas amount, but if it does, an invariant
+/// violation is returned so that the transaction can be discarded instead of halting the node.
+pub(crate) fn gas_used(max_gas_amount: Gas, balance: Gas) -> PartialVMResult<Gas> {
+    max_gas_amount.checked_sub(balance).ok_or_else(|| {
+        PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR).with_message(format!(
+            "Gas meter balance {} exceeds the max gas amount {}",
+            balance, max_gas_amount
+        ))
+    })
+}
+
 pub fn get_gas_config_from_storage(
     config_storage: &impl ConfigStorage,
 ) -> (Result<AptosGasParameters, String>, u64) {
@@ -147,7 +160,6 @@ pub(crate) fn check_gas(
     log_context: &AdapterLogSchema,
 ) -> Result<(), VMStatus> {
     let txn_gas_params = &gas_params.vm.txn;
-    let raw_bytes_len = txn_metadata.transaction_size;
 
     if is_approved_gov_script {
         let max_txn_size_gov = if gas_feature_version >= RELEASE_V1_13 {
@@ -210,15 +222,7 @@ pub(crate) fn check_gas(
     // The submitted transactions max gas units needs to be at least enough to cover the
     // intrinsic cost of the transaction as calculated against the size of the
     // underlying `RawTransaction`.
-    let keyless = if txn_metadata.is_keyless() {
-        KEYLESS_BASE_COST.evaluate(gas_feature_version, &gas_params.vm)
-    } else {
-        InternalGas::zero()
-    };
-    let intrinsic_gas = txn_gas_params
-        .calculate_intrinsic_gas(raw_bytes_len)
-        .evaluate(gas_feature_version, &gas_params.vm);
-    let total_rounded: Gas = (intrinsic_gas + keyless).to_unit_round_up_with_params(txn_gas_params);
+    let total_rounded = intrinsic_gas_units(gas_params, gas_feature_version, txn_metadata);
 
     if txn_metadata.max_gas_amount() < total_rounded {
         speculative_warn!(
@@ -275,16 +279,17 @@ pub(crate) fn check_gas(
     // gas to cover storage, execution, and IO costs.
     // TODO: This isn't the cleaning code, thus we localize it just here and will remove it
     // once accountv2 is available and we no longer need to create accounts.
-    if crate::aptos_vm::is_account_init_for_sponsored_transaction(txn_metadata, features, resolver)?
-    {
+    if let Some(account_creation_fee) = sponsored_account_creation_fee(
+        gas_params,
+        gas_feature_version,
+        resolver,
+        txn_metadata,
+        features,
+    )? {
         let gas_unit_price: u64 = txn_metadata.gas_unit_price().into();
         let max_gas_amount: u64 = txn_metadata.max_gas_amount().into();
-        let pricing = DiskSpacePricing::new(gas_feature_version, features);
-        let storage_fee_per_account_create: u64 = pricing
-            .hack_estimated_fee_for_account_creation(txn_gas_params)
-            .into();
 
-        let expected = gas_unit_price * 10 + 2 * storage_fee_per_account_create;
+        let expected = gas_unit_price * 10 + account_creation_fee;
         let actual = gas_unit_price * max_gas_amount;
 
         if actual < expected {
@@ -304,3 +309,93 @@ pub(crate) fn check_gas(
 
     Ok(())
 }
+
+/// Returns a coarse estimate of the execution and storage gas units a transaction is going to
+/// use, derived from the same analysis as [`check_gas`]: the execution part covers the intrinsic
+/// cost, and the storage part covers the account creation of sponsored transactions.
+pub(crate) fn estimate_gas_split(
+    gas_params: &AptosGasParameters,
+    gas_feature_version: u64,
+    resolver: &impl AptosMoveResolver,
+    txn_metadata: &TransactionMetadata,
+    features: &Features,
+) -> Result<(Gas, Gas), VMStatus> {
+    let execution_gas = intrinsic_gas_units(gas_params, gas_feature_version, txn_metadata);
+
+    let storage_fee = sponsored_account_creation_fee(
+        gas_params,
+        gas_feature_version,
+        resolver,
+        txn_metadata,
+        features,
+    )?
+    .unwrap_or(0);
+    let gas_unit_price: u64 = txn_metadata.gas_unit_price().into();
+    let storage_gas = if gas_unit_price == 0 {
+        0
+    } else {
+        storage_fee.div_ceil(gas_unit_price)
+    };
+
+    Ok((execution_gas, Gas::new(storage_gas)))
+}
+
+/// Returns the number of gas units needed to cover the intrinsic cost of the transaction, which
+/// depends on the size of the underlying `RawTransaction` and on its authenticator.
+fn intrinsic_gas_units(
+    gas_params: &AptosGasParameters,
+    gas_feature_version: u64,
+    txn_metadata: &TransactionMetadata,
+) -> Gas {
+    let txn_gas_params = &gas_params.vm.txn;
+    let keyless = if txn_metadata.is_keyless() {
+        KEYLESS_BASE_COST.evaluate(gas_feature_version, &gas_params.vm)
+    } else {
+        InternalGas::zero()
+    };
+    let intrinsic_gas = txn_gas_params
+        .calculate_intrinsic_gas(txn_metadata.transaction_size)
+        .evaluate(gas_feature_version, &gas_params.vm);
+    (intrinsic_gas + keyless).to_unit_round_up_with_params(txn_gas_params)
+}
+
+/// Returns the storage fee (in octas) expected for a sponsored transaction which creates its
+/// sender account, or `None` if the transaction does not create an account.
+fn sponsored_account_creation_fee(
+    gas_params: &AptosGasParameters,
+    gas_feature_version: u64,
+    resolver: &impl AptosMoveResolver,
+    txn_metadata: &TransactionMetadata,
+    features: &Features,
+) -> Result<Option<u64>, VMStatus> {
+    if !crate::aptos_vm::is_account_init_for_sponsored_transaction(
+        txn_metadata,
+        features,
+        resolver,
+    )? {
+        return Ok(None);
+    }
+
+    let pricing = DiskSpacePricing::new(gas_feature_version, features);
+    let storage_fee_per_account_create: u64 = pricing
+        .hack_estimated_fee_for_account_creation(&gas_params.vm.txn)
+        .into();
+    Ok(Some(2 * storage_fee_per_account_create))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gas_used() {
+        assert_eq!(gas_used(100.into(), 30.into()).unwrap(), 70.into());
+        assert_eq!(gas_used(100.into(), 100.into()).unwrap(), 0.into());
+        assert_eq!(gas_used(0.into(), 0.into()).unwrap(), 0.into());
+        assert_eq!(
+            gas_used(100.into(), 101.into()).unwrap_err().major_status(),
+            StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR
+        );
+        assert!(gas_used(0.into(), u64::MAX.into()).is_err());
+    }
+}
@@ -107,6 +107,7 @@ pub mod data_cache;
 
 pub mod aptos_vm;
 pub mod block_executor;
+mod call_trace;
 mod errors;
 pub mod gas;
 #[cfg(not(feature = "testing"))]
@@ -137,7 +138,11 @@ use aptos_types::{
     },
     vm_status::VMStatus,
 };
-use std::{marker::Sync, sync::Arc};
+use std::{
+    marker::Sync,
+    sync::{atomic::AtomicBool, Arc},
+    time::Instant,
+};
 pub use verifier::view_function::determine_is_view;
 
 /// This trait describes the VM's validation interfaces.
@@ -164,6 +169,30 @@ pub trait VMExecutor: Send + Sync {
         onchain_config: BlockExecutorConfigFromOnchain,
     ) -> Result<BlockOutput<TransactionOutput>, VMStatus>;
 
+    /// Executes a block of transactions like `execute_block`, but fails with an
+    /// `EXECUTION_LIMIT_REACHED` error if the execution does not finish before `deadline`.
+    /// On timeout the block is rejected as a whole, no partial outputs are returned.
+    ///
+    /// By default the deadline is ignored, executors that support it should override this.
+    fn execute_block_with_deadline(
+        transactions: &[SignatureVerifiedTransaction],
+        state_view: &(impl StateView + Sync),
+        onchain_config: BlockExecutorConfigFromOnchain,
+        _deadline: Instant,
+    ) -> Result<BlockOutput<TransactionOutput>, VMStatus> {
+        Self::execute_block(transactions, state_view, onchain_config)
+    }
+
+    /// Executes a block of transactions like `execute_block`, but fails with an
+    /// `EXECUTION_LIMIT_REACHED` error once `cancelled` is set, e.g. from another thread.
+    /// The flag is checked between transactions, and a cancelled block is rejected as a whole.
+    fn execute_block_with_cancellation(
+        transactions: &[SignatureVerifiedTransaction],
+        state_view: &(impl StateView + Sync),
+        onchain_config: BlockExecutorConfigFromOnchain,
+        cancelled: Arc<AtomicBool>,
+    ) -> Result<BlockOutput<TransactionOutput>, VMStatus>;
+
     /// Executes a block of transactions and returns output for each one of them,
     /// Without applying any block limit
     fn execute_block_no_limit(
@@ -178,6 +207,39 @@ pub trait VMExecutor: Send + Sync {
         .map(BlockOutput::into_transaction_outputs_forced)
     }
 
+    /// Executes a block of transactions like `execute_block`, but instead of discarding all
+    /// outputs on a fatal error, returns the outputs of the longest prefix of the block that
+    /// executes successfully together with the error. Meant for investigating block-level
+    /// failures, production code should keep using `execute_block`.
+    ///
+    /// The prefix is found by re-executing prefixes of the block, assuming that a prefix fails if
+    /// and only if it contains a transaction causing the fatal error.
+    fn execute_block_partial(
+        transactions: &[SignatureVerifiedTransaction],
+        state_view: &(impl StateView + Sync),
+        onchain_config: BlockExecutorConfigFromOnchain,
+    ) -> (Vec<TransactionOutput>, Option<VMStatus>) {
+        let status = match Self::execute_block(transactions, state_view, onchain_config.clone()) {
+            Ok(output) => return (output.into_inner(), None),
+            Err(status) => status,
+        };
+
+        let mut outputs = vec![];
+        // Invariant: the first `succeeded` transactions execute fine, the first `failed` do not.
+        let (mut succeeded, mut failed) = (0, transactions.len());
+        while succeeded + 1 < failed {
+            let mid = succeeded + (failed - succeeded) / 2;
+            match Self::execute_block(&transactions[..mid], state_view, onchain_config.clone()) {
+                Ok(output) => {
+                    succeeded = mid;
+                    outputs = output.into_inner();
+                },
+                Err(_) => failed = mid,
+            }
+        }
+        (outputs, Some(status))
+    }
+
     /// Executes a block of transactions using a sharded block executor and returns the results.
     fn execute_block_sharded<S: StateView + Sync + Send + 'static, E: ExecutorClient<S>>(
         sharded_block_executor: &ShardedBlockExecutor<S, E>,
@@ -32,6 +32,13 @@ pub trait ResourceGroupResolver {
     fn release_resource_group_cache(&self)
         -> Option<HashMap<StateKey, BTreeMap<StructTag, Bytes>>>;
 
+    /// Releases the cache of a single resource group, so that callers handling one group do
+    /// not have to flush the cache of all other groups.
+    fn release_resource_group_cache_for(
+        &self,
+        group_key: &StateKey,
+    ) -> Option<BTreeMap<StructTag, Bytes>>;
+
     fn resource_group_size(&self, group_key: &StateKey) -> PartialVMResult<ResourceGroupSize>;
 
     fn resource_size_in_group(
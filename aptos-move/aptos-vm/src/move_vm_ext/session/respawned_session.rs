@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
+    counters::RESPAWNED_SESSIONS,
     data_cache::StorageAdapter,
     move_vm_ext::{
         session::view_with_change_set::ExecutorViewWithChangeSet, AptosMoveResolver, SessionExt,
@@ -41,6 +42,16 @@ impl<'r, 'l> RespawnedSession<'r, 'l> {
         previous_session_change_set: VMChangeSet,
         user_transaction_context_opt: Option<UserTransactionContext>,
     ) -> Result<Self, VMStatus> {
+        RESPAWNED_SESSIONS.inc("spawned");
+        RESPAWNED_SESSIONS.observe(
+            "change_set_num_write_ops",
+            previous_session_change_set.num_write_ops() as f64,
+        );
+        RESPAWNED_SESSIONS.observe(
+            "change_set_num_events",
+            previous_session_change_set.events().len() as f64,
+        );
+
         let executor_view = ExecutorViewWithChangeSet::new(
             base.as_executor_view(),
             base.as_resource_group_view(),
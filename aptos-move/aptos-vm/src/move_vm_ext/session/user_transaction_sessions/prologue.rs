@@ -47,10 +47,13 @@ impl<'r, 'l> PrologueSession<'r, 'l> {
         resolver: &'r impl AptosMoveResolver,
         gas_feature_version: u64,
         change_set_configs: &ChangeSetConfigs,
+        skip_resession: bool,
     ) -> Result<(VMChangeSet, UserSession<'r, 'l>), VMStatus> {
         let Self { session } = self;
 
-        if gas_feature_version >= 1 {
+        // Trusted re-execution may opt out of the flush below, at the cost of gas accounting that
+        // no longer matches normal execution.
+        if gas_feature_version >= 1 && !skip_resession {
             // Create a new session so that the data cache is flushed.
             // This is to ensure we correctly charge for loading certain resources, even if they
             // have been previously cached in the prologue.
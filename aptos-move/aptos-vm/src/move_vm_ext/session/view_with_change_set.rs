@@ -310,6 +310,13 @@ impl<'r> TResourceGroupView for ExecutorViewWithChangeSet<'r> {
         unreachable!("Must not be called by RespawnedSession finish");
     }
 
+    fn release_group_cache_for(
+        &self,
+        _group_key: &Self::GroupKey,
+    ) -> Option<BTreeMap<Self::ResourceTag, Bytes>> {
+        unreachable!("Must not be called by RespawnedSession finish");
+    }
+
     fn is_resource_groups_split_in_change_set_capable(&self) -> bool {
         self.base_resource_group_view
             .is_resource_groups_split_in_change_set_capable()
@@ -347,7 +354,7 @@ mod test {
         move_vm_ext::resolver::{AsExecutorView, AsResourceGroupView},
     };
     use aptos_aggregator::delta_change_set::{delta_add, serialize};
-    use aptos_language_e2e_tests::data_store::FakeDataStore;
+    use aptos_language_e2e_tests::data_store::StateOverlay;
     use aptos_types::{account_address::AccountAddress, write_set::WriteOp};
     use aptos_vm_types::{abstract_write_op::GroupWrite, check_change_set::CheckChangeSet};
     use move_core_types::{
@@ -426,23 +433,21 @@ mod test {
 
     #[test]
     fn test_change_set_state_view() {
-        let mut state_view = FakeDataStore::default();
-        state_view.set_legacy(key("module_base"), serialize(&10));
-        state_view.set_legacy(key("module_both"), serialize(&20));
-
-        state_view.set_legacy(key("resource_base"), serialize(&30));
-        state_view.set_legacy(key("resource_both"), serialize(&40));
-
-        state_view.set_legacy(key("aggregator_base"), serialize(&50));
-        state_view.set_legacy(key("aggregator_both"), serialize(&60));
-        state_view.set_legacy(key("aggregator_delta_set"), serialize(&70));
-
         let tree: BTreeMap<StructTag, Bytes> = BTreeMap::from([
             (mock_tag_0(), serialize(&100).into()),
             (mock_tag_1(), serialize(&200).into()),
         ]);
-        state_view.set_legacy(key("resource_group_base"), bcs::to_bytes(&tree).unwrap());
-        state_view.set_legacy(key("resource_group_both"), bcs::to_bytes(&tree).unwrap());
+        let state_view = StateOverlay::builder()
+            .raw(key("module_base"), serialize(&10))
+            .raw(key("module_both"), serialize(&20))
+            .raw(key("resource_base"), serialize(&30))
+            .raw(key("resource_both"), serialize(&40))
+            .aggregator(key("aggregator_base"), 50)
+            .aggregator(key("aggregator_both"), 60)
+            .aggregator(key("aggregator_delta_set"), 70)
+            .raw(key("resource_group_base"), bcs::to_bytes(&tree).unwrap())
+            .raw(key("resource_group_both"), bcs::to_bytes(&tree).unwrap())
+            .build();
 
         let resource_write_set = BTreeMap::from([
             (key("resource_both"), (write(80), None)),
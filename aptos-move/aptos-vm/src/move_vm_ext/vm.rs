@@ -76,6 +76,7 @@ impl MoveVmExt {
         gas_hook: Option<F>,
         resolver: &impl AptosMoveResolver,
         aggregator_v2_type_tagging: bool,
+        max_dynamic_dispatches: Option<u64>,
     ) -> VMResult<Self>
     where
         F: Fn(DynamicExpression) + Send + Sync + 'static,
@@ -133,6 +134,7 @@ impl MoveVmExt {
                     type_base_cost,
                     type_byte_cost,
                     aggregator_v2_type_tagging,
+                    max_dynamic_dispatches,
                 },
                 resolver,
             )?,
@@ -150,6 +152,7 @@ impl MoveVmExt {
         timed_features: TimedFeatures,
         resolver: &impl AptosMoveResolver,
         aggregator_v2_type_tagging: bool,
+        max_dynamic_dispatches: Option<u64>,
     ) -> VMResult<Self> {
         Self::new_impl::<fn(DynamicExpression)>(
             native_gas_params,
@@ -161,6 +164,7 @@ impl MoveVmExt {
             None,
             resolver,
             aggregator_v2_type_tagging,
+            max_dynamic_dispatches,
         )
     }
 
@@ -174,6 +178,7 @@ impl MoveVmExt {
         gas_hook: Option<F>,
         resolver: &impl AptosMoveResolver,
         aggregator_v2_type_tagging: bool,
+        max_dynamic_dispatches: Option<u64>,
     ) -> VMResult<Self>
     where
         F: Fn(DynamicExpression) + Send + Sync + 'static,
@@ -188,6 +193,7 @@ impl MoveVmExt {
             gas_hook,
             resolver,
             aggregator_v2_type_tagging,
+            max_dynamic_dispatches,
         )
     }
 
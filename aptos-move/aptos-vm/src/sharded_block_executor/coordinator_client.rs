@@ -1,7 +1,7 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::sharded_block_executor::ExecutorShardCommand;
+use crate::sharded_block_executor::{executor_client::ViewFunctionResult, ExecutorShardCommand};
 use aptos_types::{state_store::StateView, transaction::TransactionOutput};
 use move_core_types::vm_status::VMStatus;
 
@@ -10,4 +10,6 @@ pub trait CoordinatorClient<S: StateView + Sync + Send + 'static>: Send + Sync {
     fn receive_execute_command(&self) -> ExecutorShardCommand<S>;
 
     fn send_execution_result(&self, result: Result<Vec<Vec<TransactionOutput>>, VMStatus>);
+
+    fn send_view_function_results(&self, results: Vec<ViewFunctionResult>);
 }
@@ -6,9 +6,14 @@ use aptos_types::{
         config::BlockExecutorConfigFromOnchain, partitioner::PartitionedTransactions,
     },
     state_store::StateView,
-    transaction::TransactionOutput,
+    transaction::{TransactionOutput, ViewFunctionOutput},
 };
-use move_core_types::vm_status::VMStatus;
+use move_core_types::{
+    identifier::Identifier,
+    language_storage::{ModuleId, TypeTag},
+    vm_status::VMStatus,
+};
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
 pub struct ShardedExecutionOutput {
@@ -32,6 +37,63 @@ impl ShardedExecutionOutput {
     }
 }
 
+/// A view function call to be executed by one of the executor shards.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ViewFunctionRequest {
+    pub module_id: ModuleId,
+    pub func_name: Identifier,
+    pub type_args: Vec<TypeTag>,
+    pub arguments: Vec<Vec<u8>>,
+    pub max_gas_amount: u64,
+}
+
+impl ViewFunctionRequest {
+    pub fn new(
+        module_id: ModuleId,
+        func_name: Identifier,
+        type_args: Vec<TypeTag>,
+        arguments: Vec<Vec<u8>>,
+        max_gas_amount: u64,
+    ) -> Self {
+        Self {
+            module_id,
+            func_name,
+            type_args,
+            arguments,
+            max_gas_amount,
+        }
+    }
+}
+
+/// Serializable counterpart of `ViewFunctionOutput`, so that it can be sent across the shard boundary.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ViewFunctionResult {
+    pub values: Result<Vec<Vec<u8>>, String>,
+    pub gas_used: u64,
+    pub gas_feature_version: Option<u64>,
+}
+
+impl From<ViewFunctionOutput> for ViewFunctionResult {
+    fn from(output: ViewFunctionOutput) -> Self {
+        Self {
+            values: output.values.map_err(|e| e.to_string()),
+            gas_used: output.gas_used,
+            gas_feature_version: output.gas_feature_version,
+        }
+    }
+}
+
+impl From<ViewFunctionResult> for ViewFunctionOutput {
+    fn from(result: ViewFunctionResult) -> Self {
+        ViewFunctionOutput {
+            values: result.values.map_err(anyhow::Error::msg),
+            gas_used: result.gas_used,
+            gas_feature_version: result.gas_feature_version,
+            read_set: None,
+        }
+    }
+}
+
 // Interface to communicate from the block executor coordinator to the executor shards.
 pub trait ExecutorClient<S: StateView + Sync + Send + 'static>: Send + Sync {
     fn num_shards(&self) -> usize;
@@ -46,5 +108,13 @@ pub trait ExecutorClient<S: StateView + Sync + Send + 'static>: Send + Sync {
         onchain_config: BlockExecutorConfigFromOnchain,
     ) -> Result<ShardedExecutionOutput, VMStatus>;
 
+    // A blocking call that executes the view functions assigned to each shard. It returns the results from each
+    // shard in the order of the requests.
+    fn execute_view_functions(
+        &self,
+        state_view: Arc<S>,
+        requests: Vec<Vec<ViewFunctionRequest>>,
+    ) -> Vec<Vec<ViewFunctionResult>>;
+
     fn shutdown(&mut self);
 }
@@ -64,6 +64,11 @@ impl<S: StateView + Sync + Send + 'static> GlobalExecutor<S> {
                     concurrency_level: self.concurrency_level,
                     allow_fallback: true,
                     discard_failed_blocks: false,
+                    deadline: None,
+                    cancelled: None,
+                    record_read_sets: false,
+                    abort_rate_alert_threshold_pct: None,
+                    skip_prologue_resession: false,
                 },
                 onchain: onchain_config,
             },
@@ -5,7 +5,9 @@ use crate::sharded_block_executor::{
     coordinator_client::CoordinatorClient,
     counters::WAIT_FOR_SHARDED_OUTPUT_SECONDS,
     cross_shard_client::CrossShardClient,
-    executor_client::{ExecutorClient, ShardedExecutionOutput},
+    executor_client::{
+        ExecutorClient, ShardedExecutionOutput, ViewFunctionRequest, ViewFunctionResult,
+    },
     global_executor::GlobalExecutor,
     messages::CrossShardMsg,
     sharded_aggregator_service,
@@ -42,9 +44,14 @@ impl<S: StateView + Sync + Send + 'static> LocalExecutorService<S> {
         num_threads: usize,
         command_rx: Receiver<ExecutorShardCommand<S>>,
         result_tx: Sender<Result<Vec<Vec<TransactionOutput>>, VMStatus>>,
+        view_result_tx: Sender<Vec<ViewFunctionResult>>,
         cross_shard_client: LocalCrossShardClient,
     ) -> Self {
-        let coordinator_client = Arc::new(LocalCoordinatorClient::new(command_rx, result_tx));
+        let coordinator_client = Arc::new(LocalCoordinatorClient::new(
+            command_rx,
+            result_tx,
+            view_result_tx,
+        ));
         let executor_service = Arc::new(ShardedExecutorService::new(
             shard_id,
             num_shards,
@@ -89,6 +96,10 @@ impl<S: StateView + Sync + Send + 'static> LocalExecutorService<S> {
             Vec<Sender<Result<Vec<Vec<TransactionOutput>>, VMStatus>>>,
             Vec<Receiver<Result<Vec<Vec<TransactionOutput>>, VMStatus>>>,
         ) = (0..num_shards).map(|_| unbounded()).unzip();
+        let (view_result_txs, view_result_rxs): (
+            Vec<Sender<Vec<ViewFunctionResult>>>,
+            Vec<Receiver<Vec<ViewFunctionResult>>>,
+        ) = (0..num_shards).map(|_| unbounded()).unzip();
         // We need to create channels for each shard and each round. This is needed because individual
         // shards might send cross shard messages to other shards that will be consumed in different rounds.
         // Having a single channel per shard will cause a shard to receiver messages that is not intended in the current round.
@@ -105,25 +116,35 @@ impl<S: StateView + Sync + Send + 'static> LocalExecutorService<S> {
         let executor_shards = command_rxs
             .into_iter()
             .zip(result_txs)
+            .zip(view_result_txs)
             .zip(cross_shard_msg_rxs)
             .enumerate()
-            .map(|(shard_id, ((command_rx, result_tx), cross_shard_rxs))| {
-                let cross_shard_client = LocalCrossShardClient::new(
-                    global_cross_shard_tx.clone(),
-                    cross_shard_msg_txs.clone(),
-                    cross_shard_rxs,
-                );
-                Self::new(
-                    shard_id as ShardId,
-                    num_shards,
-                    num_threads,
-                    command_rx,
-                    result_tx,
-                    cross_shard_client,
-                )
-            })
+            .map(
+                |(shard_id, (((command_rx, result_tx), view_result_tx), cross_shard_rxs))| {
+                    let cross_shard_client = LocalCrossShardClient::new(
+                        global_cross_shard_tx.clone(),
+                        cross_shard_msg_txs.clone(),
+                        cross_shard_rxs,
+                    );
+                    Self::new(
+                        shard_id as ShardId,
+                        num_shards,
+                        num_threads,
+                        command_rx,
+                        result_tx,
+                        view_result_tx,
+                        cross_shard_client,
+                    )
+                },
+            )
             .collect();
-        LocalExecutorClient::new(command_txs, result_rxs, executor_shards, global_executor)
+        LocalExecutorClient::new(
+            command_txs,
+            result_rxs,
+            view_result_rxs,
+            executor_shards,
+            global_executor,
+        )
     }
 }
 
@@ -132,6 +153,8 @@ pub struct LocalExecutorClient<S: StateView + Sync + Send + 'static> {
     command_txs: Vec<Sender<ExecutorShardCommand<S>>>,
     // Channels to receive execution results from the executor shards.
     result_rxs: Vec<Receiver<Result<Vec<Vec<TransactionOutput>>, VMStatus>>>,
+    // Channels to receive view function results from the executor shards.
+    view_result_rxs: Vec<Receiver<Vec<ViewFunctionResult>>>,
     executor_services: Vec<LocalExecutorService<S>>,
     global_executor: GlobalExecutor<S>,
 }
@@ -140,12 +163,14 @@ impl<S: StateView + Sync + Send + 'static> LocalExecutorClient<S> {
     pub fn new(
         command_tx: Vec<Sender<ExecutorShardCommand<S>>>,
         result_rx: Vec<Receiver<Result<Vec<Vec<TransactionOutput>>, VMStatus>>>,
+        view_result_rx: Vec<Receiver<Vec<ViewFunctionResult>>>,
         executor_shards: Vec<LocalExecutorService<S>>,
         global_executor: GlobalExecutor<S>,
     ) -> Self {
         Self {
             command_txs: command_tx,
             result_rxs: result_rx,
+            view_result_rxs: view_result_rx,
             executor_services: executor_shards,
             global_executor,
         }
@@ -222,6 +247,33 @@ impl<S: StateView + Sync + Send + 'static> ExecutorClient<S> for LocalExecutorCl
         Ok(ShardedExecutionOutput::new(sharded_output, global_output))
     }
 
+    fn execute_view_functions(
+        &self,
+        state_view: Arc<S>,
+        requests: Vec<Vec<ViewFunctionRequest>>,
+    ) -> Vec<Vec<ViewFunctionResult>> {
+        assert_eq!(requests.len(), self.num_shards());
+        for (i, requests_for_shard) in requests.into_iter().enumerate() {
+            self.command_txs[i]
+                .send(ExecutorShardCommand::ExecuteViewFunctions(
+                    state_view.clone(),
+                    requests_for_shard,
+                ))
+                .unwrap();
+        }
+
+        let _timer = WAIT_FOR_SHARDED_OUTPUT_SECONDS.start_timer();
+        self.view_result_rxs
+            .iter()
+            .enumerate()
+            .map(|(i, rx)| {
+                rx.recv().unwrap_or_else(|_| {
+                    panic!("Did not receive view function results from shard {}", i)
+                })
+            })
+            .collect()
+    }
+
     fn shutdown(&mut self) {}
 }
 
@@ -242,16 +294,20 @@ pub struct LocalCoordinatorClient<S> {
     command_rx: Receiver<ExecutorShardCommand<S>>,
     // Channel to send execution results to the coordinator.
     result_tx: Sender<Result<Vec<Vec<TransactionOutput>>, VMStatus>>,
+    // Channel to send view function results to the coordinator.
+    view_result_tx: Sender<Vec<ViewFunctionResult>>,
 }
 
 impl<S> LocalCoordinatorClient<S> {
     pub fn new(
         command_rx: Receiver<ExecutorShardCommand<S>>,
         result_tx: Sender<Result<Vec<Vec<TransactionOutput>>, VMStatus>>,
+        view_result_tx: Sender<Vec<ViewFunctionResult>>,
     ) -> Self {
         Self {
             command_rx,
             result_tx,
+            view_result_tx,
         }
     }
 }
@@ -264,6 +320,10 @@ impl<S: StateView + Sync + Send + 'static> CoordinatorClient<S> for LocalCoordin
     fn send_execution_result(&self, result: Result<Vec<Vec<TransactionOutput>>, VMStatus>) {
         self.result_tx.send(result).unwrap()
     }
+
+    fn send_view_function_results(&self, results: Vec<ViewFunctionResult>) {
+        self.view_result_tx.send(results).unwrap()
+    }
 }
 
 /// A cross shard client used by the global shard to receive cross-shard messages from other shards.
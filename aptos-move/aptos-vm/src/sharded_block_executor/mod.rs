@@ -7,7 +7,7 @@ use crate::sharded_block_executor::{
         NUM_EXECUTOR_SHARDS, SHARDED_BLOCK_EXECUTION_SECONDS,
         SHARDED_EXECUTION_RESULT_AGGREGATION_SECONDS,
     },
-    executor_client::ExecutorClient,
+    executor_client::{ExecutorClient, ViewFunctionRequest},
 };
 use aptos_logger::info;
 use aptos_types::{
@@ -16,7 +16,9 @@ use aptos_types::{
         partitioner::{PartitionedTransactions, SubBlocksForShard},
     },
     state_store::StateView,
-    transaction::{analyzed_transaction::AnalyzedTransaction, TransactionOutput},
+    transaction::{
+        analyzed_transaction::AnalyzedTransaction, TransactionOutput, ViewFunctionOutput,
+    },
 };
 use move_core_types::vm_status::VMStatus;
 use std::{marker::PhantomData, sync::Arc};
@@ -47,6 +49,7 @@ pub enum ExecutorShardCommand<S> {
         usize,
         BlockExecutorConfigFromOnchain,
     ),
+    ExecuteViewFunctions(Arc<S>, Vec<ViewFunctionRequest>),
     Stop,
 }
 
@@ -116,6 +119,29 @@ impl<S: StateView + Sync + Send + 'static, C: ExecutorClient<S>> ShardedBlockExe
         Ok(aggregated_results)
     }
 
+    /// Execute a batch of independent view functions by splitting them into contiguous chunks, one per shard.
+    /// The outputs are returned in the order of the requests.
+    pub fn execute_view_functions(
+        &self,
+        state_view: Arc<S>,
+        requests: Vec<ViewFunctionRequest>,
+    ) -> Vec<ViewFunctionOutput> {
+        let num_executor_shards = self.executor_client.num_shards();
+        let chunk_size = requests.len().div_ceil(num_executor_shards).max(1);
+        let mut requests_per_shard: Vec<_> = requests
+            .chunks(chunk_size)
+            .map(|chunk| chunk.to_vec())
+            .collect();
+        requests_per_shard.resize_with(num_executor_shards, Vec::new);
+
+        self.executor_client
+            .execute_view_functions(state_view, requests_per_shard)
+            .into_iter()
+            .flatten()
+            .map(ViewFunctionOutput::from)
+            .collect()
+    }
+
     pub fn shutdown(&mut self) {
         self.executor_client.shutdown();
     }
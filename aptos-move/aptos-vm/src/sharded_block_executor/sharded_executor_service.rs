@@ -12,9 +12,11 @@ use crate::{
         },
         cross_shard_client::{CrossShardClient, CrossShardCommitReceiver, CrossShardCommitSender},
         cross_shard_state_view::CrossShardStateView,
+        executor_client::{ViewFunctionRequest, ViewFunctionResult},
         messages::CrossShardMsg,
         ExecutorShardCommand,
     },
+    AptosVM,
 };
 use aptos_logger::{info, trace};
 use aptos_types::{
@@ -32,6 +34,7 @@ use aptos_types::{
 use aptos_vm_logging::disable_speculative_logging;
 use futures::{channel::oneshot, executor::block_on};
 use move_core_types::vm_status::VMStatus;
+use rayon::prelude::*;
 use std::sync::Arc;
 
 pub struct ShardedExecutorService<S: StateView + Sync + Send + 'static> {
@@ -201,6 +204,29 @@ impl<S: StateView + Sync + Send + 'static> ShardedExecutorService<S> {
         Ok(result)
     }
 
+    fn execute_view_functions(
+        &self,
+        state_view: &S,
+        requests: Vec<ViewFunctionRequest>,
+    ) -> Vec<ViewFunctionResult> {
+        self.executor_thread_pool.install(|| {
+            requests
+                .into_par_iter()
+                .map(|request| {
+                    AptosVM::execute_view_function(
+                        state_view,
+                        request.module_id,
+                        request.func_name,
+                        request.type_args,
+                        request.arguments,
+                        request.max_gas_amount,
+                    )
+                    .into()
+                })
+                .collect()
+        })
+    }
+
     pub fn start(&self) {
         trace!(
             "Shard starting, shard_id={}, num_shards={}.",
@@ -234,6 +260,11 @@ impl<S: StateView + Sync + Send + 'static> ShardedExecutorService<S> {
                                 concurrency_level: concurrency_level_per_shard,
                                 allow_fallback: true,
                                 discard_failed_blocks: false,
+                                deadline: None,
+                                cancelled: None,
+                                record_read_sets: false,
+                                abort_rate_alert_threshold_pct: None,
+                                skip_prologue_resession: false,
                             },
                             onchain: onchain_config,
                         },
@@ -246,6 +277,19 @@ impl<S: StateView + Sync + Send + 'static> ShardedExecutorService<S> {
                         .start_timer();
                     self.coordinator_client.send_execution_result(ret);
                 },
+                ExecutorShardCommand::ExecuteViewFunctions(state_view, requests) => {
+                    trace!(
+                        "Shard {} received ExecuteViewFunctions command of {} requests",
+                        self.shard_id,
+                        requests.len()
+                    );
+                    let _timer = SHARDED_EXECUTOR_SERVICE_SECONDS
+                        .with_label_values(&[&self.shard_id.to_string(), "execute_view_functions"])
+                        .start_timer();
+                    let results = self.execute_view_functions(state_view.as_ref(), requests);
+                    drop(state_view);
+                    self.coordinator_client.send_view_function_results(results);
+                },
                 ExecutorShardCommand::Stop => {
                     break;
                 },
@@ -5,10 +5,18 @@ use crate::AptosVM;
 #[cfg(any(test, feature = "testing"))]
 use crate::{
     aptos_vm::get_or_vm_startup_failure, data_cache::AsMoveResolver,
-    transaction_metadata::TransactionMetadata,
+    transaction_metadata::TransactionMetadata, VMExecutor,
 };
 #[cfg(any(test, feature = "testing"))]
-use aptos_types::{state_store::StateView, transaction::SignedTransaction};
+use aptos_types::{
+    block_executor::config::BlockExecutorConfigFromOnchain,
+    block_metadata::BlockMetadata,
+    state_store::StateView,
+    transaction::{
+        signature_verified_transaction::into_signature_verified_block, BlockOutput,
+        SignedTransaction, Transaction, TransactionOutput,
+    },
+};
 #[cfg(any(test, feature = "testing"))]
 use aptos_vm_logging::log_schema::AdapterLogSchema;
 #[cfg(any(test, feature = "testing"))]
@@ -113,4 +121,46 @@ impl AptosVM {
             &mut TraversalContext::new(&storage),
         )
     }
+
+    /// Runs the block prologue of `block_metadata` with its timestamp replaced by
+    /// `timestamp_usecs`, so that tests can control the on-chain time precisely.
+    #[cfg(any(test, feature = "testing"))]
+    pub fn test_block_prologue_with_timestamp(
+        &self,
+        block_metadata: BlockMetadata,
+        timestamp_usecs: u64,
+        state_view: &impl StateView,
+    ) -> Result<(VMStatus, VMOutput), VMStatus> {
+        let log_context = AdapterLogSchema::new(state_view.id(), 0);
+        let block_metadata = BlockMetadata::new(
+            block_metadata.id(),
+            block_metadata.epoch(),
+            block_metadata.round(),
+            block_metadata.proposer(),
+            block_metadata.previous_block_votes_bitvec().clone(),
+            block_metadata.failed_proposer_indices().clone(),
+            timestamp_usecs,
+        );
+        self.process_block_prologue(&state_view.as_move_resolver(), block_metadata, &log_context)
+    }
+
+    /// Executes `txns` in a block that starts with the block prologue for `block_metadata`, so
+    /// that benchmarks can run synthetic blocks without assembling them. The output of the
+    /// prologue comes first, followed by the outputs of `txns`.
+    #[cfg(any(test, feature = "testing"))]
+    pub fn execute_block_with_explicit_metadata(
+        block_metadata: BlockMetadata,
+        txns: Vec<SignedTransaction>,
+        state_view: &(impl StateView + Sync),
+        onchain_config: BlockExecutorConfigFromOnchain,
+    ) -> Result<BlockOutput<TransactionOutput>, VMStatus> {
+        let block = std::iter::once(Transaction::BlockMetadata(block_metadata))
+            .chain(txns.into_iter().map(Transaction::UserTransaction))
+            .collect();
+        Self::execute_block(
+            &into_signature_verified_block(block),
+            state_view,
+            onchain_config,
+        )
+    }
 }
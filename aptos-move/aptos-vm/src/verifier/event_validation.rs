@@ -8,7 +8,7 @@ use move_binary_format::{
     deserializer::DeserializerConfig,
     errors::{Location, PartialVMError, VMError, VMResult},
     file_format::{
-        Bytecode, CompiledScript,
+        Bytecode, CompiledScript, SignatureToken,
         SignatureToken::{Struct, StructInstantiation},
     },
     CompiledModule,
@@ -151,19 +151,19 @@ pub(crate) fn extract_event_metadata(
     Ok(event_structs)
 }
 
-pub(crate) fn verify_no_event_emission_in_script(
-    script_code: &[u8],
-    config: &DeserializerConfig,
-) -> VMResult<()> {
-    let script = match CompiledScript::deserialize_with_config(script_code, config) {
-        Ok(script) => script,
-        Err(err) => {
-            let msg = format!("[VM] deserializer for script returned error: {:?}", err);
-            return Err(PartialVMError::new(StatusCode::CODE_DESERIALIZATION_ERROR)
-                .with_message(msg)
-                .finish(Location::Script));
-        },
-    };
+fn deserialize_script(script_code: &[u8], config: &DeserializerConfig) -> VMResult<CompiledScript> {
+    CompiledScript::deserialize_with_config(script_code, config).map_err(|err| {
+        let msg = format!("[VM] deserializer for script returned error: {:?}", err);
+        PartialVMError::new(StatusCode::CODE_DESERIALIZATION_ERROR)
+            .with_message(msg)
+            .finish(Location::Script)
+    })
+}
+
+/// Returns the type argument of every `0x1::event::emit` call in the script, or `None` for a call
+/// which is missing it.
+fn script_emit_call_type_args(script: &CompiledScript) -> Vec<Option<&SignatureToken>> {
+    let mut type_args = vec![];
     for bc in &script.code().code {
         if let Bytecode::CallGeneric(index) = bc {
             let func_instantiation = &script.function_instantiation_at(*index);
@@ -176,10 +176,64 @@ pub(crate) fn verify_no_event_emission_in_script(
                 && module_name.as_str() == EVENT_MODULE_NAME
                 && func_name.as_str() == EVENT_EMIT_FUNCTION_NAME
             {
-                return Err(PartialVMError::new(StatusCode::INVALID_OPERATION_IN_SCRIPT)
-                    .finish(Location::Script));
+                type_args.push(
+                    script
+                        .signature_at(func_instantiation.type_parameters)
+                        .0
+                        .first(),
+                );
             }
         }
     }
+    type_args
+}
+
+pub(crate) fn verify_no_event_emission_in_script(
+    script_code: &[u8],
+    config: &DeserializerConfig,
+) -> VMResult<()> {
+    let script = deserialize_script(script_code, config)?;
+    if !script_emit_call_type_args(&script).is_empty() {
+        return Err(
+            PartialVMError::new(StatusCode::INVALID_OPERATION_IN_SCRIPT).finish(Location::Script)
+        );
+    }
+    Ok(())
+}
+
+/// Validate all the `0x1::event::emit` calls in a script have a struct with event attribute
+/// declared in its defining module, and that the module is published at the address of one of
+/// the script's signers, i.e., the script is authorized to emit events on behalf of the module.
+/// Used instead of `verify_no_event_emission_in_script` when scripts are allowed to emit events.
+pub(crate) fn validate_script_emit_calls(
+    session: &mut SessionExt,
+    script_code: &[u8],
+    senders: &[AccountAddress],
+) -> VMResult<()> {
+    let script = deserialize_script(script_code, &session.get_vm_config().deserializer_config)?;
+    for param in script_emit_call_type_args(&script) {
+        match param {
+            Some(StructInstantiation(index, _)) | Some(Struct(index)) => {
+                let struct_handle = script.struct_handle_at(*index);
+                let struct_name = script.identifier_at(struct_handle.name);
+                let module_handle = script.module_handle_at(struct_handle.module);
+                let module_id = ModuleId::new(
+                    *script.address_identifier_at(module_handle.address),
+                    script.identifier_at(module_handle.name).to_owned(),
+                );
+                if !senders.contains(module_id.address()) {
+                    metadata_validation_err(format!("{} passed to 0x1::event::emit function is not defined in a module of a signer of the script", struct_name).as_str())?;
+                }
+                let event_structs = extract_event_metadata_from_module(session, &module_id)?;
+                if !event_structs.contains(struct_name.as_str()) {
+                    metadata_validation_err(format!("Missing #[event] attribute on {}. The #[event] attribute is required for all structs passed into 0x1::event::emit.", struct_name).as_str())?;
+                }
+            },
+            Some(_) => {
+                metadata_validation_err("Passed in a non-struct parameter into 0x1::event::emit.")?
+            },
+            None => metadata_validation_err("Missing parameter for 0x1::event::emit function")?,
+        }
+    }
     Ok(())
 }
@@ -6,7 +6,7 @@
 //! TODO: we should not only validate the types but also the actual values, e.g.
 //! for strings whether they consist of correct characters.
 
-use crate::{move_vm_ext::SessionExt, VMStatus};
+use crate::{counters::TXN_ARGS_SIZE_LIMIT, move_vm_ext::SessionExt, VMStatus};
 use move_binary_format::{
     errors::{Location, PartialVMError},
     file_format::FunctionDefinitionIndex,
@@ -14,6 +14,7 @@ use move_binary_format::{
 };
 use move_core_types::{
     account_address::AccountAddress,
+    gas_algebra::NumBytes,
     ident_str,
     identifier::{IdentStr, Identifier},
     language_storage::ModuleId,
@@ -99,6 +100,7 @@ pub(crate) fn get_allowed_structs(
 /// 1. return signature is empty
 /// 2. number of signers is same as the number of senders
 /// 3. check arg types are allowed after signers
+/// 4. total size of the args does not exceed `max_args_bytes`, if set
 ///
 /// after validation, add senders and non-signer arguments to generate the final args
 pub fn validate_combine_signer_and_txn_args(
@@ -107,6 +109,7 @@ pub fn validate_combine_signer_and_txn_args(
     args: Vec<Vec<u8>>,
     func: &LoadedFunctionInstantiation,
     are_struct_constructors_enabled: bool,
+    max_args_bytes: Option<NumBytes>,
 ) -> Result<Vec<Vec<u8>>, VMStatus> {
     // entry function should not return
     if !func.return_tys.is_empty() {
@@ -159,6 +162,10 @@ pub fn validate_combine_signer_and_txn_args(
         ));
     }
 
+    if let Some(max_args_bytes) = max_args_bytes {
+        check_args_size(&args, max_args_bytes)?;
+    }
+
     // This also validates that the args are valid. If they are structs, they have to be allowed
     // and must be constructed successfully. If construction fails, this would fail with a
     // FAILED_TO_DESERIALIZE_ARGUMENT error.
@@ -184,6 +191,27 @@ pub fn validate_combine_signer_and_txn_args(
     Ok(combined_args)
 }
 
+// Bounds the total size of the arguments before they are deserialized, and records how often
+// transactions come within a quarter of the limit.
+fn check_args_size(args: &[Vec<u8>], max_args_bytes: NumBytes) -> Result<(), VMStatus> {
+    let args_bytes = args.iter().map(|arg| arg.len() as u64).sum::<u64>();
+    let max_args_bytes = u64::from(max_args_bytes);
+    if args_bytes > max_args_bytes {
+        TXN_ARGS_SIZE_LIMIT.with_label_values(&["exceeded"]).inc();
+        return Err(VMStatus::error(
+            StatusCode::TXN_ARGS_SIZE_LIMIT_REACHED,
+            Some(format!(
+                "Transaction arguments take {} bytes, above the maximum of {}",
+                args_bytes, max_args_bytes
+            )),
+        ));
+    }
+    if args_bytes > max_args_bytes / 4 * 3 {
+        TXN_ARGS_SIZE_LIMIT.with_label_values(&["near_limit"]).inc();
+    }
+    Ok(())
+}
+
 // Return whether the argument is valid/allowed and whether it needs construction.
 pub(crate) fn is_valid_txn_arg(
     session: &SessionExt,
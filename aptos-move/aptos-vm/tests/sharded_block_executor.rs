@@ -185,6 +185,14 @@ fn test_partitioner_v2_connected_component_sharded_block_executor_with_random_tr
     }
 }
 
+#[test]
+fn test_sharded_view_functions() {
+    let num_shards = 3;
+    let client = LocalExecutorService::setup_local_executor_shards(num_shards, Some(2));
+    let sharded_block_executor = ShardedBlockExecutor::new(client);
+    test_utils::sharded_view_functions(sharded_block_executor);
+}
+
 mod test_utils {
     use aptos_block_partitioner::BlockPartitioner;
     use aptos_language_e2e_tests::{
@@ -202,13 +210,21 @@ mod test_utils {
         },
     };
     use aptos_vm::{
-        sharded_block_executor::{executor_client::ExecutorClient, ShardedBlockExecutor},
+        sharded_block_executor::{
+            executor_client::{ExecutorClient, ViewFunctionRequest},
+            ShardedBlockExecutor,
+        },
         AptosVM, VMExecutor,
     };
-    use move_core_types::account_address::AccountAddress;
+    use move_core_types::{
+        account_address::AccountAddress,
+        ident_str,
+        language_storage::{ModuleId, TypeTag},
+    };
     use rand::{rngs::OsRng, Rng};
     use std::{
         collections::HashMap,
+        str::FromStr,
         sync::{Arc, Mutex},
     };
 
@@ -415,4 +431,43 @@ mod test_utils {
                 .unwrap();
         compare_txn_outputs(unsharded_txn_output, sharded_txn_output);
     }
+
+    pub fn sharded_view_functions<E: ExecutorClient<FakeDataStore>>(
+        sharded_block_executor: ShardedBlockExecutor<FakeDataStore, E>,
+    ) {
+        let num_requests = 10;
+        let mut executor = FakeExecutor::from_head_genesis();
+        let requests: Vec<_> = (0..num_requests)
+            .map(|i| {
+                let account = executor.create_raw_account_data(1_000 * (i + 1), 0);
+                executor.add_account_data(&account);
+                ViewFunctionRequest::new(
+                    ModuleId::new(AccountAddress::ONE, ident_str!("coin").to_owned()),
+                    ident_str!("balance").to_owned(),
+                    vec![TypeTag::from_str("0x1::aptos_coin::AptosCoin").unwrap()],
+                    vec![bcs::to_bytes(account.address()).unwrap()],
+                    u64::MAX,
+                )
+            })
+            .collect();
+
+        let sharded_outputs = sharded_block_executor
+            .execute_view_functions(Arc::new(executor.data_store().clone()), requests.clone());
+        assert_eq!(sharded_outputs.len(), num_requests as usize);
+        for (request, sharded_output) in requests.into_iter().zip(sharded_outputs) {
+            let unsharded_output = AptosVM::execute_view_function(
+                executor.data_store(),
+                request.module_id,
+                request.func_name,
+                request.type_args,
+                request.arguments,
+                request.max_gas_amount,
+            );
+            assert_eq!(
+                sharded_output.values.unwrap(),
+                unsharded_output.values.unwrap()
+            );
+            assert_eq!(sharded_output.gas_used, unsharded_output.gas_used);
+        }
+    }
 }
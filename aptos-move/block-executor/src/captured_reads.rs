@@ -707,6 +707,16 @@ impl<T: Transaction> CapturedReads<T> {
         ret
     }
 
+    /// Returns all state keys read by the transaction (resources, groups and modules).
+    pub(crate) fn get_read_keys(&self) -> HashSet<T::Key> {
+        self.data_reads
+            .keys()
+            .chain(self.group_reads.keys())
+            .chain(self.module_reads.iter())
+            .cloned()
+            .collect()
+    }
+
     pub(crate) fn mark_failure(&mut self) {
         self.speculative_failure = true;
     }
@@ -750,6 +760,16 @@ impl<T: Transaction> UnsyncReadSet<T> {
 
         ret
     }
+
+    /// Returns all state keys read by the transaction (resources, groups and modules).
+    pub(crate) fn get_read_keys(&self) -> HashSet<T::Key> {
+        self.resource_reads
+            .iter()
+            .chain(self.group_reads.keys())
+            .chain(self.module_reads.iter())
+            .cloned()
+            .collect()
+    }
 }
 
 #[cfg(test)]
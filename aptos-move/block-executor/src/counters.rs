@@ -6,7 +6,7 @@ use aptos_metrics_core::{
     register_int_counter, register_int_counter_vec, Histogram, HistogramVec, IntCounter,
     IntCounterVec,
 };
-use aptos_mvhashmap::BlockStateStats;
+use aptos_mvhashmap::{types::Incarnation, BlockStateStats};
 use aptos_types::fee_statement::FeeStatement;
 use once_cell::sync::Lazy;
 
@@ -67,6 +67,15 @@ pub static SPECULATIVE_ABORT_COUNT: Lazy<IntCounter> = Lazy::new(|| {
     .unwrap()
 });
 
+/// Count of parallel execution blocks whose speculative abort rate exceeded the alert threshold.
+pub static HIGH_ABORT_RATE_BLOCK_COUNT: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "aptos_execution_high_abort_rate_block_count",
+        "Count of blocks whose speculative aborts in parallel execution exceeded the alert threshold"
+    )
+    .unwrap()
+});
+
 /// Count of times the BlockSTM is early halted due to exceeding the per-block gas limit.
 pub static EXCEED_PER_BLOCK_GAS_LIMIT_COUNT: Lazy<IntCounterVec> = Lazy::new(|| {
     register_int_counter_vec!(
@@ -213,6 +222,25 @@ pub static BLOCK_COMMITTED_TXNS: Lazy<HistogramVec> = Lazy::new(|| {
     .unwrap()
 });
 
+pub static BLOCK_SPECULATIVE_ABORTS: Lazy<Histogram> = Lazy::new(|| {
+    register_histogram!(
+        "aptos_execution_block_speculative_aborts",
+        "The per-block number of speculative aborts of the committed txns (Block STM)",
+        exponential_buckets(/*start=*/ 1.0, /*factor=*/ 2.0, /*count=*/ 30).unwrap(),
+    )
+    .unwrap()
+});
+
+pub static BLOCK_MAX_TXN_INCARNATION: Lazy<Histogram> = Lazy::new(|| {
+    register_histogram!(
+        "aptos_execution_block_max_txn_incarnation",
+        "The per-block max incarnation any txn was committed with, i.e. the max number of \
+         re-executions of a single txn (Block STM)",
+        exponential_buckets(/*start=*/ 1.0, /*factor=*/ 2.0, /*count=*/ 20).unwrap(),
+    )
+    .unwrap()
+});
+
 pub static BLOCK_VIEW_DISTINCT_KEYS: Lazy<HistogramVec> = Lazy::new(|| {
     register_avg_counter_vec(
         "aptos_execution_block_view_distinct_keys",
@@ -294,6 +322,14 @@ pub(crate) fn update_txn_gas_counters(txn_fee_statements: &Vec<FeeStatement>, is
     }
 }
 
+pub(crate) fn update_block_abort_counters(
+    accumulated_aborts: u64,
+    max_committed_incarnation: Incarnation,
+) {
+    BLOCK_SPECULATIVE_ABORTS.observe(accumulated_aborts as f64);
+    BLOCK_MAX_TXN_INCARNATION.observe(max_committed_incarnation as f64);
+}
+
 pub(crate) fn update_state_counters(block_state_stats: BlockStateStats, is_parallel: bool) {
     let mode_str = if is_parallel {
         Mode::PARALLEL
@@ -13,6 +13,10 @@ pub(crate) enum ParallelBlockExecutionError {
     ModulePathReadWriteError,
     /// unrecoverable VM error
     FatalVMError,
+    /// The block execution deadline passed.
+    DeadlineExceeded,
+    /// The block execution was cancelled.
+    Cancelled,
 }
 
 // This is separate error because we need to match the error variant to provide a specialized
@@ -37,6 +41,10 @@ pub enum BlockExecutionError<E> {
     FatalBlockExecutorError(PanicError),
     /// unrecoverable VM error
     FatalVMError(E),
+    /// The block execution deadline passed before all transactions were executed.
+    DeadlineExceeded,
+    /// The block execution was cancelled before all transactions were executed.
+    Cancelled,
 }
 
 pub type BlockExecutionResult<T, E> = Result<T, BlockExecutionError<E>>;
@@ -58,6 +58,7 @@ use std::{
         atomic::{AtomicBool, AtomicU32, Ordering},
         Arc,
     },
+    time::Instant,
 };
 
 pub struct BlockExecutor<T, E, S, L, X> {
@@ -97,6 +98,21 @@ where
         }
     }
 
+    fn deadline_exceeded(&self) -> bool {
+        self.config
+            .local
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    fn cancelled(&self) -> bool {
+        self.config
+            .local
+            .cancelled
+            .as_ref()
+            .is_some_and(|cancelled| cancelled.load(Ordering::Relaxed))
+    }
+
     fn execute(
         idx_to_execute: TxnIndex,
         incarnation: Incarnation,
@@ -443,18 +459,19 @@ where
     ) -> Result<(), PanicOr<ParallelBlockExecutionError>> {
         let mut block_limit_processor = shared_commit_state.acquire();
 
-        while let Some((txn_idx, incarnation)) = scheduler.try_commit() {
+        while let Some((txn_idx, mut incarnation)) = scheduler.try_commit() {
             if !Self::validate_commit_ready(txn_idx, versioned_cache, last_input_output)? {
                 // Transaction needs to be re-executed, one final time.
 
                 Self::update_transaction_on_abort(txn_idx, last_input_output, versioned_cache);
+                incarnation += 1;
                 // We are going to skip reducing validation index here, as we
                 // are executing immediately, and will reduce it unconditionally
                 // after execution, inside finish_execution_during_commit.
                 // Because of that, we can also ignore _updates_outside result.
                 let _updates_outside = Self::execute(
                     txn_idx,
-                    incarnation + 1,
+                    incarnation,
                     block,
                     last_input_output,
                     versioned_cache,
@@ -483,6 +500,7 @@ where
                     .into());
                 }
             }
+            block_limit_processor.accumulate_committed_incarnation(incarnation);
 
             last_input_output
                 .check_fatal_vm_error(txn_idx)
@@ -758,6 +776,13 @@ where
         };
 
         loop {
+            if self.deadline_exceeded() {
+                return Err(PanicOr::Or(ParallelBlockExecutionError::DeadlineExceeded));
+            }
+            if self.cancelled() {
+                return Err(PanicOr::Or(ParallelBlockExecutionError::Cancelled));
+            }
+
             while scheduler.should_coordinate_commits() {
                 self.prepare_and_queue_commit_ready_txns(
                     &self.config.onchain.block_gas_limit_type,
@@ -839,7 +864,7 @@ where
         executor_initial_arguments: E::Argument,
         signature_verified_block: &[T],
         base_view: &S,
-    ) -> Result<BlockOutput<E::Output>, ()> {
+    ) -> Result<BlockOutput<E::Output, T::Key>, ()> {
         let _timer = PARALLEL_EXECUTION_SECONDS.start_timer();
         // Using parallel execution with 1 thread currently will not work as it
         // will only have a coordinator role but no workers for rolling commit.
@@ -860,10 +885,12 @@ where
 
         let num_txns = signature_verified_block.len();
 
-        let shared_commit_state = ExplicitSyncWrapper::new(BlockGasLimitProcessor::new(
-            self.config.onchain.block_gas_limit_type.clone(),
-            num_txns,
-        ));
+        let shared_commit_state = ExplicitSyncWrapper::new(
+            BlockGasLimitProcessor::new(self.config.onchain.block_gas_limit_type.clone(), num_txns)
+                .with_abort_rate_alert_threshold_pct(
+                    self.config.local.abort_rate_alert_threshold_pct,
+                ),
+        );
         let shared_maybe_error = AtomicBool::new(false);
 
         let final_results = ExplicitSyncWrapper::new(Vec::with_capacity(num_txns));
@@ -913,14 +940,37 @@ where
 
         counters::update_state_counters(versioned_cache.stats(), true);
 
+        // Committed transactions form a prefix of the block, ending at the first SkipRest.
+        let read_sets = (self.config.local.record_read_sets
+            && !shared_maybe_error.load(Ordering::SeqCst))
+        .then(|| {
+            let mut read_sets = Vec::new();
+            for txn_idx in 0..num_txns {
+                read_sets.push(
+                    last_input_output
+                        .read_set(txn_idx)
+                        .map_or_else(Vec::new, |reads| {
+                            reads.get_read_keys().into_iter().collect()
+                        }),
+                );
+                if last_input_output.block_skips_rest_at_idx(txn_idx) {
+                    break;
+                }
+            }
+            read_sets
+        });
+
         // Explicit async drops.
         DEFAULT_DROPPER.schedule_drop((last_input_output, scheduler, versioned_cache));
 
-        // TODO add block end info to output.
-        // block_limit_processor.is_block_limit_reached();
+        let block_end_reason = shared_commit_state.acquire().block_end_reason();
 
         (!shared_maybe_error.load(Ordering::SeqCst))
-            .then(|| BlockOutput::new(final_results.into_inner()))
+            .then(|| {
+                BlockOutput::new(final_results.into_inner())
+                    .with_read_sets(read_sets)
+                    .with_block_end_reason(Some(block_end_reason))
+            })
             .ok_or(())
     }
 
@@ -1004,7 +1054,7 @@ where
         signature_verified_block: &[T],
         base_view: &S,
         resource_group_bcs_fallback: bool,
-    ) -> Result<BlockOutput<E::Output>, SequentialBlockExecutionError<E::Error>> {
+    ) -> Result<BlockOutput<E::Output, T::Key>, SequentialBlockExecutionError<E::Error>> {
         let num_txns = signature_verified_block.len();
         let init_timer = VM_INIT_SECONDS.start_timer();
         let executor = E::init(executor_arguments);
@@ -1014,6 +1064,7 @@ where
         let counter = RefCell::new(start_counter);
         let unsync_map = UnsyncMap::new();
         let mut ret = Vec::with_capacity(num_txns);
+        let mut read_sets = self.config.local.record_read_sets.then(Vec::new);
         let mut block_limit_processor = BlockGasLimitProcessor::<T>::new(
             self.config.onchain.block_gas_limit_type.clone(),
             num_txns,
@@ -1023,6 +1074,17 @@ where
             TxnLastInputOutput::new(num_txns as TxnIndex);
 
         for (idx, txn) in signature_verified_block.iter().enumerate() {
+            if self.deadline_exceeded() {
+                return Err(SequentialBlockExecutionError::ErrorToReturn(
+                    BlockExecutionError::DeadlineExceeded,
+                ));
+            }
+            if self.cancelled() {
+                return Err(SequentialBlockExecutionError::ErrorToReturn(
+                    BlockExecutionError::Cancelled,
+                ));
+            }
+
             let latest_view = LatestView::<T, S, X>::new(
                 base_view,
                 ViewState::Unsync(SequentialState::new(&unsync_map, start_counter, &counter)),
@@ -1086,6 +1148,9 @@ where
                         });
 
                     let sequential_reads = latest_view.take_sequential_reads();
+                    if let Some(read_sets) = read_sets.as_mut() {
+                        read_sets.push(sequential_reads.get_read_keys().into_iter().collect());
+                    }
                     let read_write_summary = self
                         .config
                         .onchain
@@ -1277,10 +1342,9 @@ where
 
         counters::update_state_counters(unsync_map.stats(), false);
 
-        // TODO add block end info to output.
-        // block_limit_processor.is_block_limit_reached();
-
-        Ok(BlockOutput::new(ret))
+        Ok(BlockOutput::new(ret)
+            .with_read_sets(read_sets)
+            .with_block_end_reason(Some(block_limit_processor.block_end_reason())))
     }
 
     pub fn execute_block(
@@ -1288,7 +1352,7 @@ where
         executor_arguments: E::Argument,
         signature_verified_block: &[T],
         base_view: &S,
-    ) -> BlockExecutionResult<BlockOutput<E::Output>, E::Error> {
+    ) -> BlockExecutionResult<BlockOutput<E::Output, T::Key>, E::Error> {
         if self.config.local.concurrency_level > 1 {
             let parallel_result = self.execute_transactions_parallel(
                 executor_arguments,
@@ -1301,6 +1365,14 @@ where
                 return Ok(output);
             }
 
+            // Falling back to sequential execution would only stall the block further.
+            if self.deadline_exceeded() {
+                return Err(BlockExecutionError::DeadlineExceeded);
+            }
+            if self.cancelled() {
+                return Err(BlockExecutionError::Cancelled);
+            }
+
             if !self.config.local.allow_fallback {
                 panic!("Parallel execution failed and fallback is not allowed");
             }
@@ -1370,6 +1442,11 @@ where
                 BlockExecutionError::FatalVMError(_) => {
                     StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR
                 },
+                // Timed out or cancelled blocks are rejected as a whole, not committed with
+                // discarded outputs.
+                BlockExecutionError::DeadlineExceeded | BlockExecutionError::Cancelled => {
+                    return Err(sequential_error)
+                },
             };
             let ret = signature_verified_block
                 .iter()
@@ -2,10 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{counters, types::ReadWriteSummary};
-use aptos_logger::info;
+use aptos_logger::{info, warn};
+use aptos_mvhashmap::types::Incarnation;
 use aptos_types::{
-    fee_statement::FeeStatement, on_chain_config::BlockGasLimitType,
-    transaction::BlockExecutableTransaction as Transaction,
+    fee_statement::FeeStatement,
+    on_chain_config::BlockGasLimitType,
+    transaction::{BlockEndReason, BlockExecutableTransaction as Transaction},
 };
 use claims::{assert_le, assert_none};
 
@@ -16,8 +18,13 @@ pub struct BlockGasLimitProcessor<T: Transaction> {
     accumulated_fee_statement: FeeStatement,
     txn_fee_statements: Vec<FeeStatement>,
     txn_read_write_summaries: Vec<ReadWriteSummary<T>>,
-    block_limit_reached: bool,
+    // Set to the limit that was reached, if the block was ended early.
+    block_limit_reached: Option<BlockEndReason>,
     module_rw_conflict: bool,
+    // Number of speculative aborts of the committed txns, i.e. the sum of their incarnations.
+    accumulated_aborts: u64,
+    max_committed_incarnation: Incarnation,
+    abort_rate_alert_threshold_pct: Option<u64>,
 }
 
 impl<T: Transaction> BlockGasLimitProcessor<T> {
@@ -29,11 +36,24 @@ impl<T: Transaction> BlockGasLimitProcessor<T> {
             accumulated_fee_statement: FeeStatement::zero(),
             txn_fee_statements: Vec::with_capacity(init_size),
             txn_read_write_summaries: Vec::with_capacity(init_size),
-            block_limit_reached: false,
+            block_limit_reached: None,
             module_rw_conflict: false,
+            accumulated_aborts: 0,
+            max_committed_incarnation: 0,
+            abort_rate_alert_threshold_pct: None,
         }
     }
 
+    /// Sets the percentage of speculative aborts per committed transaction above which the
+    /// block is reported as contended when parallel execution finishes.
+    pub(crate) fn with_abort_rate_alert_threshold_pct(
+        mut self,
+        abort_rate_alert_threshold_pct: Option<u64>,
+    ) -> Self {
+        self.abort_rate_alert_threshold_pct = abort_rate_alert_threshold_pct;
+        self
+    }
+
     pub(crate) fn accumulate_fee_statement(
         &mut self,
         fee_statement: FeeStatement,
@@ -89,6 +109,23 @@ impl<T: Transaction> BlockGasLimitProcessor<T> {
         }
     }
 
+    /// Records the incarnation with which a transaction got committed in parallel execution.
+    /// Every incarnation before the committed one was aborted, so this is also the number of
+    /// times the transaction had to be re-executed.
+    pub(crate) fn accumulate_committed_incarnation(&mut self, incarnation: Incarnation) {
+        self.accumulated_aborts += incarnation as u64;
+        self.max_committed_incarnation = self.max_committed_incarnation.max(incarnation);
+    }
+
+    /// Returns whether the speculative aborts of the committed txns exceed the configured
+    /// percentage of the number of committed txns.
+    fn exceeds_abort_rate_alert_threshold(&self, num_committed: u32) -> bool {
+        self.abort_rate_alert_threshold_pct
+            .map_or(false, |threshold_pct| {
+                self.accumulated_aborts * 100 > threshold_pct * num_committed as u64
+            })
+    }
+
     pub(crate) fn process_module_rw_conflict(&mut self) {
         if self.module_rw_conflict
             || !self
@@ -130,7 +167,7 @@ impl<T: Transaction> BlockGasLimitProcessor<T> {
                     accumulated_block_gas {} >= PER_BLOCK_GAS_LIMIT {}",
                     mode, accumulated_block_gas, per_block_gas_limit,
                 );
-                self.block_limit_reached = true;
+                self.block_limit_reached = Some(BlockEndReason::GasLimitReached);
 
                 return true;
             }
@@ -147,7 +184,7 @@ impl<T: Transaction> BlockGasLimitProcessor<T> {
                     accumulated_output {} >= PER_BLOCK_OUTPUT_LIMIT {}",
                     mode, accumulated_output, per_block_output_limit,
                 );
-                self.block_limit_reached = true;
+                self.block_limit_reached = Some(BlockEndReason::BlockFull);
 
                 return true;
             }
@@ -207,6 +244,26 @@ impl<T: Transaction> BlockGasLimitProcessor<T> {
             is_parallel,
         );
         counters::update_txn_gas_counters(&self.txn_fee_statements, is_parallel);
+        if is_parallel {
+            counters::update_block_abort_counters(
+                self.accumulated_aborts,
+                self.max_committed_incarnation,
+            );
+            if self.exceeds_abort_rate_alert_threshold(num_committed) {
+                counters::HIGH_ABORT_RATE_BLOCK_COUNT.inc();
+                warn!(
+                    num_txns = num_total,
+                    num_committed = num_committed,
+                    speculative_aborts = self.accumulated_aborts,
+                    max_committed_incarnation = self.max_committed_incarnation,
+                    abort_rate_alert_threshold_pct = ?self.abort_rate_alert_threshold_pct,
+                    "[BlockSTM]: Parallel execution of a contended block, \
+                    {} speculative aborts for {} committed txns",
+                    self.accumulated_aborts,
+                    num_committed,
+                );
+            }
+        }
 
         info!(
             effective_block_gas = accumulated_effective_block_gas,
@@ -221,6 +278,8 @@ impl<T: Transaction> BlockGasLimitProcessor<T> {
                 .block_gas_limit_type
                 .block_output_limit()
                 .map_or(false, |limit| accumulated_approx_output_size >= limit),
+            speculative_aborts = self.accumulated_aborts,
+            max_committed_incarnation = self.max_committed_incarnation,
             "[BlockSTM]: {} execution completed. {} out of {} txns committed",
             if is_parallel {
                 "Parallel"
@@ -250,7 +309,14 @@ impl<T: Transaction> BlockGasLimitProcessor<T> {
 
     #[allow(unused)]
     pub(crate) fn is_block_limit_reached(&self) -> bool {
+        self.block_limit_reached.is_some()
+    }
+
+    /// Returns the block limit that ended the block early, or `AllTransactionsExecuted` if
+    /// no block limit was reached.
+    pub(crate) fn block_end_reason(&self) -> BlockEndReason {
         self.block_limit_reached
+            .unwrap_or(BlockEndReason::AllTransactionsExecuted)
     }
 }
 
@@ -312,8 +378,44 @@ mod test {
         assert!(!processor.should_end_block_parallel());
         processor.accumulate_fee_statement(execution_fee(50), None, None);
         assert!(!processor.should_end_block_parallel());
+        assert_eq!(
+            processor.block_end_reason(),
+            BlockEndReason::AllTransactionsExecuted
+        );
         processor.accumulate_fee_statement(execution_fee(40), None, None);
         assert!(processor.should_end_block_parallel());
+        assert_eq!(
+            processor.block_end_reason(),
+            BlockEndReason::GasLimitReached
+        );
+    }
+
+    #[test]
+    fn test_committed_incarnations() {
+        let mut processor = BlockGasLimitProcessor::<TestTxn>::new(DEFAULT_COMPLEX_LIMIT, 10);
+
+        processor.accumulate_committed_incarnation(0);
+        processor.accumulate_committed_incarnation(3);
+        processor.accumulate_committed_incarnation(1);
+        assert_eq!(processor.accumulated_aborts, 4);
+        assert_eq!(processor.max_committed_incarnation, 3);
+    }
+
+    #[test]
+    fn test_abort_rate_alert_threshold() {
+        let processor = BlockGasLimitProcessor::<TestTxn>::new(DEFAULT_COMPLEX_LIMIT, 10);
+        assert!(!processor.exceeds_abort_rate_alert_threshold(0));
+
+        let mut processor = BlockGasLimitProcessor::<TestTxn>::new(DEFAULT_COMPLEX_LIMIT, 10)
+            .with_abort_rate_alert_threshold_pct(Some(100));
+        for incarnation in [0, 2, 1, 1] {
+            processor.accumulate_committed_incarnation(incarnation);
+        }
+        // 4 aborts for 4 committed txns is exactly at the threshold.
+        assert!(!processor.exceeds_abort_rate_alert_threshold(4));
+        processor.accumulate_committed_incarnation(3);
+        // 7 aborts for 5 committed txns.
+        assert!(processor.exceeds_abort_rate_alert_threshold(5));
     }
 
     #[test]
@@ -341,6 +443,7 @@ mod test {
         processor.accumulate_fee_statement(FeeStatement::zero(), None, Some(40));
         assert_eq!(processor.accumulated_approx_output_size, 100);
         assert!(processor.should_end_block_parallel());
+        assert_eq!(processor.block_end_reason(), BlockEndReason::BlockFull);
     }
 
     fn to_map(
@@ -222,7 +222,7 @@ impl<K: Debug + Hash + Clone + Eq> BaselineOutput<K> {
         }
     }
 
-    fn assert_success<E: Debug>(&self, block_output: &BlockOutput<MockOutput<K, E>>) {
+    fn assert_success<E: Debug>(&self, block_output: &BlockOutput<MockOutput<K, E>, K>) {
         let base_map: HashMap<u32, Bytes> = HashMap::from([(RESERVED_TAG, vec![0].into())]);
         let mut group_world = HashMap::new();
 
@@ -355,7 +355,7 @@ impl<K: Debug + Hash + Clone + Eq> BaselineOutput<K> {
     // itself to be easily traceable in case of an error.
     pub(crate) fn assert_output<E: Debug>(
         &self,
-        results: &BlockExecutionResult<BlockOutput<MockOutput<K, E>>, usize>,
+        results: &BlockExecutionResult<BlockOutput<MockOutput<K, E>, K>, usize>,
     ) {
         match results {
             Ok(block_output) => {
@@ -369,12 +369,18 @@ impl<K: Debug + Hash + Clone + Eq> BaselineOutput<K> {
             Err(BlockExecutionError::FatalBlockExecutorError(e)) => {
                 unimplemented!("not tested here FallbackToSequential({:?})", e);
             },
+            Err(BlockExecutionError::DeadlineExceeded) => {
+                unimplemented!("not tested here DeadlineExceeded");
+            },
+            Err(BlockExecutionError::Cancelled) => {
+                unimplemented!("not tested here Cancelled");
+            },
         }
     }
 
     pub(crate) fn assert_parallel_output<E: Debug>(
         &self,
-        results: &Result<BlockOutput<MockOutput<K, E>>, ()>,
+        results: &Result<BlockOutput<MockOutput<K, E>, K>, ()>,
     ) {
         match results {
             Ok(block_output) => {
@@ -3,7 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    errors::SequentialBlockExecutionError,
+    errors::{BlockExecutionError, BlockExecutionResult, SequentialBlockExecutionError},
     executor::BlockExecutor,
     proptest_types::{
         baseline::BaselineOutput,
@@ -24,9 +24,12 @@ use aptos_aggregator::{
 };
 use aptos_mvhashmap::types::TxnIndex;
 use aptos_types::{
-    block_executor::config::BlockExecutorConfig,
+    block_executor::config::{
+        BlockExecutorConfig, BlockExecutorConfigFromOnchain, BlockExecutorLocalConfig,
+    },
     contract_event::TransactionEvent,
     executable::{ExecutableTestType, ModulePath},
+    transaction::{BlockEndReason, BlockOutput},
 };
 use claims::assert_matches;
 use fail::FailScenario;
@@ -37,7 +40,8 @@ use std::{
     fmt::Debug,
     hash::Hash,
     marker::PhantomData,
-    sync::Arc,
+    sync::{atomic::AtomicBool, Arc},
+    time::Instant,
 };
 
 #[test]
@@ -230,6 +234,112 @@ fn skip_rest_gas_limit() {
     let _ = block_executor.execute_transactions_parallel((), &transactions, &data_view);
 }
 
+/// Executes a block of three transactions, each reading keys 1 and 3, writing key 2 and using 10
+/// gas, with the given local config and block gas limit.
+fn execute_block_with_config(
+    local_config: BlockExecutorLocalConfig,
+    maybe_block_gas_limit: Option<u64>,
+) -> BlockExecutionResult<BlockOutput<MockOutput<KeyType<u32>, MockEvent>, KeyType<u32>>, usize> {
+    let incarnation: MockIncarnation<KeyType<u32>, MockEvent> = MockIncarnation::new(
+        vec![KeyType::<u32>(1, false), KeyType::<u32>(3, false)],
+        vec![(
+            KeyType::<u32>(2, false),
+            ValueType::from_value(vec![5], true),
+        )],
+        vec![],
+        vec![],
+        10,
+    );
+    let txn = MockTransaction::from_behavior(incarnation);
+    let transactions = Vec::from([txn.clone(), txn.clone(), txn]);
+
+    let data_view = DeltaDataView::<KeyType<u32>> {
+        phantom: PhantomData,
+    };
+    let executor_thread_pool = Arc::new(
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(num_cpus::get())
+            .build()
+            .unwrap(),
+    );
+    let config = BlockExecutorConfig {
+        local: local_config,
+        onchain: BlockExecutorConfigFromOnchain::new_maybe_block_limit(maybe_block_gas_limit),
+    };
+
+    BlockExecutor::<
+        MockTransaction<KeyType<u32>, MockEvent>,
+        MockTask<KeyType<u32>, MockEvent>,
+        DeltaDataView<KeyType<u32>>,
+        NoOpTransactionCommitHook<MockOutput<KeyType<u32>, MockEvent>, usize>,
+        ExecutableTestType,
+    >::new(config, executor_thread_pool, None)
+    .execute_block((), &transactions, &data_view)
+}
+
+#[test]
+fn block_execution_deadline_exceeded() {
+    for concurrency_level in [1, num_cpus::get()] {
+        let mut local_config = BlockExecutorConfig::new_no_block_limit(concurrency_level).local;
+        // Failed blocks must not be discarded on timeout, but rejected.
+        local_config.discard_failed_blocks = true;
+        local_config.deadline = Some(Instant::now());
+
+        let output = execute_block_with_config(local_config, None);
+        assert_matches!(output, Err(BlockExecutionError::DeadlineExceeded));
+    }
+}
+
+#[test]
+fn block_execution_cancelled() {
+    for concurrency_level in [1, num_cpus::get()] {
+        let mut local_config = BlockExecutorConfig::new_no_block_limit(concurrency_level).local;
+        // Cancelled blocks must not be discarded, but rejected.
+        local_config.discard_failed_blocks = true;
+        local_config.cancelled = Some(Arc::new(AtomicBool::new(true)));
+
+        let output = execute_block_with_config(local_config, None);
+        assert_matches!(output, Err(BlockExecutionError::Cancelled));
+    }
+}
+
+#[test]
+fn block_execution_records_read_sets() {
+    for concurrency_level in [1, num_cpus::get()] {
+        let local_config = BlockExecutorConfig::new_no_block_limit(concurrency_level).local;
+        let output = execute_block_with_config(local_config, None).unwrap();
+        assert!(output.read_sets().is_none());
+
+        let mut local_config = BlockExecutorConfig::new_no_block_limit(concurrency_level).local;
+        local_config.record_read_sets = true;
+        let output = execute_block_with_config(local_config, None).unwrap();
+        let read_sets = output.read_sets().unwrap();
+        assert_eq!(read_sets.len(), 3);
+        for read_set in read_sets {
+            let read_keys: HashSet<_> = read_set.iter().cloned().collect();
+            assert_eq!(
+                read_keys,
+                HashSet::from([KeyType::<u32>(1, false), KeyType::<u32>(3, false)])
+            );
+        }
+    }
+}
+
+#[test]
+fn block_execution_end_reason() {
+    for concurrency_level in [1, num_cpus::get()] {
+        // The second txn reaches the block gas limit, the third one is skipped.
+        for (maybe_block_gas_limit, expected_block_end_reason) in [
+            (None, BlockEndReason::AllTransactionsExecuted),
+            (Some(15), BlockEndReason::GasLimitReached),
+        ] {
+            let local_config = BlockExecutorConfig::new_no_block_limit(concurrency_level).local;
+            let output = execute_block_with_config(local_config, maybe_block_gas_limit).unwrap();
+            assert_eq!(output.block_end_reason(), Some(expected_block_end_reason));
+        }
+    }
+}
+
 // TODO: add unit test for block gas limit!
 fn run_and_assert<K, E>(transactions: Vec<MockTransaction<K, E>>)
 where
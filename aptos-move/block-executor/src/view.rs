@@ -1525,6 +1525,13 @@ impl<'a, T: Transaction, S: TStateView<Key = T::Key>, X: Executable> TResourceGr
         unimplemented!("Currently resolved by ResourceGroupAdapter");
     }
 
+    fn release_group_cache_for(
+        &self,
+        _group_key: &Self::GroupKey,
+    ) -> Option<BTreeMap<Self::ResourceTag, Bytes>> {
+        unimplemented!("Currently resolved by ResourceGroupAdapter");
+    }
+
     fn is_resource_groups_split_in_change_set_capable(&self) -> bool {
         match &self.latest_view {
             ViewState::Sync(_) => true,
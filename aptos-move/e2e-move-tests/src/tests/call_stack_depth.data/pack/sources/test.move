@@ -0,0 +1,9 @@
+module 0xbeef::test {
+    fun recurse(depth: u64): u64 {
+        if (depth == 0) 0 else recurse(depth - 1) + 1
+    }
+
+    public entry fun run(depth: u64) {
+        recurse(depth);
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{assert_success, tests::common, MoveHarness};
+use aptos_types::{
+    account_address::AccountAddress,
+    transaction::{ExecutionStatus, TransactionOutput, TransactionStatus},
+};
+use aptos_vm::AptosVM;
+use move_core_types::{value::MoveValue, vm_status::StatusCode};
+
+fn run_recursion(h: &mut MoveHarness, depth: usize) -> TransactionOutput {
+    let acc = h.new_account_at(AccountAddress::from_hex_literal("0xbeef").unwrap());
+    assert_success!(h.publish_package_cache_building(
+        &acc,
+        &common::test_dir_path("call_stack_depth.data/pack"),
+    ));
+    let txn = h.create_entry_function(
+        &acc,
+        str::parse("0xbeef::test::run").unwrap(),
+        vec![],
+        vec![MoveValue::U64(depth as u64).simple_serialize().unwrap()],
+    );
+    h.run_raw(txn)
+}
+
+#[test]
+fn recursion_below_call_stack_limit() {
+    let mut h = MoveHarness::new();
+    // The entry function and the transaction prologue use some of the frames.
+    let output = run_recursion(&mut h, AptosVM::get_max_call_stack_depth() / 2);
+    assert_success!(output.status().clone());
+}
+
+#[test]
+fn recursion_above_call_stack_limit() {
+    let mut h = MoveHarness::new();
+    let output = run_recursion(&mut h, AptosVM::get_max_call_stack_depth());
+    assert!(matches!(
+        output.status(),
+        TransactionStatus::Keep(ExecutionStatus::ExecutionFailure { .. })
+    ));
+
+    // The failure is distinguishable from other runtime errors through the error details.
+    let detail = output
+        .auxiliary_data()
+        .get_detail_error_message()
+        .expect("execution failures carry error details");
+    assert_eq!(detail.status_code(), StatusCode::CALL_STACK_OVERFLOW);
+    assert!(detail
+        .message()
+        .as_ref()
+        .unwrap()
+        .contains(&AptosVM::get_max_call_stack_depth().to_string()));
+}
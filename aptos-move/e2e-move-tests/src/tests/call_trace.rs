@@ -0,0 +1,86 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{assert_success, MoveHarness};
+use aptos_package_builder::PackageBuilder;
+use aptos_types::{
+    account_address::AccountAddress, state_store::TStateView, transaction::SignedTransaction,
+};
+use aptos_vm::data_cache::AsMoveResolver;
+use aptos_vm_logging::log_schema::AdapterLogSchema;
+use aptos_vm_types::{call_trace::TracedCall, output::VMOutput};
+use move_core_types::{
+    ident_str,
+    language_storage::{ModuleId, CORE_CODE_ADDRESS},
+    vm_status::VMStatus,
+};
+
+fn execute(h: &MoveHarness, txn: &SignedTransaction, record_call_trace: bool) -> VMOutput {
+    let state_view = h.executor.get_state_view();
+    let resolver = state_view.as_move_resolver();
+    let mut vm = h.new_vm();
+    vm.set_record_call_trace(record_call_trace);
+    let log_context = AdapterLogSchema::new(state_view.id(), 0);
+
+    let (status, output) = vm.execute_user_transaction(&resolver, txn, &log_context);
+    assert_eq!(status, VMStatus::Executed);
+    output
+}
+
+#[test]
+fn call_trace_of_publish_and_entry_function() {
+    let mut h = MoveHarness::new();
+    let acc = h.new_account_at(AccountAddress::from_hex_literal("0xcafe").unwrap());
+    let module_id = ModuleId::new(*acc.address(), ident_str!("test").to_owned());
+
+    let mut builder = PackageBuilder::new("Package");
+    builder.add_source(
+        "test.move",
+        "
+        module 0xcafe::test {
+            struct Initialized has key {}
+
+            fun init_module(account: &signer) {
+                move_to(account, Initialized {});
+            }
+
+            public entry fun run() {}
+        }
+        ",
+    );
+    let path = builder.write_to_temp().unwrap();
+    let publish_txn = h.create_publish_package(&acc, path.path(), None, |_| {});
+
+    // Nothing is recorded unless enabled on the VM.
+    assert_eq!(execute(&h, &publish_txn, false).call_trace(), None);
+
+    // Publishing runs the entry function of the code module, which then initializes the module.
+    assert_eq!(
+        execute(&h, &publish_txn, true).call_trace(),
+        Some(&vec![
+            TracedCall::EntryFunction {
+                module: ModuleId::new(CORE_CODE_ADDRESS, ident_str!("code").to_owned()),
+                function: ident_str!("publish_package_txn").to_owned(),
+            },
+            TracedCall::Function {
+                module: module_id.clone(),
+                function: ident_str!("init_module").to_owned(),
+            },
+        ])
+    );
+
+    assert_success!(h.run(publish_txn));
+    let run_txn = h.create_entry_function(
+        &acc,
+        str::parse("0xcafe::test::run").unwrap(),
+        vec![],
+        vec![],
+    );
+    assert_eq!(
+        execute(&h, &run_txn, true).call_trace(),
+        Some(&vec![TracedCall::EntryFunction {
+            module: module_id,
+            function: ident_str!("run").to_owned(),
+        }])
+    );
+}
@@ -0,0 +1,59 @@
+module 0xcafe::ten_x_token {
+    use aptos_framework::dispatchable_fungible_asset;
+    use aptos_framework::function_info;
+    use aptos_framework::fungible_asset::{Self, Metadata};
+    use aptos_framework::object::{Self, Object};
+    use aptos_framework::primary_fungible_store;
+    use std::option;
+    use std::signer;
+    use std::string;
+
+    struct Token has key {
+        metadata: Object<Metadata>,
+    }
+
+    public entry fun initialize(account: &signer) {
+        let constructor_ref = &object::create_named_object(account, b"TEN_X");
+        primary_fungible_store::create_primary_store_enabled_fungible_asset(
+            constructor_ref,
+            option::none(),
+            string::utf8(b"Ten X"),
+            string::utf8(b"TENX"),
+            0,
+            string::utf8(b""),
+            string::utf8(b""),
+        );
+        let derived_balance = function_info::new_function_info(
+            account,
+            string::utf8(b"ten_x_token"),
+            string::utf8(b"derived_balance"),
+        );
+        dispatchable_fungible_asset::register_dispatch_functions(
+            constructor_ref,
+            option::none(),
+            option::none(),
+            option::some(derived_balance),
+        );
+        let metadata = object::object_from_constructor_ref<Metadata>(constructor_ref);
+        move_to(account, Token { metadata });
+    }
+
+    public fun derived_balance<T: key>(store: Object<T>): u64 {
+        fungible_asset::balance(store) * 10
+    }
+
+    /// Reads the derived balance of the primary store of `account` `n` times, each read being a
+    /// dynamic dispatch.
+    public entry fun read_derived_balance(account: &signer, n: u64) acquires Token {
+        let metadata = borrow_global<Token>(@0xcafe).metadata;
+        let store = primary_fungible_store::ensure_primary_store_exists(
+            signer::address_of(account),
+            metadata,
+        );
+        let i = 0;
+        while (i < n) {
+            dispatchable_fungible_asset::derived_balance(store);
+            i = i + 1;
+        };
+    }
+}
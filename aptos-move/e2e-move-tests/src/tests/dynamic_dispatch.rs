@@ -0,0 +1,59 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{assert_success, assert_vm_status, tests::common, MoveHarness};
+use aptos_language_e2e_tests::account::Account;
+use aptos_types::{account_address::AccountAddress, transaction::TransactionStatus};
+use aptos_vm::counters::TXN_DYNAMIC_DISPATCH_LIMIT_REACHED;
+use move_core_types::vm_status::StatusCode;
+
+fn initialize(h: &mut MoveHarness, max_dynamic_dispatches: u64) -> Account {
+    let acc = h.new_account_at(AccountAddress::from_hex_literal("0xcafe").unwrap());
+    assert_success!(h.publish_package_cache_building(
+        &acc,
+        &common::test_dir_path("dynamic_dispatch.data/pack"),
+    ));
+    assert_success!(h.run_entry_function(
+        &acc,
+        str::parse("0xcafe::ten_x_token::initialize").unwrap(),
+        vec![],
+        vec![],
+    ));
+    h.modify_gas_schedule(|params| {
+        params.vm.txn.max_dynamic_dispatches = max_dynamic_dispatches.into();
+    });
+    acc
+}
+
+fn read_derived_balance(h: &mut MoveHarness, acc: &Account, n: u64) -> TransactionStatus {
+    h.run_entry_function(
+        acc,
+        str::parse("0xcafe::ten_x_token::read_derived_balance").unwrap(),
+        vec![],
+        vec![bcs::to_bytes(&n).unwrap()],
+    )
+}
+
+#[test]
+fn dynamic_dispatches_at_limit() {
+    let mut h = MoveHarness::new();
+    let acc = initialize(&mut h, 10);
+    assert_success!(read_derived_balance(&mut h, &acc, 10));
+}
+
+#[test]
+fn dynamic_dispatches_above_limit() {
+    let mut h = MoveHarness::new();
+    let acc = initialize(&mut h, 10);
+
+    let num_limit_reached = TXN_DYNAMIC_DISPATCH_LIMIT_REACHED.get();
+    assert_vm_status!(
+        read_derived_balance(&mut h, &acc, 11),
+        StatusCode::TOO_MANY_DYNAMIC_DISPATCHES
+    );
+    // Other tests may run concurrently, but none of them exceeds the limit.
+    assert_eq!(
+        TXN_DYNAMIC_DISPATCH_LIMIT_REACHED.get(),
+        num_limit_reached + 1
+    );
+}
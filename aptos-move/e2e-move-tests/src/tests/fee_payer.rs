@@ -28,6 +28,8 @@ use once_cell::sync::Lazy;
 // * Account doesn't exist (seq num 0), transaction aborts due to move abort, during charging of
 // account creation changeset, we run out of gas, but account must still be created. Note, this is
 // likely a duplicate of the first out of gas, but included.
+// * Account doesn't exist (seq num 0), account creation uses more gas than allowed, transaction
+// aborts, and account is created
 // * Invalid transactions are discarded during prologue, specifically the special case of seq num 0
 
 #[test]
@@ -326,6 +328,47 @@ fn test_account_not_exist_move_abort_with_fee_payer_out_of_gas() {
     assert_eq!(result.gas_used(), PRICING.new_account_upfront(1) + 1);
 }
 
+#[test]
+fn test_account_not_exist_with_fee_payer_create_account_exceeds_max_gas() {
+    let mut h = MoveHarness::new_with_features(
+        vec![
+            FeatureFlag::GAS_PAYER_ENABLED,
+            FeatureFlag::SPONSORED_AUTOMATIC_ACCOUNT_V1_CREATION,
+        ],
+        vec![],
+    );
+    // Creating an account costs more than a single gas unit.
+    h.modify_gas_schedule(|gas_params| {
+        gas_params.vm.txn.max_sponsored_account_creation_gas = 1.into();
+    });
+
+    let alice = Account::new();
+    let bob = h.new_account_at(AccountAddress::from_hex_literal("0xb0b").unwrap());
+    let bob_start = h.read_aptos_balance(bob.address());
+
+    let payload = aptos_stdlib::aptos_account_set_allow_direct_coin_transfers(true);
+    let transaction = TransactionBuilder::new(alice.clone())
+        .fee_payer(bob.clone())
+        .payload(payload)
+        .sequence_number(0)
+        .max_gas_amount(PRICING.new_account_upfront(1))
+        .gas_unit_price(1)
+        .sign_fee_payer();
+
+    let output = h.run_raw(transaction);
+    assert_eq!(
+        output.status().to_owned(),
+        TransactionStatus::Keep(ExecutionStatus::MiscellaneousError(Some(
+            StatusCode::EXECUTION_LIMIT_REACHED
+        ))),
+    );
+
+    // The payload is not executed, but the account is still created and the fee payer charged.
+    let bob_after = h.read_aptos_balance(bob.address());
+    assert_eq!(h.sequence_number(alice.address()), 1);
+    assert!(bob_start > bob_after);
+}
+
 #[test]
 fn test_account_not_exist_with_fee_payer_without_create_account() {
     let mut h = MoveHarness::new_with_features(vec![FeatureFlag::GAS_PAYER_ENABLED], vec![
@@ -8,11 +8,14 @@ mod aggregator_v2;
 mod aggregator_v2_events;
 mod aggregator_v2_runtime_checks;
 mod attributes;
+mod call_stack_depth;
+mod call_trace;
 mod chain_id;
 mod code_publishing;
 mod common;
 mod constructor_args;
 mod dependencies;
+mod dynamic_dispatch;
 mod error_map;
 mod fee_payer;
 mod fungible_asset;
@@ -65,6 +65,46 @@ fn test_module_event_enabled() {
     assert_eq!(count, 10);
 }
 
+#[test]
+fn test_too_many_module_events() {
+    let mut h = MoveHarness::new_with_features(vec![FeatureFlag::MODULE_EVENT], vec![]);
+
+    let addr = AccountAddress::from_hex_literal("0xcafe").unwrap();
+    let account = h.new_account_at(addr);
+
+    let mut build_options = aptos_framework::BuildOptions::default();
+    build_options
+        .named_addresses
+        .insert("event".to_string(), addr);
+
+    let result = h.publish_package_with_options(
+        &account,
+        &common::test_dir_path("../../../move-examples/event"),
+        build_options,
+    );
+    assert_success!(result);
+
+    h.modify_gas_schedule(|gas_params| {
+        gas_params.vm.txn.max_events_per_transaction = 10.into();
+    });
+
+    let result = h.run_entry_function(
+        &account,
+        str::parse("0xcafe::event::emit").unwrap(),
+        vec![],
+        vec![bcs::to_bytes(&5u64).unwrap()],
+    );
+    assert_success!(result);
+
+    let result = h.run_entry_function(
+        &account,
+        str::parse("0xcafe::event::emit").unwrap(),
+        vec![],
+        vec![bcs::to_bytes(&20u64).unwrap()],
+    );
+    assert_vm_status!(result, StatusCode::TOO_MANY_EVENTS);
+}
+
 #[test]
 fn verify_module_event_upgrades() {
     let mut h = MoveHarness::new_with_features(vec![FeatureFlag::MODULE_EVENT], vec![]);
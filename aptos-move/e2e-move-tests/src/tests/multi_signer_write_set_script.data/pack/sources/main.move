@@ -0,0 +1,12 @@
+script {
+    use aptos_framework::aptos_account;
+    use aptos_framework::aptos_governance;
+    use aptos_framework::block;
+
+    fun main(vm: &signer, aptos_framework: &signer, alice: &signer, receiver: address, amount: u64) {
+        aptos_account::transfer(alice, receiver, amount);
+        // A write set transaction has to start a new block and epoch.
+        block::emit_writeset_block_event(vm, @0x1);
+        aptos_governance::force_end_epoch(aptos_framework);
+    }
+}
@@ -380,6 +380,147 @@ fn test_resource_groups(resource_group_charge_as_sum_enabled: bool) {
     assert!(h.read_resource_raw(&user_addr, secondary_tag).is_none());
 }
 
+#[test]
+fn test_resource_group_size_limit() {
+    let mut h = MoveHarness::new();
+    h.enable_features(
+        vec![FeatureFlag::RESOURCE_GROUPS_SPLIT_IN_VM_CHANGE_SET],
+        vec![],
+    );
+
+    let primary_addr = AccountAddress::from_hex_literal("0xcafe").unwrap();
+    let primary_account = h.new_account_at(primary_addr);
+    let secondary_addr = AccountAddress::from_hex_literal("0xf00d").unwrap();
+    let secondary_account = h.new_account_at(secondary_addr);
+    let user_addr = AccountAddress::from_hex_literal("0x0123").unwrap();
+    let user_account = h.new_account_at(user_addr);
+
+    let mut build_options = aptos_framework::BuildOptions::default();
+    build_options
+        .named_addresses
+        .insert("resource_groups_primary".to_string(), primary_addr);
+    assert_success!(h.publish_package_with_options(
+        &primary_account,
+        &common::test_dir_path("../../../move-examples/resource_groups/primary"),
+        build_options.clone(),
+    ));
+    build_options
+        .named_addresses
+        .insert("resource_groups_secondary".to_string(), secondary_addr);
+    assert_success!(h.publish_package_with_options(
+        &secondary_account,
+        &common::test_dir_path("../../../move-examples/resource_groups/secondary"),
+        build_options,
+    ));
+
+    let secondary_init = format!("0x{}::secondary::init", secondary_addr.to_hex());
+    let secondary_set_value = format!("0x{}::secondary::set_value", secondary_addr.to_hex());
+    let primary_init = format!("0x{}::primary::init", primary_addr.to_hex());
+
+    // Creating the group already exceeds a 1 byte limit.
+    h.modify_gas_schedule(|gas_params| {
+        gas_params.vm.txn.max_bytes_per_resource_group = 1.into();
+    });
+    let result = h.run_entry_function(
+        &user_account,
+        str::parse(&secondary_init).unwrap(),
+        vec![],
+        vec![bcs::to_bytes::<u32>(&22).unwrap()],
+    );
+    assert_vm_status!(result, StatusCode::RESOURCE_GROUP_SIZE_LIMIT_REACHED);
+
+    h.modify_gas_schedule(|gas_params| {
+        gas_params.vm.txn.max_bytes_per_resource_group = (1 << 20).into();
+    });
+    assert_success!(h.run_entry_function(
+        &user_account,
+        str::parse(&secondary_init).unwrap(),
+        vec![],
+        vec![bcs::to_bytes::<u32>(&22).unwrap()],
+    ));
+
+    // Adding a member grows the group past the limit, modifying an existing member in place
+    // does not grow it and is still allowed.
+    h.modify_gas_schedule(|gas_params| {
+        gas_params.vm.txn.max_bytes_per_resource_group = 1.into();
+    });
+    let result = h.run_entry_function(
+        &user_account,
+        str::parse(&primary_init).unwrap(),
+        vec![],
+        vec![bcs::to_bytes::<u64>(&11122).unwrap()],
+    );
+    assert_vm_status!(result, StatusCode::RESOURCE_GROUP_SIZE_LIMIT_REACHED);
+    assert_success!(h.run_entry_function(
+        &user_account,
+        str::parse(&secondary_set_value).unwrap(),
+        vec![],
+        vec![bcs::to_bytes::<u32>(&33).unwrap()],
+    ));
+}
+
+#[test]
+fn test_resource_group_size_limit_at_default_settings() {
+    let mut h = MoveHarness::new();
+    h.enable_features(
+        vec![FeatureFlag::RESOURCE_GROUPS_SPLIT_IN_VM_CHANGE_SET],
+        vec![],
+    );
+
+    let account = h.new_account_at(AccountAddress::from_hex_literal("0xcafe").unwrap());
+    let source = r#"
+        module 0xcafe::large_group {
+            use std::vector;
+
+            #[resource_group(scope = global)]
+            struct LargeGroup { }
+
+            #[resource_group_member(group = 0xcafe::large_group::LargeGroup)]
+            struct Blob has key {
+                data: vector<vector<u8>>,
+            }
+
+            /// Stores a blob of `num_kbs` kilobytes in the group.
+            public entry fun store(account: &signer, num_kbs: u64) {
+                let kb = vector[];
+                while (vector::length(&kb) < 1024) {
+                    vector::push_back(&mut kb, 0u8);
+                };
+                let data = vector[];
+                while (vector::length(&data) < num_kbs) {
+                    vector::push_back(&mut data, copy kb);
+                };
+                move_to(account, Blob { data });
+            }
+        }
+        "#;
+    let mut builder = PackageBuilder::new("Package");
+    builder.add_source("large_group.move", source);
+    builder.add_alias("std", "0x1");
+    builder.add_local_dep(
+        "MoveStdlib",
+        &common::framework_dir_path("move-stdlib")
+            .display()
+            .to_string(),
+    );
+    let path = builder.write_to_temp().unwrap();
+    assert_success!(h.publish_package(&account, path.path()));
+
+    let store = str::parse("0xcafe::large_group::store").unwrap();
+    // 512KB fit into the group.
+    let alice = h.new_account_at(AccountAddress::from_hex_literal("0xa11ce").unwrap());
+    assert_success!(h.run_entry_function(&alice, store, vec![], vec![
+        bcs::to_bytes::<u64>(&512).unwrap()
+    ]));
+    // 1MB is above the default limit, which is reported instead of the write op size limit.
+    let bob = h.new_account_at(AccountAddress::from_hex_literal("0xb0b").unwrap());
+    let store = str::parse("0xcafe::large_group::store").unwrap();
+    let result = h.run_entry_function(&bob, store, vec![], vec![
+        bcs::to_bytes::<u64>(&1024).unwrap()
+    ]);
+    assert_vm_status!(result, StatusCode::RESOURCE_GROUP_SIZE_LIMIT_REACHED);
+}
+
 #[test]
 fn test_resource_groups_container_not_enabled() {
     let mut h = MoveHarness::new_with_features(vec![], vec![FeatureFlag::RESOURCE_GROUPS]);
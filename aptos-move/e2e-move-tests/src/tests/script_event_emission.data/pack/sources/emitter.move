@@ -0,0 +1,11 @@
+module 0xcafe::emitter {
+    #[event]
+    struct Greeting has drop, store {
+        value: u64,
+    }
+
+    /// Scripts can't pack structs, so they get the event to emit from here.
+    public fun new_greeting(value: u64): Greeting {
+        Greeting { value }
+    }
+}
@@ -1,13 +1,26 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{assert_success, build_package, tests::common, MoveHarness};
+use crate::{assert_success, assert_vm_status, build_package, tests::common, MoveHarness};
 use aptos_language_e2e_tests::account::TransactionBuilder;
 use aptos_types::{
     account_address::AccountAddress,
-    transaction::{Script, TransactionArgument},
+    account_config::reserved_vm_address,
+    on_chain_config::FeatureFlag,
+    transaction::{Script, Transaction, TransactionArgument, WriteSetPayload},
 };
-use move_core_types::language_storage::TypeTag;
+use move_binary_format::file_format::{
+    empty_script, Ability, AbilitySet, AddressIdentifierIndex, Bytecode, FunctionHandle,
+    FunctionHandleIndex, FunctionInstantiation, FunctionInstantiationIndex, IdentifierIndex,
+    ModuleHandle, ModuleHandleIndex, Signature, SignatureIndex, SignatureToken, StructHandle,
+    StructHandleIndex,
+};
+use move_core_types::{
+    identifier::Identifier,
+    language_storage::{TypeTag, CORE_CODE_ADDRESS},
+    vm_status::StatusCode,
+};
+use std::str::FromStr;
 
 #[test]
 fn test_script_with_type_parameter() {
@@ -103,6 +116,190 @@ fn test_two_to_two_transfer() {
     assert_eq!(david_start + amount_david, david_end);
 }
 
+fn multi_signer_write_set_script_txn(
+    alice: &AccountAddress,
+    receiver: &AccountAddress,
+    amount: u64,
+) -> Transaction {
+    let package = build_package(
+        common::test_dir_path("multi_signer_write_set_script.data/pack"),
+        aptos_framework::BuildOptions::default(),
+    )
+    .expect("building package must succeed");
+    let code = package.extract_script_code()[0].clone();
+    Transaction::GenesisTransaction(WriteSetPayload::MultiSignerScript {
+        signers: vec![reserved_vm_address(), CORE_CODE_ADDRESS, *alice],
+        script: Script::new(code, vec![], vec![
+            TransactionArgument::Address(*receiver),
+            TransactionArgument::U64(amount),
+        ]),
+    })
+}
+
+#[test]
+fn test_multi_signer_write_set_script() {
+    let mut h = MoveHarness::new();
+    h.enable_features(vec![FeatureFlag::MULTI_SIGNER_WRITE_SET_SCRIPT], vec![]);
+    // Reconfiguration is skipped if the time hasn't changed since the last one.
+    h.new_epoch();
+    h.executor.new_block();
+
+    let alice = h.new_account_at(AccountAddress::from_hex_literal("0xa11ce").unwrap());
+    let bob = h.new_account_at(AccountAddress::from_hex_literal("0xb0b").unwrap());
+    let alice_start = read_coin(&h, alice.address());
+    let bob_start = read_coin(&h, bob.address());
+
+    let amount = 100;
+    let txn = multi_signer_write_set_script_txn(alice.address(), bob.address(), amount);
+    let output = h
+        .executor
+        .execute_transaction_block(vec![txn])
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert_success!(output.status().to_owned());
+    h.executor.apply_write_set(output.write_set());
+
+    // Write set transactions are not charged for gas.
+    assert_eq!(read_coin(&h, alice.address()), alice_start - amount);
+    assert_eq!(read_coin(&h, bob.address()), bob_start + amount);
+}
+
+#[test]
+fn test_multi_signer_write_set_script_feature_gated() {
+    let mut h = MoveHarness::new();
+    h.enable_features(vec![], vec![FeatureFlag::MULTI_SIGNER_WRITE_SET_SCRIPT]);
+
+    let alice = h.new_account_at(AccountAddress::from_hex_literal("0xa11ce").unwrap());
+    let bob = h.new_account_at(AccountAddress::from_hex_literal("0xb0b").unwrap());
+
+    let txn = multi_signer_write_set_script_txn(alice.address(), bob.address(), 100);
+    let status = h.executor.execute_transaction_block(vec![txn]).unwrap_err();
+    assert_eq!(status.status_code(), StatusCode::FEATURE_UNDER_GATING);
+}
+
+/// Returns a script emitting `0xcafe::emitter::Greeting { value }`, built by hand as the compiler
+/// rejects event emission outside of the module declaring the event.
+fn greeting_emitting_script() -> Vec<u8> {
+    let mut script = empty_script();
+    script.code.code = vec![
+        Bytecode::MoveLoc(0),
+        Bytecode::Call(FunctionHandleIndex(1)),
+        Bytecode::CallGeneric(FunctionInstantiationIndex(0)),
+        Bytecode::Ret,
+    ];
+    script.parameters = SignatureIndex(3);
+    script.function_instantiations.push(FunctionInstantiation {
+        handle: FunctionHandleIndex(0),
+        type_parameters: SignatureIndex(2),
+    });
+    script.function_handles = vec![
+        // 0x1::event::emit
+        FunctionHandle {
+            module: ModuleHandleIndex(0),
+            name: IdentifierIndex(1),
+            parameters: SignatureIndex(1),
+            return_: SignatureIndex(0),
+            type_parameters: vec![
+                AbilitySet::singleton(Ability::Store) | AbilitySet::singleton(Ability::Drop),
+            ],
+            access_specifiers: None,
+        },
+        // 0xcafe::emitter::new_greeting
+        FunctionHandle {
+            module: ModuleHandleIndex(1),
+            name: IdentifierIndex(3),
+            parameters: SignatureIndex(3),
+            return_: SignatureIndex(2),
+            type_parameters: vec![],
+            access_specifiers: None,
+        },
+    ];
+    script.struct_handles.push(StructHandle {
+        module: ModuleHandleIndex(1),
+        name: IdentifierIndex(4),
+        abilities: AbilitySet::singleton(Ability::Store) | AbilitySet::singleton(Ability::Drop),
+        type_parameters: vec![],
+    });
+    script.module_handles = vec![
+        ModuleHandle {
+            address: AddressIdentifierIndex(0),
+            name: IdentifierIndex(0),
+        },
+        ModuleHandle {
+            address: AddressIdentifierIndex(1),
+            name: IdentifierIndex(2),
+        },
+    ];
+    script.address_identifiers = vec![
+        AccountAddress::ONE,
+        AccountAddress::from_hex_literal("0xcafe").unwrap(),
+    ];
+    script.identifiers = vec![
+        Identifier::new("event").unwrap(),
+        Identifier::new("emit").unwrap(),
+        Identifier::new("emitter").unwrap(),
+        Identifier::new("new_greeting").unwrap(),
+        Identifier::new("Greeting").unwrap(),
+    ];
+    script.signatures = vec![
+        Signature(vec![]),
+        Signature(vec![SignatureToken::TypeParameter(0)]),
+        Signature(vec![SignatureToken::Struct(StructHandleIndex(0))]),
+        Signature(vec![SignatureToken::U64]),
+    ];
+    let mut blob = vec![];
+    script.serialize(&mut blob).expect("script must serialize");
+    blob
+}
+
+#[test]
+fn test_script_emitting_declared_event() {
+    let mut h = MoveHarness::new_with_features(vec![FeatureFlag::SCRIPT_EVENT_EMISSION], vec![]);
+
+    let cafe = h.new_account_at(AccountAddress::from_hex_literal("0xcafe").unwrap());
+    assert_success!(h.publish_package(
+        &cafe,
+        &common::test_dir_path("script_event_emission.data/pack")
+    ));
+
+    let txn = h.create_script(&cafe, greeting_emitting_script(), vec![], vec![
+        TransactionArgument::U64(42),
+    ]);
+    let output = h.run_raw(txn);
+    assert_success!(output.status().to_owned());
+
+    let greeting_tag = TypeTag::from_str("0xcafe::emitter::Greeting").unwrap();
+    let greetings: Vec<_> = output
+        .events()
+        .iter()
+        .filter(|event| event.type_tag() == &greeting_tag)
+        .collect();
+    assert_eq!(greetings.len(), 1);
+    assert_eq!(
+        bcs::from_bytes::<u64>(greetings[0].event_data()).unwrap(),
+        42
+    );
+}
+
+#[test]
+fn test_script_emitting_event_of_unauthorized_module() {
+    let mut h = MoveHarness::new_with_features(vec![FeatureFlag::SCRIPT_EVENT_EMISSION], vec![]);
+
+    let cafe = h.new_account_at(AccountAddress::from_hex_literal("0xcafe").unwrap());
+    assert_success!(h.publish_package(
+        &cafe,
+        &common::test_dir_path("script_event_emission.data/pack")
+    ));
+
+    // The event is declared, but alice is not a signer for 0xcafe.
+    let alice = h.new_account_at(AccountAddress::from_hex_literal("0xa11ce").unwrap());
+    let txn = h.create_script(&alice, greeting_emitting_script(), vec![], vec![
+        TransactionArgument::U64(42),
+    ]);
+    assert_vm_status!(h.run(txn), StatusCode::EVENT_METADATA_VALIDATION_ERROR);
+}
+
 fn read_coin(h: &MoveHarness, account: &AccountAddress) -> u64 {
     h.read_aptos_balance(account)
 }
@@ -1,7 +1,7 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{assert_success, tests::common::test_dir_path, MoveHarness};
+use crate::{assert_success, assert_vm_status, tests::common::test_dir_path, MoveHarness};
 use aptos_language_e2e_tests::account::Account;
 use aptos_types::{
     on_chain_config::FeatureFlag,
@@ -9,7 +9,7 @@ use aptos_types::{
     write_set::WriteOp,
 };
 use aptos_vm::testing::{testing_only::inject_error_once, InjectedError};
-use move_core_types::account_address::AccountAddress;
+use move_core_types::{account_address::AccountAddress, vm_status::StatusCode};
 use serde::Serialize;
 
 #[test]
@@ -93,6 +93,39 @@ fn test_refunds() {
     assert_result(&mut h, &user_acc, "destroy_collection", vec![], 0, false);
 }
 
+#[test]
+fn test_resource_creation_limit() {
+    let mut h = MoveHarness::new();
+    h.modify_gas_schedule(|params| {
+        params.vm.txn.max_execution_gas = 40_000_000_000.into();
+    });
+    let mod_addr = AccountAddress::from_hex_literal("0xcafe").unwrap();
+    let mod_acc = h.new_account_at(mod_addr);
+    assert_success!(h.publish_package(&mod_acc, &test_dir_path("storage_refund.data/pack")));
+
+    // Creating the collection takes 1025 new slots, see `test_refunds`.
+    h.modify_gas_schedule(|params| {
+        params.vm.txn.max_resource_creations_per_txn = 1024.into();
+    });
+    let result = h.run_entry_function(
+        &mod_acc,
+        "0xcafe::test::init_collection_of_1000".parse().unwrap(),
+        vec![],
+        vec![],
+    );
+    assert_vm_status!(result, StatusCode::RESOURCE_CREATION_LIMIT_REACHED);
+
+    h.modify_gas_schedule(|params| {
+        params.vm.txn.max_resource_creations_per_txn = 1025.into();
+    });
+    assert_success!(h.run_entry_function(
+        &mod_acc,
+        "0xcafe::test::init_collection_of_1000".parse().unwrap(),
+        vec![],
+        vec![],
+    ));
+}
+
 const LEEWAY: u64 = 2000;
 
 fn read_slot_fee_from_gas_schedule(h: &MoveHarness) -> u64 {
@@ -680,3 +680,32 @@ fn huge_string_args_are_not_allowed() {
     ));
     fail(tests);
 }
+
+#[test]
+fn string_args_above_size_limit_are_not_allowed() {
+    let mut h = MoveHarness::new();
+
+    let acc = h.new_account_at(AccountAddress::from_hex_literal("0xcafe").unwrap());
+    assert_success!(
+        h.publish_package_cache_building(&acc, &common::test_dir_path("string_args.data/pack"))
+    );
+
+    h.modify_gas_schedule(|gas_params| {
+        gas_params.vm.txn.max_txn_args_bytes = 100.into();
+    });
+
+    let arg = |len: usize| vec![bcs::to_bytes(&"a".repeat(len)).unwrap()];
+    assert_success!(h.run_entry_function(
+        &acc,
+        str::parse("0xcafe::test::hi").unwrap(),
+        vec![],
+        arg(99),
+    ));
+    let status = h.run_entry_function(
+        &acc,
+        str::parse("0xcafe::test::hi").unwrap(),
+        vec![],
+        arg(100),
+    );
+    assert_vm_status!(status, StatusCode::TXN_ARGS_SIZE_LIMIT_REACHED);
+}
@@ -1,14 +1,40 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::MoveHarness;
+use crate::{assert_success, build_package, MoveHarness};
 use aptos_cached_packages::aptos_stdlib::aptos_account_transfer;
+use aptos_crypto::HashValue;
+use aptos_framework::BuildOptions;
+use aptos_gas_schedule::LATEST_GAS_FEATURE_VERSION;
+use aptos_package_builder::PackageBuilder;
 use aptos_types::{
-    state_store::state_key::StateKey, transaction::ExecutionStatus, write_set::WriteOp,
+    account_config::{AccountResource, ChainIdResource},
+    block_executor::config::BlockExecutorConfigFromOnchain,
+    block_metadata::BlockMetadata,
+    on_chain_config::{CurrentTimeMicroseconds, OnChainConfig, ValidatorSet},
+    state_store::{state_key::StateKey, TStateView},
+    transaction::{
+        signature_verified_transaction::into_signature_verified_block, ExecutionStatus,
+        ModuleBundle, Transaction,
+    },
+    write_set::WriteOp,
 };
 use aptos_vm::{data_cache::AsMoveResolver, AptosVM};
-use claims::{assert_ok_eq, assert_some};
-use move_core_types::vm_status::{StatusCode, VMStatus};
+use aptos_vm_logging::log_schema::AdapterLogSchema;
+use aptos_vm_types::{
+    abstract_write_op::AbstractResourceWriteOp, change_set::VMChangeSet,
+    storage::change_set_configs::ChangeSetConfigs,
+};
+use claims::{assert_err, assert_ok, assert_ok_eq, assert_some};
+use move_binary_format::compatibility::Incompatibility;
+use move_core_types::{
+    account_address::AccountAddress,
+    ident_str,
+    identifier::Identifier,
+    language_storage::{ModuleId, CORE_CODE_ADDRESS},
+    resolver::ModuleResolver,
+    vm_status::{StatusCode, VMStatus},
+};
 use test_case::test_case;
 
 // Make sure verification and invariant violation errors are kept.
@@ -64,3 +90,324 @@ fn failed_transaction_cleanup_charges_gas(status_code: StatusCode) {
         ExecutionStatus::MiscellaneousError(None)
     );
 }
+
+#[test]
+fn replay_rejects_newer_gas_feature_version() {
+    let h = MoveHarness::new();
+    let state_view = h.executor.get_state_view();
+    let vm = AptosVM::new(
+        &state_view.as_move_resolver(),
+        /*override_is_delayed_field_optimization_capable=*/ Some(false),
+    );
+    assert_eq!(vm.gas_feature_version(), LATEST_GAS_FEATURE_VERSION);
+
+    assert_ok!(vm.check_gas_feature_version_for_replay(LATEST_GAS_FEATURE_VERSION));
+    assert_ok!(vm.check_gas_feature_version_for_replay(LATEST_GAS_FEATURE_VERSION + 1));
+    let status =
+        assert_err!(vm.check_gas_feature_version_for_replay(LATEST_GAS_FEATURE_VERSION - 1));
+    assert_eq!(status.status_code(), StatusCode::VM_STARTUP_FAILURE);
+}
+
+#[test]
+fn module_manifest_pins_transitive_dependencies() {
+    let h = MoveHarness::new();
+    let state_view = h.executor.get_state_view();
+    let resolver = state_view.as_move_resolver();
+    let vm = AptosVM::new(
+        &resolver,
+        /*override_is_delayed_field_optimization_capable=*/ Some(false),
+    );
+
+    let manifest =
+        assert_ok!(vm.module_manifest(&resolver, &aptos_account_transfer(AccountAddress::ONE, 1)));
+
+    // The called module and its (transitive) dependencies are all pinned to their current bytes.
+    let aptos_account = ModuleId::new(CORE_CODE_ADDRESS, ident_str!("aptos_account").to_owned());
+    let coin = ModuleId::new(CORE_CODE_ADDRESS, ident_str!("coin").to_owned());
+    let vector = ModuleId::new(CORE_CODE_ADDRESS, ident_str!("vector").to_owned());
+    for module_id in [aptos_account, coin, vector] {
+        let bytes = assert_some!(assert_ok!(resolver.get_module(&module_id)));
+        let (_, hash) = assert_some!(manifest.iter().find(|(id, _)| id == &module_id));
+        assert_eq!(*hash, HashValue::sha3_256_of(&bytes));
+    }
+    assert!(manifest.windows(2).all(|w| w[0].0 < w[1].0));
+}
+
+#[test]
+fn check_upgrade_compatibility_reports_incompatibilities_per_module() {
+    let mut h = MoveHarness::new();
+    let acc = h.new_account_at(AccountAddress::from_hex_literal("0xcafe").unwrap());
+
+    let mut initial = PackageBuilder::new("Package");
+    initial.add_source("a", "module 0xcafe::a { public fun f() {} }");
+    initial.add_source(
+        "b",
+        "module 0xcafe::b { struct S has key { x: u64 } public fun g(): u64 { 1 } public fun h() {} }",
+    );
+    let initial_dir = initial.write_to_temp().unwrap();
+    assert_success!(h.publish_package(&acc, initial_dir.path()));
+
+    // `a` is unchanged, `b` changes the layout of `S` and removes `g`, and `c` is new.
+    let mut upgrade = PackageBuilder::new("Package");
+    upgrade.add_source("a", "module 0xcafe::a { public fun f() {} }");
+    upgrade.add_source(
+        "b",
+        "module 0xcafe::b { struct S has key { x: u64, y: u64 } public fun h() {} }",
+    );
+    upgrade.add_source("c", "module 0xcafe::c { public fun f() {} }");
+    let upgrade_dir = upgrade.write_to_temp().unwrap();
+    let package = assert_ok!(build_package(
+        upgrade_dir.path().to_path_buf(),
+        BuildOptions::default()
+    ));
+    let bundle = ModuleBundle::new(package.extract_code());
+
+    let state_view = h.executor.get_state_view();
+    let resolver = state_view.as_move_resolver();
+    let vm = AptosVM::new(
+        &resolver,
+        /*override_is_delayed_field_optimization_capable=*/ Some(false),
+    );
+    let result = assert_ok!(vm.check_upgrade_compatibility(&resolver, &bundle));
+
+    assert_eq!(result.len(), 3);
+    let incompatibilities = |name: &str| {
+        let module_id = ModuleId::new(*acc.address(), Identifier::new(name).unwrap());
+        let (_, incompatibilities) = assert_some!(result.iter().find(|(id, _)| id == &module_id));
+        incompatibilities.clone()
+    };
+    assert_eq!(incompatibilities("a"), vec![]);
+    assert_eq!(incompatibilities("b"), vec![
+        Incompatibility::StructLayoutChanged(ident_str!("S").to_owned()),
+        Incompatibility::FunctionRemoved(ident_str!("g").to_owned()),
+    ]);
+    assert_eq!(incompatibilities("c"), vec![]);
+}
+
+#[test]
+fn block_prologue_with_timestamp_overrides_block_metadata() {
+    let mut h = MoveHarness::new();
+    let timestamp_usecs = {
+        let state_view = h.executor.get_state_view();
+        let resolver = state_view.as_move_resolver();
+        let now = assert_some!(CurrentTimeMicroseconds::fetch_config(&resolver)).microseconds;
+        now + 42_000_000
+    };
+
+    let output = {
+        let state_view = h.executor.get_state_view();
+        let resolver = state_view.as_move_resolver();
+        let vm = AptosVM::new(
+            &resolver,
+            /*override_is_delayed_field_optimization_capable=*/ Some(false),
+        );
+
+        let validator_set = assert_some!(ValidatorSet::fetch_config(&resolver));
+        let proposer = *validator_set.payload().next().unwrap().account_address();
+        // The block metadata itself carries a different timestamp, which must be ignored.
+        let block_metadata = BlockMetadata::new(
+            HashValue::zero(),
+            0,
+            0,
+            proposer,
+            vec![],
+            vec![],
+            timestamp_usecs - 1,
+        );
+
+        let (status, output) = assert_ok!(vm.test_block_prologue_with_timestamp(
+            block_metadata,
+            timestamp_usecs,
+            state_view
+        ));
+        assert_eq!(status, VMStatus::Executed);
+        assert_ok!(output.try_materialize_into_transaction_output(&resolver))
+    };
+    h.executor.apply_write_set(output.write_set());
+
+    let resolver = h.executor.get_state_view().as_move_resolver();
+    let time = assert_some!(CurrentTimeMicroseconds::fetch_config(&resolver));
+    assert_eq!(time.microseconds, timestamp_usecs);
+}
+
+#[test]
+fn execute_block_with_explicit_metadata() {
+    let mut h = MoveHarness::new();
+    let sender = h.new_account_with_balance_and_sequence_number(1_000_000, 0);
+    let receiver = h.new_account_with_balance_and_sequence_number(1_000_000, 0);
+    let txn = sender
+        .transaction()
+        .sequence_number(0)
+        .payload(aptos_account_transfer(*receiver.address(), 1))
+        .sign();
+
+    let state_view = h.executor.get_state_view();
+    let resolver = state_view.as_move_resolver();
+    let now = assert_some!(CurrentTimeMicroseconds::fetch_config(&resolver)).microseconds;
+    let validator_set = assert_some!(ValidatorSet::fetch_config(&resolver));
+    let proposer = *validator_set.payload().next().unwrap().account_address();
+    let block_metadata =
+        BlockMetadata::new(HashValue::zero(), 0, 0, proposer, vec![], vec![], now + 1);
+
+    let outputs = assert_ok!(AptosVM::execute_block_with_explicit_metadata(
+        block_metadata,
+        vec![txn],
+        state_view,
+        BlockExecutorConfigFromOnchain::new_no_block_limit(),
+    ))
+    .into_inner();
+    assert_eq!(outputs.len(), 2);
+    for output in outputs {
+        assert_ok_eq!(output.status().status(), ExecutionStatus::Success);
+    }
+}
+
+#[test]
+fn execute_block_raw() {
+    let mut h = MoveHarness::new();
+    let sender = h.new_account_with_balance_and_sequence_number(1_000_000, 0);
+    let receiver = h.new_account_with_balance_and_sequence_number(1_000_000, 0);
+    // The second transaction is only valid if the first one is visible to it.
+    let txns: Vec<Transaction> = (0..2)
+        .map(|sequence_number| {
+            Transaction::UserTransaction(
+                sender
+                    .transaction()
+                    .sequence_number(sequence_number)
+                    .payload(aptos_account_transfer(*receiver.address(), 1))
+                    .sign(),
+            )
+        })
+        .collect();
+
+    let outputs = assert_ok!(AptosVM::execute_block_raw(
+        &into_signature_verified_block(txns),
+        h.executor.get_state_view(),
+    ));
+    assert_eq!(outputs.len(), 2);
+    for output in outputs {
+        assert_ok_eq!(output.status().status(), ExecutionStatus::Success);
+    }
+}
+
+#[test]
+fn view_function_output_records_gas_feature_version() {
+    let mut h = MoveHarness::new();
+    let output = h.execute_view_function(str::parse("0x1::chain_id::get").unwrap(), vec![], vec![]);
+    assert_ok!(output.values);
+    assert_eq!(output.gas_feature_version, Some(LATEST_GAS_FEATURE_VERSION));
+}
+
+#[test]
+fn view_function_output_records_read_set() {
+    let h = MoveHarness::new();
+    let output = AptosVM::execute_view_function_with_read_set(
+        h.executor.get_state_view(),
+        ModuleId::new(CORE_CODE_ADDRESS, ident_str!("chain_id").to_owned()),
+        ident_str!("get").to_owned(),
+        vec![],
+        vec![],
+        u64::MAX,
+    );
+    assert_ok!(output.values);
+    let chain_id_key = StateKey::resource_typed::<ChainIdResource>(&CORE_CODE_ADDRESS).unwrap();
+    assert!(assert_some!(output.read_set).contains(&chain_id_key));
+
+    // Read sets are only recorded on request.
+    let output = AptosVM::execute_view_function(
+        h.executor.get_state_view(),
+        ModuleId::new(CORE_CODE_ADDRESS, ident_str!("chain_id").to_owned()),
+        ident_str!("get").to_owned(),
+        vec![],
+        vec![],
+        u64::MAX,
+    );
+    assert!(output.read_set.is_none());
+}
+
+#[test]
+fn view_function_with_type_arg_strs() {
+    let h = MoveHarness::new();
+    let execute = |type_arg: &str| {
+        AptosVM::execute_view_function_with_type_arg_strs(
+            h.executor.get_state_view(),
+            ModuleId::new(CORE_CODE_ADDRESS, ident_str!("coin").to_owned()),
+            ident_str!("decimals").to_owned(),
+            vec![type_arg.to_string()],
+            vec![],
+            u64::MAX,
+        )
+    };
+
+    let output = execute("0x1::aptos_coin::AptosCoin");
+    assert_ok_eq!(output.values, vec![bcs::to_bytes(&8u8).unwrap()]);
+
+    // Unparsable and unknown types are rejected with an error naming the type argument.
+    for type_arg in ["0x1::aptos_coin::", "0x1::nonexistent::Foo"] {
+        let err = assert_err!(execute(type_arg).values);
+        assert!(err.to_string().contains(type_arg));
+    }
+}
+
+#[test]
+fn execute_user_transaction_with_overlay() {
+    let mut h = MoveHarness::new();
+    let sender = h.new_account_with_balance_and_sequence_number(1_000_000, 10);
+    let receiver = h.new_account_with_balance_and_sequence_number(1_000_000, 10);
+    let txn = sender
+        .transaction()
+        .sequence_number(10)
+        .payload(aptos_account_transfer(*receiver.address(), 1))
+        .sign();
+
+    let state_view = h.executor.get_state_view();
+    let resolver = state_view.as_move_resolver();
+    let vm = AptosVM::new(
+        &resolver,
+        /*override_is_delayed_field_optimization_capable=*/ Some(false),
+    );
+    let log_context = AdapterLogSchema::new(state_view.id(), 0);
+
+    let (status, output) = vm.execute_user_transaction_with_overlay(
+        &resolver,
+        VMChangeSet::empty(),
+        &txn,
+        &log_context,
+    );
+    assert_eq!(status, VMStatus::Executed);
+    assert!(!output.status().is_discarded());
+
+    // What if the sender had already sent the transaction?
+    let account_key = StateKey::resource_typed::<AccountResource>(sender.address()).unwrap();
+    let account: AccountResource = bcs::from_bytes(&assert_some!(assert_ok!(
+        state_view.get_state_value_bytes(&account_key)
+    )))
+    .unwrap();
+    let account = AccountResource::new(
+        11,
+        account.authentication_key().to_vec(),
+        account.coin_register_events().clone(),
+        account.key_rotation_events().clone(),
+    );
+    let overlay = assert_ok!(VMChangeSet::new(
+        [(
+            account_key,
+            AbstractResourceWriteOp::Write(WriteOp::legacy_modification(
+                bcs::to_bytes(&account).unwrap().into(),
+            )),
+        )]
+        .into_iter()
+        .collect(),
+        Default::default(),
+        vec![],
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        &ChangeSetConfigs::unlimited_at_gas_feature_version(LATEST_GAS_FEATURE_VERSION),
+    ));
+
+    let (status, output) =
+        vm.execute_user_transaction_with_overlay(&resolver, overlay, &txn, &log_context);
+    assert_eq!(status.status_code(), StatusCode::SEQUENCE_NUMBER_TOO_OLD);
+    assert!(output.status().is_discarded());
+}
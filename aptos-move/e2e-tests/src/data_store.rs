@@ -6,6 +6,7 @@
 
 use crate::account::AccountData;
 use aptos_types::{
+    account_address::AccountAddress,
     account_config::CoinInfoResource,
     state_store::{
         errors::StateviewError, in_memory_state_view::InMemoryStateView, state_key::StateKey,
@@ -18,7 +19,7 @@ use aptos_vm_genesis::{
     generate_genesis_change_set_for_mainnet, generate_genesis_change_set_for_testing,
     GenesisOptions,
 };
-use move_core_types::language_storage::ModuleId;
+use move_core_types::language_storage::{ModuleId, StructTag};
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -120,6 +121,70 @@ impl FakeDataStore {
     }
 }
 
+/// Entry point for building a minimal in-memory state for tests, e.g.,
+///
+/// ```ignore
+/// let state_view = StateOverlay::builder()
+///     .resource(&address, &struct_tag, bytes)
+///     .module(&module_id, blob)
+///     .aggregator(state_key, 100)
+///     .build();
+/// let resolver = state_view.as_move_resolver();
+/// ```
+pub struct StateOverlay;
+
+impl StateOverlay {
+    /// Returns a builder over an empty [`FakeDataStore`].
+    pub fn builder() -> StateOverlayBuilder {
+        StateOverlayBuilder::default()
+    }
+}
+
+/// Builder for a [`FakeDataStore`] which avoids setting every key by hand. All values are stored
+/// as legacy state values.
+#[derive(Default)]
+pub struct StateOverlayBuilder {
+    data_store: FakeDataStore,
+}
+
+impl StateOverlayBuilder {
+    /// Adds a resource of the given type stored under the given address.
+    pub fn resource(
+        mut self,
+        address: &AccountAddress,
+        struct_tag: &StructTag,
+        bytes: Vec<u8>,
+    ) -> Self {
+        let state_key = StateKey::resource(address, struct_tag).expect("Valid resource key");
+        self.data_store.set_legacy(state_key, bytes);
+        self
+    }
+
+    /// Adds a module blob. Does not do any sort of verification on the module.
+    pub fn module(mut self, module_id: &ModuleId, blob: Vec<u8>) -> Self {
+        self.data_store.add_module(module_id, blob);
+        self
+    }
+
+    /// Adds an aggregator (V1) with the given value.
+    pub fn aggregator(mut self, state_key: StateKey, value: u128) -> Self {
+        let bytes = bcs::to_bytes(&value).expect("Aggregator value should serialize");
+        self.data_store.set_legacy(state_key, bytes);
+        self
+    }
+
+    /// Adds arbitrary bytes under the given key.
+    pub fn raw(mut self, state_key: StateKey, bytes: Vec<u8>) -> Self {
+        self.data_store.set_legacy(state_key, bytes);
+        self
+    }
+
+    /// Returns the data store, ready to be used as a state view or Move resolver.
+    pub fn build(self) -> FakeDataStore {
+        self.data_store
+    }
+}
+
 // This is used by the `execute_block` API.
 impl TStateView for FakeDataStore {
     type Key = StateKey;
@@ -61,7 +61,7 @@ use aptos_vm::{
     verifier, AptosVM, VMValidator,
 };
 use aptos_vm_genesis::{generate_genesis_change_set_for_testing_with_count, GenesisOptions};
-use aptos_vm_logging::log_schema::AdapterLogSchema;
+use aptos_vm_logging::{log_schema::AdapterLogSchema, CapturedLogEntry};
 use aptos_vm_types::storage::{change_set_configs::ChangeSetConfigs, StorageGasParameters};
 use bytes::Bytes;
 use move_core_types::{
@@ -134,6 +134,7 @@ pub struct FakeExecutor {
     features: Features,
     chain_id: u8,
     allow_block_executor_fallback: bool,
+    skip_prologue_resession: bool,
 }
 
 pub enum GasMeterType {
@@ -168,6 +169,7 @@ impl FakeExecutor {
             features: Features::default(),
             chain_id: chain_id.id(),
             allow_block_executor_fallback: true,
+            skip_prologue_resession: false,
         };
         executor.apply_write_set(write_set);
         executor
@@ -191,6 +193,7 @@ impl FakeExecutor {
             features: Features::default(),
             chain_id: chain_id.id(),
             allow_block_executor_fallback: true,
+            skip_prologue_resession: false,
         };
         executor.apply_write_set(write_set);
         executor
@@ -217,6 +220,12 @@ impl FakeExecutor {
         self.allow_block_executor_fallback = false;
     }
 
+    /// Configure this executor to keep the prologue session when executing user transactions, see
+    /// `AptosVM::set_skip_prologue_resession`.
+    pub fn set_skip_prologue_resession(&mut self, enable: bool) {
+        self.skip_prologue_resession = enable;
+    }
+
     /// Creates an executor from the genesis file GENESIS_FILE_LOCATION
     pub fn from_head_genesis() -> Self {
         Self::from_genesis(GENESIS_CHANGE_SET_HEAD.clone().write_set(), ChainId::test())
@@ -275,6 +284,7 @@ impl FakeExecutor {
             features: Features::default(),
             chain_id: ChainId::test().id(),
             allow_block_executor_fallback: true,
+            skip_prologue_resession: false,
         }
     }
 
@@ -540,6 +550,11 @@ impl FakeExecutor {
                 },
                 allow_fallback: self.allow_block_executor_fallback,
                 discard_failed_blocks: false,
+                deadline: None,
+                cancelled: None,
+                record_read_sets: false,
+                abort_rate_alert_threshold_pct: None,
+                skip_prologue_resession: self.skip_prologue_resession,
             },
             onchain: onchain_config,
         };
@@ -673,6 +688,29 @@ impl FakeExecutor {
         txn_output
     }
 
+    /// Executes the transaction outside of the block executor and returns its output together
+    /// with the speculative log entries emitted during its execution.
+    pub fn execute_transaction_with_captured_logs(
+        &self,
+        txn: SignedTransaction,
+    ) -> (TransactionOutput, Vec<CapturedLogEntry>) {
+        let txn = SignatureVerifiedTransaction::from(Transaction::UserTransaction(txn));
+        let log_context = AdapterLogSchema::new(self.data_store.id(), 0);
+
+        let resolver = self.data_store.as_move_resolver();
+        let vm = AptosVM::new(
+            &resolver, /*override_is_delayed_field_optimization_capable=*/ None,
+        );
+
+        let (result, logs) =
+            vm.execute_single_transaction_with_captured_logs(&txn, &resolver, &log_context);
+        let (_status, output) = result.expect("The VM should not fail to execute a transaction");
+        let output = output
+            .try_materialize_into_transaction_output(&resolver)
+            .expect("Materializing the output should not fail");
+        (output, logs)
+    }
+
     pub fn execute_transaction_with_gas_profiler(
         &self,
         txn: SignedTransaction,
@@ -685,9 +723,10 @@ impl FakeExecutor {
 
         // TODO(Gas): revisit this.
         let resolver = self.data_store.as_move_resolver();
-        let vm = AptosVM::new(
+        let mut vm = AptosVM::new(
             &resolver, /*override_is_delayed_field_optimization_capable=*/ None,
         );
+        vm.set_skip_prologue_resession(self.skip_prologue_resession);
 
         let (_status, output, gas_profiler) = vm.execute_user_transaction_with_modified_gas_meter(
             &resolver,
@@ -899,6 +938,7 @@ impl FakeExecutor {
             timed_features,
             &resolver,
             false,
+            None,
         )
         .unwrap();
 
@@ -1019,6 +1059,7 @@ impl FakeExecutor {
                 }),
                 &resolver,
                 /*aggregator_v2_type_tagging=*/ false,
+                /*max_dynamic_dispatches=*/ None,
             )
             .unwrap();
             let mut session = vm.new_session(&resolver, SessionId::void(), None);
@@ -1096,6 +1137,7 @@ impl FakeExecutor {
                 timed_features,
                 &resolver,
                 false,
+                None,
             )
             .unwrap();
             let mut session = vm.new_session(&resolver, SessionId::void(), None);
@@ -1178,6 +1220,7 @@ impl FakeExecutor {
             timed_features,
             state_view,
             false,
+            None,
         )
         .unwrap();
         let mut session = vm.new_session(state_view, SessionId::void(), None);
@@ -1189,6 +1232,7 @@ impl FakeExecutor {
             entry_fn.args().to_vec(),
             &function,
             struct_constructors,
+            None,
         )?;
         let storage = TraversalStorage::new();
         session
@@ -1235,6 +1279,7 @@ impl FakeExecutor {
             TimedFeaturesBuilder::enable_all().build(),
             &resolver,
             false,
+            None,
         )
         .unwrap();
         let mut session = vm.new_session(&resolver, SessionId::void(), None);
@@ -2,14 +2,22 @@
 // Parts of the project are originally copyright © Meta Platforms, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use aptos_gas_algebra::{Gas, InternalGas, NumArgs, NumBytes};
+use aptos_gas_schedule::{InitialGasSchedule, TransactionGasParameters};
 use aptos_language_e2e_tests::{
     account::Account, common_transactions::peer_to_peer_txn, executor::FakeExecutor,
 };
 use aptos_types::{
     account_config::{DepositEvent, WithdrawEvent},
+    state_store::state_key::StateKey,
     transaction::{ExecutionStatus, SignedTransaction, TransactionOutput, TransactionStatus},
+    write_set::WriteOp,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    convert::TryFrom,
+    time::Instant,
 };
-use std::{convert::TryFrom, time::Instant};
 
 #[test]
 fn single_peer_to_peer_with_event() {
@@ -132,6 +140,86 @@ fn few_peer_to_peer_with_event() {
     }
 }
 
+#[test]
+fn peer_to_peer_skip_prologue_resession() {
+    let mut executor = FakeExecutor::from_head_genesis();
+    let sender = executor.create_raw_account_data(1_000_000_000, 10);
+    let receiver = executor.create_raw_account_data(100_000, 10);
+    executor.add_account_data(&sender);
+    executor.add_account_data(&receiver);
+
+    let execute = |executor: &FakeExecutor, gas_unit_price| {
+        let txn = peer_to_peer_txn(
+            sender.account(),
+            receiver.account(),
+            10,
+            1_000,
+            gas_unit_price,
+        );
+        let (output, gas_log) = executor.execute_transaction_with_gas_profiler(txn).unwrap();
+        assert_eq!(
+            output.status(),
+            &TransactionStatus::Keep(ExecutionStatus::Success)
+        );
+        (output, gas_log)
+    };
+    let changed_keys = |lhs: &TransactionOutput, rhs: &TransactionOutput| -> BTreeSet<StateKey> {
+        lhs.write_set()
+            .iter()
+            .filter(|(key, op)| rhs.write_set().get(key) != Some(*op))
+            .map(|(key, _)| key.clone())
+            .collect()
+    };
+
+    let (output, gas_log) = execute(&executor, 100);
+    // The keys whose values depend on the fee, found by paying a different price for the same gas.
+    let (other_price_output, _) = execute(&executor, 200);
+    let fee_keys = changed_keys(&output, &other_price_output);
+    assert!(!fee_keys.is_empty());
+
+    executor.set_skip_prologue_resession(true);
+    let (skipped_output, skipped_gas_log) = execute(&executor, 100);
+
+    // The sender's coin store is the only resource loaded by both the prologue and the transfer.
+    // Without the resession it is not loaded again, which saves exactly one state slot read,
+    // rounded up to a whole 4 KiB page.
+    let txn_gas_params = TransactionGasParameters::initial();
+    let resession_cost = txn_gas_params.storage_io_per_state_slot_read * NumArgs::new(1)
+        + txn_gas_params.storage_io_per_state_byte_read * NumBytes::new(4096);
+    assert_eq!(
+        gas_log.exec_io.total,
+        skipped_gas_log.exec_io.total + resession_cost
+    );
+    assert_eq!(gas_log.storage.total, skipped_gas_log.storage.total);
+    let exec_io_gas = |total: InternalGas| -> u64 {
+        let gas: Gas = total.to_unit_round_up_with_params(&txn_gas_params);
+        gas.into()
+    };
+    assert_eq!(
+        output.gas_used() - skipped_output.gas_used(),
+        exec_io_gas(gas_log.exec_io.total) - exec_io_gas(skipped_gas_log.exec_io.total)
+    );
+
+    // Apart from the fee, the same changes are made.
+    let without_fee_keys = |output: &TransactionOutput| -> BTreeMap<StateKey, WriteOp> {
+        output
+            .write_set()
+            .iter()
+            .filter(|(key, _)| !fee_keys.contains(*key))
+            .map(|(key, op)| (key.clone(), op.clone()))
+            .collect()
+    };
+    assert_eq!(without_fee_keys(&skipped_output), without_fee_keys(&output));
+    assert_eq!(
+        skipped_output
+            .write_set()
+            .iter()
+            .filter(|(key, _)| fee_keys.contains(*key))
+            .count(),
+        fee_keys.len()
+    );
+}
+
 // Holder for transaction data; arguments to transactions.
 pub(crate) struct TxnInfo {
     pub sender: Account,
@@ -6,6 +6,7 @@ use aptos_language_e2e_tests::{current_function_name, executor::FakeExecutor};
 use aptos_types::{
     account_address::AccountAddress,
     account_config,
+    on_chain_config::FeatureFlag,
     transaction::{ExecutionStatus, Script, TransactionStatus},
 };
 use move_binary_format::file_format::{
@@ -16,6 +17,7 @@ use move_binary_format::file_format::{
 use move_core_types::{
     identifier::Identifier,
     language_storage::{StructTag, TypeTag},
+    value::MoveValue,
     vm_status::{StatusCode, StatusCode::LINKER_ERROR},
 };
 
@@ -441,15 +443,8 @@ fn script_nested_type_argument_module_does_not_exist() {
     assert_eq!(11, updated_sender.sequence_number());
 }
 
-#[test]
-fn forbid_script_emitting_events() {
-    let mut executor = FakeExecutor::from_head_genesis();
-
-    // create and publish sender
-    let sender = executor.create_raw_account_data(1_000_000, 10);
-    executor.add_account_data(&sender);
-
-    // create an event-emitting script
+/// Returns a script calling `0x1::event::emit<bool>`.
+fn bool_event_emitting_script() -> Vec<u8> {
     let mut script = empty_script();
     script.code.code = vec![
         Bytecode::LdTrue,
@@ -487,6 +482,18 @@ fn forbid_script_emitting_events() {
     ];
     let mut blob = vec![];
     script.serialize(&mut blob).expect("script must serialize");
+    blob
+}
+
+#[test]
+fn forbid_script_emitting_events() {
+    let mut executor = FakeExecutor::from_head_genesis();
+
+    // create and publish sender
+    let sender = executor.create_raw_account_data(1_000_000, 10);
+    executor.add_account_data(&sender);
+
+    let blob = bool_event_emitting_script();
     let txn = sender
         .account()
         .transaction()
@@ -521,3 +528,44 @@ fn forbid_script_emitting_events() {
     assert_eq!(balance, updated_sender_balance.coin());
     assert_eq!(11, updated_sender.sequence_number());
 }
+
+#[test]
+fn script_emitting_non_event_type_with_script_event_emission() {
+    let mut executor = FakeExecutor::from_head_genesis();
+    executor.exec("features", "change_feature_flags_internal", vec![], vec![
+        MoveValue::Signer(AccountAddress::ONE)
+            .simple_serialize()
+            .unwrap(),
+        MoveValue::Vector(vec![MoveValue::U64(
+            FeatureFlag::SCRIPT_EVENT_EMISSION as u64,
+        )])
+        .simple_serialize()
+        .unwrap(),
+        MoveValue::Vector(vec![]).simple_serialize().unwrap(),
+    ]);
+
+    // create and publish sender
+    let sender = executor.create_raw_account_data(1_000_000, 10);
+    executor.add_account_data(&sender);
+
+    // `bool` is not a struct declared with the event attribute, so the script is still rejected.
+    let txn = sender
+        .account()
+        .transaction()
+        .script(Script::new(bool_event_emitting_script(), vec![], vec![]))
+        .sequence_number(10)
+        .gas_unit_price(1)
+        .sign();
+    let output = &executor.execute_transaction(txn);
+    let status = output.status();
+    match status {
+        TransactionStatus::Keep(_) => (),
+        _ => panic!("TransactionStatus must be Keep"),
+    }
+    assert_eq!(
+        status.status(),
+        Ok(ExecutionStatus::MiscellaneousError(Some(
+            StatusCode::EVENT_METADATA_VALIDATION_ERROR
+        )))
+    );
+}
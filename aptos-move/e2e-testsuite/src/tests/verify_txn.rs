@@ -395,6 +395,84 @@ fn verify_simple_payment() {
     );
 }
 
+#[test]
+fn verify_gas_estimate() {
+    let mut executor = FakeExecutor::from_head_genesis();
+    let sender = executor.create_raw_account_data(900_000, 10);
+    let receiver = executor.create_raw_account_data(100_000, 10);
+    executor.add_account_data(&sender);
+    executor.add_account_data(&receiver);
+
+    let txn = sender
+        .account()
+        .transaction()
+        .payload(aptos_stdlib::aptos_coin_transfer(
+            *receiver.address(),
+            1_000,
+        ))
+        .sequence_number(10)
+        .sign();
+    let result = executor.validate_transaction(txn);
+    assert_eq!(result.status(), None);
+    assert!(result.estimated_execution_gas().unwrap() > 0);
+    // No account is created, so no storage is expected to be paid for upfront.
+    assert_eq!(result.estimated_storage_gas(), Some(0));
+
+    // Discarded transactions come without an estimate.
+    let txn = sender
+        .account()
+        .transaction()
+        .payload(aptos_stdlib::aptos_coin_transfer(
+            *receiver.address(),
+            1_000,
+        ))
+        .sequence_number(9)
+        .sign();
+    let result = executor.validate_transaction(txn);
+    assert_eq!(result.status(), Some(StatusCode::SEQUENCE_NUMBER_TOO_OLD));
+    assert_eq!(result.estimated_execution_gas(), None);
+    assert_eq!(result.estimated_storage_gas(), None);
+}
+
+#[test]
+fn verify_captured_speculative_logs() {
+    let mut executor = FakeExecutor::from_head_genesis();
+    let sender = executor.create_raw_account_data(900_000, 10);
+    executor.add_account_data(&sender);
+    let txn_gas_params = TransactionGasParameters::initial();
+
+    let txn = sender
+        .account()
+        .transaction()
+        .payload(aptos_stdlib::aptos_coin_transfer(*sender.address(), 100))
+        .sequence_number(10)
+        .max_gas_amount((txn_gas_params.maximum_number_of_gas_units + GasQuantity::one()).into())
+        .gas_unit_price(1)
+        .sign();
+    let (output, logs) = executor.execute_transaction_with_captured_logs(txn);
+    assert_eq!(
+        output.status(),
+        &TransactionStatus::Discard(StatusCode::MAX_GAS_UNITS_EXCEEDS_MAX_GAS_UNITS_BOUND)
+    );
+    assert!(logs
+        .iter()
+        .any(|entry| entry.message.starts_with("[VM] Gas unit error")));
+
+    // Nothing is captured for a successful transaction.
+    let txn = sender
+        .account()
+        .transaction()
+        .payload(aptos_stdlib::aptos_coin_transfer(*sender.address(), 100))
+        .sequence_number(10)
+        .sign();
+    let (output, logs) = executor.execute_transaction_with_captured_logs(txn);
+    assert_eq!(
+        output.status(),
+        &TransactionStatus::Keep(ExecutionStatus::Success)
+    );
+    assert!(logs.is_empty());
+}
+
 #[test]
 pub fn test_arbitrary_script_execution() {
     // create a FakeExecutor with a genesis from file
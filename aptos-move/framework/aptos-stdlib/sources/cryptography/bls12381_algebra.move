@@ -307,6 +307,13 @@ module aptos_std::bls12381_algebra {
         let val_x = rand_insecure<Fq12>();
         assert!(eq(&mul(&val_x, &val_x), &sqr(&val_x)), 1);
 
+        // Frobenius map.
+        let val_y = rand_insecure<Fq12>();
+        assert!(eq(&val_7, &frobenius_map(&val_7, 1)), 1);
+        assert!(eq(&val_x, &frobenius_map(&val_x, 12)), 1);
+        assert!(eq(&frobenius_map(&val_x, 2), &frobenius_map(&frobenius_map(&val_x, 1), 1)), 1);
+        assert!(eq(&frobenius_map(&mul(&val_x, &val_y), 1), &mul(&frobenius_map(&val_x, 1), &frobenius_map(&val_y, 1))), 1);
+
         // Downcasting.
         assert!(eq(&zero<Gt>(), &std::option::extract(&mut downcast<Fq12, Gt>(&val_1))), 1);
     }
@@ -386,6 +393,18 @@ module aptos_std::bls12381_algebra {
         assert!(std::option::is_none(&deserialize<G1, FormatG1Uncompr>(&x"000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000ab")), 1);
         assert!(std::option::is_none(&deserialize<G1, FormatG1Compr>(&x"000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000ab")), 1);
 
+        // Batch deserialization.
+        let batch = std::option::extract(&mut batch_deserialize<G1, FormatG1Compr>(&vector[G1_GENERATOR_SERIALIZED_COMP, G1_INF_SERIALIZED_COMP, G1_GENERATOR_MUL_BY_7_SERIALIZED_COMP]));
+        assert!(std::vector::length(&batch) == 3, 1);
+        assert!(eq(std::vector::borrow(&batch, 0), &generator), 1);
+        assert!(eq(std::vector::borrow(&batch, 1), &point_at_infinity), 1);
+        assert!(eq(std::vector::borrow(&batch, 2), &point_7g_from_comp), 1);
+        assert!(std::vector::is_empty(&std::option::extract(&mut batch_deserialize<G1, FormatG1Uncompr>(&vector[]))), 1);
+
+        // Batch deserialization should fail if any input is not a valid point on the curve, or is of wrong size.
+        assert!(std::option::is_none(&batch_deserialize<G1, FormatG1Uncompr>(&vector[G1_GENERATOR_SERIALIZED_UNCOMP, x"8959e137e0719bf872abb08411010f437a8955bd42f5ba20fca64361af58ce188b1adb96ef229698bb7860b79e24ba12000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"])), 1);
+        assert!(std::option::is_none(&batch_deserialize<G1, FormatG1Compr>(&vector[G1_GENERATOR_SERIALIZED_COMP, x"000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000ab"])), 1);
+
         // Scalar multiplication.
         let scalar_7 = from_u64<Fr>(7);
         let point_7g_calc = scalar_mul(&generator, &scalar_7);
@@ -393,6 +412,23 @@ module aptos_std::bls12381_algebra {
         assert!(G1_GENERATOR_MUL_BY_7_SERIALIZED_UNCOMP == serialize<G1, FormatG1Uncompr>(&point_7g_calc), 1);
         assert!(G1_GENERATOR_MUL_BY_7_SERIALIZED_COMP == serialize<G1, FormatG1Compr>( &point_7g_calc), 1);
 
+        // GLV scalar multiplication.
+        assert!(eq(&point_7g_calc, &glv_scalar_mul(&generator, &scalar_7)), 1);
+        let scalars = rand_vector<Fr>(4);
+        let i = 0;
+        while (i < 4) {
+            let scalar = std::vector::borrow(&scalars, i);
+            assert!(eq(&scalar_mul(&generator, scalar), &glv_scalar_mul(&generator, scalar)), 1);
+            i = i + 1;
+        };
+
+        // Pedersen commitment.
+        let point_h = scalar_mul(&generator, &rand_insecure<Fr>());
+        let scalar_m = rand_insecure<Fr>();
+        let scalar_r = rand_insecure<Fr>();
+        let expected = add(&scalar_mul(&generator, &scalar_m), &scalar_mul(&point_h, &scalar_r));
+        assert!(eq(&expected, &pedersen_commit(&generator, &point_h, &scalar_m, &scalar_r)), 1);
+
         // Multi-scalar multiplication.
         let num_entries = 1;
         while (num_entries < 10) {
@@ -411,6 +447,10 @@ module aptos_std::bls12381_algebra {
             let actual = multi_scalar_mul(&elements, &scalars);
             assert!(eq(&expected, &actual), 1);
 
+            let bases = precompute_msm_bases<G1, Fr>(&elements);
+            let actual = msm_with_precomputed(&bases, &scalars);
+            assert!(eq(&expected, &actual), 1);
+
             num_entries = num_entries + 1;
         };
 
@@ -420,6 +460,26 @@ module aptos_std::bls12381_algebra {
         let point_double_g = double(&generator);
         assert!(eq(&point_2g, &point_double_g), 1);
 
+        // Cofactor clearing.
+        assert!(eq(&point_at_infinity, &clear_cofactor(&point_at_infinity)), 1);
+        assert!(eq(&double(&clear_cofactor(&generator)), &clear_cofactor(&point_2g)), 1);
+
+        // Normalization.
+        assert!(eq(&point_7g_calc, &to_affine(&point_7g_calc)), 1);
+        let points = vector[point_at_infinity, generator, point_2g, point_7g_calc];
+        let normalized = normalize_batch(&points);
+        assert!(std::vector::length(&normalized) == 4, 1);
+        assert!(eq(&point_at_infinity, std::vector::borrow(&normalized, 0)), 1);
+        assert!(eq(&generator, std::vector::borrow(&normalized, 1)), 1);
+        assert!(eq(&point_2g, std::vector::borrow(&normalized, 2)), 1);
+        assert!(eq(&point_7g_calc, std::vector::borrow(&normalized, 3)), 1);
+        assert!(std::vector::is_empty(&normalize_batch<G1>(&vector[])), 1);
+
+        // Summation.
+        let points = vector[point_at_infinity, generator, point_2g, point_7g_calc];
+        assert!(eq(&scalar_mul(&generator, &from_u64<Fr>(10)), &sum(&points)), 1);
+        assert!(eq(&point_at_infinity, &sum<G1>(&vector[])), 1);
+
         // Negation.
         let point_minus_7g_calc = neg(&point_7g_calc);
         assert!(G1_GENERATOR_MUL_BY_7_NEG_SERIALIZED_COMP == serialize<G1, FormatG1Compr>(&point_minus_7g_calc), 1);
@@ -514,6 +574,16 @@ module aptos_std::bls12381_algebra {
         assert!(G2_GENERATOR_MUL_BY_7_SERIALIZED_UNCOMP == serialize<G2, FormatG2Uncompr>(&point_7g_calc), 1);
         assert!(G2_GENERATOR_MUL_BY_7_SERIALIZED_COMP == serialize<G2, FormatG2Compr>(&point_7g_calc), 1);
 
+        // GLV scalar multiplication.
+        assert!(eq(&point_7g_calc, &glv_scalar_mul(&generator, &scalar_7)), 1);
+        let scalars = rand_vector<Fr>(4);
+        let i = 0;
+        while (i < 4) {
+            let scalar = std::vector::borrow(&scalars, i);
+            assert!(eq(&scalar_mul(&generator, scalar), &glv_scalar_mul(&generator, scalar)), 1);
+            i = i + 1;
+        };
+
         // Multi-scalar multiplication.
         let num_entries = 1;
         while (num_entries < 10) {
@@ -532,6 +602,10 @@ module aptos_std::bls12381_algebra {
             let actual = multi_scalar_mul(&elements, &scalars);
             assert!(eq(&expected, &actual), 1);
 
+            let bases = precompute_msm_bases<G2, Fr>(&elements);
+            let actual = msm_with_precomputed(&bases, &scalars);
+            assert!(eq(&expected, &actual), 1);
+
             num_entries = num_entries + 1;
         };
 
@@ -541,6 +615,26 @@ module aptos_std::bls12381_algebra {
         let point_double_g = double(&generator);
         assert!(eq(&point_2g, &point_double_g), 1);
 
+        // Cofactor clearing.
+        assert!(eq(&point_at_infinity, &clear_cofactor(&point_at_infinity)), 1);
+        assert!(eq(&double(&clear_cofactor(&generator)), &clear_cofactor(&point_2g)), 1);
+
+        // Normalization.
+        assert!(eq(&point_7g_calc, &to_affine(&point_7g_calc)), 1);
+        let points = vector[point_at_infinity, generator, point_2g, point_7g_calc];
+        let normalized = normalize_batch(&points);
+        assert!(std::vector::length(&normalized) == 4, 1);
+        assert!(eq(&point_at_infinity, std::vector::borrow(&normalized, 0)), 1);
+        assert!(eq(&generator, std::vector::borrow(&normalized, 1)), 1);
+        assert!(eq(&point_2g, std::vector::borrow(&normalized, 2)), 1);
+        assert!(eq(&point_7g_calc, std::vector::borrow(&normalized, 3)), 1);
+        assert!(std::vector::is_empty(&normalize_batch<G2>(&vector[])), 1);
+
+        // Summation.
+        let points = vector[point_at_infinity, generator, point_2g, point_7g_calc];
+        assert!(eq(&scalar_mul(&generator, &from_u64<Fr>(10)), &sum(&points)), 1);
+        assert!(eq(&point_at_infinity, &sum<G2>(&vector[])), 1);
+
         // Negation.
         let point_minus_7g_calc = neg(&point_7g_calc);
         assert!(G2_GENERATOR_MUL_BY_7_NEG_SERIALIZED_COMP == serialize<G2, FormatG2Compr>(&point_minus_7g_calc), 1);
@@ -627,7 +721,7 @@ module aptos_std::bls12381_algebra {
     }
 
     #[test_only]
-    use aptos_std::crypto_algebra::{zero, one, from_u64, eq, deserialize, serialize, neg, add, sub, mul, div, inv, rand_insecure, sqr, order, scalar_mul, multi_scalar_mul, double, hash_to, upcast, enable_cryptography_algebra_natives, pairing, multi_pairing, downcast, Element};
+    use aptos_std::crypto_algebra::{zero, one, from_u64, scalar_from_u256, eq, deserialize, batch_deserialize, serialize, deserialize_fr_with_endianness, serialize_fr_with_endianness, neg, add, sub, mul, div, inv, is_square, sqrt, rand_insecure, sqr, frobenius_map, inner_product, poly_eval, order, scalar_num_bits, scalar_bit, scalar_mul, glv_scalar_mul, multi_scalar_mul, precompute_msm_bases, msm_with_precomputed, pedersen_commit, double, clear_cofactor, to_affine, normalize_batch, sum, hash_to, upcast, enable_cryptography_algebra_natives, pairing, pairing_with_variant, pairing_variant_optimal_ate, multi_pairing, multi_pairing_check, bls_verify, bls_aggregate_verify, downcast, Element};
 
     #[test_only]
     const FR_VAL_0_SERIALIZED_LSB: vector<u8> = x"0000000000000000000000000000000000000000000000000000000000000000";
@@ -670,6 +764,17 @@ module aptos_std::bls12381_algebra {
         assert!(std::option::is_none(&deserialize<Fr, FormatFrLsb>(&x"ffff")), 1);
         assert!(std::option::is_none(&deserialize<Fr, FormatFrMsb>(&x"ffff")), 1);
 
+        // Serialization/deserialization with runtime endianness.
+        assert!(FR_VAL_7_SERIALIZED_LSB == serialize_fr_with_endianness<Fr>(&val_7, false), 1);
+        assert!(FR_VAL_7_SERIALIZED_MSB == serialize_fr_with_endianness<Fr>(&val_7, true), 1);
+        let val_7_4th = std::option::extract(&mut deserialize_fr_with_endianness<Fr>(&FR_VAL_7_SERIALIZED_LSB, false));
+        let val_7_5th = std::option::extract(&mut deserialize_fr_with_endianness<Fr>(&FR_VAL_7_SERIALIZED_MSB, true));
+        assert!(eq(&val_7, &val_7_4th), 1);
+        assert!(eq(&val_7, &val_7_5th), 1);
+        assert!(std::option::is_none(&deserialize_fr_with_endianness<Fr>(&x"01000000fffffffffe5bfeff02a4bd5305d8a10908d83933487d9d2953a7ed73", false)), 1);
+        assert!(std::option::is_none(&deserialize_fr_with_endianness<Fr>(&x"73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001", true)), 1);
+        assert!(std::option::is_none(&deserialize_fr_with_endianness<Fr>(&x"ffff", false)), 1);
+
         // Negation.
         let val_minus_7 = neg(&val_7);
         assert!(FR_VAL_7_NEG_SERIALIZED_LSB == serialize<Fr, FormatFrLsb>(&val_minus_7), 1);
@@ -698,6 +803,75 @@ module aptos_std::bls12381_algebra {
         // Squaring.
         let val_x = rand_insecure<Fr>();
         assert!(eq(&mul(&val_x, &val_x), &sqr(&val_x)), 1);
+
+        // Inner product.
+        assert!(eq(&from_u64<Fr>(130), &inner_product(&vector[val_7, val_9], &vector[val_9, val_7])), 1);
+        assert!(eq(&zero<Fr>(), &inner_product<Fr>(&vector[], &vector[])), 1);
+
+        // Polynomial evaluation.
+        assert!(eq(&from_u64<Fr>(655), &poly_eval(&vector[val_7, val_9, val_7], &val_9)), 1);
+        let expected = add(&val_7, &mul(&val_x, &add(&val_9, &mul(&val_x, &val_7))));
+        assert!(eq(&expected, &poly_eval(&vector[val_7, val_9, val_7], &val_x)), 1);
+        assert!(eq(&zero<Fr>(), &poly_eval<Fr>(&vector[], &val_x)), 1);
+
+        // Conversion from u256, reduced modulo the order.
+        assert!(eq(&val_7, &scalar_from_u256<Fr>(7)), 1);
+        assert!(eq(&zero<Fr>(), &scalar_from_u256<Fr>(0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001)), 1);
+        assert!(eq(&one<Fr>(), &scalar_from_u256<Fr>(0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000002)), 1);
+    }
+
+    #[test(fx = @std)]
+    fun test_fr_sqrt(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        // Zero is a square, and its own square root.
+        let val_0 = zero<Fr>();
+        assert!(is_square(&val_0), 1);
+        assert!(eq(&val_0, &std::option::extract(&mut sqrt(&val_0))), 2);
+
+        // 4 = 2^2.
+        let val_4 = from_u64<Fr>(4);
+        assert!(is_square(&val_4), 3);
+        let root = std::option::extract(&mut sqrt(&val_4));
+        assert!(eq(&val_4, &sqr(&root)), 4);
+
+        // 7 generates the multiplicative group of `Fr`, so it is a quadratic non-residue.
+        let non_residue = from_u64<Fr>(7);
+        assert!(!is_square(&non_residue), 5);
+        assert!(std::option::is_none(&sqrt(&non_residue)), 6);
+
+        // Squares have a root, and their product with a non-residue does not.
+        let val_x = rand_insecure<Fr>();
+        let val_x_sqr = sqr(&val_x);
+        assert!(is_square(&val_x_sqr), 7);
+        let root = std::option::extract(&mut sqrt(&val_x_sqr));
+        assert!(eq(&root, &val_x) || eq(&root, &neg(&val_x)), 8);
+        if (!eq(&val_x, &val_0)) {
+            let product = mul(&val_x_sqr, &non_residue);
+            assert!(!is_square(&product), 9);
+            assert!(std::option::is_none(&sqrt(&product)), 10);
+        };
+    }
+
+    #[test(fx = @std)]
+    fun test_fr_bits(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        let num_bits = scalar_num_bits<Fr>();
+        assert!(255 == num_bits, 1);
+
+        // 5 = 0b101.
+        let val_5 = from_u64<Fr>(5);
+        assert!(scalar_bit(&val_5, 0), 2);
+        assert!(!scalar_bit(&val_5, 1), 3);
+        assert!(scalar_bit(&val_5, 2), 4);
+        assert!(!scalar_bit(&val_5, 3), 5);
+
+        // The canonical representative of -1 is `r-1`, whose top bit is set.
+        let val_minus_1 = neg(&one<Fr>());
+        assert!(scalar_bit(&val_minus_1, num_bits - 1), 6);
+
+        // Bits beyond the modulus length are always unset.
+        assert!(!scalar_bit(&val_minus_1, num_bits), 7);
+        assert!(!scalar_bit(&val_minus_1, 18446744073709551615), 8);
     }
 
     #[test(fx = @std)]
@@ -714,6 +888,45 @@ module aptos_std::bls12381_algebra {
         assert!(eq(&gt_element, &gt_element_another), 1);
     }
 
+    #[test(fx = @std)]
+    fun test_pairing_with_variant(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+
+        // The optimal Ate pairing is the one computed by `pairing()`.
+        let element_p = rand_insecure<G1>();
+        let element_q = rand_insecure<G2>();
+        let gt_element = pairing_with_variant<G1, G2, Gt>(pairing_variant_optimal_ate(), &element_p, &element_q);
+        assert!(eq(&pairing<G1, G2, Gt>(&element_p, &element_q), &gt_element), 1);
+    }
+
+    // Pairing test vectors computed independently of arkworks, with a Python implementation of the optimal Ate pairing
+    // of py_ecc's `bls12_381` module, for `P = a*g1`, `Q = b*g2`, `a = sha256("pairing kat a")` and `b = sha256("pairing kat b")`.
+    #[test_only]
+    const PAIRING_KAT_P_SERIALIZED_UNCOMP: vector<u8> = x"1410af486c1683d74f8b368055284d167e4f9285f756c7ee7028d8e9d45a1c91eb6a34d303b70bc5b4b4abf65bb905ca12b03b868169a468dcb67eea64a7bed569d53a94da1e2f848027483f172f164e399c4ad8599810e1dfc4501619810918";
+    #[test_only]
+    const PAIRING_KAT_Q_SERIALIZED_UNCOMP: vector<u8> = x"00e9ed55668df3ecd3350425ba4c9204479f435272053ea9d0e8ea66389394058e6fdce96f5c825906620fa615899971040c4e1c10497edf8a6ffe3cb4d6cac5d7ca55b20ebb2660524ddba5cc9852c5132aac99bfe27884f0d7b2a949517320140259477243e0ad5588abb8fa371a35a9c3b9b951128670f7d48f89d995c3b7611f8bd918f2ee6ceb6dfb21cf147cf80649671310fd790a6a9d6e705757a838db08f6ab392b91b02a8fe83e82a58cff37d9d60eba243355c330cc7c77b9fb5a";
+    #[test_only]
+    /// The cube of `e(P, Q)` as computed by py_ecc, because the final exponentiation of ark-bls12-381-0.4.0
+    /// computes `f^(3(p^12-1)/r)` rather than `f^((p^12-1)/r)`.
+    const PAIRING_KAT_GT_SERIALIZED: vector<u8> = x"33c1d38b34e69919b6cd91df4425db7266781880c3234d71febef8693fdf0c4996c4e7a63f33f16dbf7935c538302f00ca4d9a876c6eb5b22dcd2d8d0169368ad8677f218004d3f6dde92caacd7cf375a51402c4c8c76c27e3b8bcc67ec68015c3790f5a45210f3706f293acc04c429a1d943c6b55e240d45561682f9a3979170ffde8c2b3954f22bcfd77a2d9660e0f0f27b0f7679319d134a378b1e667c28b6117c3ea3cf6529282377bab94e6c2534a2026584d0a7b8dbbaf4db1e7e852173f487803b39f8748789aa2e2b0579c7506639f116f91743516f1baf2f2f8e9f90bc8088573f7b70b380c0a31fb251417ecfd5edeaaea2b07abc083ce2edd306dc7f6b1ad5254ab1b69f3790f6f4ca40619c2469489c26e5b9d7ac75041e19100cd34c6bab48c38741b9fc54f41c164a20a7e119b181087dccc2e5045704af1fe2e139bbf6debbb6a95bf57beb249fe0591ee4435a6c98e3fc19e8296ad43648f33987d938121f7489243fdbfd3e1731861cd21d43f04382fc8e76a22acb357189e20f5fbf2b3ce6511cb5ce0ddd5eea5e56947ae8d4ec6169ffde8684bb62c3430968405c8e636ab53b9ce1ec14eeb00d0dbb7e1b56b98066e314ade455ee275def758b19621a8e9058a3acf9d157e620366e2256d62a6351506c4c7adb40400bc30a94b8758b8e1651608e7f2fb598d2837becf0c2a0ea937d1700b92200a4b87e92ad58ddec763b5e673efec49e40951db336f4fb2b8b1f10b78590d2c10484ccbd6f1a63d5a502d28a8917bb45c6ff23eed2480d8b1459880418ba55d930f";
+
+    #[test(fx = @std)]
+    fun test_pairing_with_variant_known_answer(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+
+        let element_p = std::option::extract(&mut deserialize<G1, FormatG1Uncompr>(&PAIRING_KAT_P_SERIALIZED_UNCOMP));
+        let element_q = std::option::extract(&mut deserialize<G2, FormatG2Uncompr>(&PAIRING_KAT_Q_SERIALIZED_UNCOMP));
+        let gt_element = pairing_with_variant<G1, G2, Gt>(pairing_variant_optimal_ate(), &element_p, &element_q);
+        assert!(PAIRING_KAT_GT_SERIALIZED == serialize<Gt, FormatGt>(&gt_element), 1);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x0C0001, location = aptos_std::crypto_algebra)]
+    fun test_pairing_with_unavailable_variant_should_abort(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        pairing_with_variant<G1, G2, Gt>(1, &one<G1>(), &one<G2>());
+    }
+
     #[test(fx = @std)]
     fun test_multi_pairing(fx: signer) {
         enable_cryptography_algebra_natives(&fx);
@@ -752,6 +965,14 @@ module aptos_std::bls12381_algebra {
         assert!(eq(&n, &m), 1);
     }
 
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010004, location = aptos_std::crypto_algebra)]
+    fun test_batch_deserialize_should_abort_when_not_in_subgroup(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        // `(0,2)` is on the curve but off its prime-order subgroup.
+        batch_deserialize<G1, FormatG1Uncompr>(&vector[G1_GENERATOR_SERIALIZED_UNCOMP, x"000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002"]);
+    }
+
     #[test(fx = @std)]
     #[expected_failure(abort_code = 0x010002, location = aptos_std::crypto_algebra)]
     fun test_multi_pairing_should_abort_when_sizes_mismatch(fx: signer) {
@@ -761,6 +982,91 @@ module aptos_std::bls12381_algebra {
         multi_pairing<G1, G2, Gt>(&g1_elements, &g2_elements);
     }
 
+    #[test(fx = @std)]
+    fun test_multi_pairing_check(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+
+        // e(a*P,Q)+e(-P,a*Q) is the identity, while e(a*P,Q) alone is not.
+        let a = rand_insecure<Fr>();
+        let element_p = rand_insecure<G1>();
+        let element_q = rand_insecure<G2>();
+        let p_a = scalar_mul(&element_p, &a);
+        let q_a = scalar_mul(&element_q, &a);
+        let neg_p = neg(&element_p);
+        assert!(multi_pairing_check<G1, G2, Gt>(&vector[p_a, neg_p], &vector[element_q, q_a]), 1);
+        assert!(!multi_pairing_check<G1, G2, Gt>(&vector[p_a], &vector[element_q]), 2);
+        assert!(multi_pairing_check<G1, G2, Gt>(&vector[], &vector[]), 3);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010002, location = aptos_std::crypto_algebra)]
+    fun test_multi_pairing_check_should_abort_when_sizes_mismatch(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        let g1_elements = vector[rand_insecure<G1>()];
+        let g2_elements = vector[rand_insecure<G2>(), rand_insecure<G2>()];
+        multi_pairing_check<G1, G2, Gt>(&g1_elements, &g2_elements);
+    }
+
+    #[test(fx = @std)]
+    fun test_bls_verify(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        let dst = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
+        let msg_1 = b"hello";
+        let msg_2 = b"world";
+        let sk_1 = rand_insecure<Fr>();
+        let pk_1 = scalar_mul(&one<G2>(), &sk_1);
+        let sig_1 = scalar_mul(&hash_to<G1, HashG1XmdSha256SswuRo>(&dst, &msg_1), &sk_1);
+        assert!(bls_verify<G1, G2, Gt, HashG1XmdSha256SswuRo>(&pk_1, &dst, &msg_1, &sig_1), 1);
+        assert!(!bls_verify<G1, G2, Gt, HashG1XmdSha256SswuRo>(&pk_1, &dst, &msg_2, &sig_1), 1);
+        assert!(!bls_verify<G1, G2, Gt, HashG1XmdSha256SswuRo>(&pk_1, &b"another dst", &msg_1, &sig_1), 1);
+
+        // The identity is rejected as a public key, even though it trivially satisfies the pairing equation.
+        assert!(!bls_verify<G1, G2, Gt, HashG1XmdSha256SswuRo>(&zero<G2>(), &dst, &msg_1, &zero<G1>()), 1);
+
+        // Aggregate verification.
+        let sk_2 = rand_insecure<Fr>();
+        let pk_2 = scalar_mul(&one<G2>(), &sk_2);
+        let sig_2 = scalar_mul(&hash_to<G1, HashG1XmdSha256SswuRo>(&dst, &msg_2), &sk_2);
+        let aggregate_sig = add(&sig_1, &sig_2);
+        assert!(bls_aggregate_verify<G1, G2, Gt, HashG1XmdSha256SswuRo>(&vector[pk_1, pk_2], &dst, &vector[msg_1, msg_2], &aggregate_sig), 1);
+        assert!(!bls_aggregate_verify<G1, G2, Gt, HashG1XmdSha256SswuRo>(&vector[pk_2, pk_1], &dst, &vector[msg_1, msg_2], &aggregate_sig), 1);
+
+        // Messages must be distinct.
+        let sig_3 = scalar_mul(&hash_to<G1, HashG1XmdSha256SswuRo>(&dst, &msg_1), &sk_2);
+        assert!(!bls_aggregate_verify<G1, G2, Gt, HashG1XmdSha256SswuRo>(&vector[pk_1, pk_2], &dst, &vector[msg_1, msg_1], &add(&sig_1, &sig_3)), 1);
+        assert!(!bls_aggregate_verify<G1, G2, Gt, HashG1XmdSha256SswuRo>(&vector[], &dst, &vector[], &zero<G1>()), 1);
+    }
+
+    #[test_only]
+    /// `sk * g2` for `sk = 0x198540c1c51f1b5f0312878bb123d7e93593fb33acf3b1fbe7e4a4f928176bdc`, computed outside of arkworks.
+    const BLS_VERIFY_KAT_PK_SERIALIZED: vector<u8> = x"05a6257f46efb4c5dc50f113f81e594bd26c46fc0b49ccd7a4d7e576ba012adfadea806922dba967ffea9ee99cf2e0310f968acd361f65089e5d4a2a90522a306f49204a56b0f7baf6733e1a0d08a5e0a249185d6359c88a64c30567d92e19c516f8ca85d1d45e47206fce4e278be6ac7c26440f8151f01c480dbed129d362431d5066da9e66463891a6f1764299fbf10f9bc527f46cf1efacd7c65d6443f70c2b64965865e6b0034dc9a17b679acad473662f1e4a0ee0a1a14df912ba27b6b3";
+    #[test_only]
+    /// `sk * H(msg)` for the same `sk`, where `H(msg)` is the hash-to-curve test vector for `msg = "abcdef0123456789"`
+    /// from the IETF hash-to-curve draft, computed outside of arkworks.
+    const BLS_VERIFY_KAT_SIG_SERIALIZED: vector<u8> = x"0469503d804a6c2d1fd02268431b357c66371808499f5e268d9ab37d0c518d638d55373686de79da5ee95ae3eb16ecb006ff85499f6b6fc3200b4d5dc6fa1db99dc92d2a00796bef601c3dfe0576e5a4d7d9db50a716f271a2d34bb4874a381c";
+
+    #[test(fx = @std)]
+    fun test_bls_verify_known_answer(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        let dst = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+        let msg = b"abcdef0123456789";
+        let pk = std::option::extract(&mut deserialize<G2, FormatG2Uncompr>(&BLS_VERIFY_KAT_PK_SERIALIZED));
+        let sig = std::option::extract(&mut deserialize<G1, FormatG1Uncompr>(&BLS_VERIFY_KAT_SIG_SERIALIZED));
+        assert!(bls_verify<G1, G2, Gt, HashG1XmdSha256SswuRo>(&pk, &dst, &msg, &sig), 1);
+        assert!(bls_aggregate_verify<G1, G2, Gt, HashG1XmdSha256SswuRo>(&vector[pk], &dst, &vector[msg], &sig), 1);
+        assert!(!bls_verify<G1, G2, Gt, HashG1XmdSha256SswuRo>(&pk, &dst, &b"", &sig), 1);
+        assert!(!bls_verify<G1, G2, Gt, HashG1XmdSha256SswuRo>(&pk, &dst, &msg, &neg(&sig)), 1);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010002, location = aptos_std::crypto_algebra)]
+    fun test_bls_aggregate_verify_should_abort_when_sizes_mismatch(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        let public_keys = vector[rand_insecure<G2>()];
+        let messages = vector[b"hello", b"world"];
+        bls_aggregate_verify<G1, G2, Gt, HashG1XmdSha256SswuRo>(&public_keys, &b"dst", &messages, &rand_insecure<G1>());
+    }
+
     #[test(fx = @std)]
     #[expected_failure(abort_code = 0x010002, location = aptos_std::crypto_algebra)]
     fun test_multi_scalar_mul_should_abort_when_sizes_mismatch(fx: signer) {
@@ -770,6 +1076,24 @@ module aptos_std::bls12381_algebra {
         multi_scalar_mul(&elements, &scalars);
     }
 
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010002, location = aptos_std::crypto_algebra)]
+    fun test_msm_with_precomputed_should_abort_when_sizes_mismatch(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        let bases = precompute_msm_bases<G1, Fr>(&vector[rand_insecure<G1>()]);
+        let scalars = vector[rand_insecure<Fr>(), rand_insecure<Fr>()];
+        msm_with_precomputed(&bases, &scalars);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010002, location = aptos_std::crypto_algebra)]
+    fun test_inner_product_should_abort_when_sizes_mismatch(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        let a = vector[rand_insecure<Fr>()];
+        let b = vector[rand_insecure<Fr>(), rand_insecure<Fr>()];
+        inner_product(&a, &b);
+    }
+
     #[test_only]
     /// The maximum number of `G1` elements that can be created in a transaction,
     /// calculated by the current memory limit (1MB) and the in-mem G1 representation size (144 bytes per element).
@@ -796,6 +1120,26 @@ module aptos_std::bls12381_algebra {
         }
     }
 
+    #[test_only]
+    /// The maximum number of `G2` bases that `precompute_msm_bases()` accepts in a transaction,
+    /// calculated by the current memory limit (1MB) and the size of their precomputed multiples
+    /// (32 windows of 200 bytes per in-mem affine G2 element).
+    const G2_PRECOMPUTED_BASES_NUM_MAX: u64 = 1048576 / (32 * 200);
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x090003, location = std::crypto_algebra)]
+    fun test_precompute_msm_bases_memory_limit_exceeded_with_g2(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        let element = rand_insecure<G2>();
+        let elements = vector[];
+        let remaining = G2_PRECOMPUTED_BASES_NUM_MAX + 1;
+        while (remaining > 0) {
+            std::vector::push_back(&mut elements, element);
+            remaining = remaining - 1;
+        };
+        precompute_msm_bases<G2, Fr>(&elements);
+    }
+
     //
     // (Tests end here.)
     //
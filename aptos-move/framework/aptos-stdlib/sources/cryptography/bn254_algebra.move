@@ -303,6 +303,13 @@ module std::bn254_algebra {
         let val_x = rand_insecure<Fq12>();
         assert!(eq(&mul(&val_x, &val_x), &sqr(&val_x)), 1);
 
+        // Frobenius map.
+        let val_y = rand_insecure<Fq12>();
+        assert!(eq(&val_7, &frobenius_map(&val_7, 1)), 1);
+        assert!(eq(&val_x, &frobenius_map(&val_x, 12)), 1);
+        assert!(eq(&frobenius_map(&val_x, 2), &frobenius_map(&frobenius_map(&val_x, 1), 1)), 1);
+        assert!(eq(&frobenius_map(&mul(&val_x, &val_y), 1), &mul(&frobenius_map(&val_x, 1), &frobenius_map(&val_y, 1))), 1);
+
         // Downcasting.
         assert!(eq(&zero<Gt>(), &std::option::extract(&mut downcast<Fq12, Gt>(&val_1))), 1);
         // upcasting
@@ -382,6 +389,18 @@ module std::bn254_algebra {
         assert!(std::option::is_none(&deserialize<G1, FormatG1Uncompr>(&x"000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000ab")), 1);
         assert!(std::option::is_none(&deserialize<G1, FormatG1Compr>(&x"000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000ab")), 1);
 
+        // Batch deserialization.
+        let batch = std::option::extract(&mut batch_deserialize<G1, FormatG1Compr>(&vector[G1_GENERATOR_SERIALIZED_COMP, G1_INF_SERIALIZED_COMP, G1_GENERATOR_MUL_BY_7_SERIALIZED_COMP]));
+        assert!(std::vector::length(&batch) == 3, 1);
+        assert!(eq(std::vector::borrow(&batch, 0), &generator), 1);
+        assert!(eq(std::vector::borrow(&batch, 1), &point_at_infinity), 1);
+        assert!(eq(std::vector::borrow(&batch, 2), &point_7g_from_comp), 1);
+        let batch = std::option::extract(&mut batch_deserialize<G1, FormatG1Uncompr>(&vector[G1_GENERATOR_SERIALIZED_UNCOMP]));
+        assert!(eq(std::vector::borrow(&batch, 0), &generator), 1);
+
+        // Batch deserialization should fail if any input is of wrong size.
+        assert!(std::option::is_none(&batch_deserialize<G1, FormatG1Compr>(&vector[G1_GENERATOR_SERIALIZED_COMP, x"00"])), 1);
+
         // Scalar multiplication.
         let scalar_7 = from_u64<Fr>(7);
         let point_7g_calc = scalar_mul(&generator, &scalar_7);
@@ -389,6 +408,23 @@ module std::bn254_algebra {
         assert!(G1_GENERATOR_MUL_BY_7_SERIALIZED_UNCOMP == serialize<G1, FormatG1Uncompr>(&point_7g_calc), 1);
         assert!(G1_GENERATOR_MUL_BY_7_SERIALIZED_COMP == serialize<G1, FormatG1Compr>( &point_7g_calc), 1);
 
+        // GLV scalar multiplication.
+        assert!(eq(&point_7g_calc, &glv_scalar_mul(&generator, &scalar_7)), 1);
+        let scalars = rand_vector<Fr>(4);
+        let i = 0;
+        while (i < 4) {
+            let scalar = std::vector::borrow(&scalars, i);
+            assert!(eq(&scalar_mul(&generator, scalar), &glv_scalar_mul(&generator, scalar)), 1);
+            i = i + 1;
+        };
+
+        // Pedersen commitment.
+        let point_h = scalar_mul(&generator, &rand_insecure<Fr>());
+        let scalar_m = rand_insecure<Fr>();
+        let scalar_r = rand_insecure<Fr>();
+        let expected = add(&scalar_mul(&generator, &scalar_m), &scalar_mul(&point_h, &scalar_r));
+        assert!(eq(&expected, &pedersen_commit(&generator, &point_h, &scalar_m, &scalar_r)), 1);
+
         // Multi-scalar multiplication.
         let num_entries = 1;
         while (num_entries < 10) {
@@ -407,6 +443,10 @@ module std::bn254_algebra {
             let actual = multi_scalar_mul(&elements, &scalars);
             assert!(eq(&expected, &actual), 1);
 
+            let bases = precompute_msm_bases<G1, Fr>(&elements);
+            let actual = msm_with_precomputed(&bases, &scalars);
+            assert!(eq(&expected, &actual), 1);
+
             num_entries = num_entries + 1;
         };
 
@@ -416,6 +456,27 @@ module std::bn254_algebra {
         let point_double_g = double(&generator);
         assert!(eq(&point_2g, &point_double_g), 1);
 
+        // Cofactor clearing.
+        assert!(eq(&point_at_infinity, &clear_cofactor(&point_at_infinity)), 1);
+        assert!(eq(&double(&clear_cofactor(&generator)), &clear_cofactor(&point_2g)), 1);
+        assert!(eq(&generator, &clear_cofactor(&generator)), 1);
+
+        // Normalization.
+        assert!(eq(&point_7g_calc, &to_affine(&point_7g_calc)), 1);
+        let points = vector[point_at_infinity, generator, point_2g, point_7g_calc];
+        let normalized = normalize_batch(&points);
+        assert!(std::vector::length(&normalized) == 4, 1);
+        assert!(eq(&point_at_infinity, std::vector::borrow(&normalized, 0)), 1);
+        assert!(eq(&generator, std::vector::borrow(&normalized, 1)), 1);
+        assert!(eq(&point_2g, std::vector::borrow(&normalized, 2)), 1);
+        assert!(eq(&point_7g_calc, std::vector::borrow(&normalized, 3)), 1);
+        assert!(std::vector::is_empty(&normalize_batch<G1>(&vector[])), 1);
+
+        // Summation.
+        let points = vector[point_at_infinity, generator, point_2g, point_7g_calc];
+        assert!(eq(&scalar_mul(&generator, &from_u64<Fr>(10)), &sum(&points)), 1);
+        assert!(eq(&point_at_infinity, &sum<G1>(&vector[])), 1);
+
         // Negation.
         let point_minus_7g_calc = neg(&point_7g_calc);
         assert!(G1_GENERATOR_MUL_BY_7_NEG_SERIALIZED_COMP == serialize<G1, FormatG1Compr>(&point_minus_7g_calc), 1);
@@ -501,6 +562,16 @@ module std::bn254_algebra {
         assert!(G2_GENERATOR_MUL_BY_7_SERIALIZED_UNCOMP == serialize<G2, FormatG2Uncompr>(&point_7g_calc), 1);
         assert!(G2_GENERATOR_MUL_BY_7_SERIALIZED_COMP == serialize<G2, FormatG2Compr>(&point_7g_calc), 1);
 
+        // GLV scalar multiplication.
+        assert!(eq(&point_7g_calc, &glv_scalar_mul(&generator, &scalar_7)), 1);
+        let scalars = rand_vector<Fr>(4);
+        let i = 0;
+        while (i < 4) {
+            let scalar = std::vector::borrow(&scalars, i);
+            assert!(eq(&scalar_mul(&generator, scalar), &glv_scalar_mul(&generator, scalar)), 1);
+            i = i + 1;
+        };
+
         // Multi-scalar multiplication.
         let num_entries = 1;
         while (num_entries < 10) {
@@ -519,6 +590,10 @@ module std::bn254_algebra {
             let actual = multi_scalar_mul(&elements, &scalars);
             assert!(eq(&expected, &actual), 1);
 
+            let bases = precompute_msm_bases<G2, Fr>(&elements);
+            let actual = msm_with_precomputed(&bases, &scalars);
+            assert!(eq(&expected, &actual), 1);
+
             num_entries = num_entries + 1;
         };
 
@@ -528,6 +603,26 @@ module std::bn254_algebra {
         let point_double_g = double(&generator);
         assert!(eq(&point_2g, &point_double_g), 1);
 
+        // Cofactor clearing.
+        assert!(eq(&point_at_infinity, &clear_cofactor(&point_at_infinity)), 1);
+        assert!(eq(&double(&clear_cofactor(&generator)), &clear_cofactor(&point_2g)), 1);
+
+        // Normalization.
+        assert!(eq(&point_7g_calc, &to_affine(&point_7g_calc)), 1);
+        let points = vector[point_at_infinity, generator, point_2g, point_7g_calc];
+        let normalized = normalize_batch(&points);
+        assert!(std::vector::length(&normalized) == 4, 1);
+        assert!(eq(&point_at_infinity, std::vector::borrow(&normalized, 0)), 1);
+        assert!(eq(&generator, std::vector::borrow(&normalized, 1)), 1);
+        assert!(eq(&point_2g, std::vector::borrow(&normalized, 2)), 1);
+        assert!(eq(&point_7g_calc, std::vector::borrow(&normalized, 3)), 1);
+        assert!(std::vector::is_empty(&normalize_batch<G2>(&vector[])), 1);
+
+        // Summation.
+        let points = vector[point_at_infinity, generator, point_2g, point_7g_calc];
+        assert!(eq(&scalar_mul(&generator, &from_u64<Fr>(10)), &sum(&points)), 1);
+        assert!(eq(&point_at_infinity, &sum<G2>(&vector[])), 1);
+
         // Negation.
         let point_minus_7g_calc = neg(&point_7g_calc);
         assert!(G2_GENERATOR_MUL_BY_7_NEG_SERIALIZED_COMP == serialize<G2, FormatG2Compr>(&point_minus_7g_calc), 1);
@@ -606,7 +701,7 @@ module std::bn254_algebra {
     }
 
     #[test_only]
-    use aptos_std::crypto_algebra::{zero, one, from_u64, eq, deserialize, serialize, neg, add, sub, mul, div, inv, rand_insecure, sqr, order, scalar_mul, multi_scalar_mul, double, upcast, enable_cryptography_algebra_natives, pairing, multi_pairing, downcast, Element};
+    use aptos_std::crypto_algebra::{zero, one, from_u64, scalar_from_u256, eq, deserialize, batch_deserialize, serialize, deserialize_fr_with_endianness, serialize_fr_with_endianness, neg, add, sub, mul, div, inv, is_square, sqrt, rand_insecure, sqr, frobenius_map, inner_product, poly_eval, order, scalar_num_bits, scalar_bit, scalar_mul, glv_scalar_mul, multi_scalar_mul, precompute_msm_bases, msm_with_precomputed, pedersen_commit, double, clear_cofactor, to_affine, normalize_batch, sum, upcast, enable_cryptography_algebra_natives, pairing, pairing_with_variant, pairing_variant_optimal_ate, multi_pairing, multi_pairing_check, downcast, Element};
 
     #[test_only]
     const FR_VAL_0_SERIALIZED_LSB: vector<u8> = x"0000000000000000000000000000000000000000000000000000000000000000";
@@ -649,6 +744,17 @@ module std::bn254_algebra {
         assert!(std::option::is_none(&deserialize<Fr, FormatFrLsb>(&x"ffff")), 1);
         assert!(std::option::is_none(&deserialize<Fr, FormatFrMsb>(&x"ffff")), 1);
 
+        // Serialization/deserialization with runtime endianness.
+        assert!(FR_VAL_7_SERIALIZED_LSB == serialize_fr_with_endianness<Fr>(&val_7, false), 1);
+        assert!(FR_VAL_7_SERIALIZED_MSB == serialize_fr_with_endianness<Fr>(&val_7, true), 1);
+        let val_7_4th = std::option::extract(&mut deserialize_fr_with_endianness<Fr>(&FR_VAL_7_SERIALIZED_LSB, false));
+        let val_7_5th = std::option::extract(&mut deserialize_fr_with_endianness<Fr>(&FR_VAL_7_SERIALIZED_MSB, true));
+        assert!(eq(&val_7, &val_7_4th), 1);
+        assert!(eq(&val_7, &val_7_5th), 1);
+        assert!(std::option::is_none(&deserialize_fr_with_endianness<Fr>(&R_SERIALIZED, false)), 1);
+        assert!(std::option::is_none(&deserialize_fr_with_endianness<Fr>(&x"30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001", true)), 1);
+        assert!(std::option::is_none(&deserialize_fr_with_endianness<Fr>(&x"ffff", true)), 1);
+
         // Negation.
         let val_minus_7 = neg(&val_7);
         assert!(FR_VAL_7_NEG_SERIALIZED_LSB == serialize<Fr, FormatFrLsb>(&val_minus_7), 1);
@@ -677,6 +783,21 @@ module std::bn254_algebra {
         // Squaring.
         let val_x = rand_insecure<Fr>();
         assert!(eq(&mul(&val_x, &val_x), &sqr(&val_x)), 1);
+
+        // Inner product.
+        assert!(eq(&from_u64<Fr>(130), &inner_product(&vector[val_7, val_9], &vector[val_9, val_7])), 1);
+        assert!(eq(&zero<Fr>(), &inner_product<Fr>(&vector[], &vector[])), 1);
+
+        // Polynomial evaluation.
+        assert!(eq(&from_u64<Fr>(655), &poly_eval(&vector[val_7, val_9, val_7], &val_9)), 1);
+        let expected = add(&val_7, &mul(&val_x, &add(&val_9, &mul(&val_x, &val_7))));
+        assert!(eq(&expected, &poly_eval(&vector[val_7, val_9, val_7], &val_x)), 1);
+        assert!(eq(&zero<Fr>(), &poly_eval<Fr>(&vector[], &val_x)), 1);
+
+        // Conversion from u256, reduced modulo the order.
+        assert!(eq(&val_7, &scalar_from_u256<Fr>(7)), 1);
+        assert!(eq(&zero<Fr>(), &scalar_from_u256<Fr>(0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001)), 1);
+        assert!(eq(&one<Fr>(), &scalar_from_u256<Fr>(0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000002)), 1);
     }
 
     #[test_only]
@@ -752,6 +873,92 @@ module std::bn254_algebra {
         assert!(eq(&mul(&val_x, &val_x), &sqr(&val_x)), 1);
     }
 
+    #[test(fx = @std)]
+    fun test_fr_sqrt(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        // Zero is a square, and its own square root.
+        let val_0 = zero<Fr>();
+        assert!(is_square(&val_0), 1);
+        assert!(eq(&val_0, &std::option::extract(&mut sqrt(&val_0))), 2);
+
+        // 4 = 2^2.
+        let val_4 = from_u64<Fr>(4);
+        assert!(is_square(&val_4), 3);
+        let root = std::option::extract(&mut sqrt(&val_4));
+        assert!(eq(&val_4, &sqr(&root)), 4);
+
+        // 5 generates the multiplicative group of `Fr`, so it is a quadratic non-residue.
+        let non_residue = from_u64<Fr>(5);
+        assert!(!is_square(&non_residue), 5);
+        assert!(std::option::is_none(&sqrt(&non_residue)), 6);
+
+        // Squares have a root, and their product with a non-residue does not.
+        let val_x = rand_insecure<Fr>();
+        let val_x_sqr = sqr(&val_x);
+        assert!(is_square(&val_x_sqr), 7);
+        let root = std::option::extract(&mut sqrt(&val_x_sqr));
+        assert!(eq(&root, &val_x) || eq(&root, &neg(&val_x)), 8);
+        if (!eq(&val_x, &val_0)) {
+            let product = mul(&val_x_sqr, &non_residue);
+            assert!(!is_square(&product), 9);
+            assert!(std::option::is_none(&sqrt(&product)), 10);
+        };
+    }
+
+    #[test(fx = @std)]
+    fun test_fq_sqrt(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        // Zero is a square, and its own square root.
+        let val_0 = zero<Fq>();
+        assert!(is_square(&val_0), 1);
+        assert!(eq(&val_0, &std::option::extract(&mut sqrt(&val_0))), 2);
+
+        // 4 = 2^2.
+        let val_4 = from_u64<Fq>(4);
+        assert!(is_square(&val_4), 3);
+        let root = std::option::extract(&mut sqrt(&val_4));
+        assert!(eq(&val_4, &sqr(&root)), 4);
+
+        // 3 generates the multiplicative group of `Fq`, so it is a quadratic non-residue.
+        let non_residue = from_u64<Fq>(3);
+        assert!(!is_square(&non_residue), 5);
+        assert!(std::option::is_none(&sqrt(&non_residue)), 6);
+
+        // Squares have a root, and their product with a non-residue does not.
+        let val_x = rand_insecure<Fq>();
+        let val_x_sqr = sqr(&val_x);
+        assert!(is_square(&val_x_sqr), 7);
+        let root = std::option::extract(&mut sqrt(&val_x_sqr));
+        assert!(eq(&root, &val_x) || eq(&root, &neg(&val_x)), 8);
+        if (!eq(&val_x, &val_0)) {
+            let product = mul(&val_x_sqr, &non_residue);
+            assert!(!is_square(&product), 9);
+            assert!(std::option::is_none(&sqrt(&product)), 10);
+        };
+    }
+
+    #[test(fx = @std)]
+    fun test_fr_bits(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        let num_bits = scalar_num_bits<Fr>();
+        assert!(254 == num_bits, 1);
+
+        // 5 = 0b101.
+        let val_5 = from_u64<Fr>(5);
+        assert!(scalar_bit(&val_5, 0), 2);
+        assert!(!scalar_bit(&val_5, 1), 3);
+        assert!(scalar_bit(&val_5, 2), 4);
+        assert!(!scalar_bit(&val_5, 3), 5);
+
+        // The canonical representative of -1 is `r-1`, whose top bit is set.
+        let val_minus_1 = neg(&one<Fr>());
+        assert!(scalar_bit(&val_minus_1, num_bits - 1), 6);
+
+        // Bits beyond the modulus length are always unset.
+        assert!(!scalar_bit(&val_minus_1, num_bits), 7);
+        assert!(!scalar_bit(&val_minus_1, 18446744073709551615), 8);
+    }
+
     #[test(fx = @std)]
     fun test_pairing(fx: signer) {
         enable_cryptography_algebra_natives(&fx);
@@ -766,6 +973,56 @@ module std::bn254_algebra {
         assert!(eq(&gt_element, &gt_element_another), 1);
     }
 
+    #[test(fx = @std)]
+    fun test_pairing_with_variant(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+
+        // The optimal Ate pairing of the generators is the `Gt` generator, as in ark-bn254-0.4.0.
+        let gt_element = pairing_with_variant<G1, G2, Gt>(pairing_variant_optimal_ate(), &one<G1>(), &one<G2>());
+        assert!(GT_GENERATOR_SERIALIZED == serialize<Gt, FormatGt>(&gt_element), 1);
+        // The optimal Ate pairing is the one computed by `pairing()`.
+        let element_p = rand_insecure<G1>();
+        let element_q = rand_insecure<G2>();
+        let gt_element = pairing_with_variant<G1, G2, Gt>(pairing_variant_optimal_ate(), &element_p, &element_q);
+        assert!(eq(&pairing<G1, G2, Gt>(&element_p, &element_q), &gt_element), 1);
+    }
+
+    // Pairing test vectors computed independently of arkworks, with a Python implementation of the optimal Ate pairing
+    // of py_ecc's `bn128` module, for `P = a*g1`, `Q = b*g2`, `a = sha256("pairing kat a")` and `b = sha256("pairing kat b")`.
+    #[test_only]
+    const PAIRING_KAT_P_SERIALIZED_UNCOMP: vector<u8> = x"3a00b52a642a49c55f20c65312a78a1b2ff8e13b8e86b2a2486d6695af1c5b2150c648a45db1bcad1130d844bf2bb0b285843f52c59cc1e78181314033d1c114";
+    #[test_only]
+    const PAIRING_KAT_Q_SERIALIZED_UNCOMP: vector<u8> = x"aeee8a989a85bc5f7d88ddd48599d3c69b3da22fc846ce321332d11caaef4711c7490d56219d42bb5e9b00cbbe0f73c74e4e4c01dcdbcf154251c786c9c1f603ca7280e643c6689944d97d32977167f6d9ca25092d6fbb7e5b0023b933bf8104120aca89bf85eb07bb96f16d4c87418ba04039f422cece476cee3b700ddbff21";
+    #[test_only]
+    /// `-(a*b)*g1`, so that `e(P, Q) + e(PAIRING_KAT_NEG_AB_G1, g2)` is the identity, as in an EIP-197 pairing check.
+    const PAIRING_KAT_NEG_AB_G1_SERIALIZED_UNCOMP: vector<u8> = x"08e4062f69f15d20a123ac1cfd6f4af7df8a676655e2b2dc818921a89a91b42ea6830ef293db6bfd991af94241bafe2301d3578d767ed5edba0534fbfd6f840e";
+    #[test_only]
+    /// `e(P, Q)` as computed by py_ecc, raised to the power `2x(6x^2+3x+1)` (`x` being the BN254 curve parameter),
+    /// because the final exponentiation of ark-bn254-0.4.0 computes that fixed power of the pairing.
+    const PAIRING_KAT_GT_SERIALIZED: vector<u8> = x"ba6b4ce83b3e361077b03386aa233a6d5dc04b8c2b77425d1392a3923606d006155b0e113ef2713420dc46c6eabdb5edbe24e8e024d3a801f45c7ef310472d30a8be4e0ffd6ff9228baf728a4015463aaecd3b3dd2b3435048fa572c1281c70cba70737a86a3c0f6f4862c53dc1bf53c34c7b4337471b04d9791551d8c5e8900786b2f9a048504f82d30d047af1e73c7b9c626917616d72eb25e876f51838625bd0e991c87971369a25427b4f3a3c13d1d6dbf3d63bacd701ef6bd81379f9412eecec59e9915e3f9105f5ac062338479c209c3a6e0f58581dc9bba7323172b2a32e4867c38bf3cdbabfdc5040ac38e90ad9443d4454a92cece8a9d7badaecf0904ec663bf6c5cf5d0075af95b2cdd467589e839b18015fbf2fed0313f363611cc8f295e1d2e26a8b2ae8bfda45043255234906d83230927c68626deb102b7e220f618fd8718c77ead269336a60258f78f5f4424d144c2ff2d5f2d9a8d1cdb61b73eb0e0697be9ff462432172ed6d9be33abd5e7b66ab34f230da35edd6dbe428";
+
+    #[test(fx = @std)]
+    fun test_pairing_with_variant_known_answer(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+
+        let element_p = std::option::extract(&mut deserialize<G1, FormatG1Uncompr>(&PAIRING_KAT_P_SERIALIZED_UNCOMP));
+        let element_q = std::option::extract(&mut deserialize<G2, FormatG2Uncompr>(&PAIRING_KAT_Q_SERIALIZED_UNCOMP));
+        let gt_element = pairing_with_variant<G1, G2, Gt>(pairing_variant_optimal_ate(), &element_p, &element_q);
+        assert!(PAIRING_KAT_GT_SERIALIZED == serialize<Gt, FormatGt>(&gt_element), 1);
+
+        // The pairing check holds regardless of which power of the pairing is computed.
+        let neg_ab_g1 = std::option::extract(&mut deserialize<G1, FormatG1Uncompr>(&PAIRING_KAT_NEG_AB_G1_SERIALIZED_UNCOMP));
+        let gt_element_2 = pairing_with_variant<G1, G2, Gt>(pairing_variant_optimal_ate(), &neg_ab_g1, &one<G2>());
+        assert!(eq(&zero<Gt>(), &add(&gt_element, &gt_element_2)), 1);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x0C0001, location = aptos_std::crypto_algebra)]
+    fun test_pairing_with_unavailable_variant_should_abort(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        pairing_with_variant<G1, G2, Gt>(1, &one<G1>(), &one<G2>());
+    }
+
     #[test(fx = @std)]
     fun test_multi_pairing(fx: signer) {
         enable_cryptography_algebra_natives(&fx);
@@ -813,6 +1070,31 @@ module std::bn254_algebra {
         multi_pairing<G1, G2, Gt>(&g1_elements, &g2_elements);
     }
 
+    #[test(fx = @std)]
+    fun test_multi_pairing_check(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+
+        // e(a*P,Q)+e(-P,a*Q) is the identity, while e(a*P,Q) alone is not.
+        let a = rand_insecure<Fr>();
+        let element_p = rand_insecure<G1>();
+        let element_q = rand_insecure<G2>();
+        let p_a = scalar_mul(&element_p, &a);
+        let q_a = scalar_mul(&element_q, &a);
+        let neg_p = neg(&element_p);
+        assert!(multi_pairing_check<G1, G2, Gt>(&vector[p_a, neg_p], &vector[element_q, q_a]), 1);
+        assert!(!multi_pairing_check<G1, G2, Gt>(&vector[p_a], &vector[element_q]), 2);
+        assert!(multi_pairing_check<G1, G2, Gt>(&vector[], &vector[]), 3);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010002, location = aptos_std::crypto_algebra)]
+    fun test_multi_pairing_check_should_abort_when_sizes_mismatch(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        let g1_elements = vector[rand_insecure<G1>()];
+        let g2_elements = vector[rand_insecure<G2>(), rand_insecure<G2>()];
+        multi_pairing_check<G1, G2, Gt>(&g1_elements, &g2_elements);
+    }
+
     #[test(fx = @std)]
     #[expected_failure(abort_code = 0x010002, location = aptos_std::crypto_algebra)]
     fun test_multi_scalar_mul_should_abort_when_sizes_mismatch(fx: signer) {
@@ -822,6 +1104,24 @@ module std::bn254_algebra {
         multi_scalar_mul(&elements, &scalars);
     }
 
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010002, location = aptos_std::crypto_algebra)]
+    fun test_msm_with_precomputed_should_abort_when_sizes_mismatch(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        let bases = precompute_msm_bases<G1, Fr>(&vector[rand_insecure<G1>()]);
+        let scalars = vector[rand_insecure<Fr>(), rand_insecure<Fr>()];
+        msm_with_precomputed(&bases, &scalars);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010002, location = aptos_std::crypto_algebra)]
+    fun test_inner_product_should_abort_when_sizes_mismatch(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        let a = vector[rand_insecure<Fr>()];
+        let b = vector[rand_insecure<Fr>(), rand_insecure<Fr>()];
+        inner_product(&a, &b);
+    }
+
     #[test_only]
     /// The maximum number of `G1` elements that can be created in a transaction,
     /// calculated by the current memory limit (1MB) and the in-mem G1 representation size (96 bytes per element).
@@ -12,9 +12,16 @@
 /// - `add()` for group operation (i.e., a group addition).
 /// - `sub()` for group element subtraction.
 /// - `double()` for efficient doubling.
+/// - `sum()` for efficient summation of many group elements.
+/// - `clear_cofactor()` for mapping a curve point into the prime-order subgroup.
+/// - `to_affine()` and `normalize_batch()` for normalizing the internal representation of curve points.
 /// - `scalar_mul()` for group scalar multiplication.
+/// - `glv_scalar_mul()` for group scalar multiplication accelerated by the GLV method where supported.
 /// - `multi_scalar_mul()` for efficient group multi-scalar multiplication.
+/// - `pedersen_commit()` for computing a Pedersen commitment in one call.
+/// - `precompute_msm_bases()` and `msm_with_precomputed()` for repeated multi-scalar multiplications over fixed bases.
 /// - `hash_to()` for hash-to-group.
+/// - `batch_deserialize()` for deserializing many group elements with amortized subgroup checks.
 ///
 /// A field may also implement the following operations.
 /// - `zero()` for getting the field additive identity.
@@ -25,10 +32,18 @@
 /// - `div()` for field division.
 /// - `neg()` for field negation.
 /// - `inv()` for field inversion.
+/// - `is_square()` and `sqrt()` for square root existence and computation in a prime field.
 /// - `sqr()` for efficient field element squaring.
+/// - `frobenius_map()` for applying a power of the Frobenius endomorphism to an extension field element.
+/// - `inner_product()` for efficient field inner product.
+/// - `poly_eval()` for efficient polynomial evaluation.
 /// - `from_u64()` for quick conversion from u64 to field element.
+/// - `scalar_from_u256()` for quick conversion from u256 to scalar field element.
+/// - `scalar_num_bits()` and `scalar_bit()` for reading the bits of a scalar field element.
 ///
 /// For 3 groups that admit a bilinear map, `pairing()` and `multi_pairing()` may be implemented.
+/// `pairing_with_variant()` may be used to request a specific pairing variant.
+/// `bls_verify()` and `bls_aggregate_verify()` verify BLS signatures over such groups in one call.
 ///
 /// For a subset/superset relationship between 2 structures, `upcast()` and `downcast()` may be implemented.
 /// E.g., in BLS12-381 pairing, since `Gt` is a subset of `Fq12`,
@@ -42,12 +57,22 @@ module aptos_std::crypto_algebra {
     const E_NOT_IMPLEMENTED: u64 = 1;
     const E_NON_EQUAL_LENGTHS: u64 = 2;
     const E_TOO_MUCH_MEMORY_USED: u64 = 3;
+    const E_NOT_IN_SUBGROUP: u64 = 4;
+
+    /// The optimal Ate pairing, which is the variant computed by `pairing()`.
+    const PAIRING_VARIANT_OPTIMAL_ATE: u8 = 0;
 
     /// This struct represents an element of a structure `S`.
     struct Element<phantom S> has copy, drop {
         handle: u64
     }
 
+    /// This struct represents precomputed multiples of a fixed list of elements of a group `G`,
+    /// which speed up `msm_with_precomputed()` over these elements. It is only valid within the transaction that created it.
+    struct PrecomputedMsmBases<phantom G> has copy, drop {
+        handle: u64
+    }
+
     //
     // Public functions begin.
     //
@@ -66,6 +91,14 @@ module aptos_std::crypto_algebra {
         }
     }
 
+    /// Convert a u256 to an element of a scalar field `S`, reducing it modulo the order of `S`.
+    public fun scalar_from_u256<S>(value: u256): Element<S> {
+        abort_unless_cryptography_algebra_natives_enabled();
+        Element<S> {
+            handle: scalar_from_u256_internal<S>(value)
+        }
+    }
+
     /// Return the additive identity of field `S`, or the identity of group `S`.
     public fun zero<S>(): Element<S> {
         abort_unless_cryptography_algebra_natives_enabled();
@@ -127,6 +160,31 @@ module aptos_std::crypto_algebra {
         }
     }
 
+    /// Compute `a[0]*b[0]+...+a[n-1]*b[n-1]` for `n` elements `a[]` and `n` elements `b[]` of a field `F`.
+    /// Faster and cheaper than combining `mul()` and `add()` in Move.
+    ///
+    /// Abort with code `std::error::invalid_argument(E_NON_EQUAL_LENGTHS)` if the sizes of `a` and `b` do not match.
+    public fun inner_product<F>(a: &vector<Element<F>>, b: &vector<Element<F>>): Element<F> {
+        abort_unless_cryptography_algebra_natives_enabled();
+        let a_handles = handles_from_elements(a);
+        let b_handles = handles_from_elements(b);
+        Element<F> {
+            handle: field_inner_product_internal<F>(a_handles, b_handles)
+        }
+    }
+
+    /// Compute `c[0]+c[1]*x+...+c[n-1]*x^(n-1)` for `n` coefficients `c[]` and a point `x` of a field `F`,
+    /// using Horner's method. Faster and cheaper than combining `mul()` and `add()` in Move.
+    ///
+    /// Return `zero<F>()` if `coefficients` is empty.
+    public fun poly_eval<F>(coefficients: &vector<Element<F>>, x: &Element<F>): Element<F> {
+        abort_unless_cryptography_algebra_natives_enabled();
+        let coefficient_handles = handles_from_elements(coefficients);
+        Element<F> {
+            handle: poly_eval_internal<F>(coefficient_handles, x.handle)
+        }
+    }
+
     /// Compute `x^2` for an element `x` of a structure `S`. Faster and cheaper than `mul(x, x)`.
     public fun sqr<S>(x: &Element<S>): Element<S> {
         abort_unless_cryptography_algebra_natives_enabled();
@@ -135,6 +193,15 @@ module aptos_std::crypto_algebra {
         }
     }
 
+    /// Compute `x^(p^power)` for an element `x` of an extension field `F` over a prime field of characteristic `p`,
+    /// i.e., apply the Frobenius endomorphism `power` times. Much cheaper than exponentiation in Move.
+    public fun frobenius_map<F>(x: &Element<F>, power: u64): Element<F> {
+        abort_unless_cryptography_algebra_natives_enabled();
+        Element<F> {
+            handle: frobenius_map_internal<F>(x.handle, power)
+        }
+    }
+
     /// Try computing `x^(-1)` for an element `x` of a structure `S`.
     /// Return none if `x` does not have a multiplicative inverse in the structure `S`
     /// (e.g., when `S` is a field, and `x` is zero).
@@ -149,6 +216,29 @@ module aptos_std::crypto_algebra {
         }
     }
 
+    /// Check whether an element `x` of a field `F` is a square (i.e., a quadratic residue or zero) in `F`.
+    ///
+    /// Currently only the fields `bls12381_algebra::Fr`, `bn254_algebra::Fr` and `bn254_algebra::Fq` are supported.
+    public fun is_square<F>(x: &Element<F>): bool {
+        abort_unless_cryptography_algebra_natives_enabled();
+        is_square_internal<F>(x.handle)
+    }
+
+    /// Try computing a square root of an element `x` of a field `F`.
+    /// Return none if `x` is not a square in `F`.
+    ///
+    /// Currently only the fields `bls12381_algebra::Fr`, `bn254_algebra::Fr` and `bn254_algebra::Fq` are supported.
+    public fun sqrt<F>(x: &Element<F>): Option<Element<F>> {
+        abort_unless_cryptography_algebra_natives_enabled();
+        let (succeeded, handle) = sqrt_internal<F>(x.handle);
+        if (succeeded) {
+            let root = Element<F> { handle };
+            some(root)
+        } else {
+            none()
+        }
+    }
+
     /// Compute `2*P` for an element `P` of a structure `S`. Faster and cheaper than `add(P, P)`.
     public fun double<S>(element_p: &Element<S>): Element<S> {
         abort_unless_cryptography_algebra_natives_enabled();
@@ -157,6 +247,47 @@ module aptos_std::crypto_algebra {
         }
     }
 
+    /// Compute `P[0]+...+P[n-1]` for `n` elements `P[]` of an elliptic curve group `G`, e.g. to aggregate signatures.
+    /// Faster and cheaper than combining `add()` in Move, as the result is normalized (see `to_affine()`) only once.
+    /// Return `zero()` if `elements` is empty.
+    public fun sum<G>(elements: &vector<Element<G>>): Element<G> {
+        abort_unless_cryptography_algebra_natives_enabled();
+        let element_handles = handles_from_elements(elements);
+        Element<G> {
+            handle: group_sum_internal<G>(element_handles)
+        }
+    }
+
+    /// Compute `h*P` for an element `P` of an elliptic curve group `G`, where `h` is the (effective) cofactor of `G`.
+    /// The result is guaranteed to be in the prime-order subgroup, which makes it the standard way to sanitize untrusted points.
+    public fun clear_cofactor<G>(element_p: &Element<G>): Element<G> {
+        abort_unless_cryptography_algebra_natives_enabled();
+        Element<G> {
+            handle: clear_cofactor_internal<G>(element_p.handle)
+        }
+    }
+
+    /// Convert an element `P` of an elliptic curve group `G` to affine coordinates.
+    /// The result is equal to `P`. Curve points are internally kept in projective coordinates,
+    /// where the group operations avoid field inversions, and the conversion back costs one inversion.
+    /// Normalized points are cheaper to serialize and to use in pairings.
+    public fun to_affine<G>(element_p: &Element<G>): Element<G> {
+        abort_unless_cryptography_algebra_natives_enabled();
+        Element<G> {
+            handle: to_affine_internal<G>(element_p.handle)
+        }
+    }
+
+    /// Convert elements `P[]` of an elliptic curve group `G` to affine coordinates, like `to_affine()`,
+    /// but sharing a single field inversion among all the elements.
+    /// Cheaper than calling `to_affine()` on each element when finalizing many accumulated points.
+    public fun normalize_batch<G>(elements: &vector<Element<G>>): vector<Element<G>> {
+        abort_unless_cryptography_algebra_natives_enabled();
+        let element_handles = handles_from_elements(elements);
+        let new_handles = normalize_batch_internal<G>(element_handles);
+        elements_from_handles<G>(&new_handles)
+    }
+
     /// Compute `k[0]*P[0]+...+k[n-1]*P[n-1]`, where
     /// `P[]` are `n` elements of group `G` represented by parameter `elements`, and
     /// `k[]` are `n` elements of the scalarfield `S` of group `G` represented by parameter `scalars`.
@@ -170,6 +301,34 @@ module aptos_std::crypto_algebra {
         }
     }
 
+    /// Precompute the multiples of `n` elements `P[]` of group `G` needed by `msm_with_precomputed()`,
+    /// where `S` is the scalar field of `G`. The precomputation is paid for once, and is worth it when
+    /// computing several multi-scalar multiplications over the same `P[]`, e.g., for fixed-base commitments.
+    ///
+    /// Abort with code `std::error::resource_exhausted(E_TOO_MUCH_MEMORY_USED)` if the precomputed table does not fit
+    /// in the memory available to the algebra natives.
+    public fun precompute_msm_bases<G, S>(elements: &vector<Element<G>>): PrecomputedMsmBases<G> {
+        abort_unless_cryptography_algebra_natives_enabled();
+        let element_handles = handles_from_elements(elements);
+        PrecomputedMsmBases<G> {
+            handle: precompute_msm_bases_internal<G, S>(element_handles)
+        }
+    }
+
+    /// Compute `k[0]*P[0]+...+k[n-1]*P[n-1]`, where
+    /// `P[]` are the `n` elements of group `G` that `bases` was precomputed from, and
+    /// `k[]` are `n` elements of the scalarfield `S` of group `G` represented by parameter `scalars`.
+    /// Equivalent to `multi_scalar_mul()` over `P[]`, but faster and cheaper.
+    ///
+    /// Abort with code `std::error::invalid_argument(E_NON_EQUAL_LENGTHS)` if the sizes of `P[]` and `scalars` do not match.
+    public fun msm_with_precomputed<G, S>(bases: &PrecomputedMsmBases<G>, scalars: &vector<Element<S>>): Element<G> {
+        abort_unless_cryptography_algebra_natives_enabled();
+        let scalar_handles = handles_from_elements(scalars);
+        Element<G> {
+            handle: msm_with_precomputed_internal<G, S>(bases.handle, scalar_handles)
+        }
+    }
+
     /// Compute `k*P`, where `P` is an element of a group `G` and `k` is an element of the scalar field `S` associated to the group `G`.
     public fun scalar_mul<G, S>(element_p: &Element<G>, scalar_k: &Element<S>): Element<G> {
         abort_unless_cryptography_algebra_natives_enabled();
@@ -178,6 +337,28 @@ module aptos_std::crypto_algebra {
         }
     }
 
+    /// Compute `k*P` like `scalar_mul()`, using the GLV decomposition of `k` for groups `G` with an efficient endomorphism.
+    /// Always returns the same element as `scalar_mul()`, but is cheaper where supported (currently BLS12-381 `G1`).
+    /// Other groups fall back to `scalar_mul()`.
+    public fun glv_scalar_mul<G, S>(element_p: &Element<G>, scalar_k: &Element<S>): Element<G> {
+        abort_unless_cryptography_algebra_natives_enabled();
+        Element<G> {
+            handle: glv_scalar_mul_internal<G, S>(element_p.handle, scalar_k.handle)
+        }
+    }
+
+    /// Compute the Pedersen commitment `m*G+r*H` to message `m` with randomness `r`, where `G` and `H` are elements of a group `G`
+    /// represented by parameters `element_g` and `element_h`, and `m` and `r` are elements of the scalar field `S` associated to the group `G`.
+    /// Returns the same element as `add(&scalar_mul(element_g, scalar_m), &scalar_mul(element_h, scalar_r))`, in a single native call.
+    ///
+    /// Currently supported for BLS12-381 `G1` and BN254 `G1`.
+    public fun pedersen_commit<G, S>(element_g: &Element<G>, element_h: &Element<G>, scalar_m: &Element<S>, scalar_r: &Element<S>): Element<G> {
+        abort_unless_cryptography_algebra_natives_enabled();
+        Element<G> {
+            handle: pedersen_commit_internal<G, S>(element_g.handle, element_h.handle, scalar_m.handle, scalar_r.handle)
+        }
+    }
+
     /// Efficiently compute `e(P[0],Q[0])+...+e(P[n-1],Q[n-1])`,
     /// where `e: (G1,G2) -> (Gt)` is the pairing function from groups `(G1,G2)` to group `Gt`,
     /// `P[]` are `n` elements of group `G1` represented by parameter `g1_elements`, and
@@ -196,6 +377,19 @@ module aptos_std::crypto_algebra {
         }
     }
 
+    /// Check whether `e(P[0],Q[0])+...+e(P[n-1],Q[n-1])` is the identity of the target group `Gt`,
+    /// with `e`, `P[]` and `Q[]` defined as in `multi_pairing()`.
+    /// This is cheaper than comparing the result of `multi_pairing()` against `zero<Gt>()`,
+    /// as the product is never materialized as a new element.
+    ///
+    /// Abort with code `std::error::invalid_argument(E_NON_EQUAL_LENGTHS)` if the sizes of `g1_elements` and `g2_elements` do not match.
+    public fun multi_pairing_check<G1,G2,Gt>(g1_elements: &vector<Element<G1>>, g2_elements: &vector<Element<G2>>): bool {
+        abort_unless_cryptography_algebra_natives_enabled();
+        let g1_handles = handles_from_elements(g1_elements);
+        let g2_handles = handles_from_elements(g2_elements);
+        multi_pairing_check_internal<G1,G2,Gt>(g1_handles, g2_handles)
+    }
+
     /// Compute the pairing function (a.k.a., bilinear map) on a `G1` element and a `G2` element.
     /// Return an element in the target group `Gt`.
     public fun pairing<G1,G2,Gt>(element_1: &Element<G1>, element_2: &Element<G2>): Element<Gt> {
@@ -205,6 +399,24 @@ module aptos_std::crypto_algebra {
         }
     }
 
+    /// Return the identifier of the optimal Ate pairing variant, to be passed to `pairing_with_variant()`.
+    public fun pairing_variant_optimal_ate(): u8 {
+        PAIRING_VARIANT_OPTIMAL_ATE
+    }
+
+    /// Compute the pairing function on a `G1` element and a `G2` element using the given pairing `variant`,
+    /// for interoperating with external verifiers that expect a specific variant.
+    /// Return an element in the target group `Gt`.
+    ///
+    /// Currently only the optimal Ate pairing (`pairing_variant_optimal_ate()`) is available, for all supported pairings.
+    /// Abort with code `std::error::not_implemented(E_NOT_IMPLEMENTED)` if the requested variant is not available.
+    public fun pairing_with_variant<G1,G2,Gt>(variant: u8, element_1: &Element<G1>, element_2: &Element<G2>): Element<Gt> {
+        if (variant != PAIRING_VARIANT_OPTIMAL_ATE) {
+            abort(std::error::not_implemented(E_NOT_IMPLEMENTED))
+        };
+        pairing<G1,G2,Gt>(element_1, element_2)
+    }
+
     /// Try deserializing a byte array to an element of an algebraic structure `S` using a given serialization format `F`.
     /// Return none if the deserialization failed.
     public fun deserialize<S, F>(bytes: &vector<u8>): Option<Element<S>> {
@@ -217,18 +429,79 @@ module aptos_std::crypto_algebra {
         }
     }
 
+    /// Try deserializing byte arrays to elements of an elliptic curve group `G` using a given serialization format `F`.
+    /// Return none if any of the byte arrays is not a valid serialization of a point on the curve.
+    ///
+    /// Instead of checking every point for membership in the prime-order subgroup like `deserialize()` does,
+    /// this checks a number of random linear combinations of the points whenever that is cheaper.
+    /// The coefficients are derived by hashing all the byte arrays,
+    /// and the number of combinations is chosen for each group so that a vector with any point outside the subgroup
+    /// passes all of them with probability at most `2^-128`.
+    ///
+    /// Abort with code `std::error::invalid_argument(E_NOT_IN_SUBGROUP)` if any point is not in the prime-order subgroup.
+    ///
+    /// Currently only the groups `G1` and `G2` of `bls12381_algebra` and `bn254_algebra` are supported.
+    public fun batch_deserialize<G, F>(bytes: &vector<vector<u8>>): Option<vector<Element<G>>> {
+        abort_unless_cryptography_algebra_natives_enabled();
+        let (succeeded, handles) = batch_deserialize_internal<G, F>(*bytes);
+        if (succeeded) {
+            some(elements_from_handles<G>(&handles))
+        } else {
+            none()
+        }
+    }
+
     /// Serialize an element of an algebraic structure `S` to a byte array using a given serialization format `F`.
     public fun serialize<S, F>(element: &Element<S>): vector<u8> {
         abort_unless_cryptography_algebra_natives_enabled();
         serialize_internal<S, F>(element.handle)
     }
 
+    /// Try deserializing a byte array to an element of a scalar field `S`,
+    /// interpreting the bytes as an integer in big-endian if `big_endian` is true, or in little-endian otherwise.
+    /// Return none if the byte array has a wrong size or the integer is not smaller than the field order.
+    /// Currently only the scalar fields `bls12381_algebra::Fr` and `bn254_algebra::Fr` are supported.
+    public fun deserialize_fr_with_endianness<S>(bytes: &vector<u8>, big_endian: bool): Option<Element<S>> {
+        abort_unless_cryptography_algebra_natives_enabled();
+        let (succeeded, handle) = deserialize_fr_with_endianness_internal<S>(bytes, big_endian);
+        if (succeeded) {
+            some(Element<S> { handle })
+        } else {
+            none()
+        }
+    }
+
+    /// Serialize an element of a scalar field `S` to a byte array as an integer,
+    /// in big-endian if `big_endian` is true, or in little-endian otherwise.
+    /// Currently only the scalar fields `bls12381_algebra::Fr` and `bn254_algebra::Fr` are supported.
+    public fun serialize_fr_with_endianness<S>(element: &Element<S>, big_endian: bool): vector<u8> {
+        abort_unless_cryptography_algebra_natives_enabled();
+        serialize_fr_with_endianness_internal<S>(element.handle, big_endian)
+    }
+
     /// Get the order of structure `S`, a big integer little-endian encoded as a byte array.
     public fun order<S>(): vector<u8> {
         abort_unless_cryptography_algebra_natives_enabled();
         order_internal<S>()
     }
 
+    /// Get the bit length of the modulus of a scalar field `S`.
+    ///
+    /// Currently only the scalar fields `bls12381_algebra::Fr` and `bn254_algebra::Fr` are supported.
+    public fun scalar_num_bits<S>(): u64 {
+        abort_unless_cryptography_algebra_natives_enabled();
+        scalar_num_bits_internal<S>()
+    }
+
+    /// Get the `i`-th bit (0-indexed, least significant first) of the canonical integer representative of
+    /// an element of a scalar field `S`. Return `false` if `i` is not less than `scalar_num_bits<S>()`.
+    ///
+    /// Currently only the scalar fields `bls12381_algebra::Fr` and `bn254_algebra::Fr` are supported.
+    public fun scalar_bit<S>(element: &Element<S>, i: u64): bool {
+        abort_unless_cryptography_algebra_natives_enabled();
+        scalar_bit_internal<S>(element.handle, i)
+    }
+
     /// Cast an element of a structure `S` to a parent structure `L`.
     public fun upcast<S,L>(element: &Element<S>): Element<L> {
         abort_unless_cryptography_algebra_natives_enabled();
@@ -262,6 +535,32 @@ module aptos_std::crypto_algebra {
         }
     }
 
+    /// Verify a BLS signature `signature` on `message` under `public_key`, i.e., check whether
+    /// `e(signature, g2) == e(hash_to<G1, H>(dst, message), public_key)`,
+    /// where `e: (G1,G2) -> (Gt)` is the pairing function and `g2` is the generator of `G2`.
+    /// Return false if `public_key` is the identity of `G2`.
+    ///
+    /// E.g., `bls_verify<G1, G2, Gt, HashG1XmdSha256SswuRo>()` from `aptos_std::bls12381_algebra` with
+    /// `dst = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_"` verifies signatures of the basic scheme
+    /// with signatures in `G1` from the IETF BLS signature draft.
+    public fun bls_verify<G1, G2, Gt, H>(public_key: &Element<G2>, dst: &vector<u8>, message: &vector<u8>, signature: &Element<G1>): bool {
+        abort_unless_cryptography_algebra_natives_enabled();
+        bls_verify_internal<G1, G2, Gt, H>(public_key.handle, dst, message, signature.handle)
+    }
+
+    /// Verify an aggregate BLS signature `aggregate_signature` on `n` distinct messages `M[]` under public keys `PK[]`,
+    /// represented by parameters `messages` and `public_keys`, i.e., check whether
+    /// `e(aggregate_signature, g2) == e(hash_to<G1, H>(dst, M[0]), PK[0])+...+e(hash_to<G1, H>(dst, M[n-1]), PK[n-1])`,
+    /// with `e` and `g2` defined as in `bls_verify()`.
+    /// Return false if `n` is 0, if the messages are not distinct, or if a public key is the identity of `G2`.
+    ///
+    /// Abort with code `std::error::invalid_argument(E_NON_EQUAL_LENGTHS)` if the sizes of `public_keys` and `messages` do not match.
+    public fun bls_aggregate_verify<G1, G2, Gt, H>(public_keys: &vector<Element<G2>>, dst: &vector<u8>, messages: &vector<vector<u8>>, aggregate_signature: &Element<G1>): bool {
+        abort_unless_cryptography_algebra_natives_enabled();
+        let pk_handles = handles_from_elements(public_keys);
+        bls_aggregate_verify_internal<G1, G2, Gt, H>(pk_handles, dst, *messages, aggregate_signature.handle)
+    }
+
     #[test_only]
     /// Generate a random element of an algebraic structure `S`.
     public fun rand_insecure<S>(): Element<S> {
@@ -303,33 +602,72 @@ module aptos_std::crypto_algebra {
         element_handles
     }
 
+    fun elements_from_handles<S>(handles: &vector<u64>): vector<Element<S>> {
+        let num_handles = std::vector::length(handles);
+        let elements = std::vector::empty();
+        let i = 0;
+        while ({
+            spec {
+                invariant len(elements) == i;
+                invariant forall k in 0..i: elements[k].handle == handles[k];
+            };
+            i < num_handles
+        }) {
+            std::vector::push_back(&mut elements, Element<S> { handle: *std::vector::borrow(handles, i) });
+            i = i + 1;
+        };
+        elements
+    }
+
     //
     // (Private functions end here.)
     // Native functions begin.
     //
 
     native fun add_internal<S>(handle_1: u64, handle_2: u64): u64;
+    native fun batch_deserialize_internal<G, F>(bytes: vector<vector<u8>>): (bool, vector<u64>);
+    native fun bls_aggregate_verify_internal<G1, G2, Gt, H>(pk_handles: vector<u64>, dst: &vector<u8>, messages: vector<vector<u8>>, sig_handle: u64): bool;
+    native fun bls_verify_internal<G1, G2, Gt, H>(pk_handle: u64, dst: &vector<u8>, message: &vector<u8>, sig_handle: u64): bool;
+    native fun clear_cofactor_internal<G>(element_handle: u64): u64;
     native fun deserialize_internal<S, F>(bytes: &vector<u8>): (bool, u64);
+    native fun deserialize_fr_with_endianness_internal<S>(bytes: &vector<u8>, big_endian: bool): (bool, u64);
     native fun div_internal<F>(handle_1: u64, handle_2: u64): (bool, u64);
     native fun double_internal<G>(element_handle: u64): u64;
     native fun downcast_internal<L,S>(handle: u64): (bool, u64);
+    native fun field_inner_product_internal<F>(a_handles: vector<u64>, b_handles: vector<u64>): u64;
+    native fun frobenius_map_internal<F>(handle: u64, power: u64): u64;
+    native fun glv_scalar_mul_internal<G, S>(element_handle: u64, scalar_handle: u64): u64;
+    native fun group_sum_internal<G>(element_handles: vector<u64>): u64;
     native fun from_u64_internal<S>(value: u64): u64;
     native fun eq_internal<S>(handle_1: u64, handle_2: u64): bool;
     native fun hash_to_internal<S, H>(dst: &vector<u8>, bytes: &vector<u8>): u64;
     native fun inv_internal<F>(handle: u64): (bool, u64);
+    native fun is_square_internal<F>(handle: u64): bool;
     #[test_only]
     native fun rand_insecure_internal<S>(): u64;
     native fun mul_internal<F>(handle_1: u64, handle_2: u64): u64;
+    native fun msm_with_precomputed_internal<G, S>(table_handle: u64, scalar_handles: vector<u64>): u64;
     native fun multi_pairing_internal<G1,G2,Gt>(g1_handles: vector<u64>, g2_handles: vector<u64>): u64;
+    native fun multi_pairing_check_internal<G1,G2,Gt>(g1_handles: vector<u64>, g2_handles: vector<u64>): bool;
     native fun multi_scalar_mul_internal<G, S>(element_handles: vector<u64>, scalar_handles: vector<u64>): u64;
     native fun neg_internal<F>(handle: u64): u64;
+    native fun normalize_batch_internal<G>(element_handles: vector<u64>): vector<u64>;
     native fun one_internal<S>(): u64;
     native fun order_internal<G>(): vector<u8>;
     native fun pairing_internal<G1,G2,Gt>(g1_handle: u64, g2_handle: u64): u64;
+    native fun pedersen_commit_internal<G, S>(g_handle: u64, h_handle: u64, m_handle: u64, r_handle: u64): u64;
+    native fun poly_eval_internal<F>(coefficient_handles: vector<u64>, x_handle: u64): u64;
+    native fun precompute_msm_bases_internal<G, S>(element_handles: vector<u64>): u64;
+    native fun scalar_bit_internal<S>(handle: u64, i: u64): bool;
+    native fun scalar_from_u256_internal<S>(value: u256): u64;
     native fun scalar_mul_internal<G, S>(element_handle: u64, scalar_handle: u64): u64;
+    native fun scalar_num_bits_internal<S>(): u64;
     native fun serialize_internal<S, F>(handle: u64): vector<u8>;
+    native fun serialize_fr_with_endianness_internal<S>(handle: u64, big_endian: bool): vector<u8>;
     native fun sqr_internal<G>(handle: u64): u64;
+    native fun sqrt_internal<F>(handle: u64): (bool, u64);
     native fun sub_internal<G>(handle_1: u64, handle_2: u64): u64;
+    native fun to_affine_internal<G>(element_handle: u64): u64;
     native fun upcast_internal<S,L>(handle: u64): u64;
     native fun zero_internal<S>(): u64;
 
@@ -5,14 +5,39 @@ spec aptos_std::crypto_algebra {
         ensures forall i in 0..len(elements): result[i] == elements[i].handle;
     }
 
+    spec elements_from_handles<S>(handles: &vector<u64>): vector<Element<S>> {
+        aborts_if false;
+        ensures forall i in 0..len(handles): result[i].handle == handles[i];
+    }
+
     spec add_internal<S>(handle_1: u64, handle_2: u64): u64 {
         pragma opaque;
     }
 
+    spec batch_deserialize_internal<G, F>(bytes: vector<vector<u8>>): (bool, vector<u64>) {
+        pragma opaque;
+    }
+
+    spec bls_aggregate_verify_internal<G1, G2, Gt, H>(pk_handles: vector<u64>, dst: &vector<u8>, messages: vector<vector<u8>>, sig_handle: u64): bool {
+        pragma opaque;
+    }
+
+    spec bls_verify_internal<G1, G2, Gt, H>(pk_handle: u64, dst: &vector<u8>, message: &vector<u8>, sig_handle: u64): bool {
+        pragma opaque;
+    }
+
+    spec clear_cofactor_internal<G>(element_handle: u64): u64 {
+        pragma opaque;
+    }
+
     spec deserialize_internal<S, F>(bytes: &vector<u8>): (bool, u64) {
         pragma opaque;
     }
 
+    spec deserialize_fr_with_endianness_internal<S>(bytes: &vector<u8>, big_endian: bool): (bool, u64) {
+        pragma opaque;
+    }
+
     spec div_internal<F>(handle_1: u64, handle_2: u64): (bool, u64) {
         pragma opaque;
     }
@@ -25,10 +50,30 @@ spec aptos_std::crypto_algebra {
         pragma opaque;
     }
 
+    spec field_inner_product_internal<F>(a_handles: vector<u64>, b_handles: vector<u64>): u64 {
+        pragma opaque;
+    }
+
+    spec frobenius_map_internal<F>(handle: u64, power: u64): u64 {
+        pragma opaque;
+    }
+
+    spec glv_scalar_mul_internal<G, S>(element_handle: u64, scalar_handle: u64): u64 {
+        pragma opaque;
+    }
+
+    spec group_sum_internal<G>(element_handles: vector<u64>): u64 {
+        pragma opaque;
+    }
+
     spec from_u64_internal<S>(value: u64): u64 {
         pragma opaque;
     }
 
+    spec scalar_from_u256_internal<S>(value: u256): u64 {
+        pragma opaque;
+    }
+
     spec eq_internal<S>(handle_1: u64, handle_2: u64): bool {
         pragma opaque;
     }
@@ -41,14 +86,26 @@ spec aptos_std::crypto_algebra {
         pragma opaque;
     }
 
+    spec is_square_internal<F>(handle: u64): bool {
+        pragma opaque;
+    }
+
     spec mul_internal<F>(handle_1: u64, handle_2: u64): u64 {
         pragma opaque;
     }
 
+    spec msm_with_precomputed_internal<G, S>(table_handle: u64, scalar_handles: vector<u64>): u64 {
+        pragma opaque;
+    }
+
     spec multi_pairing_internal<G1,G2,Gt>(g1_handles: vector<u64>, g2_handles: vector<u64>): u64 {
         pragma opaque;
     }
 
+    spec multi_pairing_check_internal<G1,G2,Gt>(g1_handles: vector<u64>, g2_handles: vector<u64>): bool {
+        pragma opaque;
+    }
+
     spec multi_scalar_mul_internal<G, S>(element_handles: vector<u64>, scalar_handles: vector<u64>): u64 {
         pragma opaque;
     }
@@ -57,6 +114,10 @@ spec aptos_std::crypto_algebra {
         pragma opaque;
     }
 
+    spec normalize_batch_internal<G>(element_handles: vector<u64>): vector<u64> {
+        pragma opaque;
+    }
+
     spec one_internal<S>(): u64 {
         pragma opaque;
     }
@@ -69,22 +130,54 @@ spec aptos_std::crypto_algebra {
         pragma opaque;
     }
 
+    spec pedersen_commit_internal<G, S>(g_handle: u64, h_handle: u64, m_handle: u64, r_handle: u64): u64 {
+        pragma opaque;
+    }
+
+    spec poly_eval_internal<F>(coefficient_handles: vector<u64>, x_handle: u64): u64 {
+        pragma opaque;
+    }
+
+    spec precompute_msm_bases_internal<G, S>(element_handles: vector<u64>): u64 {
+        pragma opaque;
+    }
+
+    spec scalar_bit_internal<S>(handle: u64, i: u64): bool {
+        pragma opaque;
+    }
+
     spec scalar_mul_internal<G, S>(element_handle: u64, scalar_handle: u64): u64 {
         pragma opaque;
     }
 
+    spec scalar_num_bits_internal<S>(): u64 {
+        pragma opaque;
+    }
+
     spec serialize_internal<S, F>(handle: u64): vector<u8> {
         pragma opaque;
     }
 
+    spec serialize_fr_with_endianness_internal<S>(handle: u64, big_endian: bool): vector<u8> {
+        pragma opaque;
+    }
+
     spec sqr_internal<G>(handle: u64): u64 {
         pragma opaque;
     }
 
+    spec sqrt_internal<F>(handle: u64): (bool, u64) {
+        pragma opaque;
+    }
+
     spec sub_internal<G>(handle_1: u64, handle_2: u64): u64 {
         pragma opaque;
     }
 
+    spec to_affine_internal<G>(element_handle: u64): u64 {
+        pragma opaque;
+    }
+
     spec upcast_internal<S,L>(handle: u64): u64 {
         pragma opaque;
     }
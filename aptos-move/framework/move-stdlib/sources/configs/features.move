@@ -530,6 +530,30 @@ module std::features {
         is_enabled(DISPATCHABLE_FUNGIBLE_ASSET)
     }
 
+    /// Whether scripts may emit events whose types are declared with the `#[event]` attribute
+    /// in a module published at the address of one of their signers, instead of any event
+    /// emission in a script being rejected.
+    ///
+    /// Lifetime: transient
+    const SCRIPT_EVENT_EMISSION: u64 = 64;
+
+    public fun get_script_event_emission_feature(): u64 { SCRIPT_EVENT_EMISSION }
+
+    public fun script_event_emission_enabled(): bool acquires Features {
+        is_enabled(SCRIPT_EVENT_EMISSION)
+    }
+
+    /// Whether write set transactions may run scripts with any number of signers.
+    ///
+    /// Lifetime: transient
+    const MULTI_SIGNER_WRITE_SET_SCRIPT: u64 = 65;
+
+    public fun get_multi_signer_write_set_script_feature(): u64 { MULTI_SIGNER_WRITE_SET_SCRIPT }
+
+    public fun multi_signer_write_set_script_enabled(): bool acquires Features {
+        is_enabled(MULTI_SIGNER_WRITE_SET_SCRIPT)
+    }
+
     // ============================================================================================
     // Feature Flag Implementation
 
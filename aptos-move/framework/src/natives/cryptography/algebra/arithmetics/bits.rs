@@ -0,0 +1,77 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    abort_unless_arithmetics_enabled_for_structure, abort_unless_feature_flag_enabled,
+    natives::cryptography::algebra::{
+        abort_invariant_violated, feature_flag_from_structure, AlgebraContext, Structure,
+        MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+    },
+    safe_borrow_element, structure_from_ty_arg,
+};
+use aptos_gas_schedule::gas_params::natives::aptos_framework::*;
+use aptos_native_interface::{
+    safely_pop_arg, SafeNativeContext, SafeNativeError, SafeNativeResult,
+};
+use ark_ff::{BigInteger, PrimeField};
+use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
+use smallvec::{smallvec, SmallVec};
+use std::{collections::VecDeque, rc::Rc};
+
+macro_rules! ark_scalar_bit_internal {
+    ($context:expr, $args:ident, $ark_typ:ty, $gas:expr) => {{
+        let i = safely_pop_arg!($args, u64);
+        let handle = safely_pop_arg!($args, u64) as usize;
+        safe_borrow_element!($context, handle, $ark_typ, element_ptr, element);
+        $context.charge($gas)?;
+        // Bits at or beyond the modulus length are always unset in a canonical representative.
+        let bit = i < u64::from(<$ark_typ as PrimeField>::MODULUS_BIT_SIZE)
+            && element.into_bigint().get_bit(i as usize);
+        Ok(smallvec![Value::bool(bit)])
+    }};
+}
+
+pub fn scalar_num_bits_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut _args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(1, ty_args.len());
+    let structure_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    abort_unless_arithmetics_enabled_for_structure!(context, structure_opt);
+    match structure_opt {
+        Some(Structure::BLS12381Fr) => Ok(smallvec![Value::u64(u64::from(
+            ark_bls12_381::Fr::MODULUS_BIT_SIZE
+        ))]),
+        Some(Structure::BN254Fr) => Ok(smallvec![Value::u64(u64::from(
+            ark_bn254::Fr::MODULUS_BIT_SIZE
+        ))]),
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
+
+pub fn scalar_bit_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(1, ty_args.len());
+    let structure_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    abort_unless_arithmetics_enabled_for_structure!(context, structure_opt);
+    match structure_opt {
+        Some(Structure::BLS12381Fr) => ark_scalar_bit_internal!(
+            context,
+            args,
+            ark_bls12_381::Fr,
+            ALGEBRA_ARK_BLS12_381_FR_BIT
+        ),
+        Some(Structure::BN254Fr) => {
+            ark_scalar_bit_internal!(context, args, ark_bn254::Fr, ALGEBRA_ARK_BN254_FR_BIT)
+        },
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
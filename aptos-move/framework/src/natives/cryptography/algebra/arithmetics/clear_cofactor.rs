@@ -0,0 +1,75 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    abort_unless_arithmetics_enabled_for_structure, abort_unless_feature_flag_enabled,
+    natives::cryptography::algebra::{
+        abort_invariant_violated, feature_flag_from_structure, AlgebraContext, Structure,
+        E_TOO_MUCH_MEMORY_USED, MEMORY_LIMIT_IN_BYTES, MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+    },
+    safe_borrow_element, store_element, structure_from_ty_arg,
+};
+use aptos_gas_schedule::gas_params::natives::aptos_framework::*;
+use aptos_native_interface::{
+    safely_pop_arg, SafeNativeContext, SafeNativeError, SafeNativeResult,
+};
+use ark_ec::{AffineRepr, CurveGroup};
+use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
+use smallvec::{smallvec, SmallVec};
+use std::{collections::VecDeque, rc::Rc};
+
+macro_rules! ark_clear_cofactor_internal {
+    ($context:expr, $args:ident, $ark_typ:ty, $to_affine_gas:expr, $gas:expr) => {{
+        let handle = safely_pop_arg!($args, u64) as usize;
+        safe_borrow_element!($context, handle, $ark_typ, element_ptr, element);
+        $context.charge($to_affine_gas)?;
+        let element_affine = element.into_affine();
+        $context.charge($gas)?;
+        let new_element: $ark_typ = element_affine.clear_cofactor().into_group();
+        let new_handle = store_element!($context, new_element)?;
+        Ok(smallvec![Value::u64(new_handle as u64)])
+    }};
+}
+
+pub fn clear_cofactor_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(1, ty_args.len());
+    let structure_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    abort_unless_arithmetics_enabled_for_structure!(context, structure_opt);
+    match structure_opt {
+        Some(Structure::BLS12381G1) => ark_clear_cofactor_internal!(
+            context,
+            args,
+            ark_bls12_381::G1Projective,
+            ALGEBRA_ARK_BLS12_381_G1_PROJ_TO_AFFINE,
+            ALGEBRA_ARK_BLS12_381_G1_AFFINE_CLEAR_COFACTOR
+        ),
+        Some(Structure::BLS12381G2) => ark_clear_cofactor_internal!(
+            context,
+            args,
+            ark_bls12_381::G2Projective,
+            ALGEBRA_ARK_BLS12_381_G2_PROJ_TO_AFFINE,
+            ALGEBRA_ARK_BLS12_381_G2_AFFINE_CLEAR_COFACTOR
+        ),
+        Some(Structure::BN254G1) => ark_clear_cofactor_internal!(
+            context,
+            args,
+            ark_bn254::G1Projective,
+            ALGEBRA_ARK_BN254_G1_PROJ_TO_AFFINE,
+            ALGEBRA_ARK_BN254_G1_AFFINE_CLEAR_COFACTOR
+        ),
+        Some(Structure::BN254G2) => ark_clear_cofactor_internal!(
+            context,
+            args,
+            ark_bn254::G2Projective,
+            ALGEBRA_ARK_BN254_G2_PROJ_TO_AFFINE,
+            ALGEBRA_ARK_BN254_G2_AFFINE_CLEAR_COFACTOR
+        ),
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
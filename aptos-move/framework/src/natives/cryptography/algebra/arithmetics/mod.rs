@@ -2,14 +2,22 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod add;
+pub mod bits;
+pub mod clear_cofactor;
 pub mod div;
 pub mod double;
+pub mod frobenius;
+pub mod inner_product;
 pub mod inv;
 pub mod mul;
 pub mod neg;
+pub mod normalize;
+pub mod poly_eval;
 pub mod scalar_mul;
 pub mod sqr;
+pub mod sqrt;
 pub mod sub;
+pub mod sum;
 
 #[macro_export]
 macro_rules! ark_binary_op_internal {
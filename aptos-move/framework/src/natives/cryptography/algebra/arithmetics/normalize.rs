@@ -0,0 +1,135 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    abort_unless_arithmetics_enabled_for_structure, abort_unless_feature_flag_enabled,
+    natives::cryptography::algebra::{
+        abort_invariant_violated, feature_flag_from_structure, AlgebraContext, Structure,
+        E_TOO_MUCH_MEMORY_USED, MEMORY_LIMIT_IN_BYTES, MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+    },
+    safe_borrow_element, store_element, structure_from_ty_arg,
+};
+use aptos_gas_schedule::gas_params::natives::aptos_framework::*;
+use aptos_native_interface::{
+    safely_pop_arg, SafeNativeContext, SafeNativeError, SafeNativeResult,
+};
+use ark_ec::CurveGroup;
+use move_core_types::gas_algebra::NumArgs;
+use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
+use smallvec::{smallvec, SmallVec};
+use std::{collections::VecDeque, rc::Rc};
+
+macro_rules! ark_to_affine_internal {
+    ($context:expr, $args:ident, $ark_typ:ty, $gas:expr) => {{
+        let handle = safely_pop_arg!($args, u64) as usize;
+        safe_borrow_element!($context, handle, $ark_typ, element_ptr, element);
+        $context.charge($gas)?;
+        // Elements are always stored in projective form, normalizing means setting `z` to 1.
+        let new_element = <$ark_typ>::from(element.into_affine());
+        let new_handle = store_element!($context, new_element)?;
+        Ok(smallvec![Value::u64(new_handle as u64)])
+    }};
+}
+
+macro_rules! ark_normalize_batch_internal {
+    ($context:expr, $args:ident, $ark_typ:ty, $base_gas:expr, $per_point_gas:expr) => {{
+        let handles = safely_pop_arg!($args, Vec<u64>);
+        let num_points = handles.len();
+        $context.charge($base_gas + $per_point_gas * NumArgs::from(num_points as u64))?;
+        let mut points = Vec::with_capacity(num_points);
+        for handle in handles {
+            safe_borrow_element!($context, handle as usize, $ark_typ, element_ptr, element);
+            points.push(*element);
+        }
+        // A single field inversion is shared by all the points.
+        let points_affine = <$ark_typ>::normalize_batch(&points);
+        let mut new_handles = Vec::with_capacity(num_points);
+        for point_affine in points_affine {
+            let new_handle = store_element!($context, <$ark_typ>::from(point_affine))?;
+            new_handles.push(new_handle as u64);
+        }
+        Ok(smallvec![Value::vector_u64(new_handles)])
+    }};
+}
+
+pub fn to_affine_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(1, ty_args.len());
+    let structure_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    abort_unless_arithmetics_enabled_for_structure!(context, structure_opt);
+    match structure_opt {
+        Some(Structure::BLS12381G1) => ark_to_affine_internal!(
+            context,
+            args,
+            ark_bls12_381::G1Projective,
+            ALGEBRA_ARK_BLS12_381_G1_PROJ_TO_AFFINE
+        ),
+        Some(Structure::BLS12381G2) => ark_to_affine_internal!(
+            context,
+            args,
+            ark_bls12_381::G2Projective,
+            ALGEBRA_ARK_BLS12_381_G2_PROJ_TO_AFFINE
+        ),
+        Some(Structure::BN254G1) => ark_to_affine_internal!(
+            context,
+            args,
+            ark_bn254::G1Projective,
+            ALGEBRA_ARK_BN254_G1_PROJ_TO_AFFINE
+        ),
+        Some(Structure::BN254G2) => ark_to_affine_internal!(
+            context,
+            args,
+            ark_bn254::G2Projective,
+            ALGEBRA_ARK_BN254_G2_PROJ_TO_AFFINE
+        ),
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
+
+pub fn normalize_batch_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(1, ty_args.len());
+    let structure_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    abort_unless_arithmetics_enabled_for_structure!(context, structure_opt);
+    match structure_opt {
+        Some(Structure::BLS12381G1) => ark_normalize_batch_internal!(
+            context,
+            args,
+            ark_bls12_381::G1Projective,
+            ALGEBRA_ARK_BLS12_381_G1_PROJ_NORMALIZE_BATCH_BASE,
+            ALGEBRA_ARK_BLS12_381_G1_PROJ_NORMALIZE_BATCH_PER_POINT
+        ),
+        Some(Structure::BLS12381G2) => ark_normalize_batch_internal!(
+            context,
+            args,
+            ark_bls12_381::G2Projective,
+            ALGEBRA_ARK_BLS12_381_G2_PROJ_NORMALIZE_BATCH_BASE,
+            ALGEBRA_ARK_BLS12_381_G2_PROJ_NORMALIZE_BATCH_PER_POINT
+        ),
+        Some(Structure::BN254G1) => ark_normalize_batch_internal!(
+            context,
+            args,
+            ark_bn254::G1Projective,
+            ALGEBRA_ARK_BN254_G1_PROJ_NORMALIZE_BATCH_BASE,
+            ALGEBRA_ARK_BN254_G1_PROJ_NORMALIZE_BATCH_PER_POINT
+        ),
+        Some(Structure::BN254G2) => ark_normalize_batch_internal!(
+            context,
+            args,
+            ark_bn254::G2Projective,
+            ALGEBRA_ARK_BN254_G2_PROJ_NORMALIZE_BATCH_BASE,
+            ALGEBRA_ARK_BN254_G2_PROJ_NORMALIZE_BATCH_PER_POINT
+        ),
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
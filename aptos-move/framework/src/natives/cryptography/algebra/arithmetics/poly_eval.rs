@@ -0,0 +1,74 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    abort_unless_arithmetics_enabled_for_structure, abort_unless_feature_flag_enabled,
+    natives::cryptography::algebra::{
+        abort_invariant_violated, feature_flag_from_structure, AlgebraContext, Structure,
+        E_TOO_MUCH_MEMORY_USED, MEMORY_LIMIT_IN_BYTES, MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+    },
+    safe_borrow_element, store_element, structure_from_ty_arg,
+};
+use aptos_gas_algebra::{Arg, GasExpression};
+use aptos_gas_schedule::gas_params::natives::aptos_framework::*;
+use aptos_native_interface::{
+    safely_pop_arg, SafeNativeContext, SafeNativeError, SafeNativeResult,
+};
+use move_core_types::gas_algebra::NumArgs;
+use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
+use smallvec::{smallvec, SmallVec};
+use std::{collections::VecDeque, rc::Rc};
+
+macro_rules! ark_poly_eval_internal {
+    ($context:expr, $args:ident, $ark_typ:ty, $mul_gas:expr, $add_gas:expr) => {{
+        let point_handle = safely_pop_arg!($args, u64) as usize;
+        let coefficient_handles = safely_pop_arg!($args, Vec<u64>);
+        let num_terms = coefficient_handles.len() as u64;
+        $context.charge(($mul_gas + $add_gas).per::<Arg>() * NumArgs::from(num_terms))?;
+        safe_borrow_element!($context, point_handle, $ark_typ, point_ptr, point);
+        let point = *point;
+        // Horner's rule: `c[0] + x*(c[1] + x*(... + x*c[n-1]))`.
+        let mut new_element = <$ark_typ>::from(0u64);
+        for handle in coefficient_handles.into_iter().rev() {
+            safe_borrow_element!(
+                $context,
+                handle as usize,
+                $ark_typ,
+                coefficient_ptr,
+                coefficient
+            );
+            new_element = new_element * point + *coefficient;
+        }
+        let new_handle = store_element!($context, new_element)?;
+        Ok(smallvec![Value::u64(new_handle as u64)])
+    }};
+}
+
+pub fn poly_eval_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(1, ty_args.len());
+    let structure_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    abort_unless_arithmetics_enabled_for_structure!(context, structure_opt);
+    match structure_opt {
+        Some(Structure::BLS12381Fr) => ark_poly_eval_internal!(
+            context,
+            args,
+            ark_bls12_381::Fr,
+            ALGEBRA_ARK_BLS12_381_FR_MUL,
+            ALGEBRA_ARK_BLS12_381_FR_ADD
+        ),
+        Some(Structure::BN254Fr) => ark_poly_eval_internal!(
+            context,
+            args,
+            ark_bn254::Fr,
+            ALGEBRA_ARK_BN254_FR_MUL,
+            ALGEBRA_ARK_BN254_FR_ADD
+        ),
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
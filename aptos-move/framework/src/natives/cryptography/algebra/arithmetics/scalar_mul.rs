@@ -5,9 +5,9 @@ use crate::{
     abort_unless_feature_flag_enabled,
     natives::cryptography::{
         algebra::{
-            abort_invariant_violated, AlgebraContext, Structure, E_TOO_MUCH_MEMORY_USED,
-            MEMORY_LIMIT_IN_BYTES, MOVE_ABORT_CODE_INPUT_VECTOR_SIZES_NOT_MATCHING,
-            MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+            abort_invariant_violated, maybe_par_chunks, AlgebraContext, Structure,
+            E_TOO_MUCH_MEMORY_USED, MEMORY_LIMIT_IN_BYTES,
+            MOVE_ABORT_CODE_INPUT_VECTOR_SIZES_NOT_MATCHING, MOVE_ABORT_CODE_NOT_IMPLEMENTED,
         },
         helpers::log2_ceil,
     },
@@ -19,8 +19,8 @@ use aptos_native_interface::{
     safely_pop_arg, SafeNativeContext, SafeNativeError, SafeNativeResult,
 };
 use aptos_types::on_chain_config::FeatureFlag;
-use ark_ec::{CurveGroup, Group};
-use ark_ff::Field;
+use ark_ec::{scalar_mul::glv::GLVConfig, CurveGroup, Group};
+use ark_ff::{BigInteger, Field, PrimeField, Zero};
 use move_core_types::gas_algebra::NumArgs;
 use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
 use smallvec::{smallvec, SmallVec};
@@ -68,7 +68,7 @@ macro_rules! ark_scalar_mul_internal {
 }
 
 /// WARNING: Be careful with the unwrap() below, if you modify this if statement.
-fn ark_msm_window_size(num_entries: usize) -> usize {
+pub fn ark_msm_window_size(num_entries: usize) -> usize {
     if num_entries < 32 {
         3
     } else {
@@ -77,8 +77,11 @@ fn ark_msm_window_size(num_entries: usize) -> usize {
 }
 
 /// The approximate cost model of <https://github.com/arkworks-rs/algebra/blob/v0.4.0/ec/src/scalar_mul/variable_base/mod.rs#L89>.
+#[macro_export]
 macro_rules! ark_msm_bigint_wnaf_cost {
     ($cost_add:expr, $cost_double:expr, $num_entries:expr $(,)?) => {{
+        use move_core_types::gas_algebra::NumArgs;
+        use $crate::natives::cryptography::algebra::arithmetics::scalar_mul::ark_msm_window_size;
         let num_entries: usize = $num_entries;
         let window_size = ark_msm_window_size(num_entries);
         let num_windows = (255 + window_size - 1) / window_size;
@@ -184,6 +187,108 @@ pub fn scalar_mul_internal(
     }
 }
 
+/// Like `scalar_mul_internal`, but uses the GLV decomposition of the scalar for groups with an
+/// efficiently computable endomorphism, which roughly halves the number of doublings.
+/// Other groups fall back to `scalar_mul_internal`. The result is the same group element either way.
+pub fn glv_scalar_mul_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(2, ty_args.len());
+    let group_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    let scalar_field_opt = structure_from_ty_arg!(context, &ty_args[1]);
+    abort_unless_group_scalar_mul_enabled!(context, group_opt, scalar_field_opt);
+    match (group_opt, scalar_field_opt) {
+        (Some(Structure::BLS12381G1), Some(Structure::BLS12381Fr)) => {
+            let scalar_handle = safely_pop_arg!(args, u64) as usize;
+            let element_handle = safely_pop_arg!(args, u64) as usize;
+            safe_borrow_element!(
+                context,
+                element_handle,
+                ark_bls12_381::G1Projective,
+                element_ptr,
+                element
+            );
+            safe_borrow_element!(
+                context,
+                scalar_handle,
+                ark_bls12_381::Fr,
+                scalar_ptr,
+                scalar
+            );
+            context.charge(ALGEBRA_ARK_BLS12_381_G1_PROJ_GLV_SCALAR_MUL)?;
+            let new_element = ark_bls12_381::g1::Config::glv_mul_projective(*element, *scalar);
+            let new_handle = store_element!(context, new_element)?;
+            Ok(smallvec![Value::u64(new_handle as u64)])
+        },
+        _ => scalar_mul_internal(context, ty_args, args),
+    }
+}
+
+macro_rules! ark_pedersen_commit_internal {
+    (
+        $context:expr,
+        $args:ident,
+        $element_typ:ty,
+        $scalar_typ:ty,
+        $scalar_mul_cost:expr,
+        $add_cost:expr
+    ) => {{
+        let r_handle = safely_pop_arg!($args, u64) as usize;
+        let m_handle = safely_pop_arg!($args, u64) as usize;
+        let h_handle = safely_pop_arg!($args, u64) as usize;
+        let g_handle = safely_pop_arg!($args, u64) as usize;
+        safe_borrow_element!($context, g_handle, $element_typ, g_ptr, g);
+        safe_borrow_element!($context, h_handle, $element_typ, h_ptr, h);
+        safe_borrow_element!($context, m_handle, $scalar_typ, m_ptr, m);
+        safe_borrow_element!($context, r_handle, $scalar_typ, r_ptr, r);
+        $context.charge($scalar_mul_cost * NumArgs::from(2) + $add_cost)?;
+        let bases = <$element_typ>::normalize_batch(&[*g, *h]);
+        let new_element: $element_typ = ark_ec::VariableBaseMSM::msm(&bases, &[*m, *r]).unwrap();
+        let new_handle = store_element!($context, new_element)?;
+        Ok(smallvec![Value::u64(new_handle as u64)])
+    }};
+}
+
+/// Computes the Pedersen commitment `m*g + r*h` as a 2-entry MSM in a single native call.
+/// The cost is that of the two scalar multiplications and the addition it replaces.
+pub fn pedersen_commit_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(2, ty_args.len());
+    let group_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    let scalar_field_opt = structure_from_ty_arg!(context, &ty_args[1]);
+    abort_unless_group_scalar_mul_enabled!(context, group_opt, scalar_field_opt);
+    match (group_opt, scalar_field_opt) {
+        (Some(Structure::BLS12381G1), Some(Structure::BLS12381Fr)) => {
+            ark_pedersen_commit_internal!(
+                context,
+                args,
+                ark_bls12_381::G1Projective,
+                ark_bls12_381::Fr,
+                ALGEBRA_ARK_BLS12_381_G1_PROJ_SCALAR_MUL.per::<Arg>(),
+                ALGEBRA_ARK_BLS12_381_G1_PROJ_ADD
+            )
+        },
+        (Some(Structure::BN254G1), Some(Structure::BN254Fr)) => {
+            ark_pedersen_commit_internal!(
+                context,
+                args,
+                ark_bn254::G1Projective,
+                ark_bn254::Fr,
+                ALGEBRA_ARK_BN254_G1_PROJ_SCALAR_MUL.per::<Arg>(),
+                ALGEBRA_ARK_BN254_G1_PROJ_ADD
+            )
+        },
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
+
 macro_rules! ark_msm_internal {
     (
         $context:expr,
@@ -225,8 +330,12 @@ macro_rules! ark_msm_internal {
             $proj_double_cost,
             num_elements,
         ))?;
-        let new_element: $element_typ =
-            ark_ec::VariableBaseMSM::msm(bases.as_slice(), scalars.as_slice()).unwrap();
+        let new_element: $element_typ = maybe_par_chunks(
+            &bases,
+            &scalars,
+            |bases, scalars| ark_ec::VariableBaseMSM::msm(bases, scalars).unwrap(),
+            |lhs, rhs| lhs + rhs,
+        );
         let new_handle = store_element!($context, new_element)?;
         Ok(smallvec![Value::u64(new_handle as u64)])
     }};
@@ -291,3 +400,253 @@ pub fn multi_scalar_mul_internal(
         }),
     }
 }
+
+/// Number of bits of the scalar windows of a precomputed MSM. With 8 bits, the digits of a scalar
+/// are simply the bytes of its little-endian representation.
+const PRECOMPUTED_MSM_WINDOW_BITS: usize = 8;
+
+/// The multiples `2^(w*k) * base` of a fixed list of bases, for every window `k` of
+/// `w = PRECOMPUTED_MSM_WINDOW_BITS` bits of a scalar, so that an MSM over these bases needs no
+/// doublings.
+pub struct PrecomputedMsmBases<G: CurveGroup> {
+    num_windows: usize,
+    /// The multiples of the `i`-th base are at `[i * num_windows, (i + 1) * num_windows)`.
+    points: Vec<G::Affine>,
+}
+
+impl<G: CurveGroup> PrecomputedMsmBases<G> {
+    fn new(bases: &[G], num_windows: usize) -> Self {
+        let mut points = Vec::with_capacity(bases.len() * num_windows);
+        for base in bases {
+            let mut multiple = *base;
+            points.push(multiple);
+            for _ in 1..num_windows {
+                for _ in 0..PRECOMPUTED_MSM_WINDOW_BITS {
+                    multiple.double_in_place();
+                }
+                points.push(multiple);
+            }
+        }
+        Self {
+            num_windows,
+            points: G::normalize_batch(&points),
+        }
+    }
+
+    fn num_bases(&self) -> usize {
+        self.points.len() / self.num_windows
+    }
+
+    /// Computes `sum_i scalars[i] * bases[i]` by adding every precomputed multiple into the bucket
+    /// of its scalar digit, then summing the buckets weighted by their digit.
+    fn msm<F: PrimeField>(&self, scalars: &[F]) -> G {
+        let mut buckets = vec![G::zero(); 1 << PRECOMPUTED_MSM_WINDOW_BITS];
+        for (multiples, scalar) in self.points.chunks(self.num_windows).zip(scalars) {
+            let digits = scalar.into_bigint().to_bytes_le();
+            for (multiple, digit) in multiples.iter().zip(digits) {
+                if digit != 0 {
+                    buckets[digit as usize] += *multiple;
+                }
+            }
+        }
+        let mut running_sum = G::zero();
+        let mut result = G::zero();
+        for bucket in buckets.into_iter().skip(1).rev() {
+            running_sum += bucket;
+            result += running_sum;
+        }
+        result
+    }
+}
+
+fn precomputed_msm_num_windows<F: PrimeField>() -> usize {
+    (F::MODULUS_BIT_SIZE as usize + PRECOMPUTED_MSM_WINDOW_BITS - 1) / PRECOMPUTED_MSM_WINDOW_BITS
+}
+
+macro_rules! ark_precompute_msm_bases_internal {
+    (
+        $context:expr,
+        $args:ident,
+        $proj_double_cost:expr,
+        $proj_to_affine_cost:expr,
+        $element_typ:ty,
+        $scalar_typ:ty
+    ) => {{
+        let element_handles = safely_pop_arg!($args, Vec<u64>);
+        let num_elements = element_handles.len();
+        let num_windows = precomputed_msm_num_windows::<$scalar_typ>();
+        let num_points = num_elements * num_windows;
+        let num_doubles = num_elements * (num_windows - 1) * PRECOMPUTED_MSM_WINDOW_BITS;
+        $context.charge(
+            $proj_double_cost * NumArgs::from(num_doubles as u64)
+                + $proj_to_affine_cost * NumArgs::from(num_points as u64),
+        )?;
+        // Check the memory limit before building the table, which may be large.
+        let num_bytes = num_points * std::mem::size_of::<<$element_typ as CurveGroup>::Affine>();
+        if $context.extensions().get::<AlgebraContext>().bytes_used + num_bytes
+            > MEMORY_LIMIT_IN_BYTES
+        {
+            return Err(SafeNativeError::Abort {
+                abort_code: E_TOO_MUCH_MEMORY_USED,
+            });
+        }
+        let mut bases = Vec::with_capacity(num_elements);
+        for handle in element_handles {
+            safe_borrow_element!(
+                $context,
+                handle as usize,
+                $element_typ,
+                element_ptr,
+                element
+            );
+            bases.push(*element);
+        }
+        let table = PrecomputedMsmBases::<$element_typ>::new(&bases, num_windows);
+        let new_handle = store_element!($context, table, num_bytes)?;
+        Ok(smallvec![Value::u64(new_handle as u64)])
+    }};
+}
+
+/// Precomputes the multiples of a fixed list of bases needed by `msm_with_precomputed_internal`.
+/// The doublings and affine conversions are charged once here, and the table counts towards the
+/// memory limit of the algebra context.
+pub fn precompute_msm_bases_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(2, ty_args.len());
+    let structure_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    let scalar_opt = structure_from_ty_arg!(context, &ty_args[1]);
+    abort_unless_group_scalar_mul_enabled!(context, structure_opt, scalar_opt);
+    match (structure_opt, scalar_opt) {
+        (Some(Structure::BLS12381G1), Some(Structure::BLS12381Fr)) => {
+            ark_precompute_msm_bases_internal!(
+                context,
+                args,
+                ALGEBRA_ARK_BLS12_381_G1_PROJ_DOUBLE.per::<Arg>(),
+                ALGEBRA_ARK_BLS12_381_G1_PROJ_TO_AFFINE.per::<Arg>(),
+                ark_bls12_381::G1Projective,
+                ark_bls12_381::Fr
+            )
+        },
+        (Some(Structure::BLS12381G2), Some(Structure::BLS12381Fr)) => {
+            ark_precompute_msm_bases_internal!(
+                context,
+                args,
+                ALGEBRA_ARK_BLS12_381_G2_PROJ_DOUBLE.per::<Arg>(),
+                ALGEBRA_ARK_BLS12_381_G2_PROJ_TO_AFFINE.per::<Arg>(),
+                ark_bls12_381::G2Projective,
+                ark_bls12_381::Fr
+            )
+        },
+        (Some(Structure::BN254G1), Some(Structure::BN254Fr)) => {
+            ark_precompute_msm_bases_internal!(
+                context,
+                args,
+                ALGEBRA_ARK_BN254_G1_PROJ_DOUBLE.per::<Arg>(),
+                ALGEBRA_ARK_BN254_G1_PROJ_TO_AFFINE.per::<Arg>(),
+                ark_bn254::G1Projective,
+                ark_bn254::Fr
+            )
+        },
+        (Some(Structure::BN254G2), Some(Structure::BN254Fr)) => {
+            ark_precompute_msm_bases_internal!(
+                context,
+                args,
+                ALGEBRA_ARK_BN254_G2_PROJ_DOUBLE.per::<Arg>(),
+                ALGEBRA_ARK_BN254_G2_PROJ_TO_AFFINE.per::<Arg>(),
+                ark_bn254::G2Projective,
+                ark_bn254::Fr
+            )
+        },
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
+
+macro_rules! ark_msm_with_precomputed_internal {
+    ($context:expr, $args:ident, $proj_add_cost:expr, $element_typ:ty, $scalar_typ:ty) => {{
+        let scalar_handles = safely_pop_arg!($args, Vec<u64>);
+        let table_handle = safely_pop_arg!($args, u64) as usize;
+        safe_borrow_element!(
+            $context,
+            table_handle,
+            PrecomputedMsmBases<$element_typ>,
+            table_ptr,
+            table
+        );
+        if table.num_bases() != scalar_handles.len() {
+            return Err(SafeNativeError::Abort {
+                abort_code: MOVE_ABORT_CODE_INPUT_VECTOR_SIZES_NOT_MATCHING,
+            });
+        }
+        let num_buckets = 1_usize << PRECOMPUTED_MSM_WINDOW_BITS;
+        $context.charge(
+            $proj_add_cost * NumArgs::from((table.points.len() + 2 * num_buckets) as u64),
+        )?;
+        let mut scalars = Vec::with_capacity(scalar_handles.len());
+        for handle in scalar_handles {
+            safe_borrow_element!($context, handle as usize, $scalar_typ, scalar_ptr, scalar);
+            scalars.push(*scalar);
+        }
+        let new_element: $element_typ = table.msm(&scalars);
+        let new_handle = store_element!($context, new_element)?;
+        Ok(smallvec![Value::u64(new_handle as u64)])
+    }};
+}
+
+/// Computes an MSM over the bases of a table built by `precompute_msm_bases_internal`, which
+/// only takes one addition per base and window of the scalars.
+pub fn msm_with_precomputed_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(2, ty_args.len());
+    let structure_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    let scalar_opt = structure_from_ty_arg!(context, &ty_args[1]);
+    abort_unless_group_scalar_mul_enabled!(context, structure_opt, scalar_opt);
+    match (structure_opt, scalar_opt) {
+        (Some(Structure::BLS12381G1), Some(Structure::BLS12381Fr)) => {
+            ark_msm_with_precomputed_internal!(
+                context,
+                args,
+                ALGEBRA_ARK_BLS12_381_G1_PROJ_ADD.per::<Arg>(),
+                ark_bls12_381::G1Projective,
+                ark_bls12_381::Fr
+            )
+        },
+        (Some(Structure::BLS12381G2), Some(Structure::BLS12381Fr)) => {
+            ark_msm_with_precomputed_internal!(
+                context,
+                args,
+                ALGEBRA_ARK_BLS12_381_G2_PROJ_ADD.per::<Arg>(),
+                ark_bls12_381::G2Projective,
+                ark_bls12_381::Fr
+            )
+        },
+        (Some(Structure::BN254G1), Some(Structure::BN254Fr)) => {
+            ark_msm_with_precomputed_internal!(
+                context,
+                args,
+                ALGEBRA_ARK_BN254_G1_PROJ_ADD.per::<Arg>(),
+                ark_bn254::G1Projective,
+                ark_bn254::Fr
+            )
+        },
+        (Some(Structure::BN254G2), Some(Structure::BN254Fr)) => {
+            ark_msm_with_precomputed_internal!(
+                context,
+                args,
+                ALGEBRA_ARK_BN254_G2_PROJ_ADD.per::<Arg>(),
+                ark_bn254::G2Projective,
+                ark_bn254::Fr
+            )
+        },
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
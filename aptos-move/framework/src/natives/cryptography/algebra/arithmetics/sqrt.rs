@@ -0,0 +1,98 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    abort_unless_arithmetics_enabled_for_structure, abort_unless_feature_flag_enabled,
+    natives::cryptography::algebra::{
+        abort_invariant_violated, feature_flag_from_structure, AlgebraContext, Structure,
+        E_TOO_MUCH_MEMORY_USED, MEMORY_LIMIT_IN_BYTES, MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+    },
+    safe_borrow_element, store_element, structure_from_ty_arg,
+};
+use aptos_gas_schedule::gas_params::natives::aptos_framework::*;
+use aptos_native_interface::{
+    safely_pop_arg, SafeNativeContext, SafeNativeError, SafeNativeResult,
+};
+use ark_ff::Field;
+use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
+use smallvec::{smallvec, SmallVec};
+use std::{collections::VecDeque, rc::Rc};
+
+macro_rules! ark_is_square_internal {
+    ($context:expr, $args:ident, $ark_typ:ty, $gas:expr) => {{
+        let handle = safely_pop_arg!($args, u64) as usize;
+        safe_borrow_element!($context, handle, $ark_typ, element_ptr, element);
+        $context.charge($gas)?;
+        // Zero is a square too, only non-residues are rejected.
+        Ok(smallvec![Value::bool(!element.legendre().is_qnr())])
+    }};
+}
+
+macro_rules! ark_sqrt_internal {
+    ($context:expr, $args:ident, $ark_typ:ty, $gas:expr) => {{
+        let handle = safely_pop_arg!($args, u64) as usize;
+        safe_borrow_element!($context, handle, $ark_typ, element_ptr, element);
+        $context.charge($gas)?;
+        match element.sqrt() {
+            Some(new_element) => {
+                let new_handle = store_element!($context, new_element)?;
+                Ok(smallvec![Value::bool(true), Value::u64(new_handle as u64)])
+            },
+            None => Ok(smallvec![Value::bool(false), Value::u64(0)]),
+        }
+    }};
+}
+
+pub fn is_square_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(1, ty_args.len());
+    let structure_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    abort_unless_arithmetics_enabled_for_structure!(context, structure_opt);
+    match structure_opt {
+        Some(Structure::BLS12381Fr) => ark_is_square_internal!(
+            context,
+            args,
+            ark_bls12_381::Fr,
+            ALGEBRA_ARK_BLS12_381_FR_IS_SQUARE
+        ),
+        Some(Structure::BN254Fr) => {
+            ark_is_square_internal!(context, args, ark_bn254::Fr, ALGEBRA_ARK_BN254_FR_IS_SQUARE)
+        },
+        Some(Structure::BN254Fq) => {
+            ark_is_square_internal!(context, args, ark_bn254::Fq, ALGEBRA_ARK_BN254_FQ_IS_SQUARE)
+        },
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
+
+pub fn sqrt_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(1, ty_args.len());
+    let structure_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    abort_unless_arithmetics_enabled_for_structure!(context, structure_opt);
+    match structure_opt {
+        Some(Structure::BLS12381Fr) => ark_sqrt_internal!(
+            context,
+            args,
+            ark_bls12_381::Fr,
+            ALGEBRA_ARK_BLS12_381_FR_SQRT
+        ),
+        Some(Structure::BN254Fr) => {
+            ark_sqrt_internal!(context, args, ark_bn254::Fr, ALGEBRA_ARK_BN254_FR_SQRT)
+        },
+        Some(Structure::BN254Fq) => {
+            ark_sqrt_internal!(context, args, ark_bn254::Fq, ALGEBRA_ARK_BN254_FQ_SQRT)
+        },
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    abort_unless_arithmetics_enabled_for_structure, abort_unless_feature_flag_enabled,
+    natives::cryptography::algebra::{
+        abort_invariant_violated, feature_flag_from_structure, AlgebraContext, Structure,
+        E_TOO_MUCH_MEMORY_USED, MEMORY_LIMIT_IN_BYTES, MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+    },
+    safe_borrow_element, store_element, structure_from_ty_arg,
+};
+use aptos_gas_algebra::{Arg, GasExpression};
+use aptos_gas_schedule::gas_params::natives::aptos_framework::*;
+use aptos_native_interface::{
+    safely_pop_arg, SafeNativeContext, SafeNativeError, SafeNativeResult,
+};
+use ark_ec::CurveGroup;
+use move_core_types::gas_algebra::NumArgs;
+use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
+use num_traits::Zero;
+use smallvec::{smallvec, SmallVec};
+use std::{collections::VecDeque, rc::Rc};
+
+macro_rules! ark_group_sum_internal {
+    (
+        $context:expr, $args:ident, $ark_typ:ty, $zero_gas:expr, $add_gas:expr, $to_affine_gas:expr
+    ) => {{
+        let handles = safely_pop_arg!($args, Vec<u64>);
+        let num_elements = handles.len() as u64;
+        $context.charge(
+            $zero_gas + $add_gas.per::<Arg>() * NumArgs::from(num_elements) + $to_affine_gas,
+        )?;
+        // Points are kept in projective coordinates, so no field inversion is needed per addition,
+        // and the sum is normalized only once at the end.
+        let mut sum = <$ark_typ>::zero();
+        for handle in handles {
+            safe_borrow_element!($context, handle as usize, $ark_typ, element_ptr, element);
+            sum += element;
+        }
+        let sum = <$ark_typ>::from(sum.into_affine());
+        let new_handle = store_element!($context, sum)?;
+        Ok(smallvec![Value::u64(new_handle as u64)])
+    }};
+}
+
+pub fn group_sum_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(1, ty_args.len());
+    let structure_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    abort_unless_arithmetics_enabled_for_structure!(context, structure_opt);
+    match structure_opt {
+        Some(Structure::BLS12381G1) => ark_group_sum_internal!(
+            context,
+            args,
+            ark_bls12_381::G1Projective,
+            ALGEBRA_ARK_BLS12_381_G1_PROJ_INFINITY,
+            ALGEBRA_ARK_BLS12_381_G1_PROJ_ADD,
+            ALGEBRA_ARK_BLS12_381_G1_PROJ_TO_AFFINE
+        ),
+        Some(Structure::BLS12381G2) => ark_group_sum_internal!(
+            context,
+            args,
+            ark_bls12_381::G2Projective,
+            ALGEBRA_ARK_BLS12_381_G2_PROJ_INFINITY,
+            ALGEBRA_ARK_BLS12_381_G2_PROJ_ADD,
+            ALGEBRA_ARK_BLS12_381_G2_PROJ_TO_AFFINE
+        ),
+        Some(Structure::BN254G1) => ark_group_sum_internal!(
+            context,
+            args,
+            ark_bn254::G1Projective,
+            ALGEBRA_ARK_BN254_G1_PROJ_INFINITY,
+            ALGEBRA_ARK_BN254_G1_PROJ_ADD,
+            ALGEBRA_ARK_BN254_G1_PROJ_TO_AFFINE
+        ),
+        Some(Structure::BN254G2) => ark_group_sum_internal!(
+            context,
+            args,
+            ark_bn254::G2Projective,
+            ALGEBRA_ARK_BN254_G2_PROJ_INFINITY,
+            ALGEBRA_ARK_BN254_G2_PROJ_ADD,
+            ALGEBRA_ARK_BN254_G2_PROJ_TO_AFFINE
+        ),
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
@@ -0,0 +1,194 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    abort_unless_feature_flag_enabled,
+    natives::cryptography::algebra::{
+        abort_invariant_violated, hash_to_structure::hash_to_bls12381g1, AlgebraContext,
+        HashToStructureSuite, Structure, MOVE_ABORT_CODE_INPUT_VECTOR_SIZES_NOT_MATCHING,
+        MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+    },
+    safe_borrow_element, structure_from_ty_arg,
+};
+use aptos_gas_algebra::{Arg, GasExpression};
+use aptos_gas_schedule::gas_params::natives::aptos_framework::*;
+use aptos_native_interface::{
+    safely_pop_arg, safely_pop_vec_arg, SafeNativeContext, SafeNativeError, SafeNativeResult,
+};
+use aptos_types::on_chain_config::FeatureFlag;
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+use ark_ff::{One, Zero};
+use move_core_types::gas_algebra::NumArgs;
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    values::{Value, VectorRef},
+};
+use smallvec::{smallvec, SmallVec};
+use std::collections::{BTreeSet, VecDeque};
+
+fn feature_flag_of_bls(
+    g1_opt: Option<Structure>,
+    g2_opt: Option<Structure>,
+    gt_opt: Option<Structure>,
+    suite_opt: Option<HashToStructureSuite>,
+) -> Option<FeatureFlag> {
+    match (g1_opt, g2_opt, gt_opt, suite_opt) {
+        (
+            Some(Structure::BLS12381G1),
+            Some(Structure::BLS12381G2),
+            Some(Structure::BLS12381Gt),
+            Some(HashToStructureSuite::Bls12381g1XmdSha256SswuRo),
+        ) => Some(FeatureFlag::BLS12_381_STRUCTURES),
+        _ => None,
+    }
+}
+
+macro_rules! abort_unless_bls_enabled {
+    ($context:ident, $g1_opt:expr, $g2_opt:expr, $gt_opt:expr, $suite_opt:expr) => {
+        let flag_opt = feature_flag_of_bls($g1_opt, $g2_opt, $gt_opt, $suite_opt);
+        abort_unless_feature_flag_enabled!($context, flag_opt);
+    };
+}
+
+macro_rules! bls_ty_args {
+    ($context:ident, $ty_args:ident) => {{
+        assert_eq!(4, $ty_args.len());
+        let g1_opt = structure_from_ty_arg!($context, &$ty_args[0]);
+        let g2_opt = structure_from_ty_arg!($context, &$ty_args[1]);
+        let gt_opt = structure_from_ty_arg!($context, &$ty_args[2]);
+        let suite_opt =
+            HashToStructureSuite::try_from($context.type_to_type_tag(&$ty_args[3])?).ok();
+        abort_unless_bls_enabled!($context, g1_opt, g2_opt, gt_opt, suite_opt);
+        (g1_opt, g2_opt, gt_opt, suite_opt)
+    }};
+}
+
+/// Checks `e(sig, g2) == e(H(msgs[0]), pks[0]) + ... + e(H(msgs[n-1]), pks[n-1])` over BLS12-381,
+/// where `g2` is the generator of G2, as the aggregate verification of the IETF BLS signature
+/// draft (with public keys in G2). Fails if a public key is the identity, if the messages are not
+/// distinct, or if there are none.
+fn bls12381_aggregate_verify(
+    context: &mut SafeNativeContext,
+    pk_handles: Vec<u64>,
+    dst: &[u8],
+    msgs: &[&[u8]],
+    sig_handle: usize,
+) -> SafeNativeResult<bool> {
+    let num_pairs = pk_handles.len();
+    if num_pairs != msgs.len() {
+        return Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_INPUT_VECTOR_SIZES_NOT_MATCHING,
+        });
+    }
+    if num_pairs == 0 || msgs.iter().collect::<BTreeSet<_>>().len() != num_pairs {
+        return Ok(false);
+    }
+
+    context.charge(ALGEBRA_ARK_BLS12_381_G1_PROJ_TO_AFFINE)?;
+    safe_borrow_element!(
+        context,
+        sig_handle,
+        ark_bls12_381::G1Projective,
+        sig_ptr,
+        sig
+    );
+    let mut g1_elements = vec![sig.into_affine()];
+    let mut g2_elements = vec![-ark_bls12_381::G2Affine::generator()];
+
+    context.charge(
+        ALGEBRA_ARK_BLS12_381_G2_PROJ_TO_AFFINE.per::<Arg>() * NumArgs::from(num_pairs as u64),
+    )?;
+    for handle in pk_handles {
+        safe_borrow_element!(
+            context,
+            handle as usize,
+            ark_bls12_381::G2Projective,
+            pk_ptr,
+            pk
+        );
+        if pk.is_zero() {
+            return Ok(false);
+        }
+        g2_elements.push(pk.into_affine());
+    }
+    for msg in msgs {
+        g1_elements.push(hash_to_bls12381g1(context, dst, msg)?);
+    }
+
+    context.charge(
+        ALGEBRA_ARK_BLS12_381_MULTI_PAIRING_BASE
+            + ALGEBRA_ARK_BLS12_381_MULTI_PAIRING_PER_PAIR * NumArgs::from(num_pairs as u64 + 1),
+    )?;
+    let miller_loop_output = ark_bls12_381::Bls12_381::multi_miller_loop(g1_elements, g2_elements);
+    let product = ark_bls12_381::Bls12_381::final_exponentiation(miller_loop_output)
+        .ok_or_else(abort_invariant_violated)?;
+    Ok(product.0.is_one())
+}
+
+pub fn bls_verify_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let structures = bls_ty_args!(context, ty_args);
+    let sig_handle = safely_pop_arg!(args, u64) as usize;
+    let msg_ref = safely_pop_arg!(args, VectorRef);
+    let msg_bytes = msg_ref.as_bytes_ref();
+    let dst_ref = safely_pop_arg!(args, VectorRef);
+    let dst_bytes = dst_ref.as_bytes_ref();
+    let pk_handle = safely_pop_arg!(args, u64);
+    match structures {
+        (
+            Some(Structure::BLS12381G1),
+            Some(Structure::BLS12381G2),
+            Some(Structure::BLS12381Gt),
+            Some(HashToStructureSuite::Bls12381g1XmdSha256SswuRo),
+        ) => {
+            let valid = bls12381_aggregate_verify(
+                context,
+                vec![pk_handle],
+                dst_bytes.as_slice(),
+                &[msg_bytes.as_slice()],
+                sig_handle,
+            )?;
+            Ok(smallvec![Value::bool(valid)])
+        },
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
+
+pub fn bls_aggregate_verify_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let structures = bls_ty_args!(context, ty_args);
+    let sig_handle = safely_pop_arg!(args, u64) as usize;
+    let msgs = safely_pop_vec_arg!(args, Vec<u8>);
+    let dst_ref = safely_pop_arg!(args, VectorRef);
+    let dst_bytes = dst_ref.as_bytes_ref();
+    let pk_handles = safely_pop_arg!(args, Vec<u64>);
+    match structures {
+        (
+            Some(Structure::BLS12381G1),
+            Some(Structure::BLS12381G2),
+            Some(Structure::BLS12381Gt),
+            Some(HashToStructureSuite::Bls12381g1XmdSha256SswuRo),
+        ) => {
+            let msgs: Vec<&[u8]> = msgs.iter().map(|msg| msg.as_slice()).collect();
+            let valid = bls12381_aggregate_verify(
+                context,
+                pk_handles,
+                dst_bytes.as_slice(),
+                &msgs,
+                sig_handle,
+            )?;
+            Ok(smallvec![Value::bool(valid)])
+        },
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
@@ -78,6 +78,30 @@ macro_rules! hash_to_bls12381gx_cost {
     }};
 }
 
+/// Hashes `msg` into BLS12-381 G1 with suite `Bls12381g1XmdSha256SswuRo` and domain separation
+/// tag `dst`, charging gas for it.
+pub(crate) fn hash_to_bls12381g1(
+    context: &mut SafeNativeContext,
+    dst: &[u8],
+    msg: &[u8],
+) -> SafeNativeResult<ark_bls12_381::G1Affine> {
+    context.charge(hash_to_bls12381gx_cost!(
+        dst.len(),
+        msg.len(),
+        HASH_SHA2_256_BASE,
+        HASH_SHA2_256_PER_BYTE,
+        ALGEBRA_ARK_H2C_BLS12381G1_XMD_SHA256_SSWU_BASE,
+        ALGEBRA_ARK_H2C_BLS12381G1_XMD_SHA256_SSWU_PER_MSG_BYTE,
+    ))?;
+    let mapper = ark_ec::hashing::map_to_curve_hasher::MapToCurveBasedHasher::<
+        ark_ec::models::short_weierstrass::Projective<ark_bls12_381::g1::Config>,
+        ark_ff::fields::field_hashers::DefaultFieldHasher<sha2_0_10_6::Sha256, 128>,
+        ark_ec::hashing::curve_maps::wb::WBMap<ark_bls12_381::g1::Config>,
+    >::new(dst)
+    .unwrap();
+    Ok(mapper.hash(msg).unwrap())
+}
+
 pub fn hash_to_internal(
     context: &mut SafeNativeContext,
     ty_args: Vec<Type>,
@@ -95,21 +119,8 @@ pub fn hash_to_internal(
     let dst = bytes_ref.as_slice();
     match (structure_opt, suite_opt) {
         (Some(Structure::BLS12381G1), Some(HashToStructureSuite::Bls12381g1XmdSha256SswuRo)) => {
-            context.charge(hash_to_bls12381gx_cost!(
-                dst.len(),
-                msg.len(),
-                HASH_SHA2_256_BASE,
-                HASH_SHA2_256_PER_BYTE,
-                ALGEBRA_ARK_H2C_BLS12381G1_XMD_SHA256_SSWU_BASE,
-                ALGEBRA_ARK_H2C_BLS12381G1_XMD_SHA256_SSWU_PER_MSG_BYTE,
-            ))?;
-            let mapper = ark_ec::hashing::map_to_curve_hasher::MapToCurveBasedHasher::<
-                ark_ec::models::short_weierstrass::Projective<ark_bls12_381::g1::Config>,
-                ark_ff::fields::field_hashers::DefaultFieldHasher<sha2_0_10_6::Sha256, 128>,
-                ark_ec::hashing::curve_maps::wb::WBMap<ark_bls12_381::g1::Config>,
-            >::new(dst)
-            .unwrap();
-            let new_element = <ark_bls12_381::G1Projective>::from(mapper.hash(msg).unwrap());
+            let new_element =
+                <ark_bls12_381::G1Projective>::from(hash_to_bls12381g1(context, dst, msg)?);
             let new_handle = store_element!(context, new_element)?;
             Ok(smallvec![Value::u64(new_handle as u64)])
         },
@@ -5,23 +5,42 @@
 use crate::natives::cryptography::algebra::rand::rand_insecure_internal;
 use crate::natives::cryptography::algebra::{
     arithmetics::{
-        add::add_internal, double::double_internal, mul::mul_internal, neg::neg_internal,
-        sqr::sqr_internal, sub::sub_internal,
+        add::add_internal,
+        bits::{scalar_bit_internal, scalar_num_bits_internal},
+        clear_cofactor::clear_cofactor_internal,
+        double::double_internal,
+        frobenius::frobenius_map_internal,
+        mul::mul_internal,
+        neg::neg_internal,
+        normalize::{normalize_batch_internal, to_affine_internal},
+        sqr::sqr_internal,
+        sqrt::{is_square_internal, sqrt_internal},
+        sub::sub_internal,
+        sum::group_sum_internal,
     },
+    bls::{bls_aggregate_verify_internal, bls_verify_internal},
     casting::{downcast_internal, upcast_internal},
     constants::{one_internal, order_internal, zero_internal},
     eq::eq_internal,
     hash_to_structure::hash_to_internal,
-    new::from_u64_internal,
-    pairing::{multi_pairing_internal, pairing_internal},
-    serialization::{deserialize_internal, serialize_internal},
+    new::{from_u64_internal, scalar_from_u256_internal},
+    pairing::{multi_pairing_check_internal, multi_pairing_internal, pairing_internal},
+    serialization::{
+        batch_deserialize_internal, deserialize_fr_with_endianness_internal, deserialize_internal,
+        serialize_fr_with_endianness_internal, serialize_internal,
+    },
 };
 use aptos_native_interface::{RawSafeNative, SafeNativeBuilder};
 use aptos_types::on_chain_config::FeatureFlag;
 use arithmetics::{
     div::div_internal,
+    inner_product::field_inner_product_internal,
     inv::inv_internal,
-    scalar_mul::{multi_scalar_mul_internal, scalar_mul_internal},
+    poly_eval::poly_eval_internal,
+    scalar_mul::{
+        glv_scalar_mul_internal, msm_with_precomputed_internal, multi_scalar_mul_internal,
+        pedersen_commit_internal, precompute_msm_bases_internal, scalar_mul_internal,
+    },
 };
 use ark_ff::{BigInteger, PrimeField};
 use ark_serialize::CanonicalDeserialize;
@@ -29,10 +48,12 @@ use better_any::{Tid, TidAble};
 use move_binary_format::errors::PartialVMError;
 use move_core_types::{language_storage::TypeTag, vm_status::StatusCode};
 use move_vm_runtime::native_functions::NativeFunction;
-use once_cell::sync::Lazy;
+use once_cell::sync::{Lazy, OnceCell};
+use rayon::{prelude::*, ThreadPool, ThreadPoolBuilder};
 use std::{any::Any, hash::Hash, rc::Rc};
 
 pub mod arithmetics;
+pub mod bls;
 pub mod casting;
 pub mod constants;
 pub mod eq;
@@ -46,6 +67,9 @@ pub mod serialization;
 /// Equivalent to `std::error::invalid_argument(0)` in Move.
 const MOVE_ABORT_CODE_INPUT_VECTOR_SIZES_NOT_MATCHING: u64 = 0x01_0002;
 
+/// Equivalent to `std::error::invalid_argument(4)` in Move.
+const MOVE_ABORT_CODE_NOT_IN_SUBGROUP: u64 = 0x01_0004;
+
 /// Equivalent to `std::error::not_implemented(0)` in Move.
 const MOVE_ABORT_CODE_NOT_IMPLEMENTED: u64 = 0x0C_0001;
 
@@ -178,6 +202,83 @@ impl TryFrom<TypeTag> for HashToStructureSuite {
     }
 }
 
+/// Multi-scalar multiplications and multi-pairings are split into chunks of this many entries to
+/// be processed in parallel. Inputs that fit into a single chunk are processed on the calling
+/// thread, where the overhead of dispatching to the thread pool would outweigh the gains.
+const PARALLELISM_CHUNK_SIZE: usize = 64;
+
+static NUM_THREADS: OnceCell<usize> = OnceCell::new();
+
+static THREAD_POOL: Lazy<Option<ThreadPool>> =
+    Lazy::new(|| build_thread_pool(NUM_THREADS.get().copied().unwrap_or(1)));
+
+/// Returns the thread pool to process chunks on, or `None` if `num_threads` is at most 1.
+fn build_thread_pool(num_threads: usize) -> Option<ThreadPool> {
+    (num_threads > 1).then(|| {
+        ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .thread_name(|index| format!("algebra_natives_{}", index))
+            .build()
+            .expect("Failed to build the algebra natives thread pool")
+    })
+}
+
+/// Sets the number of threads used for large multi-scalar multiplications and multi-pairings,
+/// when invoked the first time (and before any such operation). By default, or if set to 1,
+/// they are single-threaded.
+///
+/// Gas is charged for the inputs before the computation starts, so the number of threads only
+/// affects the wall-clock time and never the metered cost.
+pub fn set_num_threads_once(num_threads: usize) {
+    // Only the first call succeeds, due to OnceCell semantics.
+    NUM_THREADS.set(num_threads.max(1)).ok();
+}
+
+/// Applies `op` to matching chunks of `lhs` and `rhs` in parallel and folds the chunk results
+/// with `combine`, if parallelism is enabled and the inputs span multiple chunks. Otherwise,
+/// applies `op` to the inputs as a whole on the calling thread.
+///
+/// Callers must ensure that `combine` is associative and that the result does not depend on how
+/// the inputs were split, so that all nodes compute the same result regardless of thread count.
+fn maybe_par_chunks<A, B, R>(
+    lhs: &[A],
+    rhs: &[B],
+    op: impl Fn(&[A], &[B]) -> R + Send + Sync,
+    combine: impl Fn(R, R) -> R + Send + Sync,
+) -> R
+where
+    A: Sync,
+    B: Sync,
+    R: Send,
+{
+    maybe_par_chunks_in(THREAD_POOL.as_ref(), lhs, rhs, op, combine)
+}
+
+/// Same as `maybe_par_chunks`, but on the given thread pool, if any.
+fn maybe_par_chunks_in<A, B, R>(
+    pool: Option<&ThreadPool>,
+    lhs: &[A],
+    rhs: &[B],
+    op: impl Fn(&[A], &[B]) -> R + Send + Sync,
+    combine: impl Fn(R, R) -> R + Send + Sync,
+) -> R
+where
+    A: Sync,
+    B: Sync,
+    R: Send,
+{
+    match pool {
+        Some(pool) if lhs.len() > PARALLELISM_CHUNK_SIZE => pool.install(|| {
+            lhs.par_chunks(PARALLELISM_CHUNK_SIZE)
+                .zip(rhs.par_chunks(PARALLELISM_CHUNK_SIZE))
+                .map(|(lhs, rhs)| op(lhs, rhs))
+                .reduce_with(combine)
+                .expect("Inputs span multiple chunks")
+        }),
+        _ => op(lhs, rhs),
+    }
+}
+
 /// This limit ensures that no more than 1MB will be allocated for elements per VM session.
 const MEMORY_LIMIT_IN_BYTES: usize = 1 << 20;
 
@@ -235,6 +336,22 @@ macro_rules! store_element {
             Ok(ret)
         }
     }};
+    // For objects which own heap memory, whose size `size_of_val()` does not capture.
+    ($context:expr, $obj:expr, $num_bytes:expr) => {{
+        let context = &mut $context.extensions_mut().get_mut::<AlgebraContext>();
+        let new_size = context.bytes_used + $num_bytes;
+        if new_size > MEMORY_LIMIT_IN_BYTES {
+            Err(SafeNativeError::Abort {
+                abort_code: E_TOO_MUCH_MEMORY_USED,
+            })
+        } else {
+            let target_vec = &mut context.objs;
+            context.bytes_used = new_size;
+            let ret = target_vec.len();
+            target_vec.push(Rc::new($obj));
+            Ok(ret)
+        }
+    }};
 }
 
 fn feature_flag_from_structure(structure_opt: Option<Structure>) -> Option<FeatureFlag> {
@@ -326,26 +443,63 @@ pub fn make_all(
             "deserialize_internal",
             deserialize_internal as RawSafeNative,
         ),
+        ("batch_deserialize_internal", batch_deserialize_internal),
+        (
+            "deserialize_fr_with_endianness_internal",
+            deserialize_fr_with_endianness_internal,
+        ),
         ("downcast_internal", downcast_internal),
         ("eq_internal", eq_internal),
         ("add_internal", add_internal),
+        ("clear_cofactor_internal", clear_cofactor_internal),
         ("div_internal", div_internal),
+        ("field_inner_product_internal", field_inner_product_internal),
+        ("frobenius_map_internal", frobenius_map_internal),
+        ("glv_scalar_mul_internal", glv_scalar_mul_internal),
+        ("group_sum_internal", group_sum_internal),
         ("inv_internal", inv_internal),
         ("mul_internal", mul_internal),
         ("neg_internal", neg_internal),
+        ("normalize_batch_internal", normalize_batch_internal),
         ("one_internal", one_internal),
         ("sqr_internal", sqr_internal),
+        ("is_square_internal", is_square_internal),
+        ("sqrt_internal", sqrt_internal),
         ("sub_internal", sub_internal),
         ("zero_internal", zero_internal),
         ("from_u64_internal", from_u64_internal),
         ("double_internal", double_internal),
+        (
+            "msm_with_precomputed_internal",
+            msm_with_precomputed_internal,
+        ),
         ("multi_scalar_mul_internal", multi_scalar_mul_internal),
         ("order_internal", order_internal),
+        ("pedersen_commit_internal", pedersen_commit_internal),
+        ("poly_eval_internal", poly_eval_internal),
+        (
+            "precompute_msm_bases_internal",
+            precompute_msm_bases_internal,
+        ),
         ("scalar_mul_internal", scalar_mul_internal),
+        ("scalar_bit_internal", scalar_bit_internal),
+        ("scalar_from_u256_internal", scalar_from_u256_internal),
+        ("scalar_num_bits_internal", scalar_num_bits_internal),
+        ("to_affine_internal", to_affine_internal),
         ("hash_to_internal", hash_to_internal),
         ("multi_pairing_internal", multi_pairing_internal),
+        ("multi_pairing_check_internal", multi_pairing_check_internal),
         ("pairing_internal", pairing_internal),
+        ("bls_verify_internal", bls_verify_internal),
+        (
+            "bls_aggregate_verify_internal",
+            bls_aggregate_verify_internal,
+        ),
         ("serialize_internal", serialize_internal),
+        (
+            "serialize_fr_with_endianness_internal",
+            serialize_fr_with_endianness_internal,
+        ),
         ("upcast_internal", upcast_internal),
     ]);
 
@@ -358,3 +512,59 @@ pub fn make_all(
 
     builder.make_named_natives(natives)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ec::{
+        pairing::{MillerLoopOutput, Pairing},
+        CurveGroup, VariableBaseMSM,
+    };
+    use ark_std::{test_rng, UniformRand};
+
+    #[test]
+    fn test_parallel_results_match_sequential() {
+        let pool = build_thread_pool(4);
+        assert!(pool.is_some());
+        let mut rng = test_rng();
+        let num_entries = 3 * PARALLELISM_CHUNK_SIZE + 1;
+
+        let bases: Vec<ark_bls12_381::G1Affine> = (0..num_entries)
+            .map(|_| ark_bls12_381::G1Projective::rand(&mut rng).into_affine())
+            .collect();
+        let scalars: Vec<ark_bls12_381::Fr> = (0..num_entries)
+            .map(|_| ark_bls12_381::Fr::rand(&mut rng))
+            .collect();
+        let msm: ark_bls12_381::G1Projective = maybe_par_chunks_in(
+            pool.as_ref(),
+            &bases,
+            &scalars,
+            |bases, scalars| VariableBaseMSM::msm(bases, scalars).unwrap(),
+            |lhs, rhs| lhs + rhs,
+        );
+        assert_eq!(
+            msm,
+            ark_bls12_381::G1Projective::msm(&bases, &scalars).unwrap()
+        );
+
+        let g2_elements: Vec<ark_bls12_381::G2Affine> = (0..num_entries)
+            .map(|_| ark_bls12_381::G2Projective::rand(&mut rng).into_affine())
+            .collect();
+        let miller_loop_output = maybe_par_chunks_in(
+            pool.as_ref(),
+            &bases,
+            &g2_elements,
+            |g1_elements, g2_elements| {
+                ark_bls12_381::Bls12_381::multi_miller_loop(
+                    g1_elements.iter().copied(),
+                    g2_elements.iter().copied(),
+                )
+            },
+            |lhs, rhs| MillerLoopOutput(lhs.0 * rhs.0),
+        );
+        assert_eq!(
+            ark_bls12_381::Bls12_381::final_exponentiation(miller_loop_output).unwrap(),
+            ark_bls12_381::Bls12_381::multi_pairing(bases, g2_elements)
+        );
+    }
+}
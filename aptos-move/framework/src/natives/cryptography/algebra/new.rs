@@ -13,6 +13,8 @@ use aptos_gas_schedule::gas_params::natives::aptos_framework::*;
 use aptos_native_interface::{
     safely_pop_arg, SafeNativeContext, SafeNativeError, SafeNativeResult,
 };
+use ark_ff::PrimeField;
+use move_core_types::u256::U256;
 use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
 use smallvec::{smallvec, SmallVec};
 use std::{collections::VecDeque, rc::Rc};
@@ -65,3 +67,37 @@ pub fn from_u64_internal(
         }),
     }
 }
+
+macro_rules! scalar_from_u256_internal {
+    ($context:expr, $args:ident, $typ:ty, $gas:expr) => {{
+        let value = safely_pop_arg!($args, U256);
+        $context.charge($gas)?;
+        let element = <$typ>::from_le_bytes_mod_order(&value.to_le_bytes());
+        let handle = store_element!($context, element)?;
+        Ok(smallvec![Value::u64(handle as u64)])
+    }};
+}
+
+pub fn scalar_from_u256_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(1, ty_args.len());
+    let structure_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    abort_unless_arithmetics_enabled_for_structure!(context, structure_opt);
+    match structure_opt {
+        Some(Structure::BLS12381Fr) => scalar_from_u256_internal!(
+            context,
+            args,
+            ark_bls12_381::Fr,
+            ALGEBRA_ARK_BLS12_381_FR_FROM_U256
+        ),
+        Some(Structure::BN254Fr) => {
+            scalar_from_u256_internal!(context, args, ark_bn254::Fr, ALGEBRA_ARK_BN254_FR_FROM_U256)
+        },
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
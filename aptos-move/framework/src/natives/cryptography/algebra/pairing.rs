@@ -4,9 +4,9 @@
 use crate::{
     abort_unless_feature_flag_enabled,
     natives::cryptography::algebra::{
-        abort_invariant_violated, AlgebraContext, Structure, E_TOO_MUCH_MEMORY_USED,
-        MEMORY_LIMIT_IN_BYTES, MOVE_ABORT_CODE_INPUT_VECTOR_SIZES_NOT_MATCHING,
-        MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        abort_invariant_violated, maybe_par_chunks, AlgebraContext, Structure,
+        E_TOO_MUCH_MEMORY_USED, MEMORY_LIMIT_IN_BYTES,
+        MOVE_ABORT_CODE_INPUT_VECTOR_SIZES_NOT_MATCHING, MOVE_ABORT_CODE_NOT_IMPLEMENTED,
     },
     safe_borrow_element, store_element, structure_from_ty_arg,
 };
@@ -16,7 +16,11 @@ use aptos_native_interface::{
     safely_pop_arg, SafeNativeContext, SafeNativeError, SafeNativeResult,
 };
 use aptos_types::on_chain_config::FeatureFlag;
-use ark_ec::{pairing::Pairing, CurveGroup};
+use ark_ec::{
+    pairing::{MillerLoopOutput, Pairing},
+    CurveGroup,
+};
+use ark_ff::One;
 use move_core_types::gas_algebra::NumArgs;
 use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
 use smallvec::{smallvec, SmallVec};
@@ -81,7 +85,9 @@ macro_rules! pairing_internal {
         Ok(smallvec![Value::u64(new_handle as u64)])
     }};
 }
-macro_rules! multi_pairing_internal {
+/// Evaluates to the product of the pairings of the elements in the two handle vectors, as an
+/// element of the target field. Charges gas and aborts on mismatching vector sizes.
+macro_rules! multi_pairing_product {
     (
         $context:expr,
         $args:ident,
@@ -120,11 +126,77 @@ macro_rules! multi_pairing_internal {
             $multi_pairing_base_gas
                 + $multi_pairing_per_pair_gas * NumArgs::from(num_entries as u64),
         )?;
-        let new_element = <$pairing>::multi_pairing(g1_elements_affine, g2_elements_affine).0;
+        // Same as `multi_pairing`, but the Miller loop may run on multiple threads.
+        let miller_loop_output = maybe_par_chunks(
+            &g1_elements_affine,
+            &g2_elements_affine,
+            |g1_elements, g2_elements| {
+                <$pairing>::multi_miller_loop(
+                    g1_elements.iter().copied(),
+                    g2_elements.iter().copied(),
+                )
+            },
+            |lhs, rhs| MillerLoopOutput(lhs.0 * rhs.0),
+        );
+        <$pairing>::final_exponentiation(miller_loop_output)
+            .ok_or_else(abort_invariant_violated)?
+            .0
+    }};
+}
+macro_rules! multi_pairing_internal {
+    (
+        $context:expr,
+        $args:ident,
+        $pairing:ty,
+        $g1_projective:ty,
+        $g2_projective:ty,
+        $multi_pairing_base_gas:expr,
+        $multi_pairing_per_pair_gas:expr,
+        $g1_proj_to_affine_gas:expr,
+        $g2_proj_to_affine_gas:expr
+    ) => {{
+        let new_element = multi_pairing_product!(
+            $context,
+            $args,
+            $pairing,
+            $g1_projective,
+            $g2_projective,
+            $multi_pairing_base_gas,
+            $multi_pairing_per_pair_gas,
+            $g1_proj_to_affine_gas,
+            $g2_proj_to_affine_gas
+        );
         let new_handle = store_element!($context, new_element)?;
         Ok(smallvec![Value::u64(new_handle as u64)])
     }};
 }
+macro_rules! multi_pairing_check_internal {
+    (
+        $context:expr,
+        $args:ident,
+        $pairing:ty,
+        $g1_projective:ty,
+        $g2_projective:ty,
+        $multi_pairing_base_gas:expr,
+        $multi_pairing_per_pair_gas:expr,
+        $g1_proj_to_affine_gas:expr,
+        $g2_proj_to_affine_gas:expr
+    ) => {{
+        let product = multi_pairing_product!(
+            $context,
+            $args,
+            $pairing,
+            $g1_projective,
+            $g2_projective,
+            $multi_pairing_base_gas,
+            $multi_pairing_per_pair_gas,
+            $g1_proj_to_affine_gas,
+            $g2_proj_to_affine_gas
+        );
+        // The product is compared in place, without being stored as a new element.
+        Ok(smallvec![Value::bool(product.is_one())])
+    }};
+}
 pub fn multi_pairing_internal(
     context: &mut SafeNativeContext,
     ty_args: Vec<Type>,
@@ -168,6 +240,49 @@ pub fn multi_pairing_internal(
     }
 }
 
+pub fn multi_pairing_check_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(3, ty_args.len());
+    let g1_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    let g2_opt = structure_from_ty_arg!(context, &ty_args[1]);
+    let gt_opt = structure_from_ty_arg!(context, &ty_args[2]);
+    abort_unless_pairing_enabled!(context, g1_opt, g2_opt, gt_opt);
+    match (g1_opt, g2_opt, gt_opt) {
+        (Some(Structure::BLS12381G1), Some(Structure::BLS12381G2), Some(Structure::BLS12381Gt)) => {
+            multi_pairing_check_internal!(
+                context,
+                args,
+                ark_bls12_381::Bls12_381,
+                ark_bls12_381::G1Projective,
+                ark_bls12_381::G2Projective,
+                ALGEBRA_ARK_BLS12_381_MULTI_PAIRING_BASE,
+                ALGEBRA_ARK_BLS12_381_MULTI_PAIRING_PER_PAIR,
+                ALGEBRA_ARK_BLS12_381_G1_PROJ_TO_AFFINE,
+                ALGEBRA_ARK_BLS12_381_G2_PROJ_TO_AFFINE
+            )
+        },
+        (Some(Structure::BN254G1), Some(Structure::BN254G2), Some(Structure::BN254Gt)) => {
+            multi_pairing_check_internal!(
+                context,
+                args,
+                ark_bn254::Bn254,
+                ark_bn254::G1Projective,
+                ark_bn254::G2Projective,
+                ALGEBRA_ARK_BN254_MULTI_PAIRING_BASE,
+                ALGEBRA_ARK_BN254_MULTI_PAIRING_PER_PAIR,
+                ALGEBRA_ARK_BN254_G1_PROJ_TO_AFFINE,
+                ALGEBRA_ARK_BN254_G2_PROJ_TO_AFFINE
+            )
+        },
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
+
 pub fn pairing_internal(
     context: &mut SafeNativeContext,
     ty_args: Vec<Type>,
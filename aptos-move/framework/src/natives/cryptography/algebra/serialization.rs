@@ -2,30 +2,53 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    abort_unless_feature_flag_enabled,
+    abort_unless_arithmetics_enabled_for_structure, abort_unless_feature_flag_enabled,
+    ark_msm_bigint_wnaf_cost,
     natives::cryptography::algebra::{
-        abort_invariant_violated, AlgebraContext, SerializationFormat, Structure,
-        BLS12381_R_SCALAR, BN254_R_SCALAR, E_TOO_MUCH_MEMORY_USED, MEMORY_LIMIT_IN_BYTES,
-        MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        abort_invariant_violated, feature_flag_from_structure, AlgebraContext, SerializationFormat,
+        Structure, BLS12381_R_SCALAR, BN254_R_SCALAR, E_TOO_MUCH_MEMORY_USED,
+        MEMORY_LIMIT_IN_BYTES, MOVE_ABORT_CODE_NOT_IMPLEMENTED, MOVE_ABORT_CODE_NOT_IN_SUBGROUP,
     },
     safe_borrow_element, store_element, structure_from_ty_arg,
 };
-use aptos_gas_schedule::gas_params::natives::aptos_framework::*;
+use aptos_gas_algebra::{Arg, GasExpression};
+use aptos_gas_schedule::gas_params::natives::{aptos_framework::*, move_stdlib::*};
 use aptos_native_interface::{
-    safely_pop_arg, SafeNativeContext, SafeNativeError, SafeNativeResult,
+    safely_pop_arg, safely_pop_vec_arg, SafeNativeContext, SafeNativeError, SafeNativeResult,
 };
 use aptos_types::on_chain_config::FeatureFlag;
-use ark_ec::CurveGroup;
-use ark_ff::Field;
-use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_ec::{AffineRepr, CurveGroup, VariableBaseMSM};
+use ark_ff::{BigInteger, Field, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+use move_core_types::gas_algebra::{NumArgs, NumBytes};
 use move_vm_types::{
     loaded_data::runtime_types::Type,
     values::{Value, VectorRef},
 };
 use num_traits::One;
+use sha3::{Digest, Sha3_256};
 use smallvec::{smallvec, SmallVec};
 use std::{collections::VecDeque, rc::Rc};
 
+/// The number of random linear combinations that `batch_deserialize_internal()` checks for
+/// prime-order subgroup membership, chosen as `ceil(128 / log2(p))` where `p` is the smallest prime
+/// factor of the curve cofactor.
+///
+/// If any point of a batch is outside of the prime-order subgroup, a combination of the batch
+/// passes the check with probability at most `1/p`, so all of them do with probability at most
+/// `2^-128`.
+const BLS12381_G1_NUM_SUBGROUP_CHECKS: u64 = 81; // p = 3
+const BLS12381_G2_NUM_SUBGROUP_CHECKS: u64 = 35; // p = 13
+const BN254_G1_NUM_SUBGROUP_CHECKS: u64 = 0; // The cofactor is 1.
+const BN254_G2_NUM_SUBGROUP_CHECKS: u64 = 10; // p = 10069
+
+/// Domain separation tag for deriving the coefficients of the combined subgroup checks.
+const BATCH_SUBGROUP_CHECK_DST: &[u8] = b"APTOS_CRYPTO_ALGEBRA_BATCH_SUBGROUP_CHECK";
+
+/// The number of bytes hashed to derive a single coefficient: the seed, the check index and the
+/// point index.
+const BATCH_SUBGROUP_CHECK_COEFFICIENT_INPUT_LEN: u64 = 48;
+
 pub fn feature_flag_of_serialization_format(
     format_opt: Option<SerializationFormat>,
 ) -> Option<FeatureFlag> {
@@ -593,3 +616,405 @@ pub fn deserialize_internal(
         }),
     }
 }
+
+/// Commits to a batch of serialized points, so that the coefficients of the combined subgroup
+/// checks cannot be chosen independently of the points.
+fn batch_subgroup_check_seed(bytes: &[Vec<u8>]) -> Vec<u8> {
+    let mut hasher = Sha3_256::new();
+    hasher.update(BATCH_SUBGROUP_CHECK_DST);
+    hasher.update((bytes.len() as u64).to_le_bytes());
+    for point_bytes in bytes {
+        hasher.update(point_bytes);
+    }
+    hasher.finalize().to_vec()
+}
+
+/// Derives the coefficient of the `point_index`-th point in the `check_index`-th combination.
+fn batch_subgroup_check_coefficient<F: PrimeField>(
+    seed: &[u8],
+    check_index: u64,
+    point_index: u64,
+) -> F {
+    let mut hasher = Sha3_256::new();
+    hasher.update(seed);
+    hasher.update(check_index.to_le_bytes());
+    hasher.update(point_index.to_le_bytes());
+    F::from_le_bytes_mod_order(hasher.finalize().as_slice())
+}
+
+macro_rules! ark_batch_deserialize_internal {
+    (
+        $context:expr,
+        $bytes:expr,
+        $point_size:expr,
+        $affine_typ:ty,
+        $compress:expr,
+        $num_subgroup_checks:expr,
+        $deser_gas:expr,
+        $deser_unchecked_gas:expr,
+        $proj_add_gas:expr,
+        $proj_double_gas:expr,
+        $proj_to_affine_gas:expr,
+        $proj_scalar_mul_gas:expr
+    ) => {{
+        let bytes: Vec<Vec<u8>> = $bytes;
+        // Exit early if any size is incorrect, for gas safety (see `deserialize_internal()`).
+        if bytes.iter().any(|point_bytes| point_bytes.len() != $point_size) {
+            return Ok(smallvec![Value::bool(false), Value::vector_u64(vec![])]);
+        }
+        let num_points = NumArgs::from(bytes.len() as u64);
+        let num_subgroup_checks: u64 = $num_subgroup_checks;
+
+        // Checking the points one by one costs as much as deserializing each of them on its own,
+        // while the combined checks cost sub-linearly in the number of points. Pick the cheaper one.
+        let individual_checks_cost = $context.eval_gas($deser_gas.per::<Arg>() * num_points);
+        let mut combined_checks_cost =
+            $context.eval_gas($deser_unchecked_gas.per::<Arg>() * num_points);
+        if num_subgroup_checks > 0 {
+            let seed_input_len = BATCH_SUBGROUP_CHECK_DST.len() + 8 + bytes.len() * $point_size;
+            let seed_cost = $context.eval_gas(
+                HASH_SHA3_256_BASE
+                    + HASH_SHA3_256_PER_BYTE * NumBytes::new(seed_input_len as u64),
+            );
+            let coefficient_cost = $context.eval_gas(
+                HASH_SHA3_256_BASE
+                    + HASH_SHA3_256_PER_BYTE
+                        * NumBytes::new(BATCH_SUBGROUP_CHECK_COEFFICIENT_INPUT_LEN),
+            );
+            let check_cost = coefficient_cost.per::<Arg>() * num_points
+                + $context.eval_gas(ark_msm_bigint_wnaf_cost!(
+                    $proj_add_gas.per::<Arg>(),
+                    $proj_double_gas.per::<Arg>(),
+                    bytes.len(),
+                ))
+                + $context.eval_gas($proj_to_affine_gas + $proj_scalar_mul_gas);
+            combined_checks_cost +=
+                seed_cost + check_cost.per::<Arg>() * NumArgs::from(num_subgroup_checks);
+        }
+        let use_combined_checks = combined_checks_cost < individual_checks_cost;
+        $context.charge(if use_combined_checks {
+            combined_checks_cost
+        } else {
+            individual_checks_cost
+        })?;
+
+        let mut points = Vec::with_capacity(bytes.len());
+        for point_bytes in bytes.iter() {
+            match <$affine_typ>::deserialize_with_mode(
+                point_bytes.as_slice(),
+                $compress,
+                Validate::No,
+            ) {
+                Ok(point) if point.is_on_curve() => points.push(point),
+                Ok(_)
+                | Err(ark_serialize::SerializationError::InvalidData)
+                | Err(ark_serialize::SerializationError::UnexpectedFlags) => {
+                    return Ok(smallvec![Value::bool(false), Value::vector_u64(vec![])]);
+                },
+                _ => {
+                    return Err(SafeNativeError::InvariantViolation(
+                        abort_invariant_violated(),
+                    ))
+                },
+            }
+        }
+
+        let in_subgroup = if use_combined_checks {
+            let seed = batch_subgroup_check_seed(&bytes);
+            let mut in_subgroup = true;
+            for check_index in 0..num_subgroup_checks {
+                let coefficients = (0..points.len() as u64)
+                    .map(|point_index| {
+                        batch_subgroup_check_coefficient(&seed, check_index, point_index)
+                    })
+                    .collect::<Vec<<$affine_typ as AffineRepr>::ScalarField>>();
+                let combination =
+                    <<$affine_typ as AffineRepr>::Group>::msm(&points, &coefficients)
+                        .map_err(|_e| abort_invariant_violated())?;
+                if !combination
+                    .into_affine()
+                    .is_in_correct_subgroup_assuming_on_curve()
+                {
+                    in_subgroup = false;
+                    break;
+                }
+            }
+            in_subgroup
+        } else {
+            points
+                .iter()
+                .all(|point| point.is_in_correct_subgroup_assuming_on_curve())
+        };
+        if !in_subgroup {
+            return Err(SafeNativeError::Abort {
+                abort_code: MOVE_ABORT_CODE_NOT_IN_SUBGROUP,
+            });
+        }
+
+        let mut handles = Vec::with_capacity(points.len());
+        for point in points {
+            let point_proj = ark_ec::short_weierstrass::Projective::from(point);
+            let handle = store_element!($context, point_proj)?;
+            handles.push(handle as u64);
+        }
+        Ok(smallvec![Value::bool(true), Value::vector_u64(handles)])
+    }};
+}
+
+/// Deserializes a batch of points of an elliptic curve group, all in the same format.
+///
+/// Returns `false` if any of them is not a valid serialization of a point on the curve.
+/// Aborts with `MOVE_ABORT_CODE_NOT_IN_SUBGROUP` if any of them is not in the prime-order subgroup,
+/// which is checked on a number of random linear combinations of the points when that is cheaper
+/// than checking every point (see `*_NUM_SUBGROUP_CHECKS` for the soundness error).
+pub fn batch_deserialize_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(2, ty_args.len());
+    let structure_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    let format_opt = format_from_ty_arg!(context, &ty_args[1]);
+    abort_unless_serialization_format_enabled!(context, format_opt);
+    let bytes = safely_pop_vec_arg!(args, Vec<u8>);
+    match (structure_opt, format_opt) {
+        (Some(Structure::BLS12381G1), Some(SerializationFormat::BLS12381G1Uncompressed)) => {
+            ark_batch_deserialize_internal!(
+                context,
+                bytes,
+                96,
+                ark_bls12_381::G1Affine,
+                Compress::No,
+                BLS12381_G1_NUM_SUBGROUP_CHECKS,
+                ALGEBRA_ARK_BLS12_381_G1_AFFINE_DESER_UNCOMP,
+                ALGEBRA_ARK_BLS12_381_G1_AFFINE_DESER_UNCOMP_UNCHECKED,
+                ALGEBRA_ARK_BLS12_381_G1_PROJ_ADD,
+                ALGEBRA_ARK_BLS12_381_G1_PROJ_DOUBLE,
+                ALGEBRA_ARK_BLS12_381_G1_PROJ_TO_AFFINE,
+                ALGEBRA_ARK_BLS12_381_G1_PROJ_SCALAR_MUL
+            )
+        },
+        (Some(Structure::BLS12381G1), Some(SerializationFormat::BLS12381G1Compressed)) => {
+            ark_batch_deserialize_internal!(
+                context,
+                bytes,
+                48,
+                ark_bls12_381::G1Affine,
+                Compress::Yes,
+                BLS12381_G1_NUM_SUBGROUP_CHECKS,
+                ALGEBRA_ARK_BLS12_381_G1_AFFINE_DESER_COMP,
+                ALGEBRA_ARK_BLS12_381_G1_AFFINE_DESER_COMP_UNCHECKED,
+                ALGEBRA_ARK_BLS12_381_G1_PROJ_ADD,
+                ALGEBRA_ARK_BLS12_381_G1_PROJ_DOUBLE,
+                ALGEBRA_ARK_BLS12_381_G1_PROJ_TO_AFFINE,
+                ALGEBRA_ARK_BLS12_381_G1_PROJ_SCALAR_MUL
+            )
+        },
+        (Some(Structure::BLS12381G2), Some(SerializationFormat::BLS12381G2Uncompressed)) => {
+            ark_batch_deserialize_internal!(
+                context,
+                bytes,
+                192,
+                ark_bls12_381::G2Affine,
+                Compress::No,
+                BLS12381_G2_NUM_SUBGROUP_CHECKS,
+                ALGEBRA_ARK_BLS12_381_G2_AFFINE_DESER_UNCOMP,
+                ALGEBRA_ARK_BLS12_381_G2_AFFINE_DESER_UNCOMP_UNCHECKED,
+                ALGEBRA_ARK_BLS12_381_G2_PROJ_ADD,
+                ALGEBRA_ARK_BLS12_381_G2_PROJ_DOUBLE,
+                ALGEBRA_ARK_BLS12_381_G2_PROJ_TO_AFFINE,
+                ALGEBRA_ARK_BLS12_381_G2_PROJ_SCALAR_MUL
+            )
+        },
+        (Some(Structure::BLS12381G2), Some(SerializationFormat::BLS12381G2Compressed)) => {
+            ark_batch_deserialize_internal!(
+                context,
+                bytes,
+                96,
+                ark_bls12_381::G2Affine,
+                Compress::Yes,
+                BLS12381_G2_NUM_SUBGROUP_CHECKS,
+                ALGEBRA_ARK_BLS12_381_G2_AFFINE_DESER_COMP,
+                ALGEBRA_ARK_BLS12_381_G2_AFFINE_DESER_COMP_UNCHECKED,
+                ALGEBRA_ARK_BLS12_381_G2_PROJ_ADD,
+                ALGEBRA_ARK_BLS12_381_G2_PROJ_DOUBLE,
+                ALGEBRA_ARK_BLS12_381_G2_PROJ_TO_AFFINE,
+                ALGEBRA_ARK_BLS12_381_G2_PROJ_SCALAR_MUL
+            )
+        },
+        (Some(Structure::BN254G1), Some(SerializationFormat::BN254G1Uncompressed)) => {
+            ark_batch_deserialize_internal!(
+                context,
+                bytes,
+                64,
+                ark_bn254::G1Affine,
+                Compress::No,
+                BN254_G1_NUM_SUBGROUP_CHECKS,
+                ALGEBRA_ARK_BN254_G1_AFFINE_DESER_UNCOMP,
+                ALGEBRA_ARK_BN254_G1_AFFINE_DESER_UNCOMP_UNCHECKED,
+                ALGEBRA_ARK_BN254_G1_PROJ_ADD,
+                ALGEBRA_ARK_BN254_G1_PROJ_DOUBLE,
+                ALGEBRA_ARK_BN254_G1_PROJ_TO_AFFINE,
+                ALGEBRA_ARK_BN254_G1_PROJ_SCALAR_MUL
+            )
+        },
+        (Some(Structure::BN254G1), Some(SerializationFormat::BN254G1Compressed)) => {
+            ark_batch_deserialize_internal!(
+                context,
+                bytes,
+                32,
+                ark_bn254::G1Affine,
+                Compress::Yes,
+                BN254_G1_NUM_SUBGROUP_CHECKS,
+                ALGEBRA_ARK_BN254_G1_AFFINE_DESER_COMP,
+                ALGEBRA_ARK_BN254_G1_AFFINE_DESER_COMP_UNCHECKED,
+                ALGEBRA_ARK_BN254_G1_PROJ_ADD,
+                ALGEBRA_ARK_BN254_G1_PROJ_DOUBLE,
+                ALGEBRA_ARK_BN254_G1_PROJ_TO_AFFINE,
+                ALGEBRA_ARK_BN254_G1_PROJ_SCALAR_MUL
+            )
+        },
+        (Some(Structure::BN254G2), Some(SerializationFormat::BN254G2Uncompressed)) => {
+            ark_batch_deserialize_internal!(
+                context,
+                bytes,
+                128,
+                ark_bn254::G2Affine,
+                Compress::No,
+                BN254_G2_NUM_SUBGROUP_CHECKS,
+                ALGEBRA_ARK_BN254_G2_AFFINE_DESER_UNCOMP,
+                ALGEBRA_ARK_BN254_G2_AFFINE_DESER_UNCOMP_UNCHECKED,
+                ALGEBRA_ARK_BN254_G2_PROJ_ADD,
+                ALGEBRA_ARK_BN254_G2_PROJ_DOUBLE,
+                ALGEBRA_ARK_BN254_G2_PROJ_TO_AFFINE,
+                ALGEBRA_ARK_BN254_G2_PROJ_SCALAR_MUL
+            )
+        },
+        (Some(Structure::BN254G2), Some(SerializationFormat::BN254G2Compressed)) => {
+            ark_batch_deserialize_internal!(
+                context,
+                bytes,
+                64,
+                ark_bn254::G2Affine,
+                Compress::Yes,
+                BN254_G2_NUM_SUBGROUP_CHECKS,
+                ALGEBRA_ARK_BN254_G2_AFFINE_DESER_COMP,
+                ALGEBRA_ARK_BN254_G2_AFFINE_DESER_COMP_UNCHECKED,
+                ALGEBRA_ARK_BN254_G2_PROJ_ADD,
+                ALGEBRA_ARK_BN254_G2_PROJ_DOUBLE,
+                ALGEBRA_ARK_BN254_G2_PROJ_TO_AFFINE,
+                ALGEBRA_ARK_BN254_G2_PROJ_SCALAR_MUL
+            )
+        },
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
+
+macro_rules! serialize_fr_with_endianness_internal {
+    ($context:expr, $handle:expr, $big_endian:expr, $typ:ty, $gas:expr) => {{
+        safe_borrow_element!($context, $handle, $typ, element_ptr, element);
+        $context.charge($gas)?;
+        let bigint = element.into_bigint();
+        let buf = if $big_endian {
+            bigint.to_bytes_be()
+        } else {
+            bigint.to_bytes_le()
+        };
+        Ok(smallvec![Value::vector_u8(buf)])
+    }};
+}
+
+/// Serializes a scalar field element as a 32-byte integer, in big-endian if `big_endian` is set
+/// and in little-endian otherwise.
+pub fn serialize_fr_with_endianness_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(1, ty_args.len());
+    let structure_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    abort_unless_arithmetics_enabled_for_structure!(context, structure_opt);
+    let big_endian = safely_pop_arg!(args, bool);
+    let handle = safely_pop_arg!(args, u64) as usize;
+    match structure_opt {
+        Some(Structure::BLS12381Fr) => serialize_fr_with_endianness_internal!(
+            context,
+            handle,
+            big_endian,
+            ark_bls12_381::Fr,
+            ALGEBRA_ARK_BLS12_381_FR_SERIALIZE
+        ),
+        Some(Structure::BN254Fr) => serialize_fr_with_endianness_internal!(
+            context,
+            handle,
+            big_endian,
+            ark_bn254::Fr,
+            ALGEBRA_ARK_BN254_FR_SERIALIZE
+        ),
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
+
+macro_rules! deserialize_fr_with_endianness_internal {
+    ($context:expr, $bytes:expr, $big_endian:expr, $typ:ty, $gas:expr) => {{
+        // Valid serializations of both supported scalar fields are 32-byte.
+        if $bytes.len() != 32 {
+            return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+        }
+        $context.charge($gas)?;
+        let mut bytes_copy: Vec<u8> = $bytes.to_vec();
+        if $big_endian {
+            bytes_copy.reverse();
+        }
+        let bigint = ark_ff::BigInteger256::deserialize_uncompressed(bytes_copy.as_slice())
+            .map_err(|_e| abort_invariant_violated())?;
+        // Integers not smaller than the field order are rejected rather than reduced.
+        match <$typ>::from_bigint(bigint) {
+            Some(element) => {
+                let handle = store_element!($context, element)?;
+                Ok(smallvec![Value::bool(true), Value::u64(handle as u64)])
+            },
+            None => Ok(smallvec![Value::bool(false), Value::u64(0)]),
+        }
+    }};
+}
+
+/// Deserializes a scalar field element from a 32-byte integer, read in big-endian if
+/// `big_endian` is set and in little-endian otherwise.
+/// Fails if the integer is not smaller than the field order.
+pub fn deserialize_fr_with_endianness_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(1, ty_args.len());
+    let structure_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    abort_unless_arithmetics_enabled_for_structure!(context, structure_opt);
+    let big_endian = safely_pop_arg!(args, bool);
+    let vector_ref = safely_pop_arg!(args, VectorRef);
+    let bytes_ref = vector_ref.as_bytes_ref();
+    let bytes = bytes_ref.as_slice();
+    match structure_opt {
+        Some(Structure::BLS12381Fr) => deserialize_fr_with_endianness_internal!(
+            context,
+            bytes,
+            big_endian,
+            ark_bls12_381::Fr,
+            ALGEBRA_ARK_BLS12_381_FR_DESER
+        ),
+        Some(Structure::BN254Fr) => deserialize_fr_with_endianness_internal!(
+            context,
+            bytes,
+            big_endian,
+            ark_bn254::Fr,
+            ALGEBRA_ARK_BN254_FR_DESER
+        ),
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
@@ -138,6 +138,7 @@ pub fn encode_aptos_mainnet_genesis_transaction(
         TimedFeaturesBuilder::enable_all().build(),
         &data_cache,
         false,
+        None,
     )
     .unwrap();
     let id1 = HashValue::zero();
@@ -256,6 +257,7 @@ pub fn encode_genesis_change_set(
         TimedFeaturesBuilder::enable_all().build(),
         &data_cache,
         false,
+        None,
     )
     .unwrap();
     let id1 = HashValue::zero();
@@ -1094,6 +1096,7 @@ pub fn test_genesis_module_publishing() {
         TimedFeaturesBuilder::enable_all().build(),
         &data_cache,
         false,
+        None,
     )
     .unwrap();
     let id1 = HashValue::zero();
@@ -119,6 +119,7 @@ where
         TimedFeaturesBuilder::enable_all().build(),
         &resolver,
         false,
+        None,
     )
     .unwrap();
     let change_set = {
@@ -790,17 +790,19 @@ pub fn setup_environment_and_start_node(
         // validator consensus
         Some(consensus_network_interfaces) => {
             // Initialize and start consensus
-            let (runtime, consensus_db, quorum_store_db) = services::start_consensus_runtime(
-                &node_config,
-                db_rw,
-                consensus_reconfig_subscription,
-                consensus_network_interfaces,
-                consensus_notifier,
-                consensus_to_mempool_sender,
-                vtxn_pool,
-                maybe_observer_network_interfaces.map(|network| network.network_client),
-            );
+            let (runtime, consensus_db, quorum_store_db, execution_proxy) =
+                services::start_consensus_runtime(
+                    &node_config,
+                    db_rw,
+                    consensus_reconfig_subscription,
+                    consensus_network_interfaces,
+                    consensus_notifier,
+                    consensus_to_mempool_sender,
+                    vtxn_pool,
+                    maybe_observer_network_interfaces.map(|network| network.network_client),
+                );
             admin_service.set_consensus_dbs(consensus_db, quorum_store_db);
+            admin_service.set_execution_proxy(execution_proxy);
             Some(runtime)
         },
         // consensus observer
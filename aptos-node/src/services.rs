@@ -8,6 +8,7 @@ use aptos_config::config::NodeConfig;
 use aptos_consensus::{
     consensus_observer::network::ObserverMessage, network_interface::ConsensusMsg,
     persistent_liveness_storage::StorageWriteProxy, quorum_store::quorum_store_db::QuorumStoreDB,
+    ExecutionProxy,
 };
 use aptos_consensus_notifications::ConsensusNotifier;
 use aptos_data_client::client::AptosDataClient;
@@ -119,7 +120,12 @@ pub fn start_consensus_runtime(
     consensus_to_mempool_sender: Sender<QuorumStoreRequest>,
     vtxn_pool: VTxnPoolState,
     observer_network_client: Option<NetworkClient<ObserverMessage>>,
-) -> (Runtime, Arc<StorageWriteProxy>, Arc<QuorumStoreDB>) {
+) -> (
+    Runtime,
+    Arc<StorageWriteProxy>,
+    Arc<QuorumStoreDB>,
+    Arc<ExecutionProxy>,
+) {
     let instant = Instant::now();
     let observer_network_client = if node_config.consensus_observer.publisher_enabled {
         observer_network_client
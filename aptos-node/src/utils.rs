@@ -56,9 +56,15 @@ pub fn set_aptos_vm_configurations(node_config: &NodeConfig) {
     };
     AptosVM::set_concurrency_level_once(effective_concurrency_level as usize);
     AptosVM::set_discard_failed_blocks(node_config.execution.discard_failed_blocks);
+    if let Some(threshold_pct) = node_config.execution.abort_rate_alert_threshold_pct {
+        AptosVM::set_abort_rate_alert_threshold_pct(threshold_pct);
+    }
     AptosVM::set_num_proof_reading_threads_once(
         node_config.execution.num_proof_reading_threads as usize,
     );
+    AptosVM::set_num_algebra_native_threads_once(
+        node_config.execution.num_algebra_native_threads as usize,
+    );
 
     if node_config
         .execution
@@ -42,10 +42,16 @@ pub struct ExecutionConfig {
     pub concurrency_level: u16,
     /// Number of threads to read proofs
     pub num_proof_reading_threads: u16,
+    /// Number of threads for large multi-scalar multiplications and multi-pairings in the
+    /// algebra natives. If 1, they are single-threaded.
+    pub num_algebra_native_threads: u16,
     /// Enables paranoid mode for types, which adds extra runtime VM checks
     pub paranoid_type_verification: bool,
     /// Enabled discarding blocks that fail execution due to BlockSTM/VM issue.
     pub discard_failed_blocks: bool,
+    /// If set, parallel execution counts and logs blocks whose speculative aborts exceed this
+    /// percentage of their committed transactions (e.g. 200 for two aborts per transaction).
+    pub abort_rate_alert_threshold_pct: Option<u64>,
     /// Enables paranoid mode for hot potatoes, which adds extra runtime VM checks
     pub paranoid_hot_potato_verification: bool,
     /// Enables enhanced metrics around processed transactions
@@ -80,9 +86,11 @@ impl Default for ExecutionConfig {
             // use min of (num of cores/2, DEFAULT_CONCURRENCY_LEVEL) as default concurrency level
             concurrency_level: 0,
             num_proof_reading_threads: 32,
+            num_algebra_native_threads: 1,
             paranoid_type_verification: true,
             paranoid_hot_potato_verification: true,
             discard_failed_blocks: false,
+            abort_rate_alert_threshold_pct: None,
             processed_transactions_detailed_counters: false,
             transaction_filter: Filter::empty(),
             genesis_waypoint: None,
@@ -58,6 +58,10 @@ pub struct MempoolConfig {
     pub broadcast_buckets: Vec<u64>,
     pub eager_expire_threshold_ms: Option<u64>,
     pub eager_expire_time_ms: u64,
+    /// Maximum number of transaction validation results cached by each VM validator, so that
+    /// re-validating an unchanged transaction against an unchanged state is skipped. Zero
+    /// disables the cache.
+    pub validation_cache_capacity: usize,
 }
 
 impl Default for MempoolConfig {
@@ -84,6 +88,7 @@ impl Default for MempoolConfig {
             broadcast_buckets: DEFAULT_BUCKETS.to_vec(),
             eager_expire_threshold_ms: Some(10_000),
             eager_expire_time_ms: 3_000,
+            validation_cache_capacity: 0,
         }
     }
 }
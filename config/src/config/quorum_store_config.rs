@@ -61,6 +61,14 @@ pub struct QuorumStoreConfig {
     pub receiver_max_total_bytes: usize,
     pub batch_request_num_peers: usize,
     pub batch_request_retry_limit: usize,
+    /// Number of times a failed batch request RPC is retried against the same peer before the
+    /// request moves on to the next set of peers.
+    pub batch_request_per_peer_retries: usize,
+    /// Maximum number of batch request RPCs in flight at once for a single batch.
+    pub batch_request_max_in_flight: usize,
+    /// Maximum number of batches requested concurrently across all requests. Requests beyond the
+    /// limit wait for an in-progress request to finish before sending any RPCs. 0 means unbounded.
+    pub batch_request_max_concurrent_requests: usize,
     pub batch_request_retry_interval_ms: usize,
     pub batch_request_rpc_timeout_ms: usize,
     /// Duration for expiring locally created batches.
@@ -100,6 +108,9 @@ impl Default for QuorumStoreConfig {
                 + BATCH_PADDING_BYTES,
             batch_request_num_peers: 5,
             batch_request_retry_limit: 10,
+            batch_request_per_peer_retries: 0,
+            batch_request_max_in_flight: 50,
+            batch_request_max_concurrent_requests: 0,
             batch_request_retry_interval_ms: 1000,
             batch_request_rpc_timeout_ms: 5000,
             batch_expiry_gap_when_init_usecs: Duration::from_secs(60).as_micros() as u64,
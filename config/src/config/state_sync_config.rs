@@ -122,6 +122,9 @@ pub struct StateSyncDriverConfig {
     pub num_versions_to_skip_snapshot_sync: u64,
     /// Whether consensus observer mode is enabled
     pub observer_enabled: bool,
+    /// Skip verifying the proofs of synced chunks against the target ledger infos. Only safe when
+    /// all peers are trusted out of band (e.g., syncing from the operator's own nodes).
+    pub trust_chunk_source: bool,
 }
 
 /// The default state sync driver config will be the one that gets (and keeps)
@@ -143,6 +146,7 @@ impl Default for StateSyncDriverConfig {
             max_stream_wait_time_ms: 5000,
             num_versions_to_skip_snapshot_sync: 100_000_000, // At 5k TPS, this allows a node to fail for about 6 hours.
             observer_enabled: false,
+            trust_chunk_source: false,
         }
     }
 }
@@ -469,7 +473,7 @@ impl ConfigSanitizer for StateSyncConfig {
 impl ConfigSanitizer for StateSyncDriverConfig {
     fn sanitize(
         node_config: &NodeConfig,
-        _node_type: NodeType,
+        node_type: NodeType,
         _chain_id: Option<ChainId>,
     ) -> Result<(), Error> {
         let sanitizer_name = Self::get_sanitizer_name();
@@ -486,6 +490,14 @@ impl ConfigSanitizer for StateSyncDriverConfig {
             ));
         }
 
+        // Verify that validators never skip the chunk proof verification
+        if state_sync_driver_config.trust_chunk_source && node_type.is_validator() {
+            return Err(Error::ConfigSanitizerFailed(
+                sanitizer_name,
+                "Validators should not trust the chunk source!".to_string(),
+            ));
+        }
+
         Ok(())
     }
 }
@@ -791,6 +803,35 @@ mod tests {
         assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
     }
 
+    #[test]
+    fn test_sanitize_trust_chunk_source_validator() {
+        // Create a node config that trusts the chunk source
+        let node_config = NodeConfig {
+            state_sync: StateSyncConfig {
+                state_sync_driver: StateSyncDriverConfig {
+                    trust_chunk_source: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Verify that sanitization fails for validators
+        let error =
+            StateSyncConfig::sanitize(&node_config, NodeType::Validator, Some(ChainId::testnet()))
+                .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+
+        // Verify that sanitization passes for fullnodes
+        StateSyncConfig::sanitize(
+            &node_config,
+            NodeType::PublicFullnode,
+            Some(ChainId::testnet()),
+        )
+        .unwrap();
+    }
+
     /// Creates and returns a node config with the syncing modes set to execution
     fn create_execution_mode_config() -> NodeConfig {
         NodeConfig {
@@ -89,6 +89,14 @@ impl Rule {
     }
 }
 
+/// The outcome of evaluating a filter against a transaction, along with the rule that decided it
+/// (rendered like `Deny(Sender(0x1))`), if any. No rule matching means the transaction is allowed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FilterDecision {
+    pub allowed: bool,
+    pub matched_rule: Option<String>,
+}
+
 /// A filter that can be used to allow or deny transactions from being executed. It contains a set
 /// of rules that are evaluated one by one in the order of declaration.
 /// If a rule matches, the transaction is either allowed or
@@ -202,15 +210,45 @@ impl Filter {
     }
 
     pub fn allows(&self, block_id: HashValue, timestamp: u64, txn: &SignedTransaction) -> bool {
+        self.first_match(block_id, timestamp, txn)
+            .map_or(true, |(_, allowed)| allowed)
+    }
+
+    /// Like `allows`, but also reports which rule decided whether the transaction is allowed.
+    pub fn decide(
+        &self,
+        block_id: HashValue,
+        timestamp: u64,
+        txn: &SignedTransaction,
+    ) -> FilterDecision {
+        match self.first_match(block_id, timestamp, txn) {
+            Some((rule, allowed)) => FilterDecision {
+                allowed,
+                matched_rule: Some(format!("{:?}", rule)),
+            },
+            None => FilterDecision {
+                allowed: true,
+                matched_rule: None,
+            },
+        }
+    }
+
+    /// Returns the first rule that matches the transaction, and whether it allows it.
+    fn first_match(
+        &self,
+        block_id: HashValue,
+        timestamp: u64,
+        txn: &SignedTransaction,
+    ) -> Option<(&Rule, bool)> {
         for rule in &self.rules {
             // Rules are evaluated in the order and the first rule that matches is used. If no rule
             // matches, the transaction is allowed.
             match rule.eval(block_id, timestamp, txn) {
-                EvalResult::Allow => return true,
-                EvalResult::Deny => return false,
+                EvalResult::Allow => return Some((rule, true)),
+                EvalResult::Deny => return Some((rule, false)),
                 EvalResult::NoMatch => continue,
             }
         }
-        true
+        None
     }
 }
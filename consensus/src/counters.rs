@@ -45,6 +45,15 @@ pub static ERROR_COUNT: Lazy<IntGauge> = Lazy::new(|| {
     .unwrap()
 });
 
+/// Whether the execution pipeline is paused (1) or not (0).
+pub static EXECUTION_PIPELINE_PAUSED: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "aptos_consensus_execution_pipeline_paused",
+        "Whether the execution pipeline is paused (1) or not (0)"
+    )
+    .unwrap()
+});
+
 /// This counter is set to the round of the highest committed block.
 pub static LAST_COMMITTED_ROUND: Lazy<IntGauge> = Lazy::new(|| {
     register_int_gauge!(
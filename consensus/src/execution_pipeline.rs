@@ -5,6 +5,7 @@
 
 use crate::{
     block_preparer::BlockPreparer,
+    counters::EXECUTION_PIPELINE_PAUSED,
     monitor,
     state_computer::{PipelineExecutionResult, StateComputeResultFut},
 };
@@ -15,7 +16,7 @@ use aptos_executor_types::{
     ExecutorResult,
 };
 use aptos_experimental_runtimes::thread_manager::optimal_min_len;
-use aptos_logger::{debug, error};
+use aptos_logger::{debug, error, info};
 use aptos_types::{
     block_executor::{config::BlockExecutorConfigFromOnchain, partitioner::ExecutableBlock},
     block_metadata_ext::BlockMetadataExt,
@@ -26,8 +27,11 @@ use aptos_types::{
 use fail::fail_point;
 use once_cell::sync::Lazy;
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
-use std::sync::Arc;
-use tokio::sync::{mpsc, oneshot};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+use tokio::sync::{mpsc, oneshot, Notify};
 
 pub static SIG_VERIFY_POOL: Lazy<Arc<rayon::ThreadPool>> = Lazy::new(|| {
     Arc::new(
@@ -39,8 +43,38 @@ pub static SIG_VERIFY_POOL: Lazy<Arc<rayon::ThreadPool>> = Lazy::new(|| {
     )
 });
 
+/// Gate checked by the execute stage before it starts executing a block.
+#[derive(Default)]
+struct PauseGate {
+    paused: AtomicBool,
+    resumed: Notify,
+}
+
+impl PauseGate {
+    fn set_paused(&self, paused: bool) {
+        self.paused.store(paused, Ordering::Release);
+        EXECUTION_PIPELINE_PAUSED.set(paused as i64);
+        if !paused {
+            self.resumed.notify_waiters();
+        }
+    }
+
+    async fn wait_until_resumed(&self) {
+        loop {
+            // Register for the notification before checking the flag, so that a resume in between
+            // is not missed.
+            let resumed = self.resumed.notified();
+            if !self.paused.load(Ordering::Acquire) {
+                return;
+            }
+            resumed.await;
+        }
+    }
+}
+
 pub struct ExecutionPipeline {
     prepare_block_tx: mpsc::UnboundedSender<PrepareBlockCommand>,
+    pause_gate: Arc<PauseGate>,
 }
 
 impl ExecutionPipeline {
@@ -48,6 +82,8 @@ impl ExecutionPipeline {
         let (prepare_block_tx, prepare_block_rx) = mpsc::unbounded_channel();
         let (execute_block_tx, execute_block_rx) = mpsc::unbounded_channel();
         let (ledger_apply_tx, ledger_apply_rx) = mpsc::unbounded_channel();
+        let pause_gate = Arc::new(PauseGate::default());
+        EXECUTION_PIPELINE_PAUSED.set(0);
         runtime.spawn(Self::prepare_block_stage(
             prepare_block_rx,
             execute_block_tx,
@@ -56,9 +92,30 @@ impl ExecutionPipeline {
             execute_block_rx,
             ledger_apply_tx,
             executor.clone(),
+            pause_gate.clone(),
         ));
         runtime.spawn(Self::ledger_apply_stage(ledger_apply_rx, executor));
-        Self { prepare_block_tx }
+        Self {
+            prepare_block_tx,
+            pause_gate,
+        }
+    }
+
+    /// Stops the execute stage from starting any new block. Blocks that are already being
+    /// executed, or are past the execute stage, still finish; queued blocks wait for `resume()`.
+    pub fn pause(&self) {
+        info!("Pausing the execution pipeline.");
+        self.pause_gate.set_paused(true);
+    }
+
+    /// Lets the execute stage pick up blocks again after a `pause()`.
+    pub fn resume(&self) {
+        info!("Resuming the execution pipeline.");
+        self.pause_gate.set_paused(false);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.pause_gate.paused.load(Ordering::Acquire)
     }
 
     pub async fn queue(
@@ -165,6 +222,7 @@ impl ExecutionPipeline {
         mut block_rx: mpsc::UnboundedReceiver<ExecuteBlockCommand>,
         ledger_apply_tx: mpsc::UnboundedSender<LedgerApplyCommand>,
         executor: Arc<dyn BlockExecutorTrait>,
+        pause_gate: Arc<PauseGate>,
     ) {
         while let Some(ExecuteBlockCommand {
             input_txns,
@@ -176,6 +234,7 @@ impl ExecutionPipeline {
         {
             let block_id = block.block_id;
             debug!("execute_stage received block {}.", block_id);
+            pause_gate.wait_until_resumed().await;
             let executor = executor.clone();
             let state_checkpoint_output = monitor!(
                 "execute_block",
@@ -70,6 +70,10 @@ pub use consensusdb::CONSENSUS_DB_NAME;
 pub use quorum_store::quorum_store_db::QUORUM_STORE_DB_NAME;
 #[cfg(feature = "fuzzing")]
 pub use round_manager::round_manager_fuzzing;
+/// Required by the admin service, and by deployments that register commit observers
+pub use state_computer::ExecutionProxy;
+pub use state_replication::CommitObserver;
+pub use txn_hash_and_authenticator_deduper::{dedup_key, DedupKey};
 
 struct IntGaugeGuard {
     gauge: IntGauge,
@@ -23,7 +23,7 @@ use itertools::Either;
 use std::{
     collections::{hash_map::Entry, HashMap},
     sync::Arc,
-    time::Duration,
+    time::{Duration, Instant},
 };
 use tokio::{sync::oneshot, time::timeout};
 
@@ -70,7 +70,11 @@ impl PayloadManager {
                 block_timestamp
             );
             if block_timestamp <= pos.expiration() {
-                receivers.push((*pos.digest(), batch_reader.get_batch(pos)));
+                // Once the chain moves past the expiration of the batch, its signers drop it, so
+                // there's no point in requesting it any longer.
+                let deadline =
+                    Instant::now() + Duration::from_micros(pos.expiration() - block_timestamp);
+                receivers.push((*pos.digest(), batch_reader.get_batch(pos, Some(deadline))));
             } else {
                 debug!("QSE: skipped expired pos {}", pos.digest());
             }
@@ -202,8 +206,10 @@ impl PayloadManager {
                 Either::Left(data) => data,
                 Either::Right(rx) => timeout(Duration::from_millis(300), rx)
                     .await
-                    .map_err(|_| ExecutorError::CouldNotGetData)?
-                    .map_err(|_| ExecutorError::CouldNotGetData)?,
+                    .map_err(|_| ExecutorError::CouldNotGetData(CouldNotGetDataReason::Timeout))?
+                    .map_err(|_| {
+                        ExecutorError::CouldNotGetData(CouldNotGetDataReason::NetworkError)
+                    })?,
             };
             if let Some(publisher) = maybe_publisher {
                 publisher.publish(ObserverMessage::Payload((
@@ -461,8 +461,8 @@ impl BufferManager {
 
         let executed_blocks = match inner {
             Ok(result) => result,
-            Err(ExecutorError::CouldNotGetData) => {
-                warn!("Execution error - CouldNotGetData");
+            Err(ExecutorError::CouldNotGetData(reason)) => {
+                warn!("Execution error - CouldNotGetData: {}", reason);
                 return;
             },
             Err(e) => {
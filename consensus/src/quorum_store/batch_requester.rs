@@ -5,6 +5,7 @@ use crate::{
     monitor,
     network::QuorumStoreSender,
     quorum_store::{
+        bounded_futures_unordered::BoundedFuturesUnordered,
         counters,
         types::{BatchRequest, BatchResponse},
     },
@@ -14,10 +15,16 @@ use aptos_crypto::HashValue;
 use aptos_executor_types::*;
 use aptos_logger::prelude::*;
 use aptos_types::{transaction::SignedTransaction, validator_verifier::ValidatorVerifier, PeerId};
-use futures::{stream::FuturesUnordered, StreamExt};
+use futures::StreamExt;
 use rand::Rng;
-use std::{sync::Arc, time::Duration};
-use tokio::{sync::oneshot, time};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::{
+    sync::{oneshot, Semaphore},
+    time,
+};
 
 struct BatchRequesterState {
     signers: Vec<PeerId>,
@@ -25,6 +32,9 @@ struct BatchRequesterState {
     ret_tx: oneshot::Sender<ExecutorResult<Vec<SignedTransaction>>>,
     num_retries: usize,
     retry_limit: usize,
+    /// If set, no new requests are sent once they could not complete before this instant.
+    deadline: Option<Instant>,
+    rpc_timeout: Duration,
 }
 
 impl BatchRequesterState {
@@ -32,6 +42,8 @@ impl BatchRequesterState {
         signers: Vec<PeerId>,
         ret_tx: oneshot::Sender<ExecutorResult<Vec<SignedTransaction>>>,
         retry_limit: usize,
+        deadline: Option<Instant>,
+        rpc_timeout: Duration,
     ) -> Self {
         Self {
             signers,
@@ -39,10 +51,21 @@ impl BatchRequesterState {
             ret_tx,
             num_retries: 0,
             retry_limit,
+            deadline,
+            rpc_timeout,
         }
     }
 
+    fn deadline_near(&self) -> bool {
+        self.deadline.map_or(false, |deadline| {
+            Instant::now() + self.rpc_timeout >= deadline
+        })
+    }
+
     fn next_request_peers(&mut self, num_peers: usize) -> Option<Vec<PeerId>> {
+        if self.deadline_near() {
+            return None;
+        }
         if self.num_retries == 0 {
             let mut rng = rand::thread_rng();
             // make sure nodes request from the different set of nodes
@@ -68,28 +91,37 @@ impl BatchRequesterState {
         }
     }
 
-    fn serve_request(self, digest: HashValue, maybe_payload: Option<Vec<SignedTransaction>>) {
-        if let Some(payload) = maybe_payload {
-            trace!(
-                "QS: batch to oneshot, digest {}, tx {:?}",
-                digest,
-                self.ret_tx
-            );
-            if self.ret_tx.send(Ok(payload)).is_err() {
-                debug!(
-                    "Receiver of requested batch not available for digest {}",
-                    digest
-                )
-            };
-        } else if self
-            .ret_tx
-            .send(Err(ExecutorError::CouldNotGetData))
-            .is_err()
-        {
-            debug!(
-                "Receiver of requested batch not available for unavailable digest {}",
-                digest
-            );
+    fn serve_request(
+        self,
+        digest: HashValue,
+        result: Result<Vec<SignedTransaction>, CouldNotGetDataReason>,
+    ) {
+        match result {
+            Ok(payload) => {
+                trace!(
+                    "QS: batch to oneshot, digest {}, tx {:?}",
+                    digest,
+                    self.ret_tx
+                );
+                if self.ret_tx.send(Ok(payload)).is_err() {
+                    debug!(
+                        "Receiver of requested batch not available for digest {}",
+                        digest
+                    )
+                };
+            },
+            Err(reason) => {
+                if self
+                    .ret_tx
+                    .send(Err(ExecutorError::CouldNotGetData(reason)))
+                    .is_err()
+                {
+                    debug!(
+                        "Receiver of requested batch not available for unavailable digest {}",
+                        digest
+                    );
+                }
+            },
         }
     }
 }
@@ -99,10 +131,14 @@ pub(crate) struct BatchRequester<T> {
     my_peer_id: PeerId,
     request_num_peers: usize,
     retry_limit: usize,
+    per_peer_retries: usize,
+    max_in_flight: usize,
     retry_interval_ms: usize,
     rpc_timeout_ms: usize,
     network_sender: T,
     validator_verifier: Arc<ValidatorVerifier>,
+    /// Limits the number of concurrent `request_batch` invocations, if set.
+    request_admission: Option<Arc<Semaphore>>,
 }
 
 impl<T: QuorumStoreSender + Sync + 'static> BatchRequester<T> {
@@ -111,6 +147,9 @@ impl<T: QuorumStoreSender + Sync + 'static> BatchRequester<T> {
         my_peer_id: PeerId,
         request_num_peers: usize,
         retry_limit: usize,
+        per_peer_retries: usize,
+        max_in_flight: usize,
+        max_concurrent_requests: usize,
         retry_interval_ms: usize,
         rpc_timeout_ms: usize,
         network_sender: T,
@@ -121,81 +160,130 @@ impl<T: QuorumStoreSender + Sync + 'static> BatchRequester<T> {
             my_peer_id,
             request_num_peers,
             retry_limit,
+            per_peer_retries,
+            max_in_flight,
             retry_interval_ms,
             rpc_timeout_ms,
             network_sender,
             validator_verifier: Arc::new(validator_verifier),
+            request_admission: (max_concurrent_requests > 0)
+                .then(|| Arc::new(Semaphore::new(max_concurrent_requests))),
         }
     }
 
+    /// Requests the batch of `proof` from its signers, retrying until `retry_limit` rounds of
+    /// requests are exhausted. If `deadline` is set, no further requests are sent once a response
+    /// could not arrive before it.
     pub(crate) async fn request_batch(
         &self,
         proof: ProofOfStore,
         ret_tx: oneshot::Sender<ExecutorResult<Vec<SignedTransaction>>>,
+        deadline: Option<Instant>,
     ) -> Option<(BatchInfo, Vec<SignedTransaction>)> {
+        // Wait for admission before issuing any RPCs, so that bursts of requests during recovery
+        // don't overwhelm the network.
+        let _permit = match &self.request_admission {
+            Some(semaphore) => {
+                let start = Instant::now();
+                // The semaphore is never closed, so acquiring a permit cannot fail.
+                let permit = semaphore
+                    .acquire()
+                    .await
+                    .expect("BatchRequester semaphore closed");
+                counters::BATCH_REQUEST_PERMIT_WAIT_DURATION.observe_duration(start.elapsed());
+                Some(permit)
+            },
+            None => None,
+        };
         let digest = *proof.digest();
         let expiration = proof.expiration();
         let signers = proof.shuffled_signers(&self.validator_verifier);
         let validator_verifier = self.validator_verifier.clone();
-        let mut request_state = BatchRequesterState::new(signers, ret_tx, self.retry_limit);
+        let retry_interval = Duration::from_millis(self.retry_interval_ms as u64);
+        let rpc_timeout = Duration::from_millis(self.rpc_timeout_ms as u64);
+        let mut request_state =
+            BatchRequesterState::new(signers, ret_tx, self.retry_limit, deadline, rpc_timeout);
         let network_sender = self.network_sender.clone();
         let request_num_peers = self.request_num_peers;
+        let per_peer_retries = self.per_peer_retries;
+        let max_in_flight = self.max_in_flight;
         let my_peer_id = self.my_peer_id;
         let epoch = self.epoch;
-        let retry_interval = Duration::from_millis(self.retry_interval_ms as u64);
-        let rpc_timeout = Duration::from_millis(self.rpc_timeout_ms as u64);
 
         monitor!("batch_request", {
             let mut interval = time::interval(retry_interval);
-            let mut futures = FuturesUnordered::new();
+            let mut futures = BoundedFuturesUnordered::new(max_in_flight);
+            // Whether any peer answered that it does not have the batch, to tell missing batches
+            // apart from network failures.
+            let mut received_not_found = false;
             let request = BatchRequest::new(my_peer_id, epoch, digest);
+            let request_from_peer = |peer: PeerId, num_attempts: usize| {
+                let response = network_sender.request_batch(request.clone(), peer, rpc_timeout);
+                async move { (peer, num_attempts, response.await) }
+            };
             loop {
                 tokio::select! {
                     _ = interval.tick() => {
                         // send batch request to a set of peers of size request_num_peers
                         if let Some(request_peers) = request_state.next_request_peers(request_num_peers) {
                             for peer in request_peers {
-                                futures.push(network_sender.request_batch(request.clone(), peer, rpc_timeout));
+                                futures.push(request_from_peer(peer, 1));
                             }
                         } else if futures.is_empty() {
                             // end the loop when the futures are drained
                             break;
                         }
                     },
-                    Some(response) = futures.next() => {
+                    Some((peer, num_attempts, response)) = futures.next() => {
                         match response {
                             Ok(BatchResponse::Batch(batch)) => {
                                 counters::RECEIVED_BATCH_RESPONSE_COUNT.inc();
                                 let digest = *batch.digest();
                                 let batch_info = batch.batch_info().clone();
                                 let payload = batch.into_transactions();
-                                request_state.serve_request(digest, Some(payload.clone()));
+                                request_state.serve_request(digest, Ok(payload.clone()));
                                 return Some((batch_info, payload));
                             }
                             // Short-circuit if the chain has moved beyond expiration
                             Ok(BatchResponse::NotFound(ledger_info)) => {
                                 counters::RECEIVED_BATCH_NOT_FOUND_COUNT.inc();
+                                received_not_found = true;
                                 if ledger_info.commit_info().epoch() == epoch
                                     && ledger_info.commit_info().timestamp_usecs() > expiration
                                     && ledger_info.verify_signatures(&validator_verifier).is_ok()
                                 {
                                     counters::RECEIVED_BATCH_EXPIRED_COUNT.inc();
                                     debug!("QS: batch request expired, digest:{}", digest);
-                                    request_state.serve_request(digest, None);
+                                    request_state.serve_request(digest, Err(CouldNotGetDataReason::Expired));
                                     return None;
                                 }
                             }
                             Err(e) => {
                                 counters::RECEIVED_BATCH_RESPONSE_ERROR_COUNT.inc();
                                 debug!("QS: batch request error, digest:{}, error:{:?}", digest, e);
+                                // The peer may just be flaky, retry it before moving on to the next peers
+                                if num_attempts <= per_peer_retries && !request_state.deadline_near() {
+                                    counters::SENT_BATCH_REQUEST_RETRY_COUNT.inc();
+                                    futures.push(request_from_peer(peer, num_attempts + 1));
+                                }
                             }
                         }
                     },
                 }
             }
             counters::RECEIVED_BATCH_REQUEST_TIMEOUT_COUNT.inc();
-            debug!("QS: batch request timed out, digest:{}", digest);
-            request_state.serve_request(digest, None);
+            let reason = if request_state.deadline_near() {
+                CouldNotGetDataReason::Timeout
+            } else if received_not_found {
+                CouldNotGetDataReason::AllPeersExhausted
+            } else {
+                CouldNotGetDataReason::NetworkError
+            };
+            debug!(
+                "QS: batch request timed out, digest:{}, reason:{}",
+                digest, reason
+            );
+            request_state.serve_request(digest, Err(reason));
             None
         })
     }
@@ -14,7 +14,7 @@ use crate::{
 use anyhow::bail;
 use aptos_consensus_types::proof_of_store::{ProofOfStore, SignedBatchInfo};
 use aptos_crypto::HashValue;
-use aptos_executor_types::{ExecutorError, ExecutorResult};
+use aptos_executor_types::{CouldNotGetDataReason, ExecutorError, ExecutorResult};
 use aptos_logger::prelude::*;
 use aptos_types::{transaction::SignedTransaction, validator_signer::ValidatorSigner, PeerId};
 use dashmap::{
@@ -28,7 +28,7 @@ use std::{
         atomic::{AtomicU64, Ordering},
         Arc, Mutex,
     },
-    time::Duration,
+    time::{Duration, Instant},
 };
 use tokio::sync::oneshot;
 
@@ -351,7 +351,9 @@ impl BatchStore {
             Ok(Some(value)) => Ok(value),
             Ok(None) | Err(_) => {
                 warn!("Could not get batch from db");
-                Err(ExecutorError::CouldNotGetData)
+                Err(ExecutorError::CouldNotGetData(
+                    CouldNotGetDataReason::NotAvailableLocally,
+                ))
             },
         }
     }
@@ -368,7 +370,9 @@ impl BatchStore {
                 Ok(value.clone())
             }
         } else {
-            Err(ExecutorError::CouldNotGetData)
+            Err(ExecutorError::CouldNotGetData(
+                CouldNotGetDataReason::NotAvailableLocally,
+            ))
         }
     }
 }
@@ -389,9 +393,12 @@ pub trait BatchReader: Send + Sync {
     /// Check if the batch corresponding to the digest exists, return the batch author if true
     fn exists(&self, digest: &HashValue) -> Option<PeerId>;
 
+    /// Returns the batch of `proof`, requesting it from its signers if it's not available locally.
+    /// If `deadline` is set, no requests are sent once they could not complete before it.
     fn get_batch(
         &self,
         proof: ProofOfStore,
+        deadline: Option<Instant>,
     ) -> oneshot::Receiver<ExecutorResult<Vec<SignedTransaction>>>;
 
     fn update_certified_timestamp(&self, certified_time: u64);
@@ -422,6 +429,7 @@ impl<T: QuorumStoreSender + Clone + Send + Sync + 'static> BatchReader for Batch
     fn get_batch(
         &self,
         proof: ProofOfStore,
+        deadline: Option<Instant>,
     ) -> oneshot::Receiver<ExecutorResult<Vec<SignedTransaction>>> {
         let (tx, rx) = oneshot::channel();
         let batch_store = self.batch_store.clone();
@@ -440,7 +448,8 @@ impl<T: QuorumStoreSender + Clone + Send + Sync + 'static> BatchReader for Batch
             } else {
                 // Quorum store metrics
                 counters::MISSED_BATCHES_COUNT.inc();
-                if let Some((batch_info, payload)) = batch_requester.request_batch(proof, tx).await
+                if let Some((batch_info, payload)) =
+                    batch_requester.request_batch(proof, tx, deadline).await
                 {
                     batch_store.persist(vec![PersistedValue::new(batch_info, Some(payload))]);
                 }
@@ -0,0 +1,78 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use futures::{
+    future::BoxFuture,
+    stream::{FusedStream, FuturesUnordered},
+    FutureExt, Stream, StreamExt,
+};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tokio::sync::Semaphore;
+
+/// A set of futures that are polled concurrently, like [`FuturesUnordered`], but with at most
+/// `capacity` of them in flight at any time. Futures pushed beyond the capacity are only started,
+/// in the order they were pushed, once in-flight futures complete.
+///
+/// Unlike `aptos_bounded_executor::BoundedExecutor`, nothing is spawned: the futures are driven by
+/// whoever polls this stream, so they do not need to be `'static`.
+pub(crate) struct BoundedFuturesUnordered<'a, T> {
+    semaphore: Arc<Semaphore>,
+    futures: FuturesUnordered<BoxFuture<'a, T>>,
+}
+
+impl<'a, T: 'a> BoundedFuturesUnordered<'a, T> {
+    pub(crate) fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "Capacity must be positive");
+        Self {
+            semaphore: Arc::new(Semaphore::new(capacity)),
+            futures: FuturesUnordered::new(),
+        }
+    }
+
+    /// Queues `future`, which starts running as soon as fewer than `capacity` futures are in
+    /// flight.
+    pub(crate) fn push<F>(&mut self, future: F)
+    where
+        F: Future<Output = T> + Send + 'a,
+    {
+        let semaphore = self.semaphore.clone();
+        self.futures.push(
+            async move {
+                // The semaphore is never closed, so acquiring a permit cannot fail.
+                let _permit = semaphore
+                    .acquire_owned()
+                    .await
+                    .expect("BoundedFuturesUnordered semaphore closed");
+                future.await
+            }
+            .boxed(),
+        );
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.futures.is_empty()
+    }
+}
+
+impl<'a, T> Stream for BoundedFuturesUnordered<'a, T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        self.futures.poll_next_unpin(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.futures.size_hint()
+    }
+}
+
+impl<'a, T> FusedStream for BoundedFuturesUnordered<'a, T> {
+    fn is_terminated(&self) -> bool {
+        self.futures.is_terminated()
+    }
+}
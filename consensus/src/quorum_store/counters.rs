@@ -687,6 +687,18 @@ pub static BATCH_TO_POS_DURATION: Lazy<DurationHistogram> = Lazy::new(|| {
     )
 });
 
+/// Histogram of the time batch requests wait for an admission permit before sending RPCs.
+pub static BATCH_REQUEST_PERMIT_WAIT_DURATION: Lazy<DurationHistogram> = Lazy::new(|| {
+    DurationHistogram::new(
+        register_histogram!(
+            "quorum_store_batch_request_permit_wait_duration",
+            "Histogram of the time batch requests wait for an admission permit before sending RPCs.",
+            QUORUM_STORE_LATENCY_BUCKETS.to_vec()
+        )
+        .unwrap(),
+    )
+});
+
 pub static BATCH_SUCCESSFUL_CREATION: Lazy<Histogram> = Lazy::new(|| {
     register_avg_counter(
         "quorum_store_batch_successful_creation",
@@ -245,6 +245,9 @@ impl InnerBuilder {
             self.author,
             self.config.batch_request_num_peers,
             self.config.batch_request_retry_limit,
+            self.config.batch_request_per_peer_retries,
+            self.config.batch_request_max_in_flight,
+            self.config.batch_request_max_concurrent_requests,
             self.config.batch_request_retry_interval_ms,
             self.config.batch_request_rpc_timeout_ms,
             self.network_sender.clone(),
@@ -13,6 +13,7 @@ use aptos_consensus_types::{
     proof_of_store::{BatchId, ProofOfStore, SignedBatchInfo},
 };
 use aptos_crypto::HashValue;
+use aptos_executor_types::{CouldNotGetDataReason, ExecutorError};
 use aptos_types::{
     aggregate_signature::{AggregateSignature, PartialSignatures},
     block_info::BlockInfo,
@@ -21,16 +22,50 @@ use aptos_types::{
     validator_verifier::{ValidatorConsensusInfo, ValidatorVerifier},
 };
 use move_core_types::account_address::AccountAddress;
-use std::time::{Duration, Instant};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
 
 #[derive(Clone)]
 struct MockBatchRequester {
     return_value: BatchResponse,
+    remaining_failures: Arc<AtomicUsize>,
+    response_delay: Duration,
+    in_flight: Arc<AtomicUsize>,
+    peak_in_flight: Arc<AtomicUsize>,
 }
 
 impl MockBatchRequester {
     fn new(return_value: BatchResponse) -> Self {
-        Self { return_value }
+        Self::new_flaky(return_value, 0)
+    }
+
+    /// Fails the first `num_failures` requests before returning `return_value`.
+    fn new_flaky(return_value: BatchResponse, num_failures: usize) -> Self {
+        Self {
+            return_value,
+            remaining_failures: Arc::new(AtomicUsize::new(num_failures)),
+            response_delay: Duration::ZERO,
+            in_flight: Arc::new(AtomicUsize::new(0)),
+            peak_in_flight: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Answers each request only after `response_delay`, so that concurrent requests overlap.
+    fn new_slow(return_value: BatchResponse, response_delay: Duration) -> Self {
+        Self {
+            response_delay,
+            ..Self::new(return_value)
+        }
+    }
+
+    /// The most requests that were in flight at the same time.
+    fn peak_in_flight(&self) -> usize {
+        self.peak_in_flight.load(Ordering::SeqCst)
     }
 }
 
@@ -46,6 +81,18 @@ impl QuorumStoreSender for MockBatchRequester {
         _recipient: Author,
         _timeout: Duration,
     ) -> anyhow::Result<BatchResponse> {
+        let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+        tokio::time::sleep(self.response_delay).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+        if self
+            .remaining_failures
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok()
+        {
+            anyhow::bail!("flaky rpc");
+        }
         Ok(self.return_value.clone())
     }
 
@@ -93,6 +140,9 @@ async fn test_batch_request_exists() {
         AccountAddress::random(),
         1,
         2,
+        0,
+        10,
+        0,
         1_000,
         1_000,
         MockBatchRequester::new(batch_response),
@@ -106,6 +156,7 @@ async fn test_batch_request_exists() {
                 AggregateSignature::new(vec![u8::MAX].into(), None),
             ),
             tx,
+            None,
         )
         .await;
     assert!(result.is_some());
@@ -115,6 +166,101 @@ async fn test_batch_request_exists() {
     assert!(rx.try_recv().is_ok());
 }
 
+#[tokio::test]
+async fn test_batch_request_bounded_concurrent_requests() {
+    let batch = Batch::new(
+        BatchId::new_for_test(1),
+        vec![],
+        1,
+        1,
+        AccountAddress::random(),
+        0,
+    );
+    let proof = ProofOfStore::new(
+        batch.batch_info().clone(),
+        AggregateSignature::new(vec![u8::MAX].into(), None),
+    );
+
+    let validator_signer = ValidatorSigner::random(None);
+    for max_concurrent_requests in [1, 2] {
+        // Only `max_concurrent_requests` requests are admitted at a time, the rest wait for a
+        // permit
+        let network_sender = MockBatchRequester::new_slow(
+            BatchResponse::Batch(batch.clone()),
+            Duration::from_millis(50),
+        );
+        let batch_requester = BatchRequester::new(
+            1,
+            AccountAddress::random(),
+            1,
+            2,
+            0,
+            10,
+            max_concurrent_requests,
+            1_000,
+            1_000,
+            network_sender.clone(),
+            ValidatorVerifier::new_single(validator_signer.author(), validator_signer.public_key()),
+        );
+
+        let (tx1, mut rx1) = tokio::sync::oneshot::channel();
+        let (tx2, mut rx2) = tokio::sync::oneshot::channel();
+        let (result1, result2) = tokio::join!(
+            batch_requester.request_batch(proof.clone(), tx1, None),
+            batch_requester.request_batch(proof.clone(), tx2, None),
+        );
+        assert!(result1.is_some());
+        assert!(result2.is_some());
+        assert!(rx1.try_recv().is_ok());
+        assert!(rx2.try_recv().is_ok());
+        assert_eq!(network_sender.peak_in_flight(), max_concurrent_requests);
+    }
+}
+
+#[tokio::test]
+async fn test_batch_request_per_peer_retries() {
+    let batch = Batch::new(
+        BatchId::new_for_test(1),
+        vec![],
+        1,
+        1,
+        AccountAddress::random(),
+        0,
+    );
+    let validator_signer = ValidatorSigner::random(None);
+
+    for (per_peer_retries, expect_batch) in [(0, false), (1, true)] {
+        let (tx, mut rx) = tokio::sync::oneshot::channel();
+        // A single attempt against a single peer, which fails the first request
+        let batch_requester = BatchRequester::new(
+            1,
+            AccountAddress::random(),
+            1,
+            1,
+            per_peer_retries,
+            10,
+            0,
+            100,
+            1_000,
+            MockBatchRequester::new_flaky(BatchResponse::Batch(batch.clone()), 1),
+            ValidatorVerifier::new_single(validator_signer.author(), validator_signer.public_key()),
+        );
+
+        let result = batch_requester
+            .request_batch(
+                ProofOfStore::new(
+                    batch.batch_info().clone(),
+                    AggregateSignature::new(vec![u8::MAX].into(), None),
+                ),
+                tx,
+                None,
+            )
+            .await;
+        assert_eq!(result.is_some(), expect_batch);
+        assert_eq!(rx.try_recv().unwrap().is_ok(), expect_batch);
+    }
+}
+
 fn create_ledger_info_with_timestamp(
     timestamp: u64,
 ) -> (LedgerInfoWithSignatures, ValidatorVerifier) {
@@ -187,6 +333,9 @@ async fn test_batch_request_not_exists_not_expired() {
         AccountAddress::random(),
         1,
         2,
+        0,
+        10,
+        0,
         retry_interval_ms,
         1_000,
         MockBatchRequester::new(batch_response),
@@ -201,11 +350,17 @@ async fn test_batch_request_not_exists_not_expired() {
                 AggregateSignature::new(vec![u8::MAX].into(), None),
             ),
             tx,
+            None,
         )
         .await;
     let request_duration = request_start.elapsed();
     assert!(result.is_none());
-    assert!(rx.try_recv().is_ok());
+    assert_eq!(
+        rx.try_recv().unwrap(),
+        Err(ExecutorError::CouldNotGetData(
+            CouldNotGetDataReason::AllPeersExhausted
+        ))
+    );
     // Retried at least once
     assert!(request_duration > Duration::from_millis(retry_interval_ms as u64));
 }
@@ -234,6 +389,9 @@ async fn test_batch_request_not_exists_expired() {
         AccountAddress::random(),
         1,
         2,
+        0,
+        10,
+        0,
         retry_interval_ms,
         1_000,
         MockBatchRequester::new(batch_response),
@@ -248,11 +406,76 @@ async fn test_batch_request_not_exists_expired() {
                 AggregateSignature::new(vec![u8::MAX].into(), None),
             ),
             tx,
+            None,
         )
         .await;
     let request_duration = request_start.elapsed();
     assert!(result.is_none());
-    assert!(rx.try_recv().is_ok());
+    assert_eq!(
+        rx.try_recv().unwrap(),
+        Err(ExecutorError::CouldNotGetData(
+            CouldNotGetDataReason::Expired
+        ))
+    );
     // No retry because of short-circuiting of expired batch
     assert!(request_duration < Duration::from_millis(retry_interval_ms as u64));
 }
+
+#[tokio::test]
+async fn test_batch_request_stops_retrying_near_deadline() {
+    let retry_interval_ms = 1_000;
+    let rpc_timeout_ms = 1_000;
+    let expiration = 10_000;
+
+    // Batch has not expired yet, so the requester would keep retrying until the retry limit
+    let (ledger_info_with_signatures, validator_verifier) =
+        create_ledger_info_with_timestamp(expiration - 1);
+
+    let batch = Batch::new(
+        BatchId::new_for_test(1),
+        vec![],
+        1,
+        expiration,
+        AccountAddress::random(),
+        0,
+    );
+    let (tx, mut rx) = tokio::sync::oneshot::channel();
+    let batch_response = BatchResponse::NotFound(ledger_info_with_signatures);
+    let batch_requester = BatchRequester::new(
+        1,
+        AccountAddress::random(),
+        1,
+        10,
+        0,
+        10,
+        0,
+        retry_interval_ms,
+        rpc_timeout_ms,
+        MockBatchRequester::new(batch_response),
+        validator_verifier,
+    );
+
+    let request_start = Instant::now();
+    // Only the first round of requests can complete before the deadline
+    let deadline = request_start + Duration::from_millis(rpc_timeout_ms as u64 + 500);
+    let result = batch_requester
+        .request_batch(
+            ProofOfStore::new(
+                batch.batch_info().clone(),
+                AggregateSignature::new(vec![u8::MAX].into(), None),
+            ),
+            tx,
+            Some(deadline),
+        )
+        .await;
+    let request_duration = request_start.elapsed();
+    assert!(result.is_none());
+    assert_eq!(
+        rx.try_recv().unwrap(),
+        Err(ExecutorError::CouldNotGetData(
+            CouldNotGetDataReason::Timeout
+        ))
+    );
+    // Gave up at the first retry instead of exhausting the retry limit
+    assert!(request_duration < Duration::from_millis(2 * retry_interval_ms as u64));
+}
@@ -0,0 +1,76 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::quorum_store::bounded_futures_unordered::BoundedFuturesUnordered;
+use futures::StreamExt;
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+#[tokio::test]
+async fn test_bounded_futures_unordered_in_flight_never_exceeds_capacity() {
+    for (capacity, num_futures) in [(1, 5), (3, 10), (10, 4)] {
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+        let mut executor = BoundedFuturesUnordered::new(capacity);
+        for i in 0..num_futures {
+            let in_flight = in_flight.clone();
+            let max_in_flight = max_in_flight.clone();
+            executor.push(async move {
+                let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_flight.fetch_max(current, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                i
+            });
+        }
+
+        let mut results: Vec<_> = executor.collect().await;
+        results.sort();
+        assert_eq!(results, (0..num_futures).collect::<Vec<_>>());
+        assert_eq!(
+            max_in_flight.load(Ordering::SeqCst),
+            capacity.min(num_futures)
+        );
+        assert_eq!(in_flight.load(Ordering::SeqCst), 0);
+    }
+}
+
+#[tokio::test]
+async fn test_bounded_futures_unordered_push_while_polling() {
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let max_in_flight = Arc::new(AtomicUsize::new(0));
+    let capacity = 2;
+    let mut executor = BoundedFuturesUnordered::new(capacity);
+    let make_future = |remaining: usize| {
+        let in_flight = in_flight.clone();
+        let max_in_flight = max_in_flight.clone();
+        async move {
+            let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            max_in_flight.fetch_max(current, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+            remaining
+        }
+    };
+    for _ in 0..3 {
+        executor.push(make_future(2));
+    }
+
+    // Every completed future queues another one, until each chain runs out.
+    let mut num_completed = 0;
+    while let Some(remaining) = executor.next().await {
+        num_completed += 1;
+        if remaining > 0 {
+            executor.push(make_future(remaining - 1));
+        }
+        assert!(in_flight.load(Ordering::SeqCst) <= capacity);
+    }
+    assert!(executor.is_empty());
+    assert_eq!(num_completed, 9);
+    assert_eq!(max_in_flight.load(Ordering::SeqCst), capacity);
+}
@@ -19,7 +19,7 @@ use aptos_types::{
     transaction::SignedTransaction, validator_verifier::random_validator_verifier, PeerId,
 };
 use mini_moka::sync::Cache;
-use std::sync::Arc;
+use std::{sync::Arc, time::Instant};
 use tokio::sync::{mpsc::channel, oneshot::Receiver};
 
 pub struct MockBatchReader {
@@ -31,7 +31,11 @@ impl BatchReader for MockBatchReader {
         Some(self.peer)
     }
 
-    fn get_batch(&self, _proof: ProofOfStore) -> Receiver<ExecutorResult<Vec<SignedTransaction>>> {
+    fn get_batch(
+        &self,
+        _proof: ProofOfStore,
+        _deadline: Option<Instant>,
+    ) -> Receiver<ExecutorResult<Vec<SignedTransaction>>> {
         unimplemented!()
     }
 
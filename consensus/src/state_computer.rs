@@ -10,13 +10,14 @@ use crate::{
     execution_pipeline::ExecutionPipeline,
     monitor,
     payload_manager::PayloadManager,
-    state_replication::{StateComputer, StateComputerCommitCallBackType},
+    state_replication::{CommitObserver, StateComputer, StateComputerCommitCallBackType},
     transaction_deduper::TransactionDeduper,
     transaction_filter::TransactionFilter,
     transaction_shuffler::TransactionShuffler,
     txn_notifier::TxnNotifier,
 };
 use anyhow::Result;
+use aptos_config::config::transaction_filter_type::Filter;
 use aptos_consensus_notifications::ConsensusNotificationSender;
 use aptos_consensus_types::{block::Block, common::Round, pipelined_block::PipelinedBlock};
 use aptos_crypto::HashValue;
@@ -90,6 +91,7 @@ pub struct ExecutionProxy {
     transaction_filter: Arc<TransactionFilter>,
     execution_pipeline: ExecutionPipeline,
     state: RwLock<Option<MutableState>>,
+    commit_observers: RwLock<Vec<CommitObserver>>,
 }
 
 impl ExecutionProxy {
@@ -125,9 +127,35 @@ impl ExecutionProxy {
             transaction_filter: Arc::new(txn_filter),
             execution_pipeline,
             state: RwLock::new(None),
+            commit_observers: RwLock::new(vec![]),
         }
     }
 
+    /// Stops the execution pipeline from starting new blocks until `resume_execution` is called.
+    /// Blocks that are already executing still finish.
+    pub fn pause_execution(&self) {
+        self.execution_pipeline.pause();
+    }
+
+    pub fn resume_execution(&self) {
+        self.execution_pipeline.resume();
+    }
+
+    pub fn is_execution_paused(&self) -> bool {
+        self.execution_pipeline.is_paused()
+    }
+
+    /// Returns the filter currently used to exclude transactions from blocks before execution.
+    pub fn active_filter(&self) -> &Filter {
+        self.transaction_filter.active_filter()
+    }
+
+    /// Registers an observer that is invoked on every commit, after the observers registered
+    /// before it and before the commit callback.
+    pub fn register_commit_observer(&self, observer: CommitObserver) {
+        self.commit_observers.write().push(observer);
+    }
+
     fn transactions_to_commit(
         &self,
         executed_block: &PipelinedBlock,
@@ -312,7 +340,11 @@ impl StateComputer for ExecutionProxy {
         .expect("spawn_blocking failed");
 
         let blocks = blocks.to_vec();
+        let commit_observers = self.commit_observers.read().clone();
         let wrapped_callback = move || {
+            for observer in commit_observers {
+                observer(&blocks, &finality_proof);
+            }
             callback(&blocks, finality_proof);
         };
         self.async_state_sync_notifier
@@ -427,6 +459,7 @@ async fn test_commit_sync_race() {
         on_chain_config::{TransactionDeduperType, TransactionShufflerType},
         transaction::{SignedTransaction, TransactionStatus},
     };
+    use std::sync::atomic::AtomicBool;
 
     struct RecordedCommit {
         time: Mutex<LogicalTime>,
@@ -459,6 +492,16 @@ async fn test_commit_sync_race() {
             todo!()
         }
 
+        fn execute_and_state_checkpoint_with_cancellation(
+            &self,
+            _block: ExecutableBlock,
+            _parent_block_id: HashValue,
+            _onchain_config: BlockExecutorConfigFromOnchain,
+            _cancelled: Arc<AtomicBool>,
+        ) -> ExecutorResult<StateCheckpointOutput> {
+            todo!()
+        }
+
         fn ledger_update(
             &self,
             _block_id: HashValue,
@@ -481,6 +524,10 @@ async fn test_commit_sync_race() {
             Ok(())
         }
 
+        fn smt_memory_usage(&self) -> usize {
+            0
+        }
+
         fn finish(&self) {}
     }
 
@@ -12,8 +12,8 @@ use aptos_consensus_notifications::{ConsensusNotificationSender, Error};
 use aptos_consensus_types::{block::Block, block_data::BlockData, pipelined_block::PipelinedBlock};
 use aptos_crypto::HashValue;
 use aptos_executor_types::{
-    state_checkpoint_output::StateCheckpointOutput, BlockExecutorTrait, ExecutorResult,
-    StateComputeResult,
+    state_checkpoint_output::StateCheckpointOutput, BlockExecutorTrait, ExecutorError,
+    ExecutorResult, StateComputeResult,
 };
 use aptos_infallible::Mutex;
 use aptos_types::{
@@ -26,7 +26,13 @@ use aptos_types::{
     validator_txn::ValidatorTransaction,
 };
 use futures_channel::oneshot;
-use std::sync::Arc;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 use tokio::runtime::Handle;
 
 struct DummyStateSyncNotifier {
@@ -112,6 +118,19 @@ impl BlockExecutorTrait for DummyBlockExecutor {
         Ok(StateCheckpointOutput::default())
     }
 
+    fn execute_and_state_checkpoint_with_cancellation(
+        &self,
+        block: ExecutableBlock,
+        parent_block_id: HashValue,
+        onchain_config: BlockExecutorConfigFromOnchain,
+        cancelled: Arc<AtomicBool>,
+    ) -> ExecutorResult<StateCheckpointOutput> {
+        if cancelled.load(Ordering::Relaxed) {
+            return Err(ExecutorError::Cancelled);
+        }
+        self.execute_and_state_checkpoint(block, parent_block_id, onchain_config)
+    }
+
     fn ledger_update(
         &self,
         _block_id: HashValue,
@@ -130,6 +149,10 @@ impl BlockExecutorTrait for DummyBlockExecutor {
         Ok(())
     }
 
+    fn smt_memory_usage(&self) -> usize {
+        0
+    }
+
     fn finish(&self) {}
 }
 
@@ -187,6 +210,72 @@ async fn schedule_compute_should_discover_validator_txns() {
     assert_eq!(&validator_txn_1, supposed_validator_txn_1);
 }
 
+#[tokio::test]
+async fn schedule_compute_should_execute_the_validator_txns_of_the_block() {
+    let executor = Arc::new(DummyBlockExecutor::new());
+
+    let execution_policy = ExecutionProxy::new(
+        executor.clone(),
+        Arc::new(DummyTxnNotifier {}),
+        Arc::new(DummyStateSyncNotifier::new()),
+        &Handle::current(),
+        TransactionFilter::new(Filter::empty()),
+    );
+
+    let validator_txn_0 = ValidatorTransaction::dummy(vec![0xFF; 99]);
+    let validator_txn_1 = ValidatorTransaction::dummy(vec![0xFF; 999]);
+
+    let block = Block::new_for_testing(
+        HashValue::zero(),
+        BlockData::dummy_with_validator_txns(vec![
+            validator_txn_0.clone(),
+            validator_txn_1.clone(),
+        ]),
+        None,
+    );
+
+    let epoch_state = EpochState::empty();
+
+    execution_policy.new_epoch(
+        &epoch_state,
+        Arc::new(PayloadManager::DirectMempool),
+        Arc::new(NoOpShuffler {}),
+        BlockExecutorConfigFromOnchain::new_no_block_limit(),
+        Arc::new(NoOpDeduper {}),
+        false,
+    );
+
+    // Ensure the dummy executor has received the txns.
+    let _ = execution_policy
+        .schedule_compute(&block, HashValue::zero(), None)
+        .await
+        .await;
+
+    // The block metadata txn comes first, followed by the validator txns.
+    let expected = ExecutableBlock::for_test(block.id(), vec![
+        Transaction::ValidatorTransaction(validator_txn_0),
+        Transaction::ValidatorTransaction(validator_txn_1),
+    ]);
+
+    // Get the txns from the view of the dummy executor.
+    let blocks_received = executor.blocks_received.lock();
+    let received = &blocks_received[0];
+    assert_eq!(received.block_id, expected.block_id);
+    let txns = received.transactions.clone().into_txns();
+    assert_eq!(
+        txns[1..]
+            .iter()
+            .map(|txn| txn.expect_valid())
+            .collect::<Vec<_>>(),
+        expected
+            .transactions
+            .into_txns()
+            .iter()
+            .map(|txn| txn.expect_valid())
+            .collect::<Vec<_>>(),
+    );
+}
+
 #[tokio::test]
 async fn commit_should_discover_validator_txns() {
     let state_sync_notifier = Arc::new(DummyStateSyncNotifier::new());
@@ -262,3 +351,130 @@ async fn commit_should_discover_validator_txns() {
     assert_eq!(&validator_txn_0, supposed_validator_txn_0);
     assert_eq!(&validator_txn_1, supposed_validator_txn_1);
 }
+
+#[tokio::test]
+async fn commit_should_invoke_observers_in_order() {
+    let execution_policy = ExecutionProxy::new(
+        Arc::new(DummyBlockExecutor::new()),
+        Arc::new(DummyTxnNotifier {}),
+        Arc::new(DummyStateSyncNotifier::new()),
+        &tokio::runtime::Handle::current(),
+        TransactionFilter::new(Filter::empty()),
+    );
+    execution_policy.new_epoch(
+        &EpochState::empty(),
+        Arc::new(PayloadManager::DirectMempool),
+        Arc::new(NoOpShuffler {}),
+        BlockExecutorConfigFromOnchain::new_no_block_limit(),
+        Arc::new(NoOpDeduper {}),
+        false,
+    );
+
+    let invocations = Arc::new(Mutex::new(vec![]));
+    for observer_id in 0..3 {
+        let invocations = invocations.clone();
+        execution_policy.register_commit_observer(Arc::new(
+            move |blocks: &[Arc<PipelinedBlock>], _: &LedgerInfoWithSignatures| {
+                invocations.lock().push((observer_id, blocks.len()));
+            },
+        ));
+    }
+
+    let block = Block::new_for_testing(
+        HashValue::zero(),
+        BlockData::dummy_with_validator_txns(vec![]),
+        None,
+    );
+    let blocks = vec![Arc::new(PipelinedBlock::new(
+        block,
+        vec![],
+        StateComputeResult::new_dummy_with_compute_status(vec![TransactionStatus::Keep(
+            ExecutionStatus::Success,
+        )]),
+    ))];
+
+    let (tx, rx) = oneshot::channel::<()>();
+    let callback_invocations = invocations.clone();
+    let callback = Box::new(
+        move |blocks: &[Arc<PipelinedBlock>], _: LedgerInfoWithSignatures| {
+            callback_invocations.lock().push((usize::MAX, blocks.len()));
+            tx.send(()).unwrap();
+        },
+    );
+    execution_policy
+        .commit(
+            blocks.as_slice(),
+            LedgerInfoWithSignatures::new(LedgerInfo::dummy(), AggregateSignature::empty()),
+            callback,
+        )
+        .await
+        .unwrap();
+    rx.await.unwrap();
+
+    assert_eq!(*invocations.lock(), vec![
+        (0, 1),
+        (1, 1),
+        (2, 1),
+        (usize::MAX, 1)
+    ]);
+}
+
+#[tokio::test]
+async fn active_filter_should_return_configured_filter() {
+    let filter = Filter::empty()
+        .add_deny_block_id(HashValue::random())
+        .add_allow_block_timestamp_greater_than(100);
+    let execution_policy = ExecutionProxy::new(
+        Arc::new(DummyBlockExecutor::new()),
+        Arc::new(DummyTxnNotifier {}),
+        Arc::new(DummyStateSyncNotifier::new()),
+        &tokio::runtime::Handle::current(),
+        TransactionFilter::new(filter.clone()),
+    );
+    assert_eq!(execution_policy.active_filter(), &filter);
+}
+
+#[tokio::test]
+async fn schedule_compute_should_wait_while_execution_is_paused() {
+    let executor = Arc::new(DummyBlockExecutor::new());
+    let execution_policy = ExecutionProxy::new(
+        executor.clone(),
+        Arc::new(DummyTxnNotifier {}),
+        Arc::new(DummyStateSyncNotifier::new()),
+        &Handle::current(),
+        TransactionFilter::new(Filter::empty()),
+    );
+    execution_policy.new_epoch(
+        &EpochState::empty(),
+        Arc::new(PayloadManager::DirectMempool),
+        Arc::new(NoOpShuffler {}),
+        BlockExecutorConfigFromOnchain::new_no_block_limit(),
+        Arc::new(NoOpDeduper {}),
+        false,
+    );
+
+    execution_policy.pause_execution();
+    assert!(execution_policy.is_execution_paused());
+
+    let block = Block::new_for_testing(
+        HashValue::zero(),
+        BlockData::dummy_with_validator_txns(vec![]),
+        None,
+    );
+    let mut result_fut = execution_policy
+        .schedule_compute(&block, HashValue::zero(), None)
+        .await;
+
+    // The block is queued, but not executed while the pipeline is paused.
+    assert!(
+        tokio::time::timeout(Duration::from_millis(200), &mut result_fut)
+            .await
+            .is_err()
+    );
+    assert!(executor.blocks_received.lock().is_empty());
+
+    execution_policy.resume_execution();
+    assert!(!execution_policy.is_execution_paused());
+    result_fut.await.unwrap();
+    assert_eq!(executor.blocks_received.lock().len(), 1);
+}
@@ -22,6 +22,11 @@ use std::sync::Arc;
 pub type StateComputerCommitCallBackType =
     Box<dyn FnOnce(&[Arc<PipelinedBlock>], LedgerInfoWithSignatures) + Send + Sync>;
 
+/// An observer invoked with the committed blocks and their ledger info every time blocks are
+/// committed, in addition to the commit callback.
+pub type CommitObserver =
+    Arc<dyn Fn(&[Arc<PipelinedBlock>], &LedgerInfoWithSignatures) + Send + Sync>;
+
 /// While Consensus is managing proposed blocks, `StateComputer` is managing the results of the
 /// (speculative) execution of their payload.
 /// StateComputer is using proposed block ids for identifying the transactions.
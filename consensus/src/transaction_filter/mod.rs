@@ -14,26 +14,43 @@ impl TransactionFilter {
         Self { filter }
     }
 
+    /// Returns the filter whose rules are applied to block transactions.
+    pub fn active_filter(&self) -> &Filter {
+        &self.filter
+    }
+
     pub fn filter(
         &self,
         block_id: HashValue,
         timestamp: u64,
         txns: Vec<SignedTransaction>,
     ) -> Vec<SignedTransaction> {
+        let (allowed_txns, _filtered_txns) = self.partition(block_id, timestamp, txns);
+        allowed_txns
+    }
+
+    /// Splits the transactions into those allowed and those filtered out by the active filter,
+    /// in a single pass and preserving their order. Lets proposers drop transactions that
+    /// would be filtered out during execution before they take up space in a block.
+    pub fn partition(
+        &self,
+        block_id: HashValue,
+        timestamp: u64,
+        txns: Vec<SignedTransaction>,
+    ) -> (Vec<SignedTransaction>, Vec<SignedTransaction>) {
         // Special case for no filter to avoid unnecessary iteration through all transactions in the default case
         if self.filter.is_empty() {
-            return txns;
+            return (txns, vec![]);
         }
         txns.into_iter()
-            .filter(|txn| self.filter.allows(block_id, timestamp, txn))
-            .collect()
+            .partition(|txn| self.filter.allows(block_id, timestamp, txn))
     }
 }
 
 #[cfg(test)]
 mod test {
     use crate::transaction_filter::TransactionFilter;
-    use aptos_config::config::transaction_filter_type::Filter;
+    use aptos_config::config::transaction_filter_type::{Filter, FilterDecision};
     use aptos_crypto::{ed25519::Ed25519PrivateKey, HashValue, PrivateKey, SigningKey, Uniform};
     use aptos_types::{
         chain_id::ChainId,
@@ -110,6 +127,100 @@ mod test {
         assert_eq!(filtered_txns, txns);
     }
 
+    #[test]
+    fn test_partition() {
+        let txns = get_transactions();
+        let block_id = HashValue::random();
+
+        let no_filter = TransactionFilter::new(Filter::empty());
+        let (allowed_txns, filtered_txns) = no_filter.partition(block_id, 0, txns.clone());
+        assert_eq!(allowed_txns, txns);
+        assert_eq!(filtered_txns, vec![]);
+
+        let mixed_filter = TransactionFilter::new(
+            Filter::empty()
+                .add_deny_sender(txns[1].sender())
+                .add_deny_module_address(get_module_address(&txns[5])),
+        );
+        let (allowed_txns, filtered_txns) = mixed_filter.partition(block_id, 0, txns.clone());
+        assert_eq!(allowed_txns, vec![
+            txns[0].clone(),
+            txns[2].clone(),
+            txns[3].clone(),
+            txns[4].clone(),
+            txns[6].clone(),
+        ]);
+        assert_eq!(filtered_txns, vec![txns[1].clone(), txns[5].clone()]);
+        assert_eq!(allowed_txns, mixed_filter.filter(block_id, 0, txns.clone()));
+
+        let all_filter = TransactionFilter::new(Filter::empty().add_deny_all());
+        let (allowed_txns, filtered_txns) = all_filter.partition(block_id, 0, txns.clone());
+        assert_eq!(allowed_txns, vec![]);
+        assert_eq!(filtered_txns, txns);
+    }
+
+    fn decide_all(
+        filter: &TransactionFilter,
+        block_id: HashValue,
+        txns: &[SignedTransaction],
+    ) -> Vec<FilterDecision> {
+        txns.iter()
+            .map(|txn| filter.active_filter().decide(block_id, 0, txn))
+            .collect()
+    }
+
+    #[test]
+    fn test_decisions() {
+        let txns = get_transactions();
+        let block_id = HashValue::random();
+
+        let no_filter = TransactionFilter::new(Filter::empty());
+        let decisions = decide_all(&no_filter, block_id, &txns);
+        assert_eq!(decisions.len(), txns.len());
+        assert!(decisions.iter().all(|decision| *decision
+            == FilterDecision {
+                allowed: true,
+                matched_rule: None,
+            }));
+
+        let filter = TransactionFilter::new(
+            Filter::empty()
+                .add_deny_sender(txns[1].sender())
+                .add_allow_module_address(get_module_address(&txns[4]))
+                .add_deny_module_address(get_module_address(&txns[5])),
+        );
+        let decisions = decide_all(&filter, block_id, &txns);
+        assert_eq!(decisions[0], FilterDecision {
+            allowed: true,
+            matched_rule: None,
+        });
+        assert_eq!(decisions[1], FilterDecision {
+            allowed: false,
+            matched_rule: Some(format!("Deny(Sender({:?}))", txns[1].sender())),
+        });
+        assert_eq!(decisions[4], FilterDecision {
+            allowed: true,
+            matched_rule: Some(format!(
+                "Allow(ModuleAddress({:?}))",
+                get_module_address(&txns[4])
+            )),
+        });
+        assert_eq!(decisions[5], FilterDecision {
+            allowed: false,
+            matched_rule: Some(format!(
+                "Deny(ModuleAddress({:?}))",
+                get_module_address(&txns[5])
+            )),
+        });
+        let allowed: Vec<_> = txns
+            .iter()
+            .zip(&decisions)
+            .filter(|(_, decision)| decision.allowed)
+            .map(|(txn, _)| txn.clone())
+            .collect();
+        assert_eq!(allowed, filter.filter(block_id, 0, txns.clone()));
+    }
+
     #[test]
     fn test_all_filter() {
         let txns = get_transactions();
@@ -5,11 +5,39 @@ use crate::{
     counters::{TXN_DEDUP_FILTERED, TXN_DEDUP_SECONDS},
     transaction_deduper::TransactionDeduper,
 };
+use aptos_crypto::HashValue;
 use aptos_experimental_runtimes::thread_manager::optimal_min_len;
-use aptos_types::transaction::SignedTransaction;
+use aptos_types::transaction::{authenticator::TransactionAuthenticator, SignedTransaction};
 use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
 
+/// The key used by `TxnHashAndAuthenticatorDeduper` to detect duplicates: of all transactions in a
+/// block with the same key, only the first one is kept.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DedupKey {
+    txn_hash: HashValue,
+    authenticator: TransactionAuthenticator,
+}
+
+impl DedupKey {
+    pub fn txn_hash(&self) -> HashValue {
+        self.txn_hash
+    }
+
+    pub fn authenticator(&self) -> &TransactionAuthenticator {
+        &self.authenticator
+    }
+}
+
+/// Computes the key under which the transaction is deduplicated, so that clients can tell whether
+/// two transactions would be treated as duplicates of each other.
+pub fn dedup_key(txn: &SignedTransaction) -> DedupKey {
+    DedupKey {
+        txn_hash: txn.committed_hash(),
+        authenticator: txn.authenticator(),
+    }
+}
+
 /// An implementation of TransactionDeduper. Duplicate filtering is done using the pair
 /// (raw_txn.hash(), authenticator). Both the hash and signature are required because dedup
 /// happens before signatures are verified and transaction prologue is checked. (So, e.g., a bad
@@ -60,26 +88,26 @@ impl TransactionDeduper for TxnHashAndAuthenticatorDeduper {
 
         let num_txns = transactions.len();
 
-        let hash_and_authenticators: Vec<_> = possible_duplicates
+        let dedup_keys: Vec<_> = possible_duplicates
             .into_par_iter()
             .zip(&transactions)
             .with_min_len(optimal_min_len(num_txns, 48))
             .map(|(need_hash, txn)| match need_hash {
-                true => Some((txn.committed_hash(), txn.authenticator())),
+                true => Some(dedup_key(txn)),
                 false => None,
             })
             .collect();
 
         // TODO: Possibly parallelize. See struct comment.
-        let mut seen_hashes = HashSet::new();
+        let mut seen_keys = HashSet::new();
         let mut num_duplicates: usize = 0;
-        let filtered: Vec<_> = hash_and_authenticators
+        let filtered: Vec<_> = dedup_keys
             .into_iter()
             .zip(transactions)
-            .filter_map(|(maybe_hash, txn)| match maybe_hash {
+            .filter_map(|(maybe_key, txn)| match maybe_key {
                 None => Some(txn),
-                Some(hash_and_authenticator) => {
-                    if seen_hashes.insert(hash_and_authenticator) {
+                Some(key) => {
+                    if seen_keys.insert(key) {
                         Some(txn)
                     } else {
                         num_duplicates += 1;
@@ -104,7 +132,7 @@ impl TxnHashAndAuthenticatorDeduper {
 mod tests {
     use crate::{
         transaction_deduper::TransactionDeduper,
-        txn_hash_and_authenticator_deduper::TxnHashAndAuthenticatorDeduper,
+        txn_hash_and_authenticator_deduper::{dedup_key, TxnHashAndAuthenticatorDeduper},
     };
     use aptos_cached_packages::aptos_stdlib;
     use aptos_crypto::ed25519::{Ed25519PrivateKey, Ed25519PublicKey};
@@ -253,6 +281,33 @@ mod tests {
         assert_eq!(txns, deduped_txns);
     }
 
+    #[test]
+    fn test_dedup_key() {
+        let sender = Account::new();
+        let bad_signer = Account::new();
+
+        let txn = empty_txn(sender.addr, 0, 100)
+            .sign(&sender.privkey, sender.pubkey.clone())
+            .unwrap()
+            .into_inner();
+        assert_eq!(dedup_key(&txn), dedup_key(&txn.clone()));
+        assert_eq!(dedup_key(&txn).txn_hash(), txn.committed_hash());
+
+        // Same raw txn with a different authenticator is not a duplicate.
+        let txn_bad_signer = empty_txn(sender.addr, 0, 100)
+            .sign(&bad_signer.privkey, bad_signer.pubkey.clone())
+            .unwrap()
+            .into_inner();
+        assert_ne!(dedup_key(&txn), dedup_key(&txn_bad_signer));
+
+        // Different raw txn with the same sender and sequence number is not a duplicate.
+        let txn_other_price = empty_txn(sender.addr, 0, 200)
+            .sign(&sender.privkey, sender.pubkey)
+            .unwrap()
+            .into_inner();
+        assert_ne!(dedup_key(&txn), dedup_key(&txn_other_price));
+    }
+
     // The perf tests are simple micro-benchmarks and just output results without checking for regressions
     static PERF_TXN_PER_BLOCK: usize = 10_000;
 
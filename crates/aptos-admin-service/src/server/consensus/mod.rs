@@ -5,6 +5,7 @@ use anyhow::{bail, Error};
 use aptos_consensus::{
     persistent_liveness_storage::PersistentLivenessStorage,
     quorum_store::quorum_store_db::QuorumStoreStorage, util::db_tool::extract_txns_from_block,
+    ExecutionProxy,
 };
 use aptos_crypto::HashValue;
 use aptos_logger::info;
@@ -71,6 +72,38 @@ pub async fn handle_dump_quorum_store_db_request(
     }
 }
 
+pub async fn handle_set_execution_paused_request(
+    _req: Request<Body>,
+    execution_proxy: Arc<ExecutionProxy>,
+    paused: bool,
+) -> hyper::Result<Response<Body>> {
+    if paused {
+        execution_proxy.pause_execution();
+    } else {
+        execution_proxy.resume_execution();
+    }
+    let paused = execution_proxy.is_execution_paused();
+    info!("Execution pipeline paused: {paused}.");
+    Ok(reply_with_status(
+        StatusCode::OK,
+        format!("Execution pipeline paused: {paused}."),
+    ))
+}
+
+pub async fn handle_dump_transaction_filter_request(
+    _req: Request<Body>,
+    execution_proxy: Arc<ExecutionProxy>,
+) -> hyper::Result<Response<Body>> {
+    info!("Dumping transaction filter.");
+
+    let mut body = String::new();
+    for rule in execution_proxy.active_filter().rules() {
+        body.push_str(&format!("{rule:?}\n"));
+    }
+    let headers: Vec<(_, HeaderValue)> = vec![(CONTENT_LENGTH, HeaderValue::from(body.len()))];
+    Ok(reply_with(headers, body))
+}
+
 pub async fn handle_dump_block_request(
     req: Request<Body>,
     consensus_db: Arc<dyn PersistentLivenessStorage>,
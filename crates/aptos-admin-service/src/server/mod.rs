@@ -4,6 +4,7 @@
 use aptos_config::config::{AuthenticationConfig, NodeConfig};
 use aptos_consensus::{
     persistent_liveness_storage::StorageWriteProxy, quorum_store::quorum_store_db::QuorumStoreDB,
+    ExecutionProxy,
 };
 use aptos_infallible::RwLock;
 use aptos_logger::info;
@@ -34,6 +35,7 @@ pub struct Context {
     aptos_db: RwLock<Option<Arc<DbReaderWriter>>>,
     consensus_db: RwLock<Option<Arc<StorageWriteProxy>>>,
     quorum_store_db: RwLock<Option<Arc<QuorumStoreDB>>>,
+    execution_proxy: RwLock<Option<Arc<ExecutionProxy>>>,
 }
 
 impl Context {
@@ -49,6 +51,10 @@ impl Context {
         *self.consensus_db.write() = Some(consensus_db);
         *self.quorum_store_db.write() = Some(quorum_store_db);
     }
+
+    fn set_execution_proxy(&self, execution_proxy: Arc<ExecutionProxy>) {
+        *self.execution_proxy.write() = Some(execution_proxy);
+    }
 }
 
 pub struct AdminService {
@@ -107,6 +113,10 @@ impl AdminService {
             .set_consensus_dbs(consensus_db, quorum_store_db)
     }
 
+    pub fn set_execution_proxy(&self, execution_proxy: Arc<ExecutionProxy>) {
+        self.context.set_execution_proxy(execution_proxy)
+    }
+
     fn start(&self, address: SocketAddr, enabled: bool) {
         let context = self.context.clone();
         self.runtime.spawn(async move {
@@ -210,6 +220,40 @@ impl AdminService {
                     ))
                 }
             },
+            (hyper::Method::GET, "/debug/consensus/transaction_filter") => {
+                let execution_proxy = context.execution_proxy.read().clone();
+                if let Some(execution_proxy) = execution_proxy {
+                    consensus::handle_dump_transaction_filter_request(req, execution_proxy).await
+                } else {
+                    Ok(reply_with_status(
+                        StatusCode::NOT_FOUND,
+                        "Execution proxy is not available.",
+                    ))
+                }
+            },
+            (hyper::Method::POST, "/debug/consensus/pause_execution") => {
+                let execution_proxy = context.execution_proxy.read().clone();
+                if let Some(execution_proxy) = execution_proxy {
+                    consensus::handle_set_execution_paused_request(req, execution_proxy, true).await
+                } else {
+                    Ok(reply_with_status(
+                        StatusCode::NOT_FOUND,
+                        "Execution proxy is not available.",
+                    ))
+                }
+            },
+            (hyper::Method::POST, "/debug/consensus/resume_execution") => {
+                let execution_proxy = context.execution_proxy.read().clone();
+                if let Some(execution_proxy) = execution_proxy {
+                    consensus::handle_set_execution_paused_request(req, execution_proxy, false)
+                        .await
+                } else {
+                    Ok(reply_with_status(
+                        StatusCode::NOT_FOUND,
+                        "Execution proxy is not available.",
+                    ))
+                }
+            },
             _ => Ok(reply_with_status(StatusCode::NOT_FOUND, "Not found.")),
         }
     }
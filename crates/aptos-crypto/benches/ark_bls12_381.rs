@@ -5,7 +5,7 @@
 extern crate criterion;
 
 use crate::bench_utils::{
-    bench_function_add, bench_function_clone, bench_function_deser_comp,
+    bench_function_add, bench_function_bit, bench_function_clone, bench_function_deser_comp,
     bench_function_deser_uncomp, bench_function_div, bench_function_double, bench_function_eq,
     bench_function_from_u64, bench_function_inv, bench_function_mul, bench_function_neg,
     bench_function_pow_u256, bench_function_serialize_uncomp, bench_function_square,
@@ -14,8 +14,8 @@ use crate::bench_utils::{
 use aptos_crypto::test_utils::random_bytes;
 use ark_bls12_381::{Fq12, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
 use ark_ec::{
-    hashing::HashToCurve, pairing::Pairing, short_weierstrass::Projective, AffineRepr, CurveGroup,
-    Group,
+    hashing::HashToCurve, pairing::Pairing, scalar_mul::glv::GLVConfig,
+    short_weierstrass::Projective, AffineRepr, CurveGroup, Group,
 };
 use ark_ff::{One, UniformRand, Zero};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
@@ -54,6 +54,7 @@ fn bench_group(c: &mut Criterion) {
     let mut group = c.benchmark_group("ark_bls12_381");
 
     group.bench_function("fr_add", bench_function_add::<Fr>);
+    group.bench_function("fr_bit", bench_function_bit::<Fr>);
 
     group.bench_function("fr_deser", bench_function_deser_uncomp::<Fr>);
 
@@ -279,6 +280,19 @@ fn bench_group(c: &mut Criterion) {
         })
     });
 
+    group.bench_function("g1_proj_glv_scalar_mul", move |b| {
+        b.iter_with_setup(
+            || {
+                let p = rand!(G1Projective);
+                let k = rand!(Fr);
+                (p, k)
+            },
+            |(p, k)| {
+                let _q = ark_bls12_381::g1::Config::glv_mul_projective(p, k);
+            },
+        )
+    });
+
     group.bench_function("g1_proj_infinity", move |b| {
         b.iter(|| {
             let _res = G1Projective::zero();
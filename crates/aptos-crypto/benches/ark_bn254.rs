@@ -5,7 +5,7 @@
 extern crate criterion;
 
 use crate::bench_utils::{
-    bench_function_add, bench_function_clone, bench_function_deser_comp,
+    bench_function_add, bench_function_bit, bench_function_clone, bench_function_deser_comp,
     bench_function_deser_uncomp, bench_function_div, bench_function_double, bench_function_eq,
     bench_function_from_u64, bench_function_inv, bench_function_mul, bench_function_neg,
     bench_function_pow_u256, bench_function_serialize_uncomp, bench_function_square,
@@ -52,6 +52,7 @@ fn bench_group(c: &mut Criterion) {
     group.bench_function("groth16/verify", bench_groth16_verify);
 
     group.bench_function("fr_add", bench_function_add::<Fr>);
+    group.bench_function("fr_bit", bench_function_bit::<Fr>);
     group.bench_function("fr_clone", bench_function_clone::<Fr>);
     group.bench_function("fr_deser", bench_function_deser_uncomp::<Fr>);
     group.bench_function("fr_div", bench_function_div::<Fr>);
@@ -477,6 +478,43 @@ fn bench_group(c: &mut Criterion) {
         });
     }
 
+    let normalize_batch_max_num_points = 100;
+    for num_points in (0..normalize_batch_max_num_points)
+        .step_by(normalize_batch_max_num_points / linear_regression_max_num_datapoints)
+    {
+        group.bench_function(
+            BenchmarkId::new("g1_proj_normalize_batch", num_points),
+            |b| {
+                b.iter_with_setup(
+                    || {
+                        (0..num_points)
+                            .map(|_i| rand!(G1Projective))
+                            .collect::<Vec<_>>()
+                    },
+                    |points| {
+                        let _affine_points = G1Projective::normalize_batch(&points);
+                    },
+                );
+            },
+        );
+
+        group.bench_function(
+            BenchmarkId::new("g2_proj_normalize_batch", num_points),
+            |b| {
+                b.iter_with_setup(
+                    || {
+                        (0..num_points)
+                            .map(|_i| rand!(G2Projective))
+                            .collect::<Vec<_>>()
+                    },
+                    |points| {
+                        let _affine_points = G2Projective::normalize_batch(&points);
+                    },
+                );
+            },
+        );
+    }
+
     for num_entries in msm_all_bench_cases() {
         group.bench_function(BenchmarkId::new("g1_affine_msm", num_entries), |b| {
             b.iter_with_setup(
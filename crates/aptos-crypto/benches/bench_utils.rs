@@ -1,7 +1,7 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use ark_ff::{BigInteger256, Field};
+use ark_ff::{BigInteger, BigInteger256, Field, PrimeField};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_std::{test_rng, UniformRand};
 use criterion::Bencher;
@@ -20,6 +20,15 @@ pub fn bench_function_add<T: Add + UniformRand>(b: &mut Bencher) {
     )
 }
 
+pub fn bench_function_bit<T: PrimeField>(b: &mut Bencher) {
+    b.iter_with_setup(
+        || rand::<T>(),
+        |e| {
+            let _bit = e.into_bigint().get_bit(T::MODULUS_BIT_SIZE as usize - 1);
+        },
+    )
+}
+
 pub fn bench_function_clone<T: Clone + UniformRand>(b: &mut Bencher) {
     b.iter_with_setup(
         || rand::<T>(),
@@ -205,6 +205,11 @@ pub fn convert_write_set(write_set: &WriteSet) -> transaction::WriteSet {
                 });
             (write_set_type, Some(write_set))
         },
+        // The protobuf schema can't represent multi-signer write set scripts yet.
+        WriteSet::MultiSignerScriptWriteSet(_) => (
+            transaction::write_set::WriteSetType::Unspecified as i32,
+            None,
+        ),
     };
     transaction::WriteSet {
         write_set_type,
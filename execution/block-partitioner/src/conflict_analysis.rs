@@ -0,0 +1,76 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_types::transaction::analyzed_transaction::{AnalyzedTransaction, StorageLocation};
+use std::collections::HashMap;
+
+/// An estimate of the write-write conflicts within a block, computed from the transactions'
+/// read/write hints before the block is executed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConflictReport {
+    pub num_txns: usize,
+    /// Number of transactions that write at least one location already written by an earlier
+    /// transaction in the block.
+    pub num_conflicting_txns: usize,
+    /// Locations written by more than one transaction, with the number of writers, most contended
+    /// first.
+    pub hot_locations: Vec<(StorageLocation, usize)>,
+    /// Length of the longest chain of transactions in which every transaction writes a location
+    /// written by the previous one. These transactions cannot commit in parallel.
+    pub critical_path_len: usize,
+    /// `num_txns / critical_path_len`, i.e., the parallelism the block allows if all conflicts
+    /// are write-write conflicts. 1.0 for an empty block.
+    pub estimated_parallelism: f64,
+}
+
+/// Statically estimates write-write conflicts of `txns`, executed in the given order, from their
+/// write hints. Since hints are overestimated, so are the conflicts. Wildcard locations are only
+/// compared with identical wildcards.
+pub fn analyze_conflicts(txns: &[AnalyzedTransaction]) -> ConflictReport {
+    // For every location, the number of writers and the depth of the last writer in the
+    // dependency chain.
+    let mut writers: HashMap<&StorageLocation, (usize, usize)> = HashMap::new();
+    let mut num_conflicting_txns = 0;
+    let mut critical_path_len = 0;
+
+    for txn in txns {
+        let mut depth = 1;
+        let mut conflicting = false;
+        for location in txn.write_hints() {
+            if let Some((_, last_writer_depth)) = writers.get(location) {
+                conflicting = true;
+                depth = depth.max(last_writer_depth + 1);
+            }
+        }
+        for location in txn.write_hints() {
+            let entry = writers.entry(location).or_insert((0, 0));
+            entry.0 += 1;
+            entry.1 = depth;
+        }
+        if conflicting {
+            num_conflicting_txns += 1;
+        }
+        critical_path_len = critical_path_len.max(depth);
+    }
+
+    let mut hot_locations: Vec<_> = writers
+        .into_iter()
+        .filter(|(_, (num_writers, _))| *num_writers > 1)
+        .map(|(location, (num_writers, _))| (location.clone(), num_writers))
+        .collect();
+    hot_locations.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+    let estimated_parallelism = if critical_path_len == 0 {
+        1.0
+    } else {
+        txns.len() as f64 / critical_path_len as f64
+    };
+
+    ConflictReport {
+        num_txns: txns.len(),
+        num_conflicting_txns,
+        hot_locations,
+        critical_path_len,
+        estimated_parallelism,
+    }
+}
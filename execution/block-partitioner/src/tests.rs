@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
+    conflict_analysis::analyze_conflicts,
     test_utils::{
         create_non_conflicting_p2p_transaction, create_signed_p2p_transaction,
         generate_test_account, verify_partitioner_output,
@@ -143,3 +144,37 @@ fn test_no_conflict_across_shards_in_non_last_rounds() {
         }
     }
 }
+
+#[test]
+fn test_analyze_conflicts() {
+    let num_txns = 4;
+    let transactions: Vec<_> = (0..num_txns)
+        .map(|_| create_non_conflicting_p2p_transaction())
+        .collect();
+    let report = analyze_conflicts(&transactions);
+    assert_eq!(report.num_txns, num_txns);
+    assert_eq!(report.num_conflicting_txns, 0);
+    assert!(report.hot_locations.is_empty());
+    assert_eq!(report.critical_path_len, 1);
+    assert_eq!(report.estimated_parallelism, num_txns as f64);
+
+    // All transfers from the same sender write its account resource and coin store.
+    let mut sender = generate_test_account();
+    let receivers: Vec<_> = (0..3).map(|_| generate_test_account()).collect();
+    let mut transactions = create_signed_p2p_transaction(&mut sender, receivers.iter().collect());
+    transactions.push(create_non_conflicting_p2p_transaction());
+    let report = analyze_conflicts(&transactions);
+    assert_eq!(report.num_txns, 4);
+    assert_eq!(report.num_conflicting_txns, 2);
+    assert_eq!(report.hot_locations.len(), 2);
+    assert!(report
+        .hot_locations
+        .iter()
+        .all(|(_, num_writers)| *num_writers == 3));
+    assert_eq!(report.critical_path_len, 3);
+    assert_eq!(report.estimated_parallelism, 4.0 / 3.0);
+
+    let report = analyze_conflicts(&[]);
+    assert_eq!(report.critical_path_len, 0);
+    assert_eq!(report.estimated_parallelism, 1.0);
+}
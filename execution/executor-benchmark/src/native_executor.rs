@@ -5,7 +5,7 @@ use crate::{
     db_access::{Account, CoinStore, DbAccessUtil},
     metrics::TIMER,
 };
-use anyhow::Result;
+use anyhow::{ensure, Result};
 use aptos_executor::{
     block_executor::TransactionBlockExecutor, components::chunk_output::ChunkOutput,
 };
@@ -31,7 +31,13 @@ use move_core_types::{
 };
 use once_cell::sync::{Lazy, OnceCell};
 use rayon::{prelude::*, ThreadPool, ThreadPoolBuilder};
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 struct IncrementalOutput {
     write_set: Vec<(StateKey, WriteOp)>,
@@ -426,6 +432,26 @@ impl TransactionBlockExecutor for NativeExecutor {
             transactions: transactions.into_iter().map(|t| t.into_inner()).collect(),
             transaction_outputs,
             state_cache: state_view.into_state_cache(),
+            block_end_reason: None,
         })
     }
+
+    fn execute_transaction_block_with_cancellation(
+        transactions: ExecutableTransactions,
+        state_view: CachedStateView,
+        onchain_config: BlockExecutorConfigFromOnchain,
+        cancelled: Arc<AtomicBool>,
+    ) -> Result<ChunkOutput> {
+        // Transactions are executed in one parallel batch, so the flag is only checked around it.
+        ensure!(
+            !cancelled.load(Ordering::Relaxed),
+            "Block execution cancelled"
+        );
+        let output = Self::execute_transaction_block(transactions, state_view, onchain_config)?;
+        ensure!(
+            !cancelled.load(Ordering::Relaxed),
+            "Block execution cancelled"
+        );
+        Ok(output)
+    }
 }
@@ -9,6 +9,7 @@ use aptos_types::{
     transaction::{analyzed_transaction::AnalyzedTransaction, TransactionOutput},
     vm_status::VMStatus,
 };
+use aptos_vm::sharded_block_executor::executor_client::ViewFunctionRequest;
 use serde::{Deserialize, Serialize};
 
 mod error;
@@ -42,6 +43,7 @@ impl RemoteExecutionResult {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum RemoteExecutionRequest {
     ExecuteBlock(ExecuteBlockCommand),
+    ExecuteViewFunctions(Vec<ViewFunctionRequest>),
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -10,7 +10,8 @@ use aptos_types::{
     transaction::TransactionOutput, vm_status::VMStatus,
 };
 use aptos_vm::sharded_block_executor::{
-    coordinator_client::CoordinatorClient, ExecutorShardCommand,
+    coordinator_client::CoordinatorClient, executor_client::ViewFunctionResult,
+    ExecutorShardCommand,
 };
 use crossbeam_channel::{Receiver, Sender};
 use rayon::prelude::*;
@@ -20,6 +21,7 @@ pub struct RemoteCoordinatorClient {
     state_view_client: Arc<RemoteStateViewClient>,
     command_rx: Receiver<Message>,
     result_tx: Sender<Message>,
+    view_result_tx: Sender<Message>,
     shard_id: ShardId,
 }
 
@@ -31,9 +33,12 @@ impl RemoteCoordinatorClient {
     ) -> Self {
         let execute_command_type = format!("execute_command_{}", shard_id);
         let execute_result_type = format!("execute_result_{}", shard_id);
+        let view_result_type = format!("view_result_{}", shard_id);
         let command_rx = controller.create_inbound_channel(execute_command_type);
         let result_tx =
             controller.create_outbound_channel(coordinator_address, execute_result_type);
+        let view_result_tx =
+            controller.create_outbound_channel(coordinator_address, view_result_type);
 
         let state_view_client =
             RemoteStateViewClient::new(shard_id, controller, coordinator_address);
@@ -42,6 +47,7 @@ impl RemoteCoordinatorClient {
             state_view_client: Arc::new(state_view_client),
             command_rx,
             result_tx,
+            view_result_tx,
             shard_id,
         }
     }
@@ -106,6 +112,15 @@ impl CoordinatorClient<RemoteStateViewClient> for RemoteCoordinatorClient {
                             onchain_config,
                         )
                     },
+                    RemoteExecutionRequest::ExecuteViewFunctions(requests) => {
+                        // The state accessed by view functions is not known upfront, so nothing is
+                        // pre-fetched and the state values are fetched lazily from the coordinator.
+                        self.state_view_client.init_for_block(vec![]);
+                        ExecutorShardCommand::ExecuteViewFunctions(
+                            self.state_view_client.clone(),
+                            requests,
+                        )
+                    },
                 }
             },
             Err(_) => ExecutorShardCommand::Stop,
@@ -117,4 +132,11 @@ impl CoordinatorClient<RemoteStateViewClient> for RemoteCoordinatorClient {
         let output_message = bcs::to_bytes(&remote_execution_result).unwrap();
         self.result_tx.send(Message::new(output_message)).unwrap();
     }
+
+    fn send_view_function_results(&self, results: Vec<ViewFunctionResult>) {
+        let output_message = bcs::to_bytes(&results).unwrap();
+        self.view_result_tx
+            .send(Message::new(output_message))
+            .unwrap();
+    }
 }
@@ -17,7 +17,9 @@ use aptos_types::{
     vm_status::VMStatus,
 };
 use aptos_vm::sharded_block_executor::{
-    executor_client::{ExecutorClient, ShardedExecutionOutput},
+    executor_client::{
+        ExecutorClient, ShardedExecutionOutput, ViewFunctionRequest, ViewFunctionResult,
+    },
     ShardedBlockExecutor,
 };
 use crossbeam_channel::{Receiver, Sender};
@@ -83,6 +85,8 @@ pub struct RemoteExecutorClient<S: StateView + Sync + Send + 'static> {
     command_txs: Arc<Vec<Mutex<Sender<Message>>>>,
     // Channels to receive execution results from the executor shards.
     result_rxs: Vec<Receiver<Message>>,
+    // Channels to receive view function results from the executor shards.
+    view_result_rxs: Vec<Receiver<Message>>,
     // Thread pool used to pre-fetch the state values for the block in parallel and create an in-memory state view.
     thread_pool: Arc<rayon::ThreadPool>,
 
@@ -105,19 +109,23 @@ impl<S: StateView + Sync + Send + 'static> RemoteExecutorClient<S> {
                 .unwrap(),
         );
         let controller_mut_ref = &mut controller;
-        let (command_txs, result_rxs) = remote_shard_addresses
-            .iter()
-            .enumerate()
-            .map(|(shard_id, address)| {
-                let execute_command_type = format!("execute_command_{}", shard_id);
-                let execute_result_type = format!("execute_result_{}", shard_id);
-                let command_tx = Mutex::new(
-                    controller_mut_ref.create_outbound_channel(*address, execute_command_type),
-                );
-                let result_rx = controller_mut_ref.create_inbound_channel(execute_result_type);
-                (command_tx, result_rx)
-            })
-            .unzip();
+        let ((command_txs, result_rxs), view_result_rxs): ((Vec<_>, Vec<_>), Vec<_>) =
+            remote_shard_addresses
+                .iter()
+                .enumerate()
+                .map(|(shard_id, address)| {
+                    let execute_command_type = format!("execute_command_{}", shard_id);
+                    let execute_result_type = format!("execute_result_{}", shard_id);
+                    let view_result_type = format!("view_result_{}", shard_id);
+                    let command_tx = Mutex::new(
+                        controller_mut_ref.create_outbound_channel(*address, execute_command_type),
+                    );
+                    let result_rx = controller_mut_ref.create_inbound_channel(execute_result_type);
+                    let view_result_rx =
+                        controller_mut_ref.create_inbound_channel(view_result_type);
+                    ((command_tx, result_rx), view_result_rx)
+                })
+                .unzip();
 
         let state_view_service = Arc::new(RemoteStateViewService::new(
             controller_mut_ref,
@@ -140,6 +148,7 @@ impl<S: StateView + Sync + Send + 'static> RemoteExecutorClient<S> {
             _join_handle: Some(join_handle),
             command_txs: Arc::new(command_txs),
             result_rxs,
+            view_result_rxs,
             thread_pool,
             phantom: std::marker::PhantomData,
         }
@@ -212,6 +221,35 @@ impl<S: StateView + Sync + Send + 'static> ExecutorClient<S> for RemoteExecutorC
         Ok(ShardedExecutionOutput::new(execution_results, vec![]))
     }
 
+    fn execute_view_functions(
+        &self,
+        state_view: Arc<S>,
+        requests: Vec<Vec<ViewFunctionRequest>>,
+    ) -> Vec<Vec<ViewFunctionResult>> {
+        trace!("RemoteExecutorClient Sending view functions to shards");
+        self.state_view_service.set_state_view(state_view);
+        for (shard_id, requests) in requests.into_iter().enumerate() {
+            let execution_request = RemoteExecutionRequest::ExecuteViewFunctions(requests);
+            self.command_txs[shard_id]
+                .lock()
+                .unwrap()
+                .send(Message::new(bcs::to_bytes(&execution_request).unwrap()))
+                .unwrap();
+        }
+
+        let results = self
+            .view_result_rxs
+            .iter()
+            .map(|rx| {
+                let received_bytes = rx.recv().unwrap().to_bytes();
+                bcs::from_bytes(&received_bytes).unwrap()
+            })
+            .collect();
+
+        self.state_view_service.drop_state_view();
+        results
+    }
+
     fn shutdown(&mut self) {
         self.network_controller.shutdown();
     }
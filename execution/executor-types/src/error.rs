@@ -9,6 +9,25 @@ use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use thiserror::Error;
 
+/// Why the data (e.g. a batch of transactions) needed for execution could not be fetched.
+#[derive(Clone, Copy, Debug, Deserialize, Error, PartialEq, Eq, Serialize)]
+pub enum CouldNotGetDataReason {
+    #[error("request timed out")]
+    Timeout,
+
+    #[error("all peers were tried without success")]
+    AllPeersExhausted,
+
+    #[error("network error")]
+    NetworkError,
+
+    #[error("data expired")]
+    Expired,
+
+    #[error("data not available locally")]
+    NotAvailableLocally,
+}
+
 #[derive(Debug, Deserialize, Error, PartialEq, Eq, Serialize)]
 /// Different reasons for proposal rejection
 pub enum ExecutorError {
@@ -39,8 +58,29 @@ pub enum ExecutorError {
     #[error("Received Empty Blocks")]
     EmptyBlocks,
 
-    #[error("request timeout")]
-    CouldNotGetData,
+    #[error("Could not get data: {0}")]
+    CouldNotGetData(CouldNotGetDataReason),
+
+    #[error("Proof verification failed: {0}")]
+    ProofVerificationError(String),
+
+    #[error(
+        "Unexpected chunk. version in request: {}, num txns: {}, next version: {}",
+        first_version_in_request,
+        num_txns,
+        next_version
+    )]
+    UnexpectedChunkVersion {
+        first_version_in_request: Version,
+        num_txns: usize,
+        next_version: Version,
+    },
+
+    #[error("Storage error: {0}")]
+    StorageError(String),
+
+    #[error("Block execution cancelled")]
+    Cancelled,
 }
 
 impl From<anyhow::Error> for ExecutorError {
@@ -53,9 +93,7 @@ impl From<anyhow::Error> for ExecutorError {
 
 impl From<AptosDbError> for ExecutorError {
     fn from(error: AptosDbError) -> Self {
-        Self::InternalError {
-            error: format!("{}", error),
-        }
+        Self::StorageError(format!("{}", error))
     }
 }
 
@@ -13,9 +13,13 @@ use aptos_types::{
     ledger_info::LedgerInfoWithSignatures,
     proof::accumulator::InMemoryTransactionAccumulator,
     state_store::{combine_or_add_sharded_state_updates, ShardedStateUpdates},
-    transaction::{Transaction, TransactionInfo, TransactionStatus, TransactionToCommit, Version},
+    transaction::{
+        BlockEndReason, Transaction, TransactionInfo, TransactionStatus, TransactionToCommit,
+        Version,
+    },
 };
 use itertools::zip_eq;
+use move_core_types::language_storage::ModuleId;
 use std::sync::Arc;
 
 #[derive(Default, Debug)]
@@ -29,6 +33,8 @@ pub struct LedgerUpdateOutput {
     /// The in-memory Merkle Accumulator representing a blockchain state consistent with the
     /// `state_tree`.
     pub transaction_accumulator: Arc<InMemoryTransactionAccumulator>,
+    /// Why the block executor stopped executing the block, not set for chunks.
+    pub block_end_reason: Option<BlockEndReason>,
 }
 
 impl LedgerUpdateOutput {
@@ -54,6 +60,16 @@ impl LedgerUpdateOutput {
         &self.to_commit
     }
 
+    /// Returns all events emitted by the transactions to commit whose type is declared in
+    /// `module`, in commit order. Unlike the subscribable events, these are not pre-filtered.
+    pub fn events_from_module(&self, module: &ModuleId) -> Vec<&ContractEvent> {
+        self.to_commit
+            .iter()
+            .flat_map(|txn_to_commit| txn_to_commit.events())
+            .filter(|event| event.is_from_module(module))
+            .collect()
+    }
+
     /// Ensure that every block committed by consensus ends with a state checkpoint. That can be
     /// one of the two cases: 1. a reconfiguration (txns in the proposed block after the txn caused
     /// the reconfiguration will be retried) 2. a Transaction::StateCheckpoint at the end of the
@@ -174,6 +190,7 @@ impl LedgerUpdateOutput {
             self.transaction_info_hashes.clone(),
             self.subscribable_events.clone(),
         )
+        .with_block_end_reason(self.block_end_reason)
     }
 
     pub fn combine(&mut self, rhs: Self) {
@@ -185,6 +202,7 @@ impl LedgerUpdateOutput {
             state_updates_until_last_checkpoint: state_updates_before_last_checkpoint,
             sharded_state_cache,
             transaction_accumulator,
+            block_end_reason,
         } = rhs;
 
         if let Some(updates) = state_updates_before_last_checkpoint {
@@ -200,6 +218,7 @@ impl LedgerUpdateOutput {
         self.transaction_info_hashes.extend(transaction_info_hashes);
         self.sharded_state_cache.combine(sharded_state_cache);
         self.transaction_accumulator = transaction_accumulator;
+        self.block_end_reason = block_end_reason;
     }
 
     pub fn next_version(&self) -> Version {
@@ -210,3 +229,48 @@ impl LedgerUpdateOutput {
         self.transaction_accumulator.num_leaves() - self.to_commit.len() as Version
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_types::account_address::AccountAddress;
+    use move_core_types::ident_str;
+
+    #[test]
+    fn events_from_module_should_filter_by_declaring_module() {
+        let event_1 = ContractEvent::new_v2_with_type_tag_str(
+            "0x2345::random_module::RandomEvent",
+            b"random_data_x".to_vec(),
+        );
+        let event_2 = ContractEvent::new_v2_with_type_tag_str(
+            "0x2345::other_module::RandomEvent",
+            b"random_data_y".to_vec(),
+        );
+        let event_3 = ContractEvent::new_v2_with_type_tag_str(
+            "0x6789::random_module::RandomEvent",
+            b"random_data_z".to_vec(),
+        );
+        let event_4 = ContractEvent::new_v2_with_type_tag_str(
+            "0x2345::random_module::OtherEvent",
+            b"random_data_w".to_vec(),
+        );
+        let event_5 = ContractEvent::new_v2_with_type_tag_str("u64", b"random_data_v".to_vec());
+
+        let ledger_update_output = LedgerUpdateOutput {
+            to_commit: vec![
+                TransactionToCommit::dummy_with_events(vec![event_1.clone(), event_2]),
+                TransactionToCommit::dummy_with_events(vec![event_3, event_5]),
+                TransactionToCommit::dummy_with_events(vec![event_4.clone()]),
+            ],
+            ..Default::default()
+        };
+
+        let module = ModuleId::new(
+            AccountAddress::from_hex_literal("0x2345").unwrap(),
+            ident_str!("random_module").to_owned(),
+        );
+        assert_eq!(ledger_update_output.events_from_module(&module), vec![
+            &event_1, &event_4
+        ]);
+    }
+}
@@ -6,10 +6,10 @@
 use crate::state_checkpoint_output::StateCheckpointOutput;
 use anyhow::Result;
 use aptos_crypto::{
-    hash::{TransactionAccumulatorHasher, ACCUMULATOR_PLACEHOLDER_HASH},
+    hash::{CryptoHash, TransactionAccumulatorHasher, ACCUMULATOR_PLACEHOLDER_HASH},
     HashValue,
 };
-use aptos_scratchpad::{ProofRead, SparseMerkleTree};
+use aptos_scratchpad::{FrozenSparseMerkleTree, ProofRead, SparseMerkleTree};
 use aptos_types::{
     account_config::NEW_EPOCH_EVENT_MOVE_TYPE_TAG,
     block_executor::{config::BlockExecutorConfigFromOnchain, partitioner::ExecutableBlock},
@@ -21,21 +21,21 @@ use aptos_types::{
     proof::{AccumulatorExtensionProof, SparseMerkleProofExt},
     state_store::{state_key::StateKey, state_value::StateValue},
     transaction::{
-        ExecutionStatus, Transaction, TransactionInfo, TransactionListWithProof,
+        BlockEndReason, ExecutionStatus, Transaction, TransactionInfo, TransactionListWithProof,
         TransactionOutputListWithProof, TransactionStatus, Version,
     },
     write_set::WriteSet,
 };
-pub use error::{ExecutorError, ExecutorResult};
+pub use error::{CouldNotGetDataReason, ExecutorError, ExecutorResult};
 pub use executed_chunk::ExecutedChunk;
 pub use ledger_update_output::LedgerUpdateOutput;
 pub use parsed_transaction_output::ParsedTransactionOutput;
 use serde::{Deserialize, Serialize};
 use std::{
-    cmp::max,
+    cmp::{max, min},
     collections::{BTreeSet, HashMap},
     fmt::Debug,
-    ops::Deref,
+    ops::{Deref, Range, RangeInclusive},
     sync::{
         atomic::{AtomicBool, Ordering},
         Arc,
@@ -47,7 +47,10 @@ mod executed_chunk;
 pub mod execution_output;
 mod ledger_update_output;
 pub mod parsed_transaction_output;
+pub mod replay_bundle;
 pub mod state_checkpoint_output;
+#[cfg(any(test, feature = "fuzzing"))]
+pub mod test_utils;
 
 pub trait ChunkExecutorTrait: Send + Sync {
     /// Verifies the transactions based on the provided proofs and ledger info. If the transactions
@@ -61,8 +64,13 @@ pub trait ChunkExecutorTrait: Send + Sync {
         // Target LI that has been verified independently: the proofs are relative to this version.
         verified_target_li: &LedgerInfoWithSignatures,
         epoch_change_li: Option<&LedgerInfoWithSignatures>,
-    ) -> Result<()> {
-        self.enqueue_chunk_by_execution(txn_list_with_proof, verified_target_li, epoch_change_li)?;
+    ) -> ExecutorResult<()> {
+        self.enqueue_chunk_by_execution(
+            txn_list_with_proof,
+            verified_target_li,
+            epoch_change_li,
+            ChunkTrustMode::Verify,
+        )?;
 
         self.update_ledger()
     }
@@ -78,11 +86,12 @@ pub trait ChunkExecutorTrait: Send + Sync {
         // Target LI that has been verified independently: the proofs are relative to this version.
         verified_target_li: &LedgerInfoWithSignatures,
         epoch_change_li: Option<&LedgerInfoWithSignatures>,
-    ) -> Result<()> {
+    ) -> ExecutorResult<()> {
         self.enqueue_chunk_by_transaction_outputs(
             txn_output_list_with_proof,
             verified_target_li,
             epoch_change_li,
+            ChunkTrustMode::Verify,
         )?;
 
         self.update_ledger()
@@ -92,13 +101,19 @@ pub trait ChunkExecutorTrait: Send + Sync {
     /// are valid, executes them and make state checkpoint, so that a later chunk of transaction can
     /// be applied on top of it. This stage calculates the state checkpoint, but not the top level
     /// transaction accumulator.
+    ///
+    /// The chunk may overlap with transactions already persisted in the DB (e.g. a chunk that was
+    /// partially committed before a restart), in which case only the remaining suffix is executed.
+    ///
+    /// With `ChunkTrustMode::TrustSource` the proofs are not verified, see `ChunkTrustMode`.
     fn enqueue_chunk_by_execution(
         &self,
         txn_list_with_proof: TransactionListWithProof,
         // Target LI that has been verified independently: the proofs are relative to this version.
         verified_target_li: &LedgerInfoWithSignatures,
         epoch_change_li: Option<&LedgerInfoWithSignatures>,
-    ) -> Result<()>;
+        trust_mode: ChunkTrustMode,
+    ) -> ExecutorResult<()>;
 
     /// Similar to `enqueue_chunk_by_execution`, but instead of executing transactions, apply the
     /// transaction outputs directly to get the executed result.
@@ -108,16 +123,60 @@ pub trait ChunkExecutorTrait: Send + Sync {
         // Target LI that has been verified independently: the proofs are relative to this version.
         verified_target_li: &LedgerInfoWithSignatures,
         epoch_change_li: Option<&LedgerInfoWithSignatures>,
-    ) -> Result<()>;
+        trust_mode: ChunkTrustMode,
+    ) -> ExecutorResult<()>;
 
     /// As a separate stage, calculate the transaction accumulator changes, prepare for db commission.
-    fn update_ledger(&self) -> Result<()>;
+    fn update_ledger(&self) -> ExecutorResult<()>;
 
     /// Commit a previously executed chunk. Returns a chunk commit notification.
-    fn commit_chunk(&self) -> Result<ChunkCommitNotification>;
+    fn commit_chunk(&self) -> ExecutorResult<ChunkCommitNotification>;
+
+    /// Executes and commits a chunk like `execute_chunk` followed by `commit_chunk`, but commits
+    /// the progress every `checkpoint_interval` transactions. The committed version serves as the
+    /// durable marker of the progress within the chunk, see `resume_chunk`.
+    ///
+    /// The proofs are verified once for the whole chunk, and the result of each checkpoint is
+    /// checked against the verified transaction infos.
+    fn execute_and_commit_chunk_with_checkpoints(
+        &self,
+        txn_list_with_proof: TransactionListWithProof,
+        // Target LI that has been verified independently: the proofs are relative to this version.
+        verified_target_li: &LedgerInfoWithSignatures,
+        epoch_change_li: Option<&LedgerInfoWithSignatures>,
+        checkpoint_interval: usize,
+    ) -> ExecutorResult<ChunkCommitNotification>;
+
+    /// Resumes `execute_and_commit_chunk_with_checkpoints` on the same chunk after it was
+    /// interrupted, e.g. by a crash. Execution continues from the last committed checkpoint rather
+    /// than from the start of the chunk.
+    fn resume_chunk(
+        &self,
+        txn_list_with_proof: TransactionListWithProof,
+        // Target LI that has been verified independently: the proofs are relative to this version.
+        verified_target_li: &LedgerInfoWithSignatures,
+        epoch_change_li: Option<&LedgerInfoWithSignatures>,
+        checkpoint_interval: usize,
+    ) -> ExecutorResult<ChunkCommitNotification> {
+        self.reset()?;
+        self.execute_and_commit_chunk_with_checkpoints(
+            txn_list_with_proof,
+            verified_target_li,
+            epoch_change_li,
+            checkpoint_interval,
+        )
+    }
+
+    /// Returns the range of versions that have been enqueued (executed or applied) but not yet
+    /// committed, or `None` if there are none.
+    fn pending_version_range(&self) -> Option<RangeInclusive<Version>>;
+
+    /// Estimates the memory in bytes held by the in-memory SMTs, see `finish` to release it. The
+    /// estimate is also reported as a metric, which is sampled whenever a chunk is committed.
+    fn smt_memory_usage(&self) -> usize;
 
     /// Resets the chunk executor by synchronizing state with storage.
-    fn reset(&self) -> Result<()>;
+    fn reset(&self) -> ExecutorResult<()>;
 
     /// Finishes the chunk executor by releasing memory held by inner data structures(SMT).
     fn finish(&self);
@@ -158,6 +217,32 @@ pub trait BlockExecutorTrait: Send + Sync {
         onchain_config: BlockExecutorConfigFromOnchain,
     ) -> ExecutorResult<StateCheckpointOutput>;
 
+    /// Executes a block like `execute_and_state_checkpoint`, but the execution can be aborted
+    /// from another thread by setting `cancelled`, in which case `ExecutorError::Cancelled` is
+    /// returned and the block is not added to the speculative block tree.
+    fn execute_and_state_checkpoint_with_cancellation(
+        &self,
+        block: ExecutableBlock,
+        parent_block_id: HashValue,
+        onchain_config: BlockExecutorConfigFromOnchain,
+        cancelled: Arc<AtomicBool>,
+    ) -> ExecutorResult<StateCheckpointOutput>;
+
+    /// Executes a block like `execute_and_state_checkpoint`, but consults `proof_reader` for the
+    /// proofs of the state read by the block before reading them from storage, e.g. to reuse the
+    /// proofs gathered by a prior speculative execution. The output is the same either way.
+    ///
+    /// By default the proofs are ignored, executors that can make use of them should override this.
+    fn execute_and_state_checkpoint_with_proofs(
+        &self,
+        block: ExecutableBlock,
+        parent_block_id: HashValue,
+        onchain_config: BlockExecutorConfigFromOnchain,
+        _proof_reader: ProofReader,
+    ) -> ExecutorResult<StateCheckpointOutput> {
+        self.execute_and_state_checkpoint(block, parent_block_id, onchain_config)
+    }
+
     fn ledger_update(
         &self,
         block_id: HashValue,
@@ -193,15 +278,53 @@ pub trait BlockExecutorTrait: Send + Sync {
         )
     }
 
+    /// Estimates the memory in bytes held by the in-memory SMTs, see `finish` to release it. The
+    /// estimate is also reported as a metric, which is sampled whenever blocks are committed.
+    fn smt_memory_usage(&self) -> usize;
+
     /// Finishes the block executor by releasing memory held by inner data structures(SMT).
     fn finish(&self);
 }
 
+/// Whether the chunk executor verifies the proofs of the chunks it is given.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ChunkTrustMode {
+    /// Verify the chunk proofs against the target ledger info and the local accumulator.
+    #[default]
+    Verify,
+    /// Skip the proof verification and apply the chunks as they are. Only meant for syncing from
+    /// a source the operator trusts out of band, e.g. their own node or backup. A malicious or
+    /// faulty source can corrupt the DB in this mode.
+    TrustSource,
+}
+
+impl ChunkTrustMode {
+    pub fn should_verify_proofs(&self) -> bool {
+        matches!(self, Self::Verify)
+    }
+
+    /// Trusting the source defeats the purpose of verifying the execution results against it, so
+    /// `TrustSource` can't be combined with `VerifyExecutionMode::Verify`.
+    pub fn ensure_compatible_with(
+        &self,
+        verify_execution_mode: &VerifyExecutionMode,
+    ) -> ExecutorResult<()> {
+        if !self.should_verify_proofs() && verify_execution_mode.should_verify() {
+            return Err(ExecutorError::internal_err(
+                "Chunk trust mode TrustSource can't be used together with verified execution.",
+            ));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Clone)]
 pub enum VerifyExecutionMode {
     NoVerify,
     Verify {
         txns_to_skip: Arc<BTreeSet<Version>>,
+        /// When set, versions outside of this range are treated as skipped.
+        versions_to_verify: Option<RangeInclusive<Version>>,
         lazy_quit: bool,
         seen_error: Arc<AtomicBool>,
     },
@@ -211,6 +334,7 @@ impl VerifyExecutionMode {
     pub fn verify_all() -> Self {
         Self::Verify {
             txns_to_skip: Arc::new(BTreeSet::new()),
+            versions_to_verify: None,
             lazy_quit: false,
             seen_error: Arc::new(AtomicBool::new(false)),
         }
@@ -219,11 +343,46 @@ impl VerifyExecutionMode {
     pub fn verify_except(txns_to_skip: Vec<Version>) -> Self {
         Self::Verify {
             txns_to_skip: Arc::new(txns_to_skip.into_iter().collect()),
+            versions_to_verify: None,
             lazy_quit: false,
             seen_error: Arc::new(AtomicBool::new(false)),
         }
     }
 
+    /// Verifies only the versions in `[start, end]`, everything else is applied without verification.
+    pub fn verify_range(start: Version, end: Version) -> Self {
+        Self::Verify {
+            txns_to_skip: Arc::new(BTreeSet::new()),
+            versions_to_verify: Some(start..=end),
+            lazy_quit: false,
+            seen_error: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Returns the half-open range of versions within `[begin_version, end_version)` that need
+    /// verification, before taking `txns_to_skip` into account. The result can be empty.
+    pub fn versions_to_verify_within(
+        &self,
+        begin_version: Version,
+        end_version: Version,
+    ) -> Range<Version> {
+        match self {
+            VerifyExecutionMode::NoVerify => begin_version..begin_version,
+            VerifyExecutionMode::Verify {
+                versions_to_verify: None,
+                ..
+            } => begin_version..end_version,
+            VerifyExecutionMode::Verify {
+                versions_to_verify: Some(range),
+                ..
+            } => {
+                let begin = max(begin_version, *range.start()).min(end_version);
+                let end = min(end_version, range.end().saturating_add(1)).max(begin);
+                begin..end
+            },
+        }
+    }
+
     pub fn txns_to_skip(&self) -> Arc<BTreeSet<Version>> {
         match self {
             VerifyExecutionMode::NoVerify => Arc::new(BTreeSet::new()),
@@ -270,6 +429,9 @@ impl VerifyExecutionMode {
 }
 
 pub trait TransactionReplayer: Send {
+    /// Replays the transactions by applying their write sets, re-executing the ones to verify per
+    /// `verify_execution_mode`. With `ChunkTrustMode::TrustSource` the resulting transaction infos
+    /// are not checked against `transaction_infos`, and `verify_execution_mode` must not verify.
     fn replay(
         &self,
         transactions: Vec<Transaction>,
@@ -277,6 +439,7 @@ pub trait TransactionReplayer: Send {
         write_sets: Vec<WriteSet>,
         event_vecs: Vec<Vec<ContractEvent>>,
         verify_execution_mode: &VerifyExecutionMode,
+        trust_mode: ChunkTrustMode,
     ) -> Result<()>;
 
     fn commit(&self) -> Result<ExecutedChunk>;
@@ -328,7 +491,14 @@ pub struct StateComputeResult {
     /// The transaction info hashes of all success txns.
     transaction_info_hashes: Vec<HashValue>,
 
+    /// Events to be forwarded to the subscription service, in the order they were emitted,
+    /// except that the new epoch event, if any, is always the last one.
     subscribable_events: Vec<ContractEvent>,
+
+    /// Why the block executor stopped executing the block, e.g. because the block gas limit
+    /// was reached. Local to this node, so it is not serialized.
+    #[serde(skip)]
+    block_end_reason: Option<BlockEndReason>,
 }
 
 impl StateComputeResult {
@@ -341,8 +511,10 @@ impl StateComputeResult {
         epoch_state: Option<EpochState>,
         compute_status_for_input_txns: Vec<TransactionStatus>,
         transaction_info_hashes: Vec<HashValue>,
-        subscribable_events: Vec<ContractEvent>,
+        mut subscribable_events: Vec<ContractEvent>,
     ) -> Self {
+        // Stable sort, so only the new epoch event is moved, to the end.
+        subscribable_events.sort_by_key(ContractEvent::is_new_epoch_event);
         Self {
             root_hash,
             frozen_subtree_roots,
@@ -353,9 +525,15 @@ impl StateComputeResult {
             compute_status_for_input_txns,
             transaction_info_hashes,
             subscribable_events,
+            block_end_reason: None,
         }
     }
 
+    pub fn with_block_end_reason(mut self, block_end_reason: Option<BlockEndReason>) -> Self {
+        self.block_end_reason = block_end_reason;
+        self
+    }
+
     /// generate a new dummy state compute result with a given root hash.
     /// this function is used in RandomComputeResultStateComputer to assert that the compute
     /// function is really called.
@@ -370,6 +548,7 @@ impl StateComputeResult {
             compute_status_for_input_txns: vec![],
             transaction_info_hashes: vec![],
             subscribable_events: vec![],
+            block_end_reason: None,
         }
     }
 
@@ -387,6 +566,7 @@ impl StateComputeResult {
             ],
             transaction_info_hashes: vec![],
             subscribable_events: vec![],
+            block_end_reason: None,
         }
     }
 
@@ -407,10 +587,9 @@ impl StateComputeResult {
 }
 
 impl StateComputeResult {
+    /// The version of the last transaction in the ledger, or 0 if the ledger is empty.
     pub fn version(&self) -> Version {
-        max(self.num_leaves, 1)
-            .checked_sub(1)
-            .expect("Integer overflow occurred")
+        self.num_leaves.saturating_sub(1)
     }
 
     pub fn root_hash(&self) -> HashValue {
@@ -421,6 +600,10 @@ impl StateComputeResult {
         &self.compute_status_for_input_txns
     }
 
+    pub fn block_end_reason(&self) -> Option<BlockEndReason> {
+        self.block_end_reason
+    }
+
     pub fn transactions_to_commit_len(&self) -> usize {
         // StateCheckpoint/BlockEpilogue is added if there is no reconfiguration
         self.compute_status_for_input_txns().len()
@@ -506,6 +689,69 @@ impl StateComputeResult {
     pub fn subscribable_events(&self) -> &[ContractEvent] {
         &self.subscribable_events
     }
+
+    /// Returns the new epoch event emitted by the block, if any.
+    pub fn new_epoch_event(&self) -> Option<&ContractEvent> {
+        self.subscribable_events
+            .last()
+            .filter(|event| event.is_new_epoch_event())
+    }
+
+    /// Checks that the block carries a next epoch state iff it emitted a new epoch event.
+    /// Descendants of a reconfiguration block (which commit no transactions) inherit the epoch
+    /// state without the event.
+    pub fn verify_reconfig_consistency(&self) -> Result<(), String> {
+        let has_new_epoch_event = self
+            .subscribable_events
+            .iter()
+            .any(ContractEvent::is_new_epoch_event);
+        let is_reconfig_suffix = self.transaction_info_hashes.is_empty();
+        if has_new_epoch_event && !self.has_reconfiguration() {
+            return Err("New epoch event emitted without next epoch state.".to_string());
+        }
+        if !has_new_epoch_event && self.has_reconfiguration() && !is_reconfig_suffix {
+            return Err("Next epoch state set without a new epoch event.".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// How the transaction accumulators of two `StateComputeResult`s differ, e.g. the results of the
+/// same block on two nodes that forked. See `diff_accumulator_state`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccumulatorDiff {
+    /// The two root hashes, if they differ.
+    pub root_hashes: Option<(HashValue, HashValue)>,
+    /// The number of leaves of the second accumulator minus that of the first one.
+    pub num_leaves_delta: i128,
+    /// Index of the leftmost frozen subtree root that differs, or that only one side has.
+    pub first_differing_frozen_subtree_root: Option<usize>,
+}
+
+impl AccumulatorDiff {
+    pub fn is_empty(&self) -> bool {
+        self.root_hashes.is_none()
+            && self.num_leaves_delta == 0
+            && self.first_differing_frozen_subtree_root.is_none()
+    }
+}
+
+/// Compares the transaction accumulators resulting from `a` and `b`.
+pub fn diff_accumulator_state(a: &StateComputeResult, b: &StateComputeResult) -> AccumulatorDiff {
+    let root_hashes = (a.root_hash() != b.root_hash()).then_some((a.root_hash(), b.root_hash()));
+    let num_leaves_delta = b.num_leaves() as i128 - a.num_leaves() as i128;
+    let (a_roots, b_roots) = (a.frozen_subtree_roots(), b.frozen_subtree_roots());
+    let first_differing_frozen_subtree_root = a_roots
+        .iter()
+        .zip(b_roots.iter())
+        .position(|(a_root, b_root)| a_root != b_root)
+        .or_else(|| (a_roots.len() != b_roots.len()).then(|| a_roots.len().min(b_roots.len())));
+
+    AccumulatorDiff {
+        root_hashes,
+        num_leaves_delta,
+        first_differing_frozen_subtree_root,
+    }
 }
 
 pub struct ProofReader {
@@ -528,6 +774,17 @@ impl ProofRead for ProofReader {
     }
 }
 
+/// Generates a proof of the value `state_key` has in `smt`, e.g. the state resulting from executing
+/// a block, which proves a transaction's write to that key against the new state root. The part of
+/// the path that is not in memory is filled in with the persisted proof from `proof_reader`.
+pub fn get_state_proof(
+    smt: &FrozenSparseMerkleTree<StateValue>,
+    state_key: &StateKey,
+    proof_reader: &impl ProofRead,
+) -> Result<SparseMerkleProofExt> {
+    Ok(smt.get_proof(state_key.hash(), proof_reader)?)
+}
+
 /// Used in both state sync and consensus to filter the txn events that should be subscribable by node components.
 pub fn should_forward_to_subscription_service(event: &ContractEvent) -> bool {
     let type_tag = event.type_tag();
@@ -546,3 +803,179 @@ pub fn should_forward_to_subscription_service_old(event: &ContractEvent) -> bool
             0x1::jwks::ObservedJWKsUpdated"
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_types::account_config::NewEpochEvent;
+
+    #[test]
+    fn test_new_epoch_event_is_last() {
+        let new_epoch_event = NewEpochEvent::dummy().as_contract_event(0);
+        let other_event = ContractEvent::new_v2_with_type_tag_str("0x1::foo::Bar", vec![1]);
+        let another_event = ContractEvent::new_v2_with_type_tag_str("0x1::foo::Baz", vec![2]);
+
+        let result = StateComputeResult::new(
+            HashValue::zero(),
+            vec![],
+            0,
+            vec![],
+            0,
+            None,
+            vec![],
+            vec![],
+            vec![
+                other_event.clone(),
+                new_epoch_event.clone(),
+                another_event.clone(),
+            ],
+        );
+        assert_eq!(result.subscribable_events(), &[
+            other_event.clone(),
+            another_event,
+            new_epoch_event.clone()
+        ]);
+        assert_eq!(result.new_epoch_event(), Some(&new_epoch_event));
+
+        let result = StateComputeResult::new(
+            HashValue::zero(),
+            vec![],
+            0,
+            vec![],
+            0,
+            None,
+            vec![],
+            vec![],
+            vec![other_event],
+        );
+        assert_eq!(result.new_epoch_event(), None);
+    }
+
+    #[test]
+    fn test_versions_to_verify_within() {
+        assert_eq!(
+            VerifyExecutionMode::verify_all().versions_to_verify_within(3, 8),
+            3..8
+        );
+        assert!(VerifyExecutionMode::NoVerify
+            .versions_to_verify_within(3, 8)
+            .is_empty());
+
+        let mode = VerifyExecutionMode::verify_range(5, 10);
+        assert!(mode.should_verify());
+        assert_eq!(mode.versions_to_verify_within(0, 20), 5..11);
+        assert_eq!(mode.versions_to_verify_within(7, 9), 7..9);
+        assert_eq!(mode.versions_to_verify_within(8, 20), 8..11);
+        assert!(mode.versions_to_verify_within(0, 5).is_empty());
+        assert!(mode.versions_to_verify_within(11, 20).is_empty());
+
+        assert_eq!(
+            VerifyExecutionMode::verify_range(0, Version::MAX).versions_to_verify_within(1, 4),
+            1..4
+        );
+    }
+
+    #[test]
+    fn test_diff_accumulator_state() {
+        let make_result = |frozen_subtree_roots: Vec<HashValue>, num_leaves: u64| {
+            StateComputeResult::new(
+                HashValue::sha3_256_of(&num_leaves.to_le_bytes()),
+                frozen_subtree_roots,
+                num_leaves,
+                vec![],
+                0,
+                None,
+                vec![],
+                vec![],
+                vec![],
+            )
+        };
+        let (x, y, z) = (
+            HashValue::sha3_256_of(b"x"),
+            HashValue::sha3_256_of(b"y"),
+            HashValue::sha3_256_of(b"z"),
+        );
+
+        let a = make_result(vec![x, y], 6);
+        assert!(diff_accumulator_state(&a, &a.clone()).is_empty());
+
+        let diff = diff_accumulator_state(&a, &make_result(vec![x, z], 6));
+        assert_eq!(diff.root_hashes, None);
+        assert_eq!(diff.num_leaves_delta, 0);
+        assert_eq!(diff.first_differing_frozen_subtree_root, Some(1));
+
+        let b = make_result(vec![x], 4);
+        let diff = diff_accumulator_state(&a, &b);
+        assert_eq!(diff.root_hashes, Some((a.root_hash(), b.root_hash())));
+        assert_eq!(diff.num_leaves_delta, -2);
+        assert_eq!(diff.first_differing_frozen_subtree_root, Some(1));
+    }
+
+    #[test]
+    fn test_verify_reconfig_consistency() {
+        let new_epoch_event = NewEpochEvent::dummy().as_contract_event(0);
+        let make_result = |epoch_state: Option<EpochState>,
+                           transaction_info_hashes: Vec<HashValue>,
+                           events: Vec<ContractEvent>| {
+            StateComputeResult::new(
+                HashValue::zero(),
+                vec![],
+                0,
+                vec![],
+                0,
+                epoch_state,
+                vec![],
+                transaction_info_hashes,
+                events,
+            )
+        };
+
+        assert_eq!(
+            make_result(None, vec![HashValue::zero()], vec![]).verify_reconfig_consistency(),
+            Ok(())
+        );
+        assert_eq!(
+            make_result(Some(EpochState::empty()), vec![HashValue::zero()], vec![
+                new_epoch_event.clone()
+            ])
+            .verify_reconfig_consistency(),
+            Ok(())
+        );
+        // Reconfiguration suffix blocks inherit the epoch state without the event.
+        assert_eq!(
+            make_result(Some(EpochState::empty()), vec![], vec![]).verify_reconfig_consistency(),
+            Ok(())
+        );
+        assert!(
+            make_result(Some(EpochState::empty()), vec![HashValue::zero()], vec![])
+                .verify_reconfig_consistency()
+                .is_err()
+        );
+        assert!(
+            make_result(None, vec![HashValue::zero()], vec![new_epoch_event])
+                .verify_reconfig_consistency()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_version() {
+        let make_result = |num_leaves: u64| {
+            StateComputeResult::new(
+                HashValue::zero(),
+                vec![],
+                num_leaves,
+                vec![],
+                0,
+                None,
+                vec![],
+                vec![],
+                vec![],
+            )
+        };
+        assert_eq!(make_result(0).version(), 0);
+        assert_eq!(make_result(1).version(), 0);
+        assert_eq!(make_result(10).version(), 9);
+        assert_eq!(make_result(u64::MAX).version(), u64::MAX - 1);
+    }
+}
@@ -0,0 +1,223 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{ChunkTrustMode, TransactionReplayer, VerifyExecutionMode};
+use anyhow::{ensure, Result};
+use aptos_types::{
+    contract_event::ContractEvent,
+    state_store::{in_memory_state_view::InMemoryStateView, state_key::StateKey, StateView},
+    transaction::{
+        signature_verified_transaction::SignatureVerifiedTransaction, Transaction, TransactionInfo,
+        TransactionOutput, Version,
+    },
+    write_set::WriteSet,
+};
+use itertools::multizip;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, path::Path};
+
+/// A self-contained reproduction of a chunk of transactions, e.g. a block that failed or diverged
+/// during verification. It carries the transactions together with their expected outputs and the
+/// state they were executed against, and can be written to and read back from a file so that the
+/// failure can be replayed locally in isolation.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ReplayBundle {
+    /// Version of the first transaction in the bundle.
+    pub first_version: Version,
+    pub transactions: Vec<Transaction>,
+    pub transaction_infos: Vec<TransactionInfo>,
+    pub write_sets: Vec<WriteSet>,
+    pub event_vecs: Vec<Vec<ContractEvent>>,
+    /// The state (or the relevant part of it) as of `first_version - 1`.
+    pub state_view: InMemoryStateView,
+}
+
+impl ReplayBundle {
+    pub fn new(
+        first_version: Version,
+        transactions: Vec<Transaction>,
+        transaction_infos: Vec<TransactionInfo>,
+        write_sets: Vec<WriteSet>,
+        event_vecs: Vec<Vec<ContractEvent>>,
+        state_view: InMemoryStateView,
+    ) -> Result<Self> {
+        ensure!(
+            transactions.len() == transaction_infos.len()
+                && transactions.len() == write_sets.len()
+                && transactions.len() == event_vecs.len(),
+            "Mismatched lengths: {} transactions, {} infos, {} write sets, {} event vecs.",
+            transactions.len(),
+            transaction_infos.len(),
+            write_sets.len(),
+            event_vecs.len(),
+        );
+        Ok(Self {
+            first_version,
+            transactions,
+            transaction_infos,
+            write_sets,
+            event_vecs,
+            state_view,
+        })
+    }
+
+    /// Snapshots the values of `keys` from `state_view`, so that the bundle does not depend on the
+    /// original storage. Keys that do not exist are omitted.
+    pub fn capture_state(
+        state_view: &impl StateView,
+        keys: impl IntoIterator<Item = StateKey>,
+    ) -> Result<InMemoryStateView> {
+        let mut state_data = HashMap::new();
+        for key in keys {
+            if let Some(value) = state_view.get_state_value(&key)? {
+                state_data.insert(key, value);
+            }
+        }
+        Ok(InMemoryStateView::new(state_data))
+    }
+
+    pub fn num_transactions(&self) -> usize {
+        self.transactions.len()
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(bcs::to_bytes(self)?)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Ok(bcs::from_bytes(bytes)?)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        Ok(std::fs::write(path, self.to_bytes()?)?)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+
+    /// Replays the transactions against the bundled state with `execute_block`, e.g.
+    /// `AptosVM::execute_block_no_limit`, and checks the outputs against the recorded ones.
+    pub fn replay(
+        &self,
+        execute_block: impl FnOnce(
+            &[SignatureVerifiedTransaction],
+            &InMemoryStateView,
+        ) -> Result<Vec<TransactionOutput>>,
+    ) -> Result<()> {
+        let txns = self
+            .transactions
+            .iter()
+            .cloned()
+            .map(SignatureVerifiedTransaction::from)
+            .collect::<Vec<_>>();
+        let outputs = execute_block(&txns, &self.state_view)?;
+        ensure!(
+            outputs.len() == self.num_transactions(),
+            "Expected {} outputs, got {}.",
+            self.num_transactions(),
+            outputs.len(),
+        );
+
+        for (version, output, txn_info, write_set, events) in multizip((
+            self.first_version..,
+            &outputs,
+            &self.transaction_infos,
+            &self.write_sets,
+            &self.event_vecs,
+        )) {
+            output.ensure_match_transaction_info(
+                version,
+                txn_info,
+                Some(write_set),
+                Some(events),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Replays the bundle through `replayer`, re-executing the transactions to verify per
+    /// `verify_execution_mode`, and commits them. Unlike `replay`, the bundled state view is not
+    /// used: the DB behind `replayer` must already be at version `first_version - 1`, e.g. restored
+    /// from a backup of the failing node.
+    pub fn replay_with_replayer(
+        &self,
+        replayer: &impl TransactionReplayer,
+        verify_execution_mode: &VerifyExecutionMode,
+    ) -> Result<()> {
+        replayer.replay(
+            self.transactions.clone(),
+            self.transaction_infos.clone(),
+            self.write_sets.clone(),
+            self.event_vecs.clone(),
+            verify_execution_mode,
+            ChunkTrustMode::Verify,
+        )?;
+        replayer.commit()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_crypto::HashValue;
+    use aptos_types::{state_store::TStateView, transaction::ExecutionStatus};
+
+    #[test]
+    fn replay_bundle_should_round_trip_through_bytes() {
+        let key = StateKey::raw(b"key");
+        let state_view =
+            InMemoryStateView::new([(key.clone(), vec![1, 2, 3].into())].into_iter().collect());
+        let state_view =
+            ReplayBundle::capture_state(&state_view, [key.clone(), StateKey::raw(b"missing")])
+                .unwrap();
+
+        let bundle = ReplayBundle::new(
+            7,
+            vec![Transaction::StateCheckpoint(HashValue::zero())],
+            vec![TransactionInfo::new(
+                HashValue::zero(),
+                HashValue::zero(),
+                HashValue::zero(),
+                Some(HashValue::zero()),
+                0,
+                ExecutionStatus::Success,
+            )],
+            vec![WriteSet::default()],
+            vec![vec![]],
+            state_view,
+        )
+        .unwrap();
+
+        let decoded = ReplayBundle::from_bytes(&bundle.to_bytes().unwrap()).unwrap();
+        assert_eq!(decoded.first_version, 7);
+        assert_eq!(decoded.num_transactions(), 1);
+        assert_eq!(decoded.transactions, bundle.transactions);
+        assert_eq!(decoded.transaction_infos, bundle.transaction_infos);
+        assert_eq!(
+            decoded.state_view.get_state_value(&key).unwrap(),
+            Some(vec![1, 2, 3].into())
+        );
+        assert_eq!(
+            decoded
+                .state_view
+                .get_state_value(&StateKey::raw(b"missing"))
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn replay_bundle_should_reject_mismatched_lengths() {
+        assert!(ReplayBundle::new(
+            0,
+            vec![Transaction::StateCheckpoint(HashValue::zero())],
+            vec![],
+            vec![],
+            vec![],
+            InMemoryStateView::new(HashMap::new()),
+        )
+        .is_err());
+    }
+}
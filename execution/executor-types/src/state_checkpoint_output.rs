@@ -7,7 +7,10 @@ use crate::parsed_transaction_output::TransactionsWithParsedOutput;
 use anyhow::{ensure, Result};
 use aptos_crypto::HashValue;
 use aptos_storage_interface::cached_state_view::ShardedStateCache;
-use aptos_types::{state_store::ShardedStateUpdates, transaction::TransactionStatus};
+use aptos_types::{
+    state_store::ShardedStateUpdates,
+    transaction::{BlockEndReason, TransactionStatus},
+};
 use itertools::zip_eq;
 
 #[derive(Default)]
@@ -65,6 +68,7 @@ pub struct StateCheckpointOutput {
     state_checkpoint_hashes: Vec<Option<HashValue>>,
     state_updates_before_last_checkpoint: Option<ShardedStateUpdates>,
     sharded_state_cache: ShardedStateCache,
+    block_end_reason: Option<BlockEndReason>,
 }
 
 impl StateCheckpointOutput {
@@ -81,9 +85,19 @@ impl StateCheckpointOutput {
             state_checkpoint_hashes,
             state_updates_before_last_checkpoint,
             sharded_state_cache,
+            block_end_reason: None,
         }
     }
 
+    pub fn with_block_end_reason(mut self, block_end_reason: Option<BlockEndReason>) -> Self {
+        self.block_end_reason = block_end_reason;
+        self
+    }
+
+    pub fn block_end_reason(&self) -> Option<BlockEndReason> {
+        self.block_end_reason
+    }
+
     pub fn input_txns_len(&self) -> usize {
         self.txns.input_txns_len()
     }
@@ -0,0 +1,121 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_crypto::HashValue;
+use aptos_scratchpad::ProofRead;
+use aptos_types::proof::{definition::NodeInProof, SparseMerkleLeafNode, SparseMerkleProofExt};
+use std::collections::HashMap;
+
+/// A `ProofRead` for tests that need to feed specific, possibly wrong, proofs to the executor,
+/// e.g. to exercise the error paths of chunk execution. Keys that were not registered are
+/// served by the base proof reader, if any.
+pub struct MockProofReader {
+    proofs: HashMap<HashValue, Option<SparseMerkleProofExt>>,
+    base: Option<Box<dyn ProofRead>>,
+}
+
+impl ProofRead for MockProofReader {
+    fn get_proof(&self, key: HashValue) -> Option<&SparseMerkleProofExt> {
+        match self.proofs.get(&key) {
+            Some(maybe_proof) => maybe_proof.as_ref(),
+            None => self.base.as_ref().and_then(|base| base.get_proof(key)),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct MockProofReaderBuilder {
+    proofs: HashMap<HashValue, Option<SparseMerkleProofExt>>,
+    base: Option<Box<dyn ProofRead>>,
+}
+
+impl MockProofReaderBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serves proofs for all keys that are not explicitly registered from `base`.
+    pub fn with_base(mut self, base: Box<dyn ProofRead>) -> Self {
+        self.base = Some(base);
+        self
+    }
+
+    /// Returns `proof` for `key`, e.g. a valid proof against an older (stale) state root.
+    pub fn with_proof(mut self, key: HashValue, proof: SparseMerkleProofExt) -> Self {
+        self.proofs.insert(key, Some(proof));
+        self
+    }
+
+    /// Returns no proof for `key`.
+    pub fn with_missing_proof(mut self, key: HashValue) -> Self {
+        self.proofs.insert(key, None);
+        self
+    }
+
+    /// Returns a proof for `key` that does not verify against any realistic root hash.
+    pub fn with_malformed_proof(mut self, key: HashValue) -> Self {
+        let leaf = SparseMerkleLeafNode::new(key, HashValue::random());
+        let siblings = vec![NodeInProof::Other(HashValue::random())];
+        self.proofs
+            .insert(key, Some(SparseMerkleProofExt::new(Some(leaf), siblings)));
+        self
+    }
+
+    pub fn build(self) -> MockProofReader {
+        MockProofReader {
+            proofs: self.proofs,
+            base: self.base,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProofReader;
+    use aptos_crypto::hash::CryptoHash;
+    use aptos_scratchpad::{SparseMerkleTree, UpdateError};
+    use aptos_types::state_store::{
+        state_storage_usage::StateStorageUsage, state_value::StateValue,
+    };
+
+    #[test]
+    fn test_mock_proof_reader() {
+        let key = HashValue::random();
+        let leaf = SparseMerkleLeafNode::new(key, HashValue::random());
+        let proof = SparseMerkleProofExt::new(Some(leaf), vec![]);
+        let base = ProofReader::new([(key, proof.clone())].into_iter().collect());
+
+        let other_key = HashValue::random();
+        let proof_reader = MockProofReaderBuilder::new()
+            .with_base(Box::new(base))
+            .with_malformed_proof(other_key)
+            .build();
+        assert_eq!(proof_reader.get_proof(key), Some(&proof));
+        assert!(proof_reader
+            .get_proof(other_key)
+            .unwrap()
+            .verify_by_hash(leaf.hash(), other_key, None)
+            .is_err());
+
+        let proof_reader = MockProofReaderBuilder::new()
+            .with_base(Box::new(ProofReader::new_empty()))
+            .with_missing_proof(key)
+            .build();
+        assert_eq!(proof_reader.get_proof(key), None);
+
+        // The SMT fails to update a persisted key without a proof.
+        let smt = SparseMerkleTree::<StateValue>::new(leaf.hash(), StateStorageUsage::zero());
+        let value = StateValue::from(vec![1]);
+        assert_eq!(
+            smt.freeze(&smt)
+                .batch_update(
+                    vec![(key, Some(&value))],
+                    StateStorageUsage::new_untracked(),
+                    &proof_reader,
+                )
+                .unwrap_err(),
+            UpdateError::MissingProof
+        );
+    }
+}
@@ -12,19 +12,20 @@ use crate::{
     metrics::{
         APTOS_EXECUTOR_COMMIT_BLOCKS_SECONDS, APTOS_EXECUTOR_EXECUTE_BLOCK_SECONDS,
         APTOS_EXECUTOR_LEDGER_UPDATE_SECONDS, APTOS_EXECUTOR_OTHER_TIMERS_SECONDS,
-        APTOS_EXECUTOR_SAVE_TRANSACTIONS_SECONDS, APTOS_EXECUTOR_TRANSACTIONS_SAVED,
-        APTOS_EXECUTOR_VM_EXECUTE_BLOCK_SECONDS,
+        APTOS_EXECUTOR_SAVE_TRANSACTIONS_SECONDS, APTOS_EXECUTOR_SMT_MEMORY_USAGE_BYTES,
+        APTOS_EXECUTOR_TRANSACTIONS_SAVED, APTOS_EXECUTOR_VM_EXECUTE_BLOCK_SECONDS,
     },
 };
 use anyhow::Result;
 use aptos_crypto::HashValue;
 use aptos_executor_types::{
     execution_output::ExecutionOutput, state_checkpoint_output::StateCheckpointOutput,
-    BlockExecutorTrait, ExecutorError, ExecutorResult, StateComputeResult,
+    BlockExecutorTrait, ExecutorError, ExecutorResult, ProofReader, StateComputeResult,
 };
 use aptos_experimental_runtimes::thread_manager::THREAD_MANAGER;
 use aptos_infallible::RwLock;
 use aptos_logger::prelude::*;
+use aptos_metrics_core::IntGaugeHelper;
 use aptos_scratchpad::SparseMerkleTree;
 use aptos_storage_interface::{
     async_proof_fetcher::AsyncProofFetcher, cached_state_view::CachedStateView, DbReaderWriter,
@@ -39,7 +40,13 @@ use aptos_types::{
 };
 use aptos_vm::AptosVM;
 use fail::fail_point;
-use std::{marker::PhantomData, sync::Arc};
+use std::{
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 pub trait TransactionBlockExecutor: Send + Sync {
     fn execute_transaction_block(
@@ -47,6 +54,14 @@ pub trait TransactionBlockExecutor: Send + Sync {
         state_view: CachedStateView,
         onchain_config: BlockExecutorConfigFromOnchain,
     ) -> Result<ChunkOutput>;
+
+    /// Executes the block like `execute_transaction_block`, but aborts once `cancelled` is set.
+    fn execute_transaction_block_with_cancellation(
+        transactions: ExecutableTransactions,
+        state_view: CachedStateView,
+        onchain_config: BlockExecutorConfigFromOnchain,
+        cancelled: Arc<AtomicBool>,
+    ) -> Result<ChunkOutput>;
 }
 
 impl TransactionBlockExecutor for AptosVM {
@@ -57,6 +72,20 @@ impl TransactionBlockExecutor for AptosVM {
     ) -> Result<ChunkOutput> {
         ChunkOutput::by_transaction_execution::<AptosVM>(transactions, state_view, onchain_config)
     }
+
+    fn execute_transaction_block_with_cancellation(
+        transactions: ExecutableTransactions,
+        state_view: CachedStateView,
+        onchain_config: BlockExecutorConfigFromOnchain,
+        cancelled: Arc<AtomicBool>,
+    ) -> Result<ChunkOutput> {
+        ChunkOutput::by_transaction_execution_with_cancellation::<AptosVM>(
+            transactions,
+            state_view,
+            onchain_config,
+            cancelled,
+        )
+    }
 }
 
 pub struct BlockExecutor<V> {
@@ -120,7 +149,49 @@ where
             .read()
             .as_ref()
             .expect("BlockExecutor is not reset")
-            .execute_and_state_checkpoint(block, parent_block_id, onchain_config)
+            .execute_and_state_checkpoint(block, parent_block_id, onchain_config, None, None)
+    }
+
+    fn execute_and_state_checkpoint_with_cancellation(
+        &self,
+        block: ExecutableBlock,
+        parent_block_id: HashValue,
+        onchain_config: BlockExecutorConfigFromOnchain,
+        cancelled: Arc<AtomicBool>,
+    ) -> ExecutorResult<StateCheckpointOutput> {
+        self.maybe_initialize()?;
+        self.inner
+            .read()
+            .as_ref()
+            .expect("BlockExecutor is not reset")
+            .execute_and_state_checkpoint(
+                block,
+                parent_block_id,
+                onchain_config,
+                Some(cancelled),
+                None,
+            )
+    }
+
+    fn execute_and_state_checkpoint_with_proofs(
+        &self,
+        block: ExecutableBlock,
+        parent_block_id: HashValue,
+        onchain_config: BlockExecutorConfigFromOnchain,
+        proof_reader: ProofReader,
+    ) -> ExecutorResult<StateCheckpointOutput> {
+        self.maybe_initialize()?;
+        self.inner
+            .read()
+            .as_ref()
+            .expect("BlockExecutor is not reset")
+            .execute_and_state_checkpoint(
+                block,
+                parent_block_id,
+                onchain_config,
+                None,
+                Some(proof_reader),
+            )
     }
 
     fn ledger_update(
@@ -147,7 +218,22 @@ where
             .read()
             .as_ref()
             .expect("BlockExecutor is not reset")
-            .commit_blocks_ext(block_ids, ledger_info_with_sigs, save_state_snapshots)
+            .commit_blocks_ext(block_ids, ledger_info_with_sigs, save_state_snapshots)?;
+        // Sample the memory held by the SMTs into the metric, now that the committed blocks were
+        // pruned from the block tree.
+        self.smt_memory_usage();
+        Ok(())
+    }
+
+    fn smt_memory_usage(&self) -> usize {
+        // The trees of all the speculative blocks are spawned from the root block's tree.
+        let usage = self
+            .inner
+            .read()
+            .as_ref()
+            .map_or(0, |inner| inner.root_smt().estimated_memory_usage());
+        APTOS_EXECUTOR_SMT_MEMORY_USAGE_BYTES.set_with(&["block"], usage as i64);
+        usage
     }
 
     fn finish(&self) {
@@ -192,6 +278,8 @@ where
         block: ExecutableBlock,
         parent_block_id: HashValue,
         onchain_config: BlockExecutorConfigFromOnchain,
+        cancelled: Option<Arc<AtomicBool>>,
+        proof_reader: Option<ProofReader>,
     ) -> ExecutorResult<StateCheckpointOutput> {
         let _timer = APTOS_EXECUTOR_EXECUTE_BLOCK_SECONDS.start_timer();
         let ExecutableBlock {
@@ -211,51 +299,76 @@ where
             "execute_block"
         );
         let committed_block_id = self.committed_block_id();
-        let (state, epoch_state, state_checkpoint_output) =
-            if parent_block_id != committed_block_id && parent_output.has_reconfiguration() {
-                info!(
-                    LogSchema::new(LogEntry::BlockExecutor).block_id(block_id),
-                    "reconfig_descendant_block_received"
-                );
-                (
-                    parent_output.state().clone(),
-                    parent_output.epoch_state().clone(),
-                    StateCheckpointOutput::default(),
-                )
-            } else {
-                let state_view = {
-                    let _timer = APTOS_EXECUTOR_OTHER_TIMERS_SECONDS
-                        .with_label_values(&["verified_state_view"])
-                        .start_timer();
-                    info!("next_version: {}", parent_output.next_version());
-                    CachedStateView::new(
-                        StateViewId::BlockExecution { block_id },
-                        Arc::clone(&self.db.reader),
-                        parent_output.next_version(),
-                        parent_output.state().current.clone(),
-                        Arc::new(AsyncProofFetcher::new(self.db.reader.clone())),
-                    )?
-                };
-
-                let chunk_output = {
-                    let _timer = APTOS_EXECUTOR_VM_EXECUTE_BLOCK_SECONDS.start_timer();
-                    fail_point!("executor::vm_execute_block", |_| {
-                        Err(ExecutorError::from(anyhow::anyhow!(
-                            "Injected error in vm_execute_block"
-                        )))
-                    });
-                    V::execute_transaction_block(transactions, state_view, onchain_config.clone())?
-                };
-
+        let (state, epoch_state, state_checkpoint_output) = if parent_block_id != committed_block_id
+            && parent_output.has_reconfiguration()
+        {
+            info!(
+                LogSchema::new(LogEntry::BlockExecutor).block_id(block_id),
+                "reconfig_descendant_block_received"
+            );
+            (
+                parent_output.state().clone(),
+                parent_output.epoch_state().clone(),
+                StateCheckpointOutput::default(),
+            )
+        } else {
+            let state_view = {
                 let _timer = APTOS_EXECUTOR_OTHER_TIMERS_SECONDS
-                    .with_label_values(&["state_checkpoint"])
+                    .with_label_values(&["verified_state_view"])
                     .start_timer();
+                info!("next_version: {}", parent_output.next_version());
+                let mut proof_fetcher = AsyncProofFetcher::new(self.db.reader.clone());
+                if let Some(proof_reader) = proof_reader {
+                    proof_fetcher = proof_fetcher.with_precomputed_proofs(Box::new(proof_reader));
+                }
+                CachedStateView::new(
+                    StateViewId::BlockExecution { block_id },
+                    Arc::clone(&self.db.reader),
+                    parent_output.next_version(),
+                    parent_output.state().current.clone(),
+                    Arc::new(proof_fetcher),
+                )?
+            };
 
-                THREAD_MANAGER.get_exe_cpu_pool().install(|| {
-                    chunk_output.into_state_checkpoint_output(parent_output.state(), block_id)
-                })?
+            let chunk_output = {
+                let _timer = APTOS_EXECUTOR_VM_EXECUTE_BLOCK_SECONDS.start_timer();
+                fail_point!("executor::vm_execute_block", |_| {
+                    Err(ExecutorError::from(anyhow::anyhow!(
+                        "Injected error in vm_execute_block"
+                    )))
+                });
+                match cancelled {
+                    Some(cancelled) => V::execute_transaction_block_with_cancellation(
+                        transactions,
+                        state_view,
+                        onchain_config.clone(),
+                        cancelled.clone(),
+                    )
+                    .map_err(|err| {
+                        // The block is rejected as a whole, nothing is added to the block tree.
+                        if cancelled.load(Ordering::Relaxed) {
+                            ExecutorError::Cancelled
+                        } else {
+                            err.into()
+                        }
+                    })?,
+                    None => V::execute_transaction_block(
+                        transactions,
+                        state_view,
+                        onchain_config.clone(),
+                    )?,
+                }
             };
 
+            let _timer = APTOS_EXECUTOR_OTHER_TIMERS_SECONDS
+                .with_label_values(&["state_checkpoint"])
+                .start_timer();
+
+            THREAD_MANAGER.get_exe_cpu_pool().install(|| {
+                chunk_output.into_state_checkpoint_output(parent_output.state(), block_id)
+            })?
+        };
+
         let _ = self.block_tree.add_block(
             parent_block_id,
             block_id,
@@ -291,13 +404,15 @@ where
         let current_output = block_vec.pop().expect("Must exist").unwrap();
         parent_block.ensure_has_child(block_id)?;
         if current_output.output.has_ledger_update() {
-            return Ok(current_output
+            let state_compute_result = current_output
                 .output
                 .get_ledger_update()
                 .as_state_compute_result(
                     parent_accumulator,
                     current_output.output.epoch_state().clone(),
-                ));
+                );
+            debug_assert_eq!(state_compute_result.verify_reconfig_consistency(), Ok(()));
+            return Ok(state_compute_result);
         }
 
         let output =
@@ -325,6 +440,7 @@ where
             parent_accumulator,
             current_output.output.epoch_state().clone(),
         );
+        debug_assert_eq!(state_compute_result.verify_reconfig_consistency(), Ok(()));
         current_output.output.set_ledger_update(output);
         Ok(state_compute_result)
     }
@@ -14,19 +14,19 @@ use crate::{
     metrics::{
         APTOS_CHUNK_EXECUTOR_OTHER_SECONDS, APTOS_EXECUTOR_APPLY_CHUNK_SECONDS,
         APTOS_EXECUTOR_COMMIT_CHUNK_SECONDS, APTOS_EXECUTOR_EXECUTE_CHUNK_SECONDS,
-        APTOS_EXECUTOR_VM_EXECUTE_CHUNK_SECONDS,
+        APTOS_EXECUTOR_SMT_MEMORY_USAGE_BYTES, APTOS_EXECUTOR_VM_EXECUTE_CHUNK_SECONDS,
     },
 };
-use anyhow::{anyhow, ensure, Result};
+use anyhow::Result;
 use aptos_drop_helper::DEFAULT_DROPPER;
 use aptos_executor_types::{
-    ChunkCommitNotification, ChunkExecutorTrait, ExecutedChunk, ParsedTransactionOutput,
-    TransactionReplayer, VerifyExecutionMode,
+    ChunkCommitNotification, ChunkExecutorTrait, ChunkTrustMode, ExecutedChunk, ExecutorError,
+    ExecutorResult, ParsedTransactionOutput, TransactionReplayer, VerifyExecutionMode,
 };
 use aptos_experimental_runtimes::thread_manager::{optimal_min_len, THREAD_MANAGER};
 use aptos_infallible::{Mutex, RwLock};
 use aptos_logger::prelude::*;
-use aptos_metrics_core::TimerHelper;
+use aptos_metrics_core::{IntGaugeHelper, TimerHelper};
 use aptos_storage_interface::{
     async_proof_fetcher::AsyncProofFetcher, cached_state_view::CachedStateView,
     state_delta::StateDelta, DbReaderWriter, ExecutedTrees,
@@ -35,6 +35,7 @@ use aptos_types::{
     block_executor::config::BlockExecutorConfigFromOnchain,
     contract_event::ContractEvent,
     ledger_info::LedgerInfoWithSignatures,
+    proof::{AccumulatorRangeProof, TransactionInfoListWithProof},
     state_store::StateViewId,
     transaction::{
         signature_verified_transaction::SignatureVerifiedTransaction, Transaction,
@@ -48,7 +49,7 @@ use fail::fail_point;
 use itertools::multizip;
 use once_cell::sync::Lazy;
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
-use std::{iter::once, marker::PhantomData, sync::Arc};
+use std::{iter::once, marker::PhantomData, ops::RangeInclusive, sync::Arc};
 
 pub static SIG_VERIFY_POOL: Lazy<Arc<rayon::ThreadPool>> = Lazy::new(|| {
     Arc::new(
@@ -73,7 +74,7 @@ impl<V: VMExecutor> ChunkExecutor<V> {
         }
     }
 
-    fn maybe_initialize(&self) -> Result<()> {
+    fn maybe_initialize(&self) -> ExecutorResult<()> {
         if self.inner.read().is_none() {
             self.reset()?;
         }
@@ -87,13 +88,19 @@ impl<V: VMExecutor> ChunkExecutorTrait for ChunkExecutor<V> {
         txn_list_with_proof: TransactionListWithProof,
         verified_target_li: &LedgerInfoWithSignatures,
         epoch_change_li: Option<&LedgerInfoWithSignatures>,
-    ) -> Result<()> {
+        trust_mode: ChunkTrustMode,
+    ) -> ExecutorResult<()> {
         self.maybe_initialize()?;
         self.inner
             .read()
             .as_ref()
             .expect("not reset")
-            .enqueue_chunk_by_execution(txn_list_with_proof, verified_target_li, epoch_change_li)
+            .enqueue_chunk_by_execution(
+                txn_list_with_proof,
+                verified_target_li,
+                epoch_change_li,
+                trust_mode,
+            )
     }
 
     fn enqueue_chunk_by_transaction_outputs(
@@ -101,7 +108,8 @@ impl<V: VMExecutor> ChunkExecutorTrait for ChunkExecutor<V> {
         txn_output_list_with_proof: TransactionOutputListWithProof,
         verified_target_li: &LedgerInfoWithSignatures,
         epoch_change_li: Option<&LedgerInfoWithSignatures>,
-    ) -> Result<()> {
+        trust_mode: ChunkTrustMode,
+    ) -> ExecutorResult<()> {
         self.inner
             .read()
             .as_ref()
@@ -110,10 +118,11 @@ impl<V: VMExecutor> ChunkExecutorTrait for ChunkExecutor<V> {
                 txn_output_list_with_proof,
                 verified_target_li,
                 epoch_change_li,
+                trust_mode,
             )
     }
 
-    fn update_ledger(&self) -> Result<()> {
+    fn update_ledger(&self) -> ExecutorResult<()> {
         self.inner
             .read()
             .as_ref()
@@ -121,15 +130,57 @@ impl<V: VMExecutor> ChunkExecutorTrait for ChunkExecutor<V> {
             .update_ledger()
     }
 
-    fn commit_chunk(&self) -> Result<ChunkCommitNotification> {
+    fn commit_chunk(&self) -> ExecutorResult<ChunkCommitNotification> {
+        let notification = self
+            .inner
+            .read()
+            .as_ref()
+            .expect("not reset")
+            .commit_chunk()?;
+        // Sample the memory held by the SMTs into the metric, now that the committed chunk was
+        // released from the commit queue.
+        self.smt_memory_usage();
+        Ok(notification)
+    }
+
+    fn execute_and_commit_chunk_with_checkpoints(
+        &self,
+        txn_list_with_proof: TransactionListWithProof,
+        verified_target_li: &LedgerInfoWithSignatures,
+        epoch_change_li: Option<&LedgerInfoWithSignatures>,
+        checkpoint_interval: usize,
+    ) -> ExecutorResult<ChunkCommitNotification> {
+        self.maybe_initialize()?;
         self.inner
             .read()
             .as_ref()
             .expect("not reset")
-            .commit_chunk()
+            .execute_and_commit_chunk_with_checkpoints(
+                txn_list_with_proof,
+                verified_target_li,
+                epoch_change_li,
+                checkpoint_interval,
+            )
+    }
+
+    fn pending_version_range(&self) -> Option<RangeInclusive<Version>> {
+        self.inner
+            .read()
+            .as_ref()
+            .and_then(|inner| inner.commit_queue.lock().pending_version_range())
     }
 
-    fn reset(&self) -> Result<()> {
+    fn smt_memory_usage(&self) -> usize {
+        let usage = self
+            .inner
+            .read()
+            .as_ref()
+            .map_or(0, |inner| inner.commit_queue.lock().smt_memory_usage());
+        APTOS_EXECUTOR_SMT_MEMORY_USAGE_BYTES.set_with(&["chunk"], usage as i64);
+        usage
+    }
+
+    fn reset(&self) -> ExecutorResult<()> {
         *self.inner.write() = Some(ChunkExecutorInner::new(self.db.clone())?);
         Ok(())
     }
@@ -166,7 +217,7 @@ impl<V: VMExecutor> ChunkExecutorInner<V> {
         )?)
     }
 
-    fn commit_chunk_impl(&self) -> Result<ExecutedChunk> {
+    fn commit_chunk_impl(&self) -> ExecutorResult<ExecutedChunk> {
         let _timer = APTOS_CHUNK_EXECUTOR_OTHER_SECONDS.timer_with(&["commit_chunk_impl__total"]);
         let (persisted_state, chunk) = {
             let _timer = APTOS_CHUNK_EXECUTOR_OTHER_SECONDS
@@ -178,7 +229,7 @@ impl<V: VMExecutor> ChunkExecutorInner<V> {
             let _timer =
                 APTOS_CHUNK_EXECUTOR_OTHER_SECONDS.timer_with(&["commit_chunk_impl__save_txns"]);
             fail_point!("executor::commit_chunk", |_| {
-                Err(anyhow::anyhow!("Injected error in commit_chunk"))
+                Err(anyhow::anyhow!("Injected error in commit_chunk").into())
             });
             self.db.writer.save_transactions(
                 chunk.transactions_to_commit(),
@@ -213,47 +264,61 @@ impl<V: VMExecutor> ChunkExecutorInner<V> {
         txn_list_with_proof: TransactionListWithProof,
         verified_target_li: &LedgerInfoWithSignatures,
         epoch_change_li: Option<&LedgerInfoWithSignatures>,
-    ) -> Result<()> {
+        trust_mode: ChunkTrustMode,
+    ) -> ExecutorResult<()> {
         let _timer = APTOS_EXECUTOR_EXECUTE_CHUNK_SECONDS.start_timer();
 
         let num_txns = txn_list_with_proof.transactions.len();
-        ensure!(num_txns != 0, "Empty transaction list!");
-        let first_version_in_request = txn_list_with_proof
-            .first_transaction_version
-            .ok_or_else(|| anyhow!("Non-empty chunk with first_version == None."))?;
+        if num_txns == 0 {
+            return Err(ExecutorError::internal_err("Empty transaction list!"));
+        }
+        let Some(first_version_in_request) = txn_list_with_proof.first_transaction_version else {
+            return Err(ExecutorError::internal_err(
+                "Non-empty chunk with first_version == None.",
+            ));
+        };
         let parent_state = self.commit_queue.lock().latest_state();
-        ensure!(
-            first_version_in_request == parent_state.next_version(),
-            "Unexpected chunk. version in request: {}, current_version: {:?}",
-            first_version_in_request,
-            parent_state.current_version,
-        );
-
+        // A chunk overlapping with already applied transactions is resumed from the parent state,
+        // e.g. after a restart in the middle of syncing it.
+        if first_version_in_request > parent_state.next_version()
+            || parent_state.next_version() >= first_version_in_request + num_txns as Version
         {
+            return Err(ExecutorError::UnexpectedChunkVersion {
+                first_version_in_request,
+                num_txns,
+                next_version: parent_state.next_version(),
+            });
+        }
+        let num_txns_to_skip = (parent_state.next_version() - first_version_in_request) as usize;
+
+        if trust_mode.should_verify_proofs() {
             let _timer = APTOS_CHUNK_EXECUTOR_OTHER_SECONDS
                 .timer_with(&["enqueue_chunk_by_execution__verify_chunk"]);
             THREAD_MANAGER
                 .get_exe_cpu_pool()
-                .install(|| -> Result<()> {
+                .install(|| {
                     verify_chunk(
                         &txn_list_with_proof,
                         verified_target_li,
                         Some(first_version_in_request),
                     )
-                })?;
+                })
+                .map_err(|e| ExecutorError::ProofVerificationError(e.to_string()))?;
         }
 
         let TransactionListWithProof {
-            transactions,
+            mut transactions,
             events: _,
             first_transaction_version: _,
             proof: txn_infos_with_proof,
         } = txn_list_with_proof;
+        transactions.drain(..num_txns_to_skip);
         let verified_target_li = verified_target_li.clone();
         let epoch_change_li = epoch_change_li.cloned();
         let known_state_checkpoints: Vec<_> = txn_infos_with_proof
             .transaction_infos
             .iter()
+            .skip(num_txns_to_skip)
             .map(|t| t.state_checkpoint_hash())
             .collect();
 
@@ -299,7 +364,9 @@ impl<V: VMExecutor> ChunkExecutorInner<V> {
                 next_epoch_state,
                 verified_target_li,
                 epoch_change_li,
+                first_transaction_info_version: first_version_in_request,
                 txn_infos_with_proof,
+                trust_mode,
             })?;
 
         info!(
@@ -317,33 +384,42 @@ impl<V: VMExecutor> ChunkExecutorInner<V> {
         txn_output_list_with_proof: TransactionOutputListWithProof,
         verified_target_li: &LedgerInfoWithSignatures,
         epoch_change_li: Option<&LedgerInfoWithSignatures>,
-    ) -> Result<()> {
+        trust_mode: ChunkTrustMode,
+    ) -> ExecutorResult<()> {
         let _timer = APTOS_EXECUTOR_APPLY_CHUNK_SECONDS.start_timer();
 
         let num_txns = txn_output_list_with_proof.transactions_and_outputs.len();
-        ensure!(num_txns != 0, "Empty transaction list!");
-        let first_version_in_request = txn_output_list_with_proof
-            .first_transaction_output_version
-            .ok_or_else(|| anyhow!("Non-empty chunk with first_version == None."))?;
+        if num_txns == 0 {
+            return Err(ExecutorError::internal_err("Empty transaction list!"));
+        }
+        let Some(first_version_in_request) =
+            txn_output_list_with_proof.first_transaction_output_version
+        else {
+            return Err(ExecutorError::internal_err(
+                "Non-empty chunk with first_version == None.",
+            ));
+        };
         let parent_state = self.commit_queue.lock().latest_state();
-        ensure!(
-            first_version_in_request == parent_state.next_version(),
-            "Unexpected chunk. version in request: {}, current_version: {:?}",
-            first_version_in_request,
-            parent_state.current_version,
-        );
+        if first_version_in_request != parent_state.next_version() {
+            return Err(ExecutorError::UnexpectedChunkVersion {
+                first_version_in_request,
+                num_txns,
+                next_version: parent_state.next_version(),
+            });
+        }
 
-        {
+        if trust_mode.should_verify_proofs() {
             let _timer = APTOS_CHUNK_EXECUTOR_OTHER_SECONDS.timer_with(&["apply_chunk__verify"]);
             // Verify input transaction list.
             THREAD_MANAGER
                 .get_exe_cpu_pool()
-                .install(|| -> Result<()> {
+                .install(|| {
                     txn_output_list_with_proof.verify(
                         verified_target_li.ledger_info(),
                         Some(first_version_in_request),
                     )
-                })?;
+                })
+                .map_err(|e| ExecutorError::ProofVerificationError(e.to_string()))?;
         }
         let TransactionOutputListWithProof {
             transactions_and_outputs,
@@ -387,7 +463,9 @@ impl<V: VMExecutor> ChunkExecutorInner<V> {
                 next_epoch_state,
                 verified_target_li,
                 epoch_change_li,
+                first_transaction_info_version: first_version_in_request,
                 txn_infos_with_proof,
+                trust_mode,
             })?;
 
         info!(
@@ -400,7 +478,7 @@ impl<V: VMExecutor> ChunkExecutorInner<V> {
         Ok(())
     }
 
-    pub fn update_ledger(&self) -> Result<()> {
+    pub fn update_ledger(&self) -> ExecutorResult<()> {
         let _timer = APTOS_CHUNK_EXECUTOR_OTHER_SECONDS.timer_with(&["chunk_update_ledger_total"]);
 
         let (parent_accumulator, chunk) = {
@@ -414,21 +492,31 @@ impl<V: VMExecutor> ChunkExecutorInner<V> {
             next_epoch_state,
             verified_target_li,
             epoch_change_li,
+            first_transaction_info_version,
             txn_infos_with_proof,
+            trust_mode,
         } = chunk;
 
         let first_version = parent_accumulator.num_leaves();
+        let num_overlap = (first_version - first_transaction_info_version) as usize;
 
         // In consensus-only mode, we cannot verify the proof against the executed output,
         // because the proof returned by the remote peer is an empty one.
         #[cfg(not(feature = "consensus-only-perf-test"))]
-        {
-            let num_overlap = txn_infos_with_proof.verify_extends_ledger(
-                first_version,
-                parent_accumulator.root_hash(),
-                Some(first_version),
-            )?;
-            assert_eq!(num_overlap, 0, "overlapped chunks");
+        if trust_mode.should_verify_proofs() {
+            let num_verified_overlap = txn_infos_with_proof
+                .verify_extends_ledger(
+                    first_version,
+                    parent_accumulator.root_hash(),
+                    Some(first_transaction_info_version),
+                )
+                .map_err(|e| ExecutorError::ProofVerificationError(e.to_string()))?;
+            if num_verified_overlap != num_overlap {
+                return Err(ExecutorError::internal_err(format!(
+                    "Overlapped chunk. first version: {}, expected overlap: {}, verified overlap: {}",
+                    first_transaction_info_version, num_overlap, num_verified_overlap,
+                )));
+            }
         }
 
         let (ledger_update_output, to_discard, to_retry) = {
@@ -436,10 +524,11 @@ impl<V: VMExecutor> ChunkExecutorInner<V> {
                 APTOS_CHUNK_EXECUTOR_OTHER_SECONDS.timer_with(&["chunk_update_ledger__calculate"]);
             ApplyChunkOutput::calculate_ledger_update(state_checkpoint_output, parent_accumulator)?
         };
-        ensure!(to_discard.is_empty(), "Unexpected discard.");
-        ensure!(to_retry.is_empty(), "Unexpected retry.");
-        ledger_update_output
-            .ensure_transaction_infos_match(&txn_infos_with_proof.transaction_infos)?;
+        ensure_no_discard(to_discard)?;
+        ensure_no_retry(to_retry)?;
+        ledger_update_output.ensure_transaction_infos_match(
+            &txn_infos_with_proof.transaction_infos[num_overlap..],
+        )?;
         let ledger_info_opt = ledger_update_output.maybe_select_chunk_ending_ledger_info(
             &verified_target_li,
             epoch_change_li.as_ref(),
@@ -467,7 +556,150 @@ impl<V: VMExecutor> ChunkExecutorInner<V> {
         Ok(())
     }
 
-    fn commit_chunk(&self) -> Result<ChunkCommitNotification> {
+    fn execute_and_commit_chunk_with_checkpoints(
+        &self,
+        txn_list_with_proof: TransactionListWithProof,
+        verified_target_li: &LedgerInfoWithSignatures,
+        epoch_change_li: Option<&LedgerInfoWithSignatures>,
+        checkpoint_interval: usize,
+    ) -> ExecutorResult<ChunkCommitNotification> {
+        if checkpoint_interval == 0 {
+            return Err(ExecutorError::internal_err(
+                "Checkpoint interval must be positive.",
+            ));
+        }
+        let num_txns = txn_list_with_proof.transactions.len();
+        if num_txns == 0 {
+            return Err(ExecutorError::internal_err("Empty transaction list!"));
+        }
+        let Some(first_version_in_request) = txn_list_with_proof.first_transaction_version else {
+            return Err(ExecutorError::internal_err(
+                "Non-empty chunk with first_version == None.",
+            ));
+        };
+        // Each checkpoint is committed right after it is executed, so earlier chunks must not be
+        // pending in the queue.
+        let latest_view = {
+            let commit_queue = self.commit_queue.lock();
+            if let Some(pending_versions) = commit_queue.pending_version_range() {
+                return Err(ExecutorError::internal_err(format!(
+                    "Can't checkpoint a chunk while versions {:?} are pending.",
+                    pending_versions,
+                )));
+            }
+            commit_queue.expect_latest_view()?
+        };
+        let next_version = latest_view.num_transactions() as Version;
+        if first_version_in_request > next_version
+            || next_version >= first_version_in_request + num_txns as Version
+        {
+            return Err(ExecutorError::UnexpectedChunkVersion {
+                first_version_in_request,
+                num_txns,
+                next_version,
+            });
+        }
+        let num_txns_to_skip = (next_version - first_version_in_request) as usize;
+
+        // Verify the whole chunk once, the checkpoints are then executed in `TrustSource` mode and
+        // checked against the verified transaction infos.
+        THREAD_MANAGER
+            .get_exe_cpu_pool()
+            .install(|| {
+                verify_chunk(
+                    &txn_list_with_proof,
+                    verified_target_li,
+                    Some(first_version_in_request),
+                )
+            })
+            .map_err(|e| ExecutorError::ProofVerificationError(e.to_string()))?;
+        #[cfg(not(feature = "consensus-only-perf-test"))]
+        {
+            txn_list_with_proof
+                .proof
+                .verify_extends_ledger(
+                    next_version,
+                    latest_view.txn_accumulator().root_hash(),
+                    Some(first_version_in_request),
+                )
+                .map_err(|e| ExecutorError::ProofVerificationError(e.to_string()))?;
+        }
+
+        let TransactionListWithProof {
+            transactions,
+            events: _,
+            first_transaction_version: _,
+            proof: txn_infos_with_proof,
+        } = txn_list_with_proof;
+        let mut transactions = transactions.into_iter().skip(num_txns_to_skip);
+        let mut transaction_infos = txn_infos_with_proof
+            .transaction_infos
+            .into_iter()
+            .skip(num_txns_to_skip);
+
+        let mut commit_notification = ChunkCommitNotification {
+            subscribable_events: vec![],
+            committed_transactions: vec![],
+            reconfiguration_occurred: false,
+        };
+        let mut checkpoint_version = next_version;
+        let mut num_remaining_txns = num_txns - num_txns_to_skip;
+        while num_remaining_txns > 0 {
+            let num_checkpoint_txns = checkpoint_interval.min(num_remaining_txns);
+            num_remaining_txns -= num_checkpoint_txns;
+            let checkpoint_txns: Vec<_> = transactions.by_ref().take(num_checkpoint_txns).collect();
+            let checkpoint_txn_infos: Vec<_> = transaction_infos
+                .by_ref()
+                .take(num_checkpoint_txns)
+                .collect();
+            // Only the last checkpoint can end the epoch.
+            let checkpoint_epoch_change_li = if num_remaining_txns == 0 {
+                epoch_change_li
+            } else {
+                None
+            };
+
+            self.enqueue_chunk_by_execution(
+                TransactionListWithProof::new(
+                    checkpoint_txns,
+                    None,
+                    Some(checkpoint_version),
+                    TransactionInfoListWithProof::new(
+                        AccumulatorRangeProof::new_empty(),
+                        checkpoint_txn_infos,
+                    ),
+                ),
+                verified_target_li,
+                checkpoint_epoch_change_li,
+                ChunkTrustMode::TrustSource,
+            )?;
+            self.update_ledger()?;
+            let ChunkCommitNotification {
+                subscribable_events,
+                committed_transactions,
+                reconfiguration_occurred,
+            } = self.commit_chunk()?;
+            commit_notification
+                .subscribable_events
+                .extend(subscribable_events);
+            commit_notification
+                .committed_transactions
+                .extend(committed_transactions);
+            commit_notification.reconfiguration_occurred |= reconfiguration_occurred;
+
+            info!(
+                LogSchema::new(LogEntry::ChunkExecutor)
+                    .first_version_in_request(Some(checkpoint_version))
+                    .num_txns_in_request(num_checkpoint_txns),
+                "Committed chunk checkpoint!",
+            );
+            checkpoint_version += num_checkpoint_txns as Version;
+        }
+
+        Ok(commit_notification)
+    }
+
+    fn commit_chunk(&self) -> ExecutorResult<ChunkCommitNotification> {
         let _timer = APTOS_EXECUTOR_COMMIT_CHUNK_SECONDS.start_timer();
         let executed_chunk = self.commit_chunk_impl()?;
 
@@ -516,6 +748,7 @@ impl<V: VMExecutor> TransactionReplayer for ChunkExecutor<V> {
         write_sets: Vec<WriteSet>,
         event_vecs: Vec<Vec<ContractEvent>>,
         verify_execution_mode: &VerifyExecutionMode,
+        trust_mode: ChunkTrustMode,
     ) -> Result<()> {
         self.maybe_initialize()?;
         self.inner.read().as_ref().expect("not reset").replay(
@@ -524,6 +757,7 @@ impl<V: VMExecutor> TransactionReplayer for ChunkExecutor<V> {
             write_sets,
             event_vecs,
             verify_execution_mode,
+            trust_mode,
         )
     }
 
@@ -540,7 +774,10 @@ impl<V: VMExecutor> TransactionReplayer for ChunkExecutorInner<V> {
         mut write_sets: Vec<WriteSet>,
         mut event_vecs: Vec<Vec<ContractEvent>>,
         verify_execution_mode: &VerifyExecutionMode,
+        trust_mode: ChunkTrustMode,
     ) -> Result<()> {
+        trust_mode.ensure_compatible_with(verify_execution_mode)?;
+
         let mut latest_view = self.commit_queue.lock().expect_latest_view()?;
         let chunk_begin = latest_view.num_transactions() as Version;
         let chunk_end = chunk_begin + transactions.len() as Version; // right-exclusive
@@ -574,6 +811,7 @@ impl<V: VMExecutor> TransactionReplayer for ChunkExecutorInner<V> {
                 begin,
                 end,
                 verify_execution_mode,
+                trust_mode,
             )?;
         }
 
@@ -583,7 +821,7 @@ impl<V: VMExecutor> TransactionReplayer for ChunkExecutorInner<V> {
     }
 
     fn commit(&self) -> Result<ExecutedChunk> {
-        self.commit_chunk_impl()
+        Ok(self.commit_chunk_impl()?)
     }
 }
 
@@ -602,16 +840,39 @@ impl<V: VMExecutor> ChunkExecutorInner<V> {
         begin_version: Version,
         end_version: Version,
         verify_execution_mode: &VerifyExecutionMode,
+        trust_mode: ChunkTrustMode,
     ) -> Result<()> {
+        // versions outside of the range to verify are applied directly
+        let verify_range =
+            verify_execution_mode.versions_to_verify_within(begin_version, end_version);
+        let (verify_begin, verify_end) = if verify_range.is_empty() {
+            (end_version, end_version)
+        } else {
+            (verify_range.start, verify_range.end)
+        };
+        if begin_version < verify_begin {
+            self.remove_and_apply(
+                executed_chunk,
+                latest_view,
+                transactions,
+                transaction_infos,
+                write_sets,
+                event_vecs,
+                begin_version,
+                verify_begin,
+                trust_mode,
+            )?;
+        }
+
         // we try to apply the txns in sub-batches split by known txns to skip and the end of the batch
         let txns_to_skip = verify_execution_mode.txns_to_skip();
         let mut batch_ends = txns_to_skip
-            .range(begin_version..end_version)
-            .chain(once(&end_version));
+            .range(verify_begin..verify_end)
+            .chain(once(&verify_end));
 
-        let mut batch_begin = begin_version;
+        let mut batch_begin = verify_begin;
         let mut batch_end = *batch_ends.next().unwrap();
-        while batch_begin < end_version {
+        while batch_begin < verify_end {
             if batch_begin == batch_end {
                 // batch_end is a known broken version that won't pass execution verification
                 self.remove_and_apply(
@@ -623,6 +884,7 @@ impl<V: VMExecutor> ChunkExecutorInner<V> {
                     event_vecs,
                     batch_begin,
                     batch_begin + 1,
+                    trust_mode,
                 )?;
                 info!(
                     version_skipped = batch_begin,
@@ -657,10 +919,25 @@ impl<V: VMExecutor> ChunkExecutorInner<V> {
                 event_vecs,
                 batch_begin,
                 next_begin,
+                trust_mode,
             )?;
             batch_begin = next_begin;
         }
 
+        if verify_end < end_version {
+            self.remove_and_apply(
+                executed_chunk,
+                latest_view,
+                transactions,
+                transaction_infos,
+                write_sets,
+                event_vecs,
+                verify_end,
+                end_version,
+                trust_mode,
+            )?;
+        }
+
         Ok(())
     }
 
@@ -728,6 +1005,7 @@ impl<V: VMExecutor> ChunkExecutorInner<V> {
         event_vecs: &mut Vec<Vec<ContractEvent>>,
         begin_version: Version,
         end_version: Version,
+        trust_mode: ChunkTrustMode,
     ) -> Result<()> {
         let num_txns = (end_version - begin_version) as usize;
         let txn_infos: Vec<_> = transaction_infos.drain(..num_txns).collect();
@@ -764,9 +1042,11 @@ impl<V: VMExecutor> ChunkExecutorInner<V> {
         )?;
         ensure_no_discard(to_discard)?;
         ensure_no_retry(to_retry)?;
-        executed_batch
-            .ledger_update_output
-            .ensure_transaction_infos_match(&txn_infos)?;
+        if trust_mode.should_verify_proofs() {
+            executed_batch
+                .ledger_update_output
+                .ensure_transaction_infos_match(&txn_infos)?;
+        }
 
         match executed_chunk {
             Some(chunk) => chunk.combine(executed_batch),
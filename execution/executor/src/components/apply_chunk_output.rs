@@ -17,10 +17,11 @@ use aptos_executor_types::{
     parsed_transaction_output::TransactionsWithParsedOutput,
     should_forward_to_subscription_service,
     state_checkpoint_output::{StateCheckpointOutput, TransactionsByStatus},
-    ExecutedChunk, LedgerUpdateOutput, ParsedTransactionOutput,
+    ExecutedChunk, LedgerUpdateOutput, ParsedTransactionOutput, ProofReader,
 };
 use aptos_experimental_runtimes::thread_manager::optimal_min_len;
 use aptos_logger::error;
+use aptos_scratchpad::ProofRead;
 use aptos_storage_interface::{state_delta::StateDelta, ExecutedTrees};
 use aptos_types::{
     contract_event::ContractEvent,
@@ -40,16 +41,39 @@ pub struct ApplyChunkOutput;
 
 impl ApplyChunkOutput {
     pub fn calculate_state_checkpoint(
+        mut chunk_output: ChunkOutput,
+        parent_state: &StateDelta,
+        append_state_checkpoint_to_block: Option<HashValue>,
+        known_state_checkpoints: Option<Vec<Option<HashValue>>>,
+        is_block: bool,
+    ) -> Result<(StateDelta, Option<EpochState>, StateCheckpointOutput)> {
+        let proof_reader = ProofReader::new(std::mem::take(&mut chunk_output.state_cache.proofs));
+        Self::calculate_state_checkpoint_with_proof_reader(
+            chunk_output,
+            parent_state,
+            append_state_checkpoint_to_block,
+            known_state_checkpoints,
+            is_block,
+            &proof_reader,
+        )
+    }
+
+    /// Same as `calculate_state_checkpoint`, but reads the proofs of the updated keys from
+    /// `proof_reader` instead of using the ones fetched during execution, e.g. to feed missing or
+    /// malformed proofs to the state calculation in tests.
+    pub fn calculate_state_checkpoint_with_proof_reader(
         chunk_output: ChunkOutput,
         parent_state: &StateDelta,
         append_state_checkpoint_to_block: Option<HashValue>,
         known_state_checkpoints: Option<Vec<Option<HashValue>>>,
         is_block: bool,
+        proof_reader: &impl ProofRead,
     ) -> Result<(StateDelta, Option<EpochState>, StateCheckpointOutput)> {
         let ChunkOutput {
             state_cache,
             transactions,
             transaction_outputs,
+            block_end_reason,
         } = chunk_output;
         let (new_epoch, statuses_for_input_txns, to_commit, to_discard, to_retry) = {
             let _timer = APTOS_EXECUTOR_OTHER_TIMERS_SECONDS
@@ -82,6 +106,7 @@ impl ApplyChunkOutput {
                 &to_commit,
                 new_epoch,
                 is_block,
+                proof_reader,
             )?
         };
 
@@ -91,7 +116,8 @@ impl ApplyChunkOutput {
             state_checkpoint_hashes,
             state_updates_before_last_checkpoint,
             sharded_state_cache,
-        );
+        )
+        .with_block_end_reason(block_end_reason);
 
         // On state sync/replay, we generate state checkpoints only periodically, for the
         // last state checkpoint of each chunk.
@@ -109,6 +135,7 @@ impl ApplyChunkOutput {
         state_checkpoint_output: StateCheckpointOutput,
         base_txn_accumulator: Arc<InMemoryTransactionAccumulator>,
     ) -> Result<(LedgerUpdateOutput, Vec<Transaction>, Vec<Transaction>)> {
+        let block_end_reason = state_checkpoint_output.block_end_reason();
         let (
             txns,
             state_updates_vec,
@@ -153,6 +180,7 @@ impl ApplyChunkOutput {
                 state_updates_until_last_checkpoint: state_updates_before_last_checkpoint,
                 sharded_state_cache,
                 transaction_accumulator,
+                block_end_reason,
             },
             to_discard.into_txns(),
             to_retry.into_txns(),
@@ -5,14 +5,17 @@
 #![forbid(unsafe_code)]
 
 use anyhow::{anyhow, ensure, Result};
-use aptos_executor_types::{state_checkpoint_output::StateCheckpointOutput, ExecutedChunk};
+use aptos_executor_types::{
+    state_checkpoint_output::StateCheckpointOutput, ChunkTrustMode, ExecutedChunk,
+};
 use aptos_storage_interface::{state_delta::StateDelta, DbReader, ExecutedTrees};
 use aptos_types::{
     epoch_state::EpochState,
     ledger_info::LedgerInfoWithSignatures,
     proof::{accumulator::InMemoryTransactionAccumulator, TransactionInfoListWithProof},
+    transaction::Version,
 };
-use std::{collections::VecDeque, sync::Arc};
+use std::{collections::VecDeque, ops::RangeInclusive, sync::Arc};
 
 pub(crate) struct ChunkToUpdateLedger {
     pub result_state: StateDelta,
@@ -24,7 +27,12 @@ pub(crate) struct ChunkToUpdateLedger {
     /// is updated.
     pub verified_target_li: LedgerInfoWithSignatures,
     pub epoch_change_li: Option<LedgerInfoWithSignatures>,
+    /// Version of the first transaction info in `txn_infos_with_proof`, which can be lower than
+    /// the first version executed in the chunk when resuming a partially applied chunk.
+    pub first_transaction_info_version: Version,
     pub txn_infos_with_proof: TransactionInfoListWithProof,
+    /// Whether the proofs are to be verified, see `ChunkTrustMode`.
+    pub trust_mode: ChunkTrustMode,
 }
 
 /// It's a two stage pipeline:
@@ -64,6 +72,18 @@ impl ChunkCommitQueue {
         self.latest_state.clone()
     }
 
+    /// The persisted state is the oldest tree the queue holds on to, all the pending states are
+    /// spawned from it.
+    pub(crate) fn smt_memory_usage(&self) -> usize {
+        self.persisted_state.current.estimated_memory_usage()
+    }
+
+    pub(crate) fn pending_version_range(&self) -> Option<RangeInclusive<Version>> {
+        let first_pending_version = self.persisted_state.next_version();
+        let next_version = self.latest_state.next_version();
+        (next_version > first_pending_version).then(|| first_pending_version..=next_version - 1)
+    }
+
     pub(crate) fn expect_latest_view(&self) -> Result<ExecutedTrees> {
         ensure!(
             self.to_update_ledger.is_empty(),
@@ -29,14 +29,18 @@ use aptos_types::{
     transaction::{
         authenticator::AccountAuthenticator,
         signature_verified_transaction::{SignatureVerifiedTransaction, TransactionProvider},
-        BlockOutput, ExecutionStatus, Transaction, TransactionOutput, TransactionOutputProvider,
-        TransactionStatus,
+        BlockEndReason, BlockOutput, ExecutionStatus, Transaction, TransactionOutput,
+        TransactionOutputProvider, TransactionStatus,
     },
 };
 use aptos_vm::{AptosVM, VMExecutor};
 use fail::fail_point;
 use move_core_types::vm_status::StatusCode;
-use std::{ops::Deref, sync::Arc, time::Duration};
+use std::{
+    ops::Deref,
+    sync::{atomic::AtomicBool, Arc},
+    time::Duration,
+};
 
 pub struct ChunkOutput {
     /// Input transactions.
@@ -47,6 +51,8 @@ pub struct ChunkOutput {
     /// execution result is processed; as well as all the accounts touched during execution, together
     /// with their proofs.
     pub state_cache: StateCache,
+    /// Why the block executor stopped executing the block, only set for unsharded blocks.
+    pub block_end_reason: Option<BlockEndReason>,
 }
 
 impl ChunkOutput {
@@ -54,10 +60,40 @@ impl ChunkOutput {
         transactions: ExecutableTransactions,
         state_view: CachedStateView,
         onchain_config: BlockExecutorConfigFromOnchain,
+    ) -> Result<Self> {
+        Self::by_transaction_execution_impl::<V>(transactions, state_view, onchain_config, None)
+    }
+
+    /// Like `by_transaction_execution`, but the execution is aborted once `cancelled` is set.
+    /// Only unsharded blocks can be cancelled, sharded blocks are always executed to completion.
+    pub fn by_transaction_execution_with_cancellation<V: VMExecutor>(
+        transactions: ExecutableTransactions,
+        state_view: CachedStateView,
+        onchain_config: BlockExecutorConfigFromOnchain,
+        cancelled: Arc<AtomicBool>,
+    ) -> Result<Self> {
+        Self::by_transaction_execution_impl::<V>(
+            transactions,
+            state_view,
+            onchain_config,
+            Some(cancelled),
+        )
+    }
+
+    fn by_transaction_execution_impl<V: VMExecutor>(
+        transactions: ExecutableTransactions,
+        state_view: CachedStateView,
+        onchain_config: BlockExecutorConfigFromOnchain,
+        cancelled: Option<Arc<AtomicBool>>,
     ) -> Result<Self> {
         match transactions {
             ExecutableTransactions::Unsharded(txns) => {
-                Self::by_transaction_execution_unsharded::<V>(txns, state_view, onchain_config)
+                Self::by_transaction_execution_unsharded::<V>(
+                    txns,
+                    state_view,
+                    onchain_config,
+                    cancelled,
+                )
             },
             ExecutableTransactions::Sharded(txns) => {
                 Self::by_transaction_execution_sharded::<V>(txns, state_view, onchain_config)
@@ -69,15 +105,18 @@ impl ChunkOutput {
         transactions: Vec<SignatureVerifiedTransaction>,
         state_view: CachedStateView,
         onchain_config: BlockExecutorConfigFromOnchain,
+        cancelled: Option<Arc<AtomicBool>>,
     ) -> Result<Self> {
-        let block_output = Self::execute_block::<V>(&transactions, &state_view, onchain_config)?;
+        let block_output =
+            Self::execute_block::<V>(&transactions, &state_view, onchain_config, cancelled)?;
 
+        let block_end_reason = block_output.block_end_reason();
         let transaction_outputs = block_output.into_inner();
-        // TODO add block_limit_info to ChunkOutput, to add it to StateCheckpoint
         Ok(Self {
             transactions: transactions.into_iter().map(|t| t.into_inner()).collect(),
             transaction_outputs,
             state_cache: state_view.into_state_cache(),
+            block_end_reason,
         })
     }
 
@@ -105,6 +144,7 @@ impl ChunkOutput {
                 .collect(),
             transaction_outputs,
             state_cache: state_view.into_state_cache(),
+            block_end_reason: None,
         })
     }
 
@@ -130,6 +170,7 @@ impl ChunkOutput {
             transactions,
             transaction_outputs,
             state_cache: state_view.into_state_cache(),
+            block_end_reason: None,
         })
     }
 
@@ -195,8 +236,17 @@ impl ChunkOutput {
         transactions: &[SignatureVerifiedTransaction],
         state_view: &CachedStateView,
         onchain_config: BlockExecutorConfigFromOnchain,
+        cancelled: Option<Arc<AtomicBool>>,
     ) -> Result<BlockOutput<TransactionOutput>> {
-        Ok(V::execute_block(transactions, state_view, onchain_config)?)
+        Ok(match cancelled {
+            Some(cancelled) => V::execute_block_with_cancellation(
+                transactions,
+                state_view,
+                onchain_config,
+                cancelled,
+            )?,
+            None => V::execute_block(transactions, state_view, onchain_config)?,
+        })
     }
 
     /// In consensus-only mode, executes the block of [Transaction]s using the
@@ -208,6 +258,7 @@ impl ChunkOutput {
         transactions: &[SignatureVerifiedTransaction],
         state_view: &CachedStateView,
         onchain_config: BlockExecutorConfigFromOnchain,
+        _cancelled: Option<Arc<AtomicBool>>,
     ) -> Result<BlockOutput<TransactionOutput>> {
         use aptos_types::{
             state_store::{StateViewId, TStateView},
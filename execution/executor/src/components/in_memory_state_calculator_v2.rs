@@ -7,7 +7,7 @@ use aptos_crypto::{hash::CryptoHash, HashValue};
 use aptos_drop_helper::DEFAULT_DROPPER;
 use aptos_executor_types::{parsed_transaction_output::TransactionsWithParsedOutput, ProofReader};
 use aptos_logger::info;
-use aptos_scratchpad::FrozenSparseMerkleTree;
+use aptos_scratchpad::{FrozenSparseMerkleTree, ProofRead};
 use aptos_storage_interface::{
     cached_state_view::{ShardedStateCache, StateCache},
     state_delta::StateDelta,
@@ -71,12 +71,14 @@ impl<'a> TStateView for StateCacheView<'a> {
 pub struct InMemoryStateCalculatorV2 {}
 
 impl InMemoryStateCalculatorV2 {
+    /// The proofs of the updated keys are read from `proof_reader` rather than from `state_cache`.
     pub fn calculate_for_transactions(
         base: &StateDelta,
         state_cache: StateCache,
         to_commit: &TransactionsWithParsedOutput,
         new_epoch: bool,
         is_block: bool,
+        proof_reader: &impl ProofRead,
     ) -> Result<(
         Vec<ShardedStateUpdates>,
         Vec<Option<HashValue>>,
@@ -105,16 +107,18 @@ impl InMemoryStateCalculatorV2 {
             last_checkpoint_index,
             new_epoch,
             is_block,
+            proof_reader,
         )
     }
 
     pub fn calculate_for_write_sets_after_snapshot(
         base: &StateDelta,
-        state_cache: StateCache,
+        mut state_cache: StateCache,
         last_checkpoint_index: Option<usize>,
         write_sets: &[WriteSet],
     ) -> Result<(Option<ShardedStateUpdates>, StateDelta)> {
         let state_updates_vec = Self::get_sharded_state_updates(write_sets, |write_set| write_set);
+        let proof_reader = ProofReader::new(std::mem::take(&mut state_cache.proofs));
 
         let (_, _, result_state, _, updates_until_latest_checkpoint, _) = Self::calculate_impl(
             base,
@@ -123,6 +127,7 @@ impl InMemoryStateCalculatorV2 {
             last_checkpoint_index,
             /*new_epoch=*/ false,
             /*is_block=*/ false,
+            &proof_reader,
         )?;
 
         Ok((updates_until_latest_checkpoint, result_state))
@@ -135,6 +140,7 @@ impl InMemoryStateCalculatorV2 {
         last_checkpoint_index: Option<usize>,
         new_epoch: bool,
         is_block: bool,
+        proof_reader: &impl ProofRead,
     ) -> Result<(
         Vec<ShardedStateUpdates>,
         Vec<Option<HashValue>>,
@@ -148,7 +154,7 @@ impl InMemoryStateCalculatorV2 {
             // calculation
             frozen_base,
             sharded_state_cache,
-            proofs,
+            proofs: _,
         } = state_cache;
         assert!(frozen_base.smt.is_the_same(&base.current));
 
@@ -187,7 +193,6 @@ impl InMemoryStateCalculatorV2 {
         ]);
 
         let first_version = base.current_version.map_or(0, |v| v + 1);
-        let proof_reader = ProofReader::new(proofs);
         let latest_checkpoint = if let Some(index) = last_checkpoint_index {
             Self::make_checkpoint(
                 base.current.freeze(&frozen_base.base_smt),
@@ -197,7 +202,7 @@ impl InMemoryStateCalculatorV2 {
                 } else {
                     StateStorageUsage::new_untracked()
                 },
-                &proof_reader,
+                proof_reader,
             )?
         } else {
             // If there is no checkpoint in this chunk, the latest checkpoint will be the existing
@@ -229,7 +234,7 @@ impl InMemoryStateCalculatorV2 {
                 latest_tree,
                 &updates_after_last_checkpoint,
                 usage,
-                &proof_reader,
+                proof_reader,
             )?
             .smt
         };
@@ -401,7 +406,7 @@ impl InMemoryStateCalculatorV2 {
         latest_checkpoint: FrozenSparseMerkleTree<StateValue>,
         updates: &ShardedStateUpdates,
         usage: StateStorageUsage,
-        proof_reader: &ProofReader,
+        proof_reader: &impl ProofRead,
     ) -> Result<FrozenSparseMerkleTree<StateValue>> {
         let _timer = APTOS_EXECUTOR_OTHER_TIMERS_SECONDS
             .with_label_values(&["make_checkpoint"])
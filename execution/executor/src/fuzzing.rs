@@ -28,13 +28,16 @@ use aptos_types::{
         },
         BlockOutput, Transaction, TransactionOutput, TransactionToCommit, Version,
     },
-    vm_status::VMStatus,
+    vm_status::{StatusCode, VMStatus},
 };
 use aptos_vm::{
     sharded_block_executor::{executor_client::ExecutorClient, ShardedBlockExecutor},
     VMExecutor,
 };
-use std::sync::Arc;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
 
 fn create_test_executor() -> BlockExecutor<FakeVM> {
     // setup fake db
@@ -76,6 +79,20 @@ impl TransactionBlockExecutor for FakeVM {
     ) -> Result<ChunkOutput> {
         ChunkOutput::by_transaction_execution::<FakeVM>(transactions, state_view, onchain_config)
     }
+
+    fn execute_transaction_block_with_cancellation(
+        transactions: ExecutableTransactions,
+        state_view: CachedStateView,
+        onchain_config: BlockExecutorConfigFromOnchain,
+        cancelled: Arc<AtomicBool>,
+    ) -> Result<ChunkOutput> {
+        ChunkOutput::by_transaction_execution_with_cancellation::<FakeVM>(
+            transactions,
+            state_view,
+            onchain_config,
+            cancelled,
+        )
+    }
 }
 
 impl VMExecutor for FakeVM {
@@ -95,6 +112,21 @@ impl VMExecutor for FakeVM {
     ) -> Result<BlockOutput<TransactionOutput>, VMStatus> {
         Ok(BlockOutput::new(vec![]))
     }
+
+    fn execute_block_with_cancellation(
+        _transactions: &[SignatureVerifiedTransaction],
+        _state_view: &(impl StateView + Sync),
+        _onchain_config: BlockExecutorConfigFromOnchain,
+        cancelled: Arc<AtomicBool>,
+    ) -> Result<BlockOutput<TransactionOutput>, VMStatus> {
+        if cancelled.load(Ordering::Relaxed) {
+            return Err(VMStatus::error(
+                StatusCode::EXECUTION_LIMIT_REACHED,
+                Some("Block execution cancelled".to_string()),
+            ));
+        }
+        Ok(BlockOutput::new(vec![]))
+    }
 }
 
 /// A fake database implementing DbReader and DbWriter
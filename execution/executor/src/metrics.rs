@@ -4,7 +4,8 @@
 
 use aptos_metrics_core::{
     exponential_buckets, register_histogram, register_histogram_vec, register_int_counter,
-    register_int_counter_vec, Histogram, HistogramVec, IntCounter, IntCounterVec,
+    register_int_counter_vec, register_int_gauge_vec, Histogram, HistogramVec, IntCounter,
+    IntCounterVec, IntGaugeVec,
 };
 use once_cell::sync::Lazy;
 
@@ -239,3 +240,12 @@ pub static APTOS_PROCESSED_TXNS_AUTHENTICATOR: Lazy<IntCounterVec> = Lazy::new(|
     )
     .unwrap()
 });
+
+pub static APTOS_EXECUTOR_SMT_MEMORY_USAGE_BYTES: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "aptos_executor_smt_memory_usage_bytes",
+        "Estimated memory held by the in-memory sparse merkle trees, by executor",
+        &["executor"]
+    )
+    .unwrap()
+});
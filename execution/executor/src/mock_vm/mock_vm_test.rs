@@ -3,18 +3,34 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::{balance_ap, encode_mint_transaction, encode_transfer_transaction, seqnum_ap, MockVM};
+use aptos_crypto::HashValue;
 use aptos_types::{
     account_address::AccountAddress,
+    block_executor::{
+        config::BlockExecutorConfigFromOnchain, partitioner::PartitionedTransactions,
+    },
     bytes::NumToBytes,
     state_store::{
         state_key::StateKey, state_storage_usage::StateStorageUsage, state_value::StateValue,
-        Result, TStateView,
+        Result, StateView, TStateView,
+    },
+    transaction::{
+        signature_verified_transaction::{
+            into_signature_verified_block, SignatureVerifiedTransaction,
+        },
+        BlockOutput, Transaction, TransactionOutput,
     },
-    transaction::signature_verified_transaction::into_signature_verified_block,
+    vm_status::{StatusCode, VMStatus},
     write_set::WriteOp,
 };
-use aptos_vm::VMExecutor;
-use std::collections::BTreeMap;
+use aptos_vm::{
+    sharded_block_executor::{executor_client::ExecutorClient, ShardedBlockExecutor},
+    VMExecutor,
+};
+use std::{
+    collections::BTreeMap,
+    sync::{atomic::AtomicBool, Arc},
+};
 
 fn gen_address(index: u8) -> AccountAddress {
     AccountAddress::new([index; AccountAddress::LENGTH])
@@ -34,6 +50,56 @@ impl TStateView for MockStateView {
     }
 }
 
+/// Behaves like `MockVM`, but fails any block that contains a state checkpoint transaction.
+struct FailOnStateCheckpointVM;
+
+impl FailOnStateCheckpointVM {
+    fn check_no_state_checkpoint(
+        transactions: &[SignatureVerifiedTransaction],
+    ) -> std::result::Result<(), VMStatus> {
+        if transactions
+            .iter()
+            .any(|txn| matches!(txn.expect_valid(), Transaction::StateCheckpoint(_)))
+        {
+            return Err(VMStatus::error(
+                StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR,
+                None,
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl VMExecutor for FailOnStateCheckpointVM {
+    fn execute_block(
+        transactions: &[SignatureVerifiedTransaction],
+        state_view: &(impl StateView + Sync),
+        onchain_config: BlockExecutorConfigFromOnchain,
+    ) -> std::result::Result<BlockOutput<TransactionOutput>, VMStatus> {
+        Self::check_no_state_checkpoint(transactions)?;
+        MockVM::execute_block(transactions, state_view, onchain_config)
+    }
+
+    fn execute_block_with_cancellation(
+        transactions: &[SignatureVerifiedTransaction],
+        state_view: &(impl StateView + Sync),
+        onchain_config: BlockExecutorConfigFromOnchain,
+        cancelled: Arc<AtomicBool>,
+    ) -> std::result::Result<BlockOutput<TransactionOutput>, VMStatus> {
+        Self::check_no_state_checkpoint(transactions)?;
+        MockVM::execute_block_with_cancellation(transactions, state_view, onchain_config, cancelled)
+    }
+
+    fn execute_block_sharded<S: StateView + Sync + Send + 'static, E: ExecutorClient<S>>(
+        _sharded_block_executor: &ShardedBlockExecutor<S, E>,
+        _transactions: PartitionedTransactions,
+        _state_view: Arc<S>,
+        _onchain_config: BlockExecutorConfigFromOnchain,
+    ) -> std::result::Result<Vec<TransactionOutput>, VMStatus> {
+        unimplemented!()
+    }
+}
+
 #[test]
 fn test_mock_vm_different_senders() {
     let amount = 100;
@@ -149,3 +215,59 @@ fn test_mock_vm_payment() {
         .collect()
     );
 }
+
+#[test]
+fn test_mock_vm_execute_block_partial_without_error() {
+    let txns = vec![
+        encode_mint_transaction(gen_address(0), 100),
+        encode_transfer_transaction(gen_address(0), gen_address(1), 50),
+    ];
+    let txns = into_signature_verified_block(txns);
+
+    let (outputs, status) = MockVM::execute_block_partial(
+        &txns,
+        &MockStateView,
+        BlockExecutorConfigFromOnchain::new_no_block_limit(),
+    );
+    assert!(status.is_none());
+    assert_eq!(
+        outputs,
+        MockVM::execute_block_no_limit(&txns, &MockStateView).unwrap()
+    );
+}
+
+#[test]
+fn test_mock_vm_execute_block_partial_with_error() {
+    let txns = vec![
+        encode_mint_transaction(gen_address(0), 100),
+        encode_transfer_transaction(gen_address(0), gen_address(1), 50),
+        encode_mint_transaction(gen_address(1), 100),
+        Transaction::StateCheckpoint(HashValue::zero()),
+        encode_mint_transaction(gen_address(2), 100),
+    ];
+    let txns = into_signature_verified_block(txns);
+
+    let (outputs, status) = FailOnStateCheckpointVM::execute_block_partial(
+        &txns,
+        &MockStateView,
+        BlockExecutorConfigFromOnchain::new_no_block_limit(),
+    );
+    // Only the outputs of the transactions before the failing one are returned.
+    assert_eq!(
+        status.unwrap().status_code(),
+        StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR
+    );
+    assert_eq!(
+        outputs,
+        MockVM::execute_block_no_limit(&txns[..3], &MockStateView).unwrap()
+    );
+
+    // A block failing on its first transaction has no outputs.
+    let (outputs, status) = FailOnStateCheckpointVM::execute_block_partial(
+        &txns[3..],
+        &MockStateView,
+        BlockExecutorConfigFromOnchain::new_no_block_limit(),
+    );
+    assert!(status.is_some());
+    assert!(outputs.is_empty());
+}
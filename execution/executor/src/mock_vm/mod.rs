@@ -36,7 +36,13 @@ use aptos_vm::{
 };
 use move_core_types::language_storage::TypeTag;
 use once_cell::sync::Lazy;
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 #[derive(Debug)]
 enum MockVMTransaction {
@@ -68,13 +74,27 @@ impl TransactionBlockExecutor for MockVM {
     ) -> Result<ChunkOutput> {
         ChunkOutput::by_transaction_execution::<MockVM>(transactions, state_view, onchain_config)
     }
+
+    fn execute_transaction_block_with_cancellation(
+        transactions: ExecutableTransactions,
+        state_view: CachedStateView,
+        onchain_config: BlockExecutorConfigFromOnchain,
+        cancelled: Arc<AtomicBool>,
+    ) -> Result<ChunkOutput> {
+        ChunkOutput::by_transaction_execution_with_cancellation::<MockVM>(
+            transactions,
+            state_view,
+            onchain_config,
+            cancelled,
+        )
+    }
 }
 
-impl VMExecutor for MockVM {
-    fn execute_block(
+impl MockVM {
+    fn execute_block_impl(
         transactions: &[SignatureVerifiedTransaction],
         state_view: &impl StateView,
-        _onchain_config: BlockExecutorConfigFromOnchain,
+        cancelled: Option<&AtomicBool>,
     ) -> Result<BlockOutput<TransactionOutput>, VMStatus> {
         // output_cache is used to store the output of transactions so they are visible to later
         // transactions.
@@ -82,6 +102,13 @@ impl VMExecutor for MockVM {
         let mut outputs = vec![];
 
         for txn in transactions {
+            if cancelled.map_or(false, |cancelled| cancelled.load(Ordering::Relaxed)) {
+                return Err(VMStatus::error(
+                    StatusCode::EXECUTION_LIMIT_REACHED,
+                    Some("Block execution cancelled".to_string()),
+                ));
+            }
+
             let txn = txn.expect_valid();
             if matches!(txn, Transaction::StateCheckpoint(_)) {
                 outputs.push(TransactionOutput::new(
@@ -188,6 +215,25 @@ impl VMExecutor for MockVM {
 
         Ok(BlockOutput::new(outputs))
     }
+}
+
+impl VMExecutor for MockVM {
+    fn execute_block(
+        transactions: &[SignatureVerifiedTransaction],
+        state_view: &impl StateView,
+        _onchain_config: BlockExecutorConfigFromOnchain,
+    ) -> Result<BlockOutput<TransactionOutput>, VMStatus> {
+        Self::execute_block_impl(transactions, state_view, None)
+    }
+
+    fn execute_block_with_cancellation(
+        transactions: &[SignatureVerifiedTransaction],
+        state_view: &(impl StateView + Sync),
+        _onchain_config: BlockExecutorConfigFromOnchain,
+        cancelled: Arc<AtomicBool>,
+    ) -> Result<BlockOutput<TransactionOutput>, VMStatus> {
+        Self::execute_block_impl(transactions, state_view, Some(&cancelled))
+    }
 
     fn execute_block_sharded<S: StateView + Sync + Send + 'static, E: ExecutorClient<S>>(
         _sharded_block_executor: &ShardedBlockExecutor<S, E>,
@@ -254,13 +300,13 @@ fn decode_bytes(bytes: &[u8]) -> u64 {
     u64::from_le_bytes(buf)
 }
 
-fn balance_ap(account: AccountAddress) -> Vec<u8> {
+pub(crate) fn balance_ap(account: AccountAddress) -> Vec<u8> {
     let mut path = account.to_vec();
     path.extend(b"balance");
     path
 }
 
-fn seqnum_ap(account: AccountAddress) -> Vec<u8> {
+pub(crate) fn seqnum_ap(account: AccountAddress) -> Vec<u8> {
     let mut path = account.to_vec();
     path.extend(b"seqnum");
     path
@@ -7,20 +7,30 @@
 use crate::{
     block_executor::BlockExecutor,
     chunk_executor::ChunkExecutor,
+    components::{apply_chunk_output::ApplyChunkOutput, chunk_output::ChunkOutput},
     db_bootstrapper::{generate_waypoint, maybe_bootstrap},
-    mock_vm::{encode_mint_transaction, MockVM},
+    mock_vm::{balance_ap, encode_mint_transaction, seqnum_ap, MockVM},
     tests,
 };
-use aptos_crypto::HashValue;
+use aptos_crypto::{hash::CryptoHash, HashValue};
 use aptos_db::AptosDB;
-use aptos_executor_types::{BlockExecutorTrait, ChunkExecutorTrait};
-use aptos_storage_interface::DbReaderWriter;
+use aptos_executor_types::{
+    test_utils::MockProofReaderBuilder, BlockExecutorTrait, ChunkExecutorTrait, ChunkTrustMode,
+    ExecutorError, ProofReader, TransactionReplayer, VerifyExecutionMode,
+};
+use aptos_scratchpad::UpdateError;
+use aptos_storage_interface::{
+    async_proof_fetcher::AsyncProofFetcher, cached_state_view::CachedStateView, DbReaderWriter,
+};
 use aptos_types::{
+    block_executor::config::BlockExecutorConfigFromOnchain,
     ledger_info::LedgerInfoWithSignatures,
+    state_store::{state_key::StateKey, StateViewId},
     test_helpers::transaction_test_helpers::{block, TEST_BLOCK_EXECUTOR_ONCHAIN_CONFIG},
-    transaction::TransactionListWithProof,
+    transaction::{Transaction, TransactionListWithProof},
 };
 use rand::Rng;
+use std::sync::Arc;
 
 pub struct TestExecutor {
     _path: aptos_temppath::TempPath,
@@ -79,6 +89,101 @@ fn execute_and_commit_chunks(
     assert_eq!(li, ledger_info);
 }
 
+/// Executes `txns` as a chunk on top of the latest state in `db` and calculates its state
+/// checkpoints, reading the proofs from the ones fetched during execution as modified by
+/// `mock_proofs`.
+fn calculate_chunk_state_checkpoints(
+    db: &DbReaderWriter,
+    txns: Vec<Transaction>,
+    known_state_checkpoints: Option<Vec<Option<HashValue>>>,
+    mock_proofs: impl FnOnce(MockProofReaderBuilder) -> MockProofReaderBuilder,
+) -> anyhow::Result<Vec<Option<HashValue>>> {
+    let parent_state = db.reader.get_latest_executed_trees()?.state().clone();
+    let state_view = CachedStateView::new(
+        StateViewId::Miscellaneous,
+        db.reader.clone(),
+        parent_state.next_version(),
+        parent_state.current.clone(),
+        Arc::new(AsyncProofFetcher::new(db.reader.clone())),
+    )?;
+    let mut chunk_output = ChunkOutput::by_transaction_execution::<MockVM>(
+        block(txns).into(),
+        state_view,
+        BlockExecutorConfigFromOnchain::new_no_block_limit(),
+    )?;
+    let proofs = std::mem::take(&mut chunk_output.state_cache.proofs);
+    let proof_reader =
+        mock_proofs(MockProofReaderBuilder::new().with_base(Box::new(ProofReader::new(proofs))))
+            .build();
+
+    let (_, _, state_checkpoint_output) =
+        ApplyChunkOutput::calculate_state_checkpoint_with_proof_reader(
+            chunk_output,
+            &parent_state,
+            None, // append_state_checkpoint_to_block
+            known_state_checkpoints,
+            false, // is_block
+            &proof_reader,
+        )?;
+    let (_, _, state_checkpoint_hashes, _, _) = state_checkpoint_output.into_inner();
+    Ok(state_checkpoint_hashes)
+}
+
+#[test]
+#[cfg_attr(feature = "consensus-only-perf-test", ignore)]
+fn test_executor_execute_chunk_with_missing_proof() {
+    let TestExecutor { _path, db, .. } = TestExecutor::new();
+    let address = tests::gen_address(1);
+    let txns = vec![
+        encode_mint_transaction(address, 100),
+        Transaction::StateCheckpoint(HashValue::zero()),
+    ];
+    let balance_key = StateKey::raw(&balance_ap(address)).hash();
+    let seqnum_key = StateKey::raw(&seqnum_ap(address)).hash();
+
+    let err = calculate_chunk_state_checkpoints(&db, txns, None, |proofs| {
+        proofs
+            .with_missing_proof(balance_key)
+            .with_missing_proof(seqnum_key)
+    })
+    .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<UpdateError>(),
+        Some(&UpdateError::MissingProof)
+    );
+}
+
+#[test]
+#[cfg_attr(feature = "consensus-only-perf-test", ignore)]
+fn test_executor_execute_chunk_with_malformed_proof() {
+    let TestExecutor { _path, db, .. } = TestExecutor::new();
+    let address = tests::gen_address(1);
+    let txns = vec![
+        encode_mint_transaction(address, 100),
+        Transaction::StateCheckpoint(HashValue::zero()),
+    ];
+    let balance_key = StateKey::raw(&balance_ap(address)).hash();
+    let seqnum_key = StateKey::raw(&seqnum_ap(address)).hash();
+
+    // With the proofs fetched during execution, the chunk ends with the expected checkpoint.
+    let state_checkpoint_hashes =
+        calculate_chunk_state_checkpoints(&db, txns.clone(), None, |proofs| proofs).unwrap();
+    assert!(state_checkpoint_hashes[1].is_some());
+
+    // Malformed proofs yield a different state root, so the chunk doesn't match the trusted
+    // checkpoint.
+    let err =
+        calculate_chunk_state_checkpoints(&db, txns, Some(state_checkpoint_hashes), |proofs| {
+            proofs
+                .with_malformed_proof(balance_key)
+                .with_malformed_proof(seqnum_key)
+        })
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("State checkpoint hash doesn't match"));
+}
+
 #[test]
 #[cfg_attr(feature = "consensus-only-perf-test", ignore)]
 fn test_executor_execute_or_apply_and_commit_chunk() {
@@ -202,6 +307,309 @@ fn test_executor_execute_and_commit_chunk_restart() {
     }
 }
 
+#[test]
+#[cfg_attr(feature = "consensus-only-perf-test", ignore)]
+fn test_executor_resume_partially_committed_chunk() {
+    let first_batch_size = 30;
+    let full_batch_size = 70;
+
+    // The second chunk covers the first one, as if it was requested again after a restart.
+    let (chunks, ledger_info) =
+        tests::create_transaction_chunks(vec![1..1 + first_batch_size, 1..1 + full_batch_size]);
+
+    let TestExecutor {
+        _path,
+        db,
+        executor,
+    } = TestExecutor::new();
+
+    executor
+        .execute_chunk(chunks[0].clone(), &ledger_info, None)
+        .unwrap();
+    executor.commit_chunk().unwrap();
+    assert_eq!(db.reader.get_latest_version().unwrap(), first_batch_size);
+
+    // Restart the executor and resume from the persisted version within the second chunk.
+    let executor = ChunkExecutor::<MockVM>::new(db.clone());
+    executor
+        .execute_chunk(chunks[1].clone(), &ledger_info, None)
+        .unwrap();
+    executor.commit_chunk().unwrap();
+    let li = db.reader.get_latest_ledger_info().unwrap();
+    assert_eq!(li, ledger_info);
+}
+
+#[test]
+fn test_executor_execute_chunk_with_version_gap() {
+    let first_batch_size = 30;
+    let second_batch_size = 40;
+
+    let (chunks, ledger_info) = {
+        let first_batch_start = 1;
+        let second_batch_start = first_batch_start + first_batch_size;
+        tests::create_transaction_chunks(vec![
+            first_batch_start..first_batch_start + first_batch_size,
+            second_batch_start..second_batch_start + second_batch_size,
+        ])
+    };
+
+    let TestExecutor {
+        _path,
+        db: _,
+        executor,
+    } = TestExecutor::new();
+
+    // Skipping the first chunk leaves a gap between the ledger and the second chunk.
+    assert_eq!(
+        executor
+            .execute_chunk(chunks[1].clone(), &ledger_info, None)
+            .unwrap_err(),
+        ExecutorError::UnexpectedChunkVersion {
+            first_version_in_request: first_batch_size + 1,
+            num_txns: second_batch_size as usize,
+            next_version: 1,
+        }
+    );
+}
+
+#[test]
+#[cfg_attr(feature = "consensus-only-perf-test", ignore)]
+fn test_executor_execute_chunk_trust_source() {
+    let batch_size = 10;
+    let (chunks, ledger_info) = tests::create_transaction_chunks(vec![
+        1..1 + batch_size,
+        1 + batch_size..1 + 2 * batch_size,
+    ]);
+    // The chunk proofs don't match this ledger info.
+    let wrong_ledger_info = tests::gen_ledger_info(
+        ledger_info.ledger_info().version(),
+        HashValue::zero(),
+        tests::gen_block_id(1),
+        1,
+    );
+
+    let TestExecutor {
+        _path,
+        db,
+        executor,
+    } = TestExecutor::new();
+
+    assert!(matches!(
+        executor
+            .enqueue_chunk_by_execution(
+                chunks[0].clone(),
+                &wrong_ledger_info,
+                None,
+                ChunkTrustMode::Verify,
+            )
+            .unwrap_err(),
+        ExecutorError::ProofVerificationError(_)
+    ));
+
+    // A trusted source is applied without checking the proofs.
+    executor
+        .enqueue_chunk_by_execution(
+            chunks[0].clone(),
+            &wrong_ledger_info,
+            None,
+            ChunkTrustMode::TrustSource,
+        )
+        .unwrap();
+    executor.update_ledger().unwrap();
+    executor.commit_chunk().unwrap();
+    assert_eq!(db.reader.get_latest_version().unwrap(), batch_size);
+}
+
+#[test]
+fn test_executor_replay_trust_source_with_verified_execution() {
+    let TestExecutor {
+        _path,
+        db: _,
+        executor,
+    } = TestExecutor::new();
+
+    // Trusting the source can't be combined with verifying the execution against it.
+    let err = executor
+        .replay(
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            &VerifyExecutionMode::verify_all(),
+            ChunkTrustMode::TrustSource,
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("TrustSource"));
+}
+
+#[test]
+#[cfg_attr(feature = "consensus-only-perf-test", ignore)]
+fn test_executor_pending_version_range() {
+    let batch_size = 10;
+    let (chunks, ledger_info) = tests::create_transaction_chunks(vec![
+        1..1 + batch_size,
+        1 + batch_size..1 + 2 * batch_size,
+    ]);
+
+    let TestExecutor {
+        _path,
+        db: _,
+        executor,
+    } = TestExecutor::new();
+    assert_eq!(executor.pending_version_range(), None);
+
+    for chunk in &chunks {
+        executor
+            .enqueue_chunk_by_execution(chunk.clone(), &ledger_info, None, ChunkTrustMode::Verify)
+            .unwrap();
+    }
+    assert_eq!(executor.pending_version_range(), Some(1..=2 * batch_size));
+
+    executor.update_ledger().unwrap();
+    executor.commit_chunk().unwrap();
+    assert_eq!(
+        executor.pending_version_range(),
+        Some(1 + batch_size..=2 * batch_size)
+    );
+
+    executor.update_ledger().unwrap();
+    executor.commit_chunk().unwrap();
+    assert_eq!(executor.pending_version_range(), None);
+}
+
+#[test]
+#[cfg_attr(feature = "consensus-only-perf-test", ignore)]
+fn test_executor_smt_memory_usage() {
+    let batch_size = 10;
+    let (chunks, ledger_info) = tests::create_transaction_chunks(vec![
+        1..1 + batch_size,
+        1 + batch_size..1 + 2 * batch_size,
+    ]);
+
+    let TestExecutor {
+        _path,
+        db: _,
+        executor,
+    } = TestExecutor::new();
+    assert_eq!(executor.smt_memory_usage(), 0);
+
+    for chunk in &chunks {
+        executor
+            .enqueue_chunk_by_execution(chunk.clone(), &ledger_info, None, ChunkTrustMode::Verify)
+            .unwrap();
+    }
+    // Both pending chunks are spawned from the persisted state.
+    let pending_usage = executor.smt_memory_usage();
+    assert!(pending_usage > 0);
+
+    executor.update_ledger().unwrap();
+    executor.commit_chunk().unwrap();
+    let usage = executor.smt_memory_usage();
+    assert!(usage <= pending_usage);
+
+    executor.update_ledger().unwrap();
+    executor.commit_chunk().unwrap();
+    assert!(executor.smt_memory_usage() <= usage);
+
+    executor.finish();
+    assert_eq!(executor.smt_memory_usage(), 0);
+}
+
+#[test]
+#[cfg_attr(feature = "consensus-only-perf-test", ignore)]
+fn test_executor_execute_partially_overlapping_chunk() {
+    let first_batch_size = 30;
+    let full_batch_size = 70;
+
+    // The second chunk starts within the first one.
+    let (chunks, ledger_info) = tests::create_transaction_chunks(vec![
+        1..1 + first_batch_size,
+        1 + first_batch_size / 2..1 + full_batch_size,
+    ]);
+
+    let TestExecutor {
+        _path,
+        db,
+        executor,
+    } = TestExecutor::new();
+
+    executor
+        .execute_chunk(chunks[0].clone(), &ledger_info, None)
+        .unwrap();
+    executor.commit_chunk().unwrap();
+    assert_eq!(db.reader.get_latest_version().unwrap(), first_batch_size);
+
+    // Only the transactions after the first chunk are executed.
+    executor
+        .execute_chunk(chunks[1].clone(), &ledger_info, None)
+        .unwrap();
+    let notification = executor.commit_chunk().unwrap();
+    assert_eq!(
+        notification.committed_transactions.len() as u64,
+        full_batch_size - first_batch_size
+    );
+    let li = db.reader.get_latest_ledger_info().unwrap();
+    assert_eq!(li, ledger_info);
+}
+
+#[test]
+#[cfg_attr(feature = "consensus-only-perf-test", ignore)]
+fn test_executor_resume_chunk_from_checkpoint() {
+    let checkpoint_interval = 10;
+    let interrupted_batch_size = 35;
+    let full_batch_size = 70;
+
+    // The first chunk stands for the part of the full chunk that was executed before a crash.
+    let (chunks, ledger_info) = tests::create_transaction_chunks(vec![
+        1..1 + interrupted_batch_size,
+        1..1 + full_batch_size,
+    ]);
+
+    let TestExecutor {
+        _path,
+        db,
+        executor,
+    } = TestExecutor::new();
+
+    let notification = executor
+        .execute_and_commit_chunk_with_checkpoints(
+            chunks[0].clone(),
+            &ledger_info,
+            None,
+            checkpoint_interval,
+        )
+        .unwrap();
+    assert_eq!(
+        notification.committed_transactions.len() as u64,
+        interrupted_batch_size
+    );
+    assert_eq!(
+        db.reader.get_latest_version().unwrap(),
+        interrupted_batch_size
+    );
+    // Not at the target yet, so the genesis ledger info is still the latest one.
+    let li = db.reader.get_latest_ledger_info().unwrap();
+    assert_eq!(li.ledger_info().version(), 0);
+
+    // Restart the executor and resume the full chunk from the last checkpoint.
+    let executor = ChunkExecutor::<MockVM>::new(db.clone());
+    let notification = executor
+        .resume_chunk(chunks[1].clone(), &ledger_info, None, checkpoint_interval)
+        .unwrap();
+    assert_eq!(
+        notification.committed_transactions.len() as u64,
+        full_batch_size - interrupted_batch_size
+    );
+    let li = db.reader.get_latest_ledger_info().unwrap();
+    assert_eq!(li, ledger_info);
+
+    // Resuming a chunk that has been fully committed is rejected.
+    assert!(matches!(
+        executor.resume_chunk(chunks[1].clone(), &ledger_info, None, checkpoint_interval),
+        Err(ExecutorError::UnexpectedChunkVersion { .. })
+    ));
+}
+
 #[test]
 #[cfg_attr(feature = "consensus-only-perf-test", ignore)]
 fn test_executor_execute_and_commit_chunk_local_result_mismatch() {
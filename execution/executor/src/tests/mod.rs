@@ -7,17 +7,21 @@ use crate::{
     components::chunk_output::ChunkOutput,
     db_bootstrapper::{generate_waypoint, maybe_bootstrap},
     mock_vm::{
-        encode_mint_transaction, encode_reconfiguration_transaction, encode_transfer_transaction,
-        MockVM, DISCARD_STATUS, KEEP_STATUS,
+        balance_ap, encode_mint_transaction, encode_reconfiguration_transaction,
+        encode_transfer_transaction, seqnum_ap, MockVM, DISCARD_STATUS, KEEP_STATUS,
     },
 };
-use aptos_crypto::{ed25519::Ed25519PrivateKey, HashValue, PrivateKey, SigningKey, Uniform};
+use aptos_crypto::{
+    ed25519::Ed25519PrivateKey, hash::CryptoHash, HashValue, PrivateKey, SigningKey, Uniform,
+};
 use aptos_db::AptosDB;
 use aptos_executor_types::{
-    BlockExecutorTrait, ExecutedChunk, LedgerUpdateOutput, TransactionReplayer, VerifyExecutionMode,
+    replay_bundle::ReplayBundle, BlockExecutorTrait, ChunkTrustMode, ExecutedChunk, ExecutorError,
+    LedgerUpdateOutput, ProofReader, TransactionReplayer, VerifyExecutionMode,
 };
 use aptos_storage_interface::{
-    async_proof_fetcher::AsyncProofFetcher, DbReaderWriter, ExecutedTrees, Result,
+    async_proof_fetcher::AsyncProofFetcher, state_view::DbStateViewAtVersion, DbReaderWriter,
+    ExecutedTrees, Result,
 };
 use aptos_types::{
     account_address::AccountAddress,
@@ -28,7 +32,10 @@ use aptos_types::{
     chain_id::ChainId,
     ledger_info::{LedgerInfo, LedgerInfoWithSignatures},
     proof::definition::LeafCount,
-    state_store::{state_key::StateKey, state_value::StateValue, StateViewId},
+    state_store::{
+        in_memory_state_view::InMemoryStateView, state_key::StateKey, state_value::StateValue,
+        StateViewId,
+    },
     test_helpers::transaction_test_helpers::{block, TEST_BLOCK_EXECUTOR_ONCHAIN_CONFIG},
     transaction::{
         signature_verified_transaction::SignatureVerifiedTransaction, ExecutionStatus,
@@ -38,8 +45,15 @@ use aptos_types::{
     },
     write_set::{WriteOp, WriteSet, WriteSetMut},
 };
+use aptos_vm::VMExecutor;
 use proptest::prelude::*;
-use std::{iter::once, sync::Arc};
+use std::{
+    iter::once,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 mod chunk_executor_tests;
 
@@ -223,6 +237,37 @@ fn test_executor_one_block() {
     executor.commit_blocks(vec![block_id], ledger_info).unwrap();
 }
 
+#[test]
+fn test_executor_smt_memory_usage() {
+    let executor = TestExecutor::new();
+    assert_eq!(executor.smt_memory_usage(), 0);
+
+    let parent_block_id = executor.committed_block_id();
+    let persisted_usage = executor.smt_memory_usage();
+
+    let block_id = gen_block_id(1);
+    let txns = (0..10)
+        .map(|i| encode_mint_transaction(gen_address(i), 100))
+        .collect::<Vec<_>>();
+    let output = executor
+        .execute_block(
+            (block_id, block(txns)).into(),
+            parent_block_id,
+            TEST_BLOCK_EXECUTOR_ONCHAIN_CONFIG,
+        )
+        .unwrap();
+    // The tree of the speculative block is spawned from the root block's tree.
+    let speculative_usage = executor.smt_memory_usage();
+    assert!(speculative_usage > persisted_usage);
+
+    let ledger_info = gen_ledger_info(output.version(), output.root_hash(), block_id, 1);
+    executor.commit_blocks(vec![block_id], ledger_info).unwrap();
+    assert!(executor.smt_memory_usage() <= speculative_usage);
+
+    executor.finish();
+    assert_eq!(executor.smt_memory_usage(), 0);
+}
+
 #[test]
 fn test_executor_multiple_blocks() {
     let executor = TestExecutor::new();
@@ -326,6 +371,198 @@ fn test_executor_execute_same_block_multiple_times() {
     assert_eq!(responses.len(), 1);
 }
 
+#[test]
+fn test_executor_cancelled_block() {
+    let executor = TestExecutor::new();
+    let parent_block_id = executor.committed_block_id();
+    let block_id = gen_block_id(1);
+
+    let txns: Vec<_> = (0..10)
+        .map(|i| encode_mint_transaction(gen_address(i), 100))
+        .collect();
+
+    let cancelled = Arc::new(AtomicBool::new(true));
+    let result = executor.execute_and_state_checkpoint_with_cancellation(
+        (block_id, block(txns.clone())).into(),
+        parent_block_id,
+        TEST_BLOCK_EXECUTOR_ONCHAIN_CONFIG,
+        cancelled.clone(),
+    );
+    assert!(matches!(result, Err(ExecutorError::Cancelled)));
+
+    // The cancelled block was not added to the block tree, so it can be executed again.
+    cancelled.store(false, Ordering::Relaxed);
+    executor
+        .execute_and_state_checkpoint_with_cancellation(
+            (block_id, block(txns)).into(),
+            parent_block_id,
+            TEST_BLOCK_EXECUTOR_ONCHAIN_CONFIG,
+            cancelled,
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_executor_execute_block_with_proofs() {
+    let num_user_txns = 10;
+    let txns: Vec<_> = (0..num_user_txns)
+        .map(|i| encode_mint_transaction(gen_address(i), 100))
+        .collect();
+    let block_id = gen_block_id(2);
+
+    let execute =
+        |executor: &TestExecutor, parent_block_id: HashValue, proof_reader: Option<ProofReader>| {
+            let block = (block_id, block(txns.clone())).into();
+            let output = match proof_reader {
+                Some(proof_reader) => executor.execute_and_state_checkpoint_with_proofs(
+                    block,
+                    parent_block_id,
+                    TEST_BLOCK_EXECUTOR_ONCHAIN_CONFIG,
+                    proof_reader,
+                ),
+                None => executor.execute_and_state_checkpoint(
+                    block,
+                    parent_block_id,
+                    TEST_BLOCK_EXECUTOR_ONCHAIN_CONFIG,
+                ),
+            }
+            .unwrap();
+            executor
+                .ledger_update(block_id, parent_block_id, output)
+                .unwrap()
+        };
+
+    let executor = TestExecutor::new();
+    let parent_block_id = execute_and_commit_block(&executor, executor.committed_block_id(), 0);
+    let output = execute(&executor, parent_block_id, None);
+
+    // Proofs of the state touched by the block, as of the version the block is executed on.
+    let executor = TestExecutor::new();
+    let parent_block_id = execute_and_commit_block(&executor, executor.committed_block_id(), 0);
+    let version = executor.db.reader.get_latest_version().unwrap();
+    let proofs = (0..num_user_txns)
+        .flat_map(|i| {
+            [
+                StateKey::raw(&balance_ap(gen_address(i))),
+                StateKey::raw(&seqnum_ap(gen_address(i))),
+            ]
+        })
+        .map(|key| {
+            let proof = executor
+                .db
+                .reader
+                .get_state_proof_by_version_ext(&key, version, 0)
+                .unwrap();
+            (key.hash(), proof)
+        })
+        .collect();
+    let output_with_proofs = execute(&executor, parent_block_id, Some(ProofReader::new(proofs)));
+
+    assert_eq!(output, output_with_proofs);
+}
+
+#[test]
+fn test_replay_bundle() {
+    let executor = TestExecutor::new();
+    let parent_block_id = executor.committed_block_id();
+    let block_id = gen_block_id(1);
+    let (alice, bob) = (gen_address(0), gen_address(1));
+    let txns = vec![
+        encode_mint_transaction(alice, 100),
+        encode_transfer_transaction(alice, bob, 30),
+    ];
+    let output = executor
+        .execute_block(
+            (block_id, block(txns)).into(),
+            parent_block_id,
+            TEST_BLOCK_EXECUTOR_ONCHAIN_CONFIG,
+        )
+        .unwrap();
+    let version = output.version();
+    let ledger_info = gen_ledger_info(version, output.root_hash(), block_id, 1);
+    executor.commit_blocks(vec![block_id], ledger_info).unwrap();
+
+    let db = executor.db.reader.clone();
+    let txn_list = db.get_transactions(1, version, version, false).unwrap();
+    let write_sets = db
+        .get_write_set_iterator(1, version)
+        .unwrap()
+        .collect::<Result<_>>()
+        .unwrap();
+    let event_vecs = db
+        .get_events_iterator(1, version)
+        .unwrap()
+        .collect::<Result<_>>()
+        .unwrap();
+    let state_keys = [alice, bob]
+        .into_iter()
+        .flat_map(|account| [balance_ap(account), seqnum_ap(account)])
+        .map(|access_path| StateKey::raw(&access_path));
+    let state_view =
+        ReplayBundle::capture_state(&db.state_view_at_version(Some(0)).unwrap(), state_keys)
+            .unwrap();
+    let bundle = ReplayBundle::new(
+        1,
+        txn_list.transactions,
+        txn_list.proof.transaction_infos,
+        write_sets,
+        event_vecs,
+        state_view,
+    )
+    .unwrap();
+    let bundle = ReplayBundle::from_bytes(&bundle.to_bytes().unwrap()).unwrap();
+
+    let execute_block = |txns: &[SignatureVerifiedTransaction],
+                         state_view: &InMemoryStateView|
+     -> anyhow::Result<Vec<TransactionOutput>> {
+        Ok(MockVM::execute_block(
+            txns,
+            state_view,
+            BlockExecutorConfigFromOnchain::new_no_block_limit(),
+        )?
+        .into_transaction_outputs_forced())
+    };
+    bundle.replay(execute_block).unwrap();
+
+    // The bundle can be loaded from a file and replayed by a chunk executor on top of a DB at the
+    // version before the bundle.
+    let bundle_path = aptos_temppath::TempPath::new();
+    bundle.save(bundle_path.path()).unwrap();
+    let loaded_bundle = ReplayBundle::load(bundle_path.path()).unwrap();
+    let replayer = chunk_executor_tests::TestExecutor::new();
+    loaded_bundle
+        .replay_with_replayer(&replayer.executor, &VerifyExecutionMode::verify_all())
+        .unwrap();
+    assert_eq!(
+        replayer
+            .db
+            .reader
+            .get_accumulator_root_hash(version)
+            .unwrap(),
+        db.get_accumulator_root_hash(version).unwrap()
+    );
+
+    // Recorded outputs that don't match the execution fail the replay.
+    let mut diverged_bundle = loaded_bundle;
+    diverged_bundle.write_sets.swap(0, 1);
+    let replayer = chunk_executor_tests::TestExecutor::new();
+    assert!(diverged_bundle
+        .replay_with_replayer(&replayer.executor, &VerifyExecutionMode::verify_all())
+        .is_err());
+
+    // Replaying against a different state diverges from the recorded outputs.
+    let mut diverged_bundle = bundle;
+    diverged_bundle.state_view = InMemoryStateView::new(
+        [(
+            StateKey::raw(&balance_ap(alice)),
+            StateValue::from(10u64.le_bytes()),
+        )]
+        .into_iter()
+        .collect(),
+    );
+    assert!(diverged_bundle.replay(execute_block).is_err());
+}
+
 /// Generates a list of `TransactionListWithProof`s according to the given ranges.
 fn create_transaction_chunks(
     chunk_ranges: Vec<std::ops::Range<Version>>,
@@ -493,6 +730,7 @@ fn apply_transaction_by_writeset(
         state_updates_until_last_checkpoint: state_updates_before_last_checkpoint,
         sharded_state_cache,
         transaction_accumulator: _,
+        block_end_reason: _,
     } = ledger_update_output;
 
     db.writer
@@ -710,6 +948,7 @@ fn run_transactions_naive(
             state_updates_until_last_checkpoint: state_updates_before_last_checkpoint,
             sharded_state_cache,
             transaction_accumulator: _,
+            block_end_reason: _,
         } = ledger_update_output;
         db.writer
             .save_transactions(
@@ -791,7 +1030,7 @@ proptest! {
             // replay txns in one batch across epoch boundary,
             // and the replayer should deal with `Retry`s automatically
             let replayer = chunk_executor_tests::TestExecutor::new();
-            replayer.executor.replay(txn_list.transactions, txn_infos, write_sets, event_vecs, &VerifyExecutionMode::verify_all()).unwrap();
+            replayer.executor.replay(txn_list.transactions, txn_infos, write_sets, event_vecs, &VerifyExecutionMode::verify_all(), ChunkTrustMode::Verify).unwrap();
             replayer.executor.commit().unwrap();
             let replayed_db = replayer.db.reader.clone();
             prop_assert_eq!(
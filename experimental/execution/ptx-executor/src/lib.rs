@@ -43,8 +43,12 @@ use aptos_vm::{
     sharded_block_executor::{executor_client::ExecutorClient, ShardedBlockExecutor},
     AptosVM, VMExecutor,
 };
-use move_core_types::vm_status::VMStatus;
-use std::sync::{mpsc::channel, Arc};
+use move_core_types::vm_status::{StatusCode, VMStatus};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    mpsc::channel,
+    Arc,
+};
 
 pub struct PtxBlockExecutor;
 
@@ -104,6 +108,29 @@ impl VMExecutor for PtxBlockExecutor {
         Ok(BlockOutput::new(ret))
     }
 
+    fn execute_block_with_cancellation(
+        transactions: &[SignatureVerifiedTransaction],
+        state_view: &(impl StateView + Sync),
+        onchain_config: BlockExecutorConfigFromOnchain,
+        cancelled: Arc<AtomicBool>,
+    ) -> Result<BlockOutput<TransactionOutput>, VMStatus> {
+        // The pipeline can't be interrupted once fed, so the flag is only checked around it.
+        let check_cancelled = || {
+            if cancelled.load(Ordering::Relaxed) {
+                Err(VMStatus::error(
+                    StatusCode::EXECUTION_LIMIT_REACHED,
+                    Some("Block execution cancelled".to_string()),
+                ))
+            } else {
+                Ok(())
+            }
+        };
+        check_cancelled()?;
+        let output = Self::execute_block(transactions, state_view, onchain_config)?;
+        check_cancelled()?;
+        Ok(output)
+    }
+
     fn execute_block_sharded<S: StateView + Sync + Send + 'static, E: ExecutorClient<S>>(
         _sharded_block_executor: &ShardedBlockExecutor<S, E>,
         _transactions: PartitionedTransactions,
@@ -126,4 +153,18 @@ impl TransactionBlockExecutor for PtxBlockExecutor {
             onchain_config,
         )
     }
+
+    fn execute_transaction_block_with_cancellation(
+        transactions: ExecutableTransactions,
+        state_view: CachedStateView,
+        onchain_config: BlockExecutorConfigFromOnchain,
+        cancelled: Arc<AtomicBool>,
+    ) -> anyhow::Result<ChunkOutput> {
+        ChunkOutput::by_transaction_execution_with_cancellation::<PtxBlockExecutor>(
+            transactions,
+            state_view,
+            onchain_config,
+            cancelled,
+        )
+    }
 }
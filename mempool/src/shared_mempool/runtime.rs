@@ -99,9 +99,10 @@ pub fn bootstrap(
 ) -> Runtime {
     let runtime = aptos_runtimes::spawn_named_runtime("shared-mem".into(), None);
     let mempool = Arc::new(Mutex::new(CoreMempool::new(config)));
-    let vm_validator = Arc::new(RwLock::new(PooledVMValidator::new(
+    let vm_validator = Arc::new(RwLock::new(PooledVMValidator::new_with_validation_cache(
         Arc::clone(&db),
         num_cpus::get(),
+        config.mempool.validation_cache_capacity,
     )));
     start_shared_mempool(
         runtime.handle(),
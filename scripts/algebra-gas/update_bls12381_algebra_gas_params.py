@@ -35,6 +35,7 @@ def prettify_number(x:int) -> str:
 def get_algebra_lines(gas_per_ns):
     nanoseconds = {}
     nanoseconds['ark_bls12_381_fr_add'] = load_bench_ns.main('target/criterion/ark_bls12_381/fr_add')
+    nanoseconds['ark_bls12_381_fr_bit'] = load_bench_ns.main('target/criterion/ark_bls12_381/fr_bit')
     nanoseconds['ark_bls12_381_fr_deser'] = load_bench_ns.main('target/criterion/ark_bls12_381/fr_deser')
     nanoseconds['ark_bls12_381_fr_div'] = load_bench_ns.main('target/criterion/ark_bls12_381/fr_div')
     nanoseconds['ark_bls12_381_fr_eq'] = load_bench_ns.main('target/criterion/ark_bls12_381/fr_eq')
@@ -70,6 +71,7 @@ def get_algebra_lines(gas_per_ns):
     nanoseconds['ark_bls12_381_g1_proj_double'] = load_bench_ns.main('target/criterion/ark_bls12_381/g1_proj_double')
     nanoseconds['ark_bls12_381_g1_proj_eq'] = load_bench_ns.main('target/criterion/ark_bls12_381/g1_proj_eq')
     nanoseconds['ark_bls12_381_g1_proj_generator'] = 1
+    nanoseconds['ark_bls12_381_g1_proj_glv_scalar_mul'] = load_bench_ns.main('target/criterion/ark_bls12_381/g1_proj_glv_scalar_mul')
     nanoseconds['ark_bls12_381_g1_proj_infinity'] = 1
     nanoseconds['ark_bls12_381_g1_proj_neg'] = 1
     nanoseconds['ark_bls12_381_g1_proj_scalar_mul'] = load_bench_ns.main('target/criterion/ark_bls12_381/g1_proj_scalar_mul')
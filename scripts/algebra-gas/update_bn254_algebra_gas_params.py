@@ -35,6 +35,7 @@ def prettify_number(x:int) -> str:
 def get_algebra_lines(gas_per_ns):
     nanoseconds = {}
     nanoseconds['ark_bn254_fr_add'] = load_bench_ns.main('target/criterion/ark_bn254/fr_add')
+    nanoseconds['ark_bn254_fr_bit'] = load_bench_ns.main('target/criterion/ark_bn254/fr_bit')
     nanoseconds['ark_bn254_fr_deser'] = load_bench_ns.main('target/criterion/ark_bn254/fr_deser')
     nanoseconds['ark_bn254_fr_div'] = load_bench_ns.main('target/criterion/ark_bn254/fr_div')
     nanoseconds['ark_bn254_fr_eq'] = load_bench_ns.main('target/criterion/ark_bn254/fr_eq')
@@ -105,9 +106,13 @@ def get_algebra_lines(gas_per_ns):
     nanoseconds['ark_bn254_g2_proj_to_affine'] = load_bench_ns.main('target/criterion/ark_bn254/g2_proj_to_affine')
     nanoseconds['ark_bn254_pairing'] = load_bench_ns.main('target/criterion/ark_bn254/pairing')
     _,_,nanoseconds['ark_bn254_multi_pairing_per_pair'],nanoseconds['ark_bn254_multi_pairing_base'] = get_bench_ns_linear('target/criterion/ark_bn254/pairing_product')
+    _,_,nanoseconds['ark_bn254_g1_proj_normalize_batch_per_point'],nanoseconds['ark_bn254_g1_proj_normalize_batch_base'] = get_bench_ns_linear('target/criterion/ark_bn254/g1_proj_normalize_batch')
+    _,_,nanoseconds['ark_bn254_g2_proj_normalize_batch_per_point'],nanoseconds['ark_bn254_g2_proj_normalize_batch_base'] = get_bench_ns_linear('target/criterion/ark_bn254/g2_proj_normalize_batch')
 
     quantity_types = defaultdict(lambda: "InternalGas")
     quantity_types['ark_bn254_multi_pairing_per_pair'] = 'InternalGasPerArg'
+    quantity_types['ark_bn254_g1_proj_normalize_batch_per_point'] = 'InternalGasPerArg'
+    quantity_types['ark_bn254_g2_proj_normalize_batch_per_point'] = 'InternalGasPerArg'
 
     gas_param_entries = { k:(int(gas_per_ns*v), quantity_types[k]) for k,v in nanoseconds.items()}
     lines = [f'        [algebra_{k}: {unt}, {{ {TARGET_GAS_VERSION}.. => "algebra.{k}" }}, {prettify_number(qty)}],' for k,(qty,unt) in sorted(gas_param_entries.items())]
@@ -16,7 +16,9 @@ use crate::{
 use aptos_config::config::StateSyncDriverConfig;
 use aptos_data_streaming_service::data_notification::NotificationId;
 use aptos_event_notifications::EventSubscriptionService;
-use aptos_executor_types::{ChunkCommitNotification, ChunkExecutorTrait};
+use aptos_executor_types::{
+    ChunkCommitNotification, ChunkExecutorTrait, ChunkTrustMode, ExecutorResult,
+};
 use aptos_infallible::Mutex;
 use aptos_logger::prelude::*;
 use aptos_mempool_notifications::MempoolNotificationSender;
@@ -234,6 +236,7 @@ impl<
         let runtime = runtime.map(|runtime| runtime.handle().clone());
         let executor_handle = spawn_executor(
             chunk_executor.clone(),
+            chunk_trust_mode(&driver_config),
             error_notification_sender.clone(),
             executor_listener,
             ledger_updater_notifier,
@@ -472,6 +475,7 @@ enum StorageDataChunk {
 /// Spawns a dedicated executor that executes/applies storage data chunks
 fn spawn_executor<ChunkExecutor: ChunkExecutorTrait + 'static>(
     chunk_executor: Arc<ChunkExecutor>,
+    trust_mode: ChunkTrustMode,
     error_notification_sender: mpsc::UnboundedSender<ErrorNotification>,
     mut executor_listener: mpsc::Receiver<StorageDataChunk>,
     mut ledger_updater_notifier: mpsc::Sender<NotificationMetadata>,
@@ -498,6 +502,7 @@ fn spawn_executor<ChunkExecutor: ChunkExecutorTrait + 'static>(
                         transactions_with_proof,
                         target_ledger_info,
                         end_of_epoch_ledger_info,
+                        trust_mode,
                     )
                     .await;
                     (notification_metadata, result, true)
@@ -514,6 +519,7 @@ fn spawn_executor<ChunkExecutor: ChunkExecutorTrait + 'static>(
                         outputs_with_proof,
                         target_ledger_info,
                         end_of_epoch_ledger_info,
+                        trust_mode,
                     )
                     .await;
                     (notification_metadata, result, false)
@@ -946,6 +952,17 @@ fn spawn_state_snapshot_receiver<
     spawn(runtime, receiver)
 }
 
+/// Returns the trust mode for the chunk executor, as specified by the driver config
+fn chunk_trust_mode(driver_config: &StateSyncDriverConfig) -> ChunkTrustMode {
+    if driver_config.trust_chunk_source {
+        warn!(LogSchema::new(LogEntry::StorageSynchronizer)
+            .message("Chunk proofs will not be verified! The chunk source is trusted."));
+        ChunkTrustMode::TrustSource
+    } else {
+        ChunkTrustMode::Verify
+    }
+}
+
 /// Spawns a dedicated task that applies the given output chunk. We use
 /// `spawn_blocking` so that the heavy synchronous function doesn't
 /// block the async thread.
@@ -954,7 +971,8 @@ async fn apply_output_chunk<ChunkExecutor: ChunkExecutorTrait + 'static>(
     outputs_with_proof: TransactionOutputListWithProof,
     target_ledger_info: LedgerInfoWithSignatures,
     end_of_epoch_ledger_info: Option<LedgerInfoWithSignatures>,
-) -> anyhow::Result<()> {
+    trust_mode: ChunkTrustMode,
+) -> ExecutorResult<()> {
     // Apply the output chunk
     let num_outputs = outputs_with_proof.transactions_and_outputs.len();
     let result = tokio::task::spawn_blocking(move || {
@@ -962,6 +980,7 @@ async fn apply_output_chunk<ChunkExecutor: ChunkExecutorTrait + 'static>(
             outputs_with_proof,
             &target_ledger_info,
             end_of_epoch_ledger_info.as_ref(),
+            trust_mode,
         )
     })
     .await
@@ -994,7 +1013,8 @@ async fn execute_transaction_chunk<ChunkExecutor: ChunkExecutorTrait + 'static>(
     transactions_with_proof: TransactionListWithProof,
     target_ledger_info: LedgerInfoWithSignatures,
     end_of_epoch_ledger_info: Option<LedgerInfoWithSignatures>,
-) -> anyhow::Result<()> {
+    trust_mode: ChunkTrustMode,
+) -> ExecutorResult<()> {
     // Execute the transaction chunk
     let num_transactions = transactions_with_proof.transactions.len();
     let result = tokio::task::spawn_blocking(move || {
@@ -1002,6 +1022,7 @@ async fn execute_transaction_chunk<ChunkExecutor: ChunkExecutorTrait + 'static>(
             transactions_with_proof,
             &target_ledger_info,
             end_of_epoch_ledger_info.as_ref(),
+            trust_mode,
         )
     })
     .await
@@ -1045,7 +1066,7 @@ fn update_synchronizer_chunk_metrics(num_items: usize, operation_label: &str) {
 /// block the async thread.
 async fn update_ledger<ChunkExecutor: ChunkExecutorTrait + 'static>(
     chunk_executor: Arc<ChunkExecutor>,
-) -> anyhow::Result<()> {
+) -> ExecutorResult<()> {
     tokio::task::spawn_blocking(move || chunk_executor.update_ledger())
         .await
         .expect("Spawn_blocking(update_ledger) failed!")
@@ -1056,7 +1077,7 @@ async fn update_ledger<ChunkExecutor: ChunkExecutorTrait + 'static>(
 /// block the async thread.
 async fn commit_chunk<ChunkExecutor: ChunkExecutorTrait + 'static>(
     chunk_executor: Arc<ChunkExecutor>,
-) -> anyhow::Result<ChunkCommitNotification> {
+) -> ExecutorResult<ChunkCommitNotification> {
     tokio::task::spawn_blocking(move || chunk_executor.commit_chunk())
         .await
         .expect("Spawn_blocking(commit_chunk) failed!")
@@ -14,7 +14,9 @@ use aptos_data_streaming_service::{
     data_stream::{DataStreamId, DataStreamListener},
     streaming_client::{DataStreamingClient, Epoch, NotificationAndFeedback},
 };
-use aptos_executor_types::{ChunkCommitNotification, ChunkExecutorTrait};
+use aptos_executor_types::{
+    ChunkCommitNotification, ChunkExecutorTrait, ChunkTrustMode, ExecutorResult,
+};
 use aptos_storage_interface::{
     cached_state_view::ShardedStateCache, state_delta::StateDelta, DbReader, DbReaderWriter,
     DbWriter, ExecutedTrees, Order, Result, StateSnapshotReceiver,
@@ -43,7 +45,7 @@ use aptos_types::{
 };
 use async_trait::async_trait;
 use mockall::mock;
-use std::sync::Arc;
+use std::{ops::RangeInclusive, sync::Arc};
 use tokio::task::JoinHandle;
 
 // TODO(joshlind): if we see these as generally useful, we should
@@ -140,34 +142,56 @@ mock! {
             txn_list_with_proof: TransactionListWithProof,
             verified_target_li: &LedgerInfoWithSignatures,
             epoch_change_li: Option<&'a LedgerInfoWithSignatures>,
-        ) -> AnyhowResult<()>;
+        ) -> ExecutorResult<()>;
 
         fn apply_chunk<'a>(
             &self,
             txn_output_list_with_proof: TransactionOutputListWithProof,
             verified_target_li: &LedgerInfoWithSignatures,
             epoch_change_li: Option<&'a LedgerInfoWithSignatures>,
-        ) -> AnyhowResult<()>;
+        ) -> ExecutorResult<()>;
 
         fn enqueue_chunk_by_execution<'a>(
             &self,
             txn_list_with_proof: TransactionListWithProof,
             verified_target_li: &LedgerInfoWithSignatures,
             epoch_change_li: Option<&'a LedgerInfoWithSignatures>,
-        ) -> AnyhowResult<()>;
+            trust_mode: ChunkTrustMode,
+        ) -> ExecutorResult<()>;
 
         fn enqueue_chunk_by_transaction_outputs<'a>(
             &self,
             txn_output_list_with_proof: TransactionOutputListWithProof,
             verified_target_li: &LedgerInfoWithSignatures,
             epoch_change_li: Option<&'a LedgerInfoWithSignatures>,
-        ) -> AnyhowResult<()>;
+            trust_mode: ChunkTrustMode,
+        ) -> ExecutorResult<()>;
+
+        fn update_ledger(&self) -> ExecutorResult<()>;
+
+        fn commit_chunk(&self) -> ExecutorResult<ChunkCommitNotification>;
+
+        fn execute_and_commit_chunk_with_checkpoints<'a>(
+            &self,
+            txn_list_with_proof: TransactionListWithProof,
+            verified_target_li: &LedgerInfoWithSignatures,
+            epoch_change_li: Option<&'a LedgerInfoWithSignatures>,
+            checkpoint_interval: usize,
+        ) -> ExecutorResult<ChunkCommitNotification>;
+
+        fn resume_chunk<'a>(
+            &self,
+            txn_list_with_proof: TransactionListWithProof,
+            verified_target_li: &LedgerInfoWithSignatures,
+            epoch_change_li: Option<&'a LedgerInfoWithSignatures>,
+            checkpoint_interval: usize,
+        ) -> ExecutorResult<ChunkCommitNotification>;
 
-        fn update_ledger(&self) -> AnyhowResult<()>;
+        fn pending_version_range(&self) -> Option<RangeInclusive<Version>>;
 
-        fn commit_chunk(&self) -> AnyhowResult<ChunkCommitNotification>;
+        fn smt_memory_usage(&self) -> usize;
 
-        fn reset(&self) -> AnyhowResult<()>;
+        fn reset(&self) -> ExecutorResult<()>;
 
         fn finish(&self);
     }
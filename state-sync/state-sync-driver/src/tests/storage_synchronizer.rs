@@ -56,8 +56,8 @@ async fn test_apply_outputs() {
     let mut chunk_executor = create_mock_executor();
     chunk_executor
         .expect_enqueue_chunk_by_transaction_outputs()
-        .with(always(), always(), always())
-        .returning(|_, _, _| Ok(()));
+        .with(always(), always(), always(), always())
+        .returning(|_, _, _, _| Ok(()));
     chunk_executor.expect_update_ledger().returning(|| Ok(()));
     let expected_commit_return = Ok(ChunkCommitNotification {
         subscribable_events: vec![event_to_commit.clone()],
@@ -122,8 +122,8 @@ async fn test_apply_outputs_error() {
     let mut chunk_executor = create_mock_executor();
     chunk_executor
         .expect_enqueue_chunk_by_transaction_outputs()
-        .with(always(), always(), always())
-        .returning(|_, _, _| Err(format_err!("Failed to apply chunk!")));
+        .with(always(), always(), always(), always())
+        .returning(|_, _, _, _| Err(format_err!("Failed to apply chunk!").into()));
 
     // Create the storage synchronizer
     let (_, mut error_listener, _, _, _, mut storage_synchronizer, _) =
@@ -152,8 +152,8 @@ async fn test_apply_outputs_send_error() {
     let mut chunk_executor = create_mock_executor();
     chunk_executor
         .expect_enqueue_chunk_by_transaction_outputs()
-        .with(always(), always(), always())
-        .returning(|_, _, _| Ok(()));
+        .with(always(), always(), always(), always())
+        .returning(|_, _, _, _| Ok(()));
 
     // Create the storage synchronizer
     let (_, mut error_listener, _, _, _, mut storage_synchronizer, storage_synchronizer_handles) =
@@ -186,11 +186,11 @@ async fn test_apply_outputs_update_error() {
     let mut chunk_executor = create_mock_executor();
     chunk_executor
         .expect_enqueue_chunk_by_transaction_outputs()
-        .with(always(), always(), always())
-        .returning(|_, _, _| Ok(()));
+        .with(always(), always(), always(), always())
+        .returning(|_, _, _, _| Ok(()));
     chunk_executor
         .expect_update_ledger()
-        .returning(|| Err(format_err!("Failed to update the ledger!")));
+        .returning(|| Err(format_err!("Failed to update the ledger!").into()));
 
     // Create the storage synchronizer
     let (_, mut error_listener, _, _, _, mut storage_synchronizer, _) =
@@ -219,8 +219,8 @@ async fn test_apply_outputs_update_send_error() {
     let mut chunk_executor = create_mock_executor();
     chunk_executor
         .expect_enqueue_chunk_by_transaction_outputs()
-        .with(always(), always(), always())
-        .returning(|_, _, _| Ok(()));
+        .with(always(), always(), always(), always())
+        .returning(|_, _, _, _| Ok(()));
     chunk_executor.expect_update_ledger().returning(|| Ok(()));
 
     // Create the storage synchronizer
@@ -254,12 +254,12 @@ async fn test_apply_outputs_commit_error() {
     let mut chunk_executor = create_mock_executor();
     chunk_executor
         .expect_enqueue_chunk_by_transaction_outputs()
-        .with(always(), always(), always())
-        .returning(|_, _, _| Ok(()));
+        .with(always(), always(), always(), always())
+        .returning(|_, _, _, _| Ok(()));
     chunk_executor.expect_update_ledger().returning(|| Ok(()));
     chunk_executor
         .expect_commit_chunk()
-        .return_once(|| Err(format_err!("Failed to commit chunk!")));
+        .return_once(|| Err(format_err!("Failed to commit chunk!").into()));
 
     // Create the storage synchronizer
     let (_, mut error_listener, _, _, _, mut storage_synchronizer, _) =
@@ -292,8 +292,8 @@ async fn test_apply_outputs_commit_send_error() {
     let mut chunk_executor = create_mock_executor();
     chunk_executor
         .expect_enqueue_chunk_by_transaction_outputs()
-        .with(always(), always(), always())
-        .returning(|_, _, _| Ok(()));
+        .with(always(), always(), always(), always())
+        .returning(|_, _, _, _| Ok(()));
     chunk_executor.expect_update_ledger().returning(|| Ok(()));
     let expected_commit_return = Ok(ChunkCommitNotification {
         subscribable_events: vec![event_to_commit.clone()],
@@ -344,8 +344,8 @@ async fn test_execute_transactions() {
     let mut chunk_executor = create_mock_executor();
     chunk_executor
         .expect_enqueue_chunk_by_execution()
-        .with(always(), always(), always())
-        .returning(|_, _, _| Ok(()));
+        .with(always(), always(), always(), always())
+        .returning(|_, _, _, _| Ok(()));
     let expected_commit_return = Ok(ChunkCommitNotification {
         subscribable_events: vec![event_to_commit.clone()],
         committed_transactions: vec![transaction_to_commit.clone()],
@@ -410,8 +410,8 @@ async fn test_execute_transactions_error() {
     let mut chunk_executor = create_mock_executor();
     chunk_executor
         .expect_enqueue_chunk_by_execution()
-        .with(always(), always(), always())
-        .returning(|_, _, _| Err(format_err!("Failed to execute chunk!")));
+        .with(always(), always(), always(), always())
+        .returning(|_, _, _, _| Err(format_err!("Failed to execute chunk!").into()));
 
     // Create the storage synchronizer
     let (_, mut error_listener, _, _, _, mut storage_synchronizer, _) =
@@ -440,8 +440,8 @@ async fn test_execute_transactions_send_error() {
     let mut chunk_executor = create_mock_executor();
     chunk_executor
         .expect_enqueue_chunk_by_execution()
-        .with(always(), always(), always())
-        .returning(|_, _, _| Ok(()));
+        .with(always(), always(), always(), always())
+        .returning(|_, _, _, _| Ok(()));
 
     // Create the storage synchronizer
     let (_, mut error_listener, _, _, _, mut storage_synchronizer, storage_synchronizer_handles) =
@@ -474,11 +474,11 @@ async fn test_execute_transactions_update_error() {
     let mut chunk_executor = create_mock_executor();
     chunk_executor
         .expect_enqueue_chunk_by_execution()
-        .with(always(), always(), always())
-        .returning(|_, _, _| Ok(()));
+        .with(always(), always(), always(), always())
+        .returning(|_, _, _, _| Ok(()));
     chunk_executor
         .expect_update_ledger()
-        .returning(|| Err(format_err!("Failed to update the ledger!")));
+        .returning(|| Err(format_err!("Failed to update the ledger!").into()));
 
     // Create the storage synchronizer
     let (_, mut error_listener, _, _, _, mut storage_synchronizer, _) =
@@ -507,8 +507,8 @@ async fn test_execute_transactions_update_send_error() {
     let mut chunk_executor = create_mock_executor();
     chunk_executor
         .expect_enqueue_chunk_by_execution()
-        .with(always(), always(), always())
-        .returning(|_, _, _| Ok(()));
+        .with(always(), always(), always(), always())
+        .returning(|_, _, _, _| Ok(()));
     chunk_executor.expect_update_ledger().returning(|| Ok(()));
 
     // Create the storage synchronizer
@@ -542,12 +542,12 @@ async fn test_execute_transactions_commit_error() {
     let mut chunk_executor = create_mock_executor();
     chunk_executor
         .expect_enqueue_chunk_by_execution()
-        .with(always(), always(), always())
-        .returning(|_, _, _| Ok(()));
+        .with(always(), always(), always(), always())
+        .returning(|_, _, _, _| Ok(()));
     chunk_executor.expect_update_ledger().returning(|| Ok(()));
     chunk_executor
         .expect_commit_chunk()
-        .return_once(|| Err(format_err!("Failed to commit chunk!")));
+        .return_once(|| Err(format_err!("Failed to commit chunk!").into()));
 
     // Create the storage synchronizer
     let (_, mut error_listener, _, _, _, mut storage_synchronizer, _) =
@@ -580,8 +580,8 @@ async fn test_execute_transactions_commit_send_error() {
     let mut chunk_executor = create_mock_executor();
     chunk_executor
         .expect_enqueue_chunk_by_execution()
-        .with(always(), always(), always())
-        .returning(|_, _, _| Ok(()));
+        .with(always(), always(), always(), always())
+        .returning(|_, _, _, _| Ok(()));
     let expected_commit_return = Ok(ChunkCommitNotification {
         subscribable_events: vec![event_to_commit.clone()],
         committed_transactions: vec![transaction_to_commit.clone()],
@@ -27,7 +27,7 @@ use crate::{
 use anyhow::{anyhow, ensure, Result};
 use aptos_db::backup::restore_handler::RestoreHandler;
 use aptos_executor::chunk_executor::ChunkExecutor;
-use aptos_executor_types::{TransactionReplayer, VerifyExecutionMode};
+use aptos_executor_types::{ChunkTrustMode, TransactionReplayer, VerifyExecutionMode};
 use aptos_logger::prelude::*;
 use aptos_storage_interface::DbReaderWriter;
 use aptos_types::{
@@ -612,6 +612,7 @@ impl TransactionRestoreBatchController {
                             write_sets,
                             events,
                             &verify_execution_mode,
+                            ChunkTrustMode::Verify,
                         )
                     })
                     .err_into::<anyhow::Error>()
@@ -10,5 +10,5 @@ mod sparse_merkle;
 pub use crate::sparse_merkle::test_utils;
 pub use crate::sparse_merkle::{
     ancestors::SmtAncestors, utils::get_state_shard_id, FrozenSparseMerkleTree, ProofRead,
-    SparseMerkleTree, StateStoreStatus,
+    SparseMerkleTree, StateStoreStatus, UpdateError,
 };
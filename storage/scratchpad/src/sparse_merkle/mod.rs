@@ -84,7 +84,7 @@ pub mod utils;
 use crate::sparse_merkle::{
     dropper::SUBTREE_DROPPER,
     metrics::{GENERATION, TIMER},
-    node::{NodeInner, SubTree},
+    node::{Node, NodeInner, SubTree},
     updater::SubTreeUpdater,
     utils::get_state_shard_id,
 };
@@ -96,7 +96,7 @@ use aptos_infallible::Mutex;
 use aptos_metrics_core::IntGaugeHelper;
 use aptos_types::{
     nibble::{nibble_path::NibblePath, Nibble},
-    proof::SparseMerkleProofExt,
+    proof::{definition::NodeInProof, SparseMerkleLeafNode, SparseMerkleProofExt},
     state_store::state_storage_usage::StateStorageUsage,
 };
 use std::{
@@ -275,6 +275,41 @@ where
         self.inner.usage
     }
 
+    /// Estimates the memory held by the nodes of this tree and the trees spawned from it, as the
+    /// number of nodes in memory times the size of a node. Values held by the leaves are not
+    /// accounted for. This walks the whole tree, so it is not meant for hot paths.
+    pub fn estimated_memory_usage(&self) -> usize {
+        self.num_nodes_in_mem() * std::mem::size_of::<Node<V>>()
+    }
+
+    /// Counts the distinct nodes still in memory that are reachable from this tree and all the
+    /// trees spawned from it.
+    fn num_nodes_in_mem(&self) -> usize {
+        // Holds on to the visited nodes, so that their addresses can't be reused while counting.
+        let mut seen = HashMap::new();
+        let mut inners = vec![self.inner.clone()];
+        let mut subtrees = Vec::new();
+        while let Some(inner) = inners.pop() {
+            if let Some(root) = &inner.root {
+                subtrees.push(root.clone());
+            }
+            inners.extend(inner.children.lock().iter().cloned());
+        }
+        while let Some(subtree) = subtrees.pop() {
+            if let Some(node) = subtree.get_node_if_in_mem(0) {
+                if seen.contains_key(&Arc::as_ptr(&node)) {
+                    continue;
+                }
+                if let NodeInner::Internal(internal) = node.inner() {
+                    subtrees.push(internal.left.clone());
+                    subtrees.push(internal.right.clone());
+                }
+                seen.insert(Arc::as_ptr(&node), node);
+            }
+        }
+        seen.len()
+    }
+
     /// Compares an old and a new SMTs and return the newly created node hashes in between.
     ///
     /// Assumes 16 shards in total.
@@ -568,6 +603,83 @@ where
         } // end loop
     }
 
+    /// Generates a proof for `key` against the root of this `SparseMerkleTree`. Siblings on the
+    /// in-memory part of the path are read from the tree, and the rest of the path, which only
+    /// exists in persisted storage, is taken from the proof `proof_reader` provides for `key`.
+    pub fn get_proof(
+        &self,
+        key: HashValue,
+        proof_reader: &impl ProofRead,
+    ) -> Result<SparseMerkleProofExt, UpdateError> {
+        let mut subtree = self.smt.root_weak();
+        let mut bits = key.iter_bits();
+        let mut siblings = Vec::new();
+
+        loop {
+            match subtree {
+                SubTree::Empty => return Ok(SparseMerkleProofExt::new(None, siblings)),
+                SubTree::NonEmpty { .. } => {
+                    match subtree.get_node_if_in_mem(self.base_generation) {
+                        None => {
+                            let depth = siblings.len();
+                            let proof = proof_reader
+                                .get_proof(key)
+                                .ok_or(UpdateError::MissingProof)?;
+                            if depth > proof.bottom_depth() {
+                                return Err(UpdateError::ShortProof {
+                                    key,
+                                    num_siblings: proof.bottom_depth(),
+                                    depth,
+                                });
+                            }
+                            // A partial proof can't provide the siblings above its root.
+                            for d in depth + 1..=proof.bottom_depth() {
+                                let sibling = proof.sibling_at_depth(d).map_err(|_| {
+                                    UpdateError::PartialProof {
+                                        key,
+                                        root_depth: proof.root_depth(),
+                                        depth,
+                                    }
+                                })?;
+                                siblings.push(*sibling);
+                            }
+                            return Ok(SparseMerkleProofExt::new(proof.leaf(), siblings));
+                        },
+                        Some(node) => match node.inner() {
+                            NodeInner::Internal(internal_node) => {
+                                let go_right = bits.next().expect("Tree is too deep.");
+                                let (on_path, sibling) = if go_right {
+                                    (&internal_node.right, &internal_node.left)
+                                } else {
+                                    (&internal_node.left, &internal_node.right)
+                                };
+                                siblings.push(self.node_in_proof(sibling));
+                                subtree = on_path.weak();
+                            },
+                            NodeInner::Leaf(leaf_node) => {
+                                let leaf =
+                                    SparseMerkleLeafNode::new(leaf_node.key, leaf_node.value.hash);
+                                return Ok(SparseMerkleProofExt::new(Some(leaf), siblings));
+                            },
+                        },
+                    }
+                },
+            }
+        }
+    }
+
+    fn node_in_proof(&self, subtree: &SubTree<V>) -> NodeInProof {
+        match subtree.get_node_if_in_mem(self.base_generation) {
+            Some(node) => match node.inner() {
+                NodeInner::Leaf(leaf_node) => {
+                    SparseMerkleLeafNode::new(leaf_node.key, leaf_node.value.hash).into()
+                },
+                NodeInner::Internal(_) => subtree.hash().into(),
+            },
+            None => subtree.hash().into(),
+        }
+    }
+
     pub fn usage(&self) -> StateStorageUsage {
         self.smt.usage()
     }
@@ -599,4 +711,17 @@ pub enum UpdateError {
         num_siblings: usize,
         depth: usize,
     },
+    /// At `depth` a persisted subtree was encountered and a proof was requested to assist finding
+    /// details about the subtree, but the result proof only starts below it, at `root_depth`.
+    #[error(
+        "Partial proof: key: {}, root_depth: {}, depth: {}",
+        key,
+        root_depth,
+        depth
+    )]
+    PartialProof {
+        key: HashValue,
+        root_depth: usize,
+        depth: usize,
+    },
 }
@@ -4,6 +4,7 @@
 
 use super::*;
 use crate::test_utils::{
+    naive_smt::NaiveSmt,
     proof_reader::ProofReader,
     proptest_helpers::{arb_smt_correctness_case, test_smt_correctness_impl},
 };
@@ -250,6 +251,100 @@ fn test_update_256_siblings_in_proof() {
     assert_eq!(new_smt.get(key2), StateStoreStatus::UnknownValue);
 }
 
+#[test]
+fn test_get_proof() {
+    let key1 = HashValue::from_slice([0; 32]).unwrap();
+    let key2 = update_byte(&key1, 0, 0b01000000);
+    let key3 = update_byte(&key1, 0, 0b10000000);
+    let key4 = update_byte(&key1, 0, 0b11000000);
+
+    let value1: StateValue = vec![1].into();
+    let value2: StateValue = vec![2].into();
+    let value3: StateValue = vec![3].into();
+    let new_value3: StateValue = vec![4].into();
+
+    let mut naive_smt = NaiveSmt::new(&[(key1, &value1), (key2, &value2), (key3, &value3)]);
+    let proof_reader = ProofReader::new(
+        [key1, key2, key3, key4]
+            .iter()
+            .map(|key| (*key, naive_smt.get_proof(key)))
+            .collect(),
+    );
+
+    let smt = SparseMerkleTree::new_test(naive_smt.get_root_hash());
+    let updated = smt
+        .batch_update(vec![(key3, Some(&new_value3))], &proof_reader)
+        .unwrap();
+    let frozen = updated.clone().freeze(&smt);
+    let mut naive_smt = naive_smt.update(&[(key3, Some(&new_value3))]);
+
+    for key in [key1, key2, key3, key4] {
+        let proof = frozen.get_proof(key, &proof_reader).unwrap();
+        assert_eq!(proof, naive_smt.get_proof(&key));
+    }
+    frozen
+        .get_proof(key3, &proof_reader)
+        .unwrap()
+        .verify(updated.root_hash(), key3, Some(&new_value3))
+        .unwrap();
+
+    assert_eq!(
+        frozen.get_proof(key1, &ProofReader::default()),
+        Err(UpdateError::MissingProof)
+    );
+}
+
+#[test]
+fn test_get_proof_with_partial_proof() {
+    let key1 = HashValue::from_slice([0; 32]).unwrap();
+    let key2 = update_byte(&key1, 0, 0b01000000);
+    let key3 = update_byte(&key1, 0, 0b10000000);
+
+    let value1: StateValue = vec![1].into();
+    let value2: StateValue = vec![2].into();
+    let value3: StateValue = vec![3].into();
+    let new_value3: StateValue = vec![4].into();
+
+    let mut naive_smt = NaiveSmt::new(&[(key1, &value1), (key2, &value2), (key3, &value3)]);
+    // Drop the sibling at depth 1 from the proof of key1, so it proves the subtree at depth 1.
+    let full_proof = naive_smt.get_proof(&key1);
+    let partial_proof = SparseMerkleProofExt::new_partial(
+        full_proof.leaf(),
+        (2..=full_proof.bottom_depth())
+            .map(|depth| *full_proof.sibling_at_depth(depth).unwrap())
+            .collect(),
+        1,
+    );
+    let proof_reader = ProofReader::new(vec![
+        (key1, partial_proof),
+        (key3, naive_smt.get_proof(&key3)),
+    ]);
+
+    // The root is persisted, so the proof has to start at depth 0.
+    let smt = SparseMerkleTree::new_test(naive_smt.get_root_hash());
+    assert_eq!(
+        smt.freeze(&smt).get_proof(key1, &proof_reader),
+        Err(UpdateError::PartialProof {
+            key: key1,
+            root_depth: 1,
+            depth: 0,
+        })
+    );
+
+    // Once key3 is updated, the root is in memory and the subtree of key1 is persisted at depth 1,
+    // where the partial proof starts.
+    let updated = smt
+        .batch_update(vec![(key3, Some(&new_value3))], &proof_reader)
+        .unwrap();
+    let frozen = updated.clone().freeze(&smt);
+    let mut naive_smt = naive_smt.update(&[(key3, Some(&new_value3))]);
+    let proof = frozen.get_proof(key1, &proof_reader).unwrap();
+    assert_eq!(proof, naive_smt.get_proof(&key1));
+    proof
+        .verify(updated.root_hash(), key1, Some(&value1))
+        .unwrap();
+}
+
 #[test]
 fn test_new_unknown() {
     let root_hash = HashValue::new([1; HashValue::LENGTH]);
@@ -475,6 +570,32 @@ fn test_multithread_branching() {
         .for_each(|t| t.join().unwrap())
 }
 
+#[test]
+fn test_estimated_memory_usage() {
+    let node_size = std::mem::size_of::<Node<StateValue>>();
+    let proof_reader = ProofReader::default();
+    let root_smt = SparseMerkleTree::new_empty();
+    assert_eq!(root_smt.estimated_memory_usage(), 0);
+
+    let key1 = HashValue::from_slice([0; 32]).unwrap();
+    let key2 = HashValue::from_slice([0xFF; 32]).unwrap();
+    let value: StateValue = vec![1, 2, 3].into();
+    let smt1 = root_smt
+        .batch_update(vec![(key1, Some(&value))], &proof_reader)
+        .unwrap();
+    assert_eq!(smt1.estimated_memory_usage(), node_size);
+
+    // An internal node and two leaves.
+    let smt2 = smt1
+        .batch_update(vec![(key2, Some(&value))], &proof_reader)
+        .unwrap();
+    assert_eq!(smt2.estimated_memory_usage(), 3 * node_size);
+
+    // Older trees account for the trees spawned from them.
+    assert!(root_smt.estimated_memory_usage() >= smt2.estimated_memory_usage());
+    assert!(smt1.estimated_memory_usage() >= smt2.estimated_memory_usage());
+}
+
 #[test]
 fn test_drop() {
     let proof_reader = ProofReader::default();
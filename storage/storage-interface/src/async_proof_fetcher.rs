@@ -5,6 +5,7 @@ use crate::{metrics::TIMER, DbReader};
 use anyhow::{anyhow, Result};
 use aptos_crypto::{hash::CryptoHash, HashValue};
 use aptos_logger::{error, sample, sample::SampleRate};
+use aptos_scratchpad::ProofRead;
 use aptos_types::{
     proof::SparseMerkleProofExt,
     state_store::{state_key::StateKey, state_value::StateValue},
@@ -41,6 +42,9 @@ pub struct AsyncProofFetcher {
     data_sender: Sender<Proof>,
     data_receiver: Receiver<Proof>,
     num_proofs_to_read: AtomicUsize,
+    /// Proofs gathered ahead of time, e.g. by a prior speculative execution of the same block,
+    /// consulted before reading a proof from the DB.
+    precomputed_proofs: Option<Box<dyn ProofRead + Send>>,
 }
 
 impl AsyncProofFetcher {
@@ -52,9 +56,20 @@ impl AsyncProofFetcher {
             data_sender,
             data_receiver,
             num_proofs_to_read: AtomicUsize::new(0),
+            precomputed_proofs: None,
         }
     }
 
+    /// Uses the proofs in `precomputed_proofs` instead of reading them from the DB, as long as
+    /// they are valid for the requested subtree. Other proofs are still read from the DB.
+    pub fn with_precomputed_proofs(
+        mut self,
+        precomputed_proofs: Box<dyn ProofRead + Send>,
+    ) -> Self {
+        self.precomputed_proofs = Some(precomputed_proofs);
+        self
+    }
+
     pub fn fetch_state_value(
         &self,
         state_key: &StateKey,
@@ -115,6 +130,30 @@ impl AsyncProofFetcher {
         proofs
     }
 
+    // Returns the precomputed proof of `state_key`, if one was supplied for the same subtree and
+    // it verifies against the subtree root hash (when known).
+    fn get_precomputed_proof(
+        &self,
+        state_key: &StateKey,
+        subtree_root_depth: usize,
+        subtree_root_hash: Option<HashValue>,
+        value_hash: Option<HashValue>,
+    ) -> Option<SparseMerkleProofExt> {
+        let proof = self
+            .precomputed_proofs
+            .as_ref()?
+            .get_proof(state_key.hash())?;
+        if proof.root_depth() != subtree_root_depth {
+            return None;
+        }
+        if let Some(subtree_root_hash) = subtree_root_hash {
+            proof
+                .verify_by_hash(subtree_root_hash, state_key.hash(), value_hash)
+                .ok()?;
+        }
+        Some(proof.clone())
+    }
+
     // Schedules proof reading work in a background running thread pool.
     fn schedule_proof_read(
         &self,
@@ -128,6 +167,20 @@ impl AsyncProofFetcher {
             .with_label_values(&["schedule_async_proof_read"])
             .start_timer();
         self.num_proofs_to_read.fetch_add(1, Ordering::SeqCst);
+        if let Some(proof) = self.get_precomputed_proof(
+            &state_key,
+            subtree_root_depth,
+            subtree_root_hash,
+            value_hash,
+        ) {
+            self.data_sender
+                .send(Proof {
+                    state_key_hash: state_key.hash(),
+                    proof,
+                })
+                .expect("The receiver is owned by the proof fetcher.");
+            return;
+        }
         let reader = self.reader.clone();
         let data_sender = self.data_sender.clone();
         IO_POOL.execute(move || {
@@ -172,7 +225,7 @@ impl AsyncProofFetcher {
 mod tests {
     use super::*;
     use crate::mock::MockDbReaderWriter;
-    use aptos_types::state_store::state_key::inner::StateKeyInner;
+    use aptos_types::{proof::SparseMerkleLeafNode, state_store::state_key::inner::StateKeyInner};
     use assert_unordered::assert_eq_unordered;
 
     #[test]
@@ -196,4 +249,45 @@ mod tests {
         assert_eq!(proofs.len(), 10);
         assert_eq_unordered!(proofs.into_keys().collect::<Vec<_>>(), expected_key_hashes);
     }
+
+    struct PrecomputedProofs(HashMap<HashValue, SparseMerkleProofExt>);
+
+    impl ProofRead for PrecomputedProofs {
+        fn get_proof(&self, key: HashValue) -> Option<&SparseMerkleProofExt> {
+            self.0.get(&key)
+        }
+    }
+
+    #[test]
+    fn test_fetch_with_precomputed_proofs() {
+        let precomputed_key = StateKey::raw(b"precomputed_key");
+        let other_key = StateKey::raw(b"other_key");
+        // The mock DB returns proofs without a leaf, so a proof with one must be precomputed.
+        let precomputed_proof = SparseMerkleProofExt::new(
+            Some(SparseMerkleLeafNode::new(
+                precomputed_key.hash(),
+                HashValue::random(),
+            )),
+            vec![],
+        );
+        let fetcher = AsyncProofFetcher::new(Arc::new(MockDbReaderWriter)).with_precomputed_proofs(
+            Box::new(PrecomputedProofs(HashMap::from([(
+                precomputed_key.hash(),
+                precomputed_proof.clone(),
+            )]))),
+        );
+        for state_key in [&precomputed_key, &other_key] {
+            fetcher
+                .fetch_state_value_with_version_and_schedule_proof_read(state_key, 0, 0, None)
+                .expect("Should not fail.");
+        }
+
+        let proofs = fetcher.get_proof_cache();
+        assert_eq!(proofs.len(), 2);
+        assert_eq!(proofs[&precomputed_key.hash()], precomputed_proof);
+        assert_eq!(
+            proofs[&other_key.hash()],
+            SparseMerkleProofExt::new(None, vec![])
+        );
+    }
 }
@@ -23,6 +23,10 @@ pub struct DbStateView {
 }
 
 impl DbStateView {
+    pub fn version(&self) -> Option<Version> {
+        self.version
+    }
+
     fn get(&self, key: &StateKey) -> Result<Option<StateValue>> {
         Ok(if let Some(version) = self.version {
             if let Some(root_hash) = self.verify_against_state_root_hash {
@@ -8,8 +8,8 @@ use crate::{
     file_format_common::VERSION_5,
     normalized::Module,
 };
-use move_core_types::vm_status::StatusCode;
-use std::collections::BTreeSet;
+use move_core_types::{identifier::Identifier, language_storage::ModuleId, vm_status::StatusCode};
+use std::{collections::BTreeSet, fmt};
 
 /// The result of a linking and layout compatibility check. Here is what the different combinations. NOTE that if `check_struct_layout` is false, type safety over a series of upgrades cannot be guaranteed.
 /// mean:
@@ -71,13 +71,60 @@ impl Compatibility {
 
     /// Check compatibility for `new_module` relative to old module `old_module`.
     pub fn check(&self, old_module: &Module, new_module: &Module) -> PartialVMResult<()> {
-        let mut struct_and_pub_function_linking = true;
-        let mut struct_layout = true;
-        let mut friend_linking = true;
+        let incompatibilities = Self::find_incompatibilities(old_module, new_module);
+        let struct_and_pub_function_linking = !incompatibilities
+            .iter()
+            .any(Incompatibility::breaks_struct_and_pub_function_linking);
+        let struct_layout = !incompatibilities
+            .iter()
+            .any(Incompatibility::breaks_struct_layout);
+        let friend_linking = !incompatibilities
+            .iter()
+            .any(Incompatibility::breaks_friend_linking);
+
+        if self.check_struct_and_pub_function_linking && !struct_and_pub_function_linking {
+            return Err(PartialVMError::new(
+                StatusCode::BACKWARD_INCOMPATIBLE_MODULE_UPDATE,
+            ).with_message(format!("Module Update Failure: Public function/struct signature of new module differs from existing module in {:?}::{}", old_module.address, old_module.name)));
+        }
+        if self.check_struct_layout && !struct_layout {
+            return Err(PartialVMError::new(
+                StatusCode::BACKWARD_INCOMPATIBLE_MODULE_UPDATE,
+            ).with_message(format!("Module Update Failure: Struct layout of new module differs from existing modul in {:?}::{}", old_module.address, old_module.name)));
+        }
+        if self.check_friend_linking && !friend_linking {
+            return Err(PartialVMError::new(
+                StatusCode::BACKWARD_INCOMPATIBLE_MODULE_UPDATE,
+            ).with_message(format!("Module Update Failure: Friend signature of new module differs from existing module in {:?}::{}", old_module.address, old_module.name)));
+        }
+
+        Ok(())
+    }
+
+    /// Returns every incompatibility of `new_module` relative to old module `old_module` which is
+    /// rejected by this configuration. An empty result means that the upgrade is compatible.
+    pub fn incompatibilities(
+        &self,
+        old_module: &Module,
+        new_module: &Module,
+    ) -> Vec<Incompatibility> {
+        Self::find_incompatibilities(old_module, new_module)
+            .into_iter()
+            .filter(|incompatibility| {
+                (self.check_struct_and_pub_function_linking
+                    && incompatibility.breaks_struct_and_pub_function_linking())
+                    || (self.check_struct_layout && incompatibility.breaks_struct_layout())
+                    || (self.check_friend_linking && incompatibility.breaks_friend_linking())
+            })
+            .collect()
+    }
+
+    fn find_incompatibilities(old_module: &Module, new_module: &Module) -> Vec<Incompatibility> {
+        let mut incompatibilities = vec![];
 
         // module's name and address are unchanged
         if old_module.address != new_module.address || old_module.name != new_module.name {
-            struct_and_pub_function_linking = false;
+            incompatibilities.push(Incompatibility::ModuleRenamed);
         }
 
         // old module's structs are a subset of the new module's structs
@@ -88,9 +135,8 @@ impl Compatibility {
                     // Struct not present in new . Existing modules that depend on this struct will fail to link with the new version of the module.
                     // Also, struct layout cannot be guaranteed transitively, because after
                     // removing the struct, it could be re-added later with a different layout.
-                    struct_and_pub_function_linking = false;
-                    struct_layout = false;
-                    break;
+                    incompatibilities.push(Incompatibility::StructRemoved(name.clone()));
+                    continue;
                 },
             };
 
@@ -100,7 +146,7 @@ impl Compatibility {
                     &new_struct.type_parameters,
                 )
             {
-                struct_and_pub_function_linking = false;
+                incompatibilities.push(Incompatibility::StructSignatureChanged(name.clone()));
             }
             if new_struct.fields != old_struct.fields {
                 // Fields changed. Code in this module will fail at runtime if it tries to
@@ -109,7 +155,7 @@ impl Compatibility {
                 // choose that changing the name (but not position or type) of a field is
                 // compatible. The VM does not care about the name of a field
                 // (it's purely informational), but clients presumably do.
-                struct_layout = false
+                incompatibilities.push(Incompatibility::StructLayoutChanged(name.clone()));
             }
         }
 
@@ -128,14 +174,17 @@ impl Compatibility {
         // friend list. But for simplicity, we decided to go to the more restrictive form now and
         // we may revisit this in the future.
         for (name, old_func) in &old_module.exposed_functions {
+            let is_friend = matches!(old_func.visibility, Visibility::Friend);
             let new_func = match new_module.exposed_functions.get(name) {
                 Some(new_func) => new_func,
                 None => {
-                    if matches!(old_func.visibility, Visibility::Friend) {
-                        friend_linking = false;
-                    } else {
-                        struct_and_pub_function_linking = false;
-                    }
+                    incompatibilities.push(
+                        if is_friend {
+                            Incompatibility::FriendFunctionRemoved(name.clone())
+                        } else {
+                            Incompatibility::FunctionRemoved(name.clone())
+                        },
+                    );
                     continue;
                 },
             };
@@ -170,11 +219,13 @@ impl Compatibility {
                     &new_func.type_parameters,
                 )
             {
-                if matches!(old_func.visibility, Visibility::Friend) {
-                    friend_linking = false;
-                } else {
-                    struct_and_pub_function_linking = false;
-                }
+                incompatibilities.push(
+                    if is_friend {
+                        Incompatibility::FriendFunctionSignatureChanged(name.clone())
+                    } else {
+                        Incompatibility::FunctionSignatureChanged(name.clone())
+                    },
+                );
             }
         }
 
@@ -183,29 +234,96 @@ impl Compatibility {
         // - additions to the list are allowed
         // - removals are not allowed
         //
-        let old_friend_module_ids: BTreeSet<_> = old_module.friends.iter().cloned().collect();
-        let new_friend_module_ids: BTreeSet<_> = new_module.friends.iter().cloned().collect();
-        if !old_friend_module_ids.is_subset(&new_friend_module_ids) {
-            friend_linking = false;
+        let new_friend_module_ids: BTreeSet<_> = new_module.friends.iter().collect();
+        for friend in &old_module.friends {
+            if !new_friend_module_ids.contains(friend) {
+                incompatibilities.push(Incompatibility::FriendRemoved(friend.clone()));
+            }
         }
 
-        if self.check_struct_and_pub_function_linking && !struct_and_pub_function_linking {
-            return Err(PartialVMError::new(
-                StatusCode::BACKWARD_INCOMPATIBLE_MODULE_UPDATE,
-            ).with_message(format!("Module Update Failure: Public function/struct signature of new module differs from existing module in {:?}::{}", old_module.address, old_module.name)));
-        }
-        if self.check_struct_layout && !struct_layout {
-            return Err(PartialVMError::new(
-                StatusCode::BACKWARD_INCOMPATIBLE_MODULE_UPDATE,
-            ).with_message(format!("Module Update Failure: Struct layout of new module differs from existing modul in {:?}::{}", old_module.address, old_module.name)));
-        }
-        if self.check_friend_linking && !friend_linking {
-            return Err(PartialVMError::new(
-                StatusCode::BACKWARD_INCOMPATIBLE_MODULE_UPDATE,
-            ).with_message(format!("Module Update Failure: Friend signature of new module differs from existing module in {:?}::{}", old_module.address, old_module.name)));
-        }
+        incompatibilities
+    }
+}
 
-        Ok(())
+/// A single reason for which a new version of a module is not compatible with the old one.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Incompatibility {
+    /// The module's address or name differs.
+    ModuleRenamed,
+    /// A struct was removed.
+    StructRemoved(Identifier),
+    /// A struct's abilities or type parameters changed incompatibly.
+    StructSignatureChanged(Identifier),
+    /// A struct's fields changed.
+    StructLayoutChanged(Identifier),
+    /// A public or entry function was removed or made private.
+    FunctionRemoved(Identifier),
+    /// A public or entry function's visibility or signature changed incompatibly.
+    FunctionSignatureChanged(Identifier),
+    /// A friend function was removed or made private.
+    FriendFunctionRemoved(Identifier),
+    /// A friend function's visibility or signature changed incompatibly.
+    FriendFunctionSignatureChanged(Identifier),
+    /// A friend declaration was removed.
+    FriendRemoved(ModuleId),
+}
+
+impl Incompatibility {
+    /// Whether dependent modules that reference public functions or structs may fail to link.
+    pub fn breaks_struct_and_pub_function_linking(&self) -> bool {
+        matches!(
+            self,
+            Self::ModuleRenamed
+                | Self::StructRemoved(_)
+                | Self::StructSignatureChanged(_)
+                | Self::FunctionRemoved(_)
+                | Self::FunctionSignatureChanged(_)
+        )
+    }
+
+    /// Whether previously published values of the module's structs may fail to be read.
+    pub fn breaks_struct_layout(&self) -> bool {
+        matches!(self, Self::StructRemoved(_) | Self::StructLayoutChanged(_))
+    }
+
+    /// Whether friend modules may fail to link.
+    pub fn breaks_friend_linking(&self) -> bool {
+        matches!(
+            self,
+            Self::FriendFunctionRemoved(_)
+                | Self::FriendFunctionSignatureChanged(_)
+                | Self::FriendRemoved(_)
+        )
+    }
+}
+
+impl fmt::Display for Incompatibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::ModuleRenamed => write!(f, "module address or name changed"),
+            Self::StructRemoved(name) => write!(f, "struct `{}` was removed", name),
+            Self::StructSignatureChanged(name) => {
+                write!(
+                    f,
+                    "abilities or type parameters of struct `{}` changed",
+                    name
+                )
+            },
+            Self::StructLayoutChanged(name) => write!(f, "fields of struct `{}` changed", name),
+            Self::FunctionRemoved(name) => write!(f, "function `{}` was removed", name),
+            Self::FunctionSignatureChanged(name) => {
+                write!(f, "signature of function `{}` changed", name)
+            },
+            Self::FriendFunctionRemoved(name) => {
+                write!(f, "friend function `{}` was removed", name)
+            },
+            Self::FriendFunctionSignatureChanged(name) => {
+                write!(f, "signature of friend function `{}` changed", name)
+            },
+            Self::FriendRemoved(module_id) => {
+                write!(f, "friend declaration of `{}` was removed", module_id)
+            },
+        }
     }
 }
 
@@ -1,7 +1,11 @@
 // Copyright (c) The Move Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{compatibility::Compatibility, file_format::*, normalized};
+use crate::{
+    compatibility::{Compatibility, Incompatibility},
+    file_format::*,
+    normalized,
+};
 use move_core_types::{account_address::AccountAddress, identifier::Identifier};
 use std::convert::TryFrom;
 
@@ -115,3 +119,32 @@ fn deprecated_add_script_visibility() {
         .check(&friend_module, &script_module)
         .is_err());
 }
+
+#[test]
+fn incompatibilities_of_narrowed_visibility() {
+    let fn_name = Identifier::new("fn").unwrap();
+    let private_module = mk_module(Visibility::Private as u8);
+    // public -> private, not allowed; the function is no longer exposed
+    let public_module = mk_module(Visibility::Public as u8);
+    assert_eq!(
+        Compatibility::full_check().incompatibilities(&public_module, &private_module),
+        vec![Incompatibility::FunctionRemoved(fn_name.clone())]
+    );
+    // friend -> private, only allowed if friend linking is not checked
+    let friend_module = mk_module(Visibility::Friend as u8);
+    assert_eq!(
+        Compatibility::full_check().incompatibilities(&friend_module, &private_module),
+        vec![Incompatibility::FriendFunctionRemoved(fn_name.clone())]
+    );
+    // public -> friend, not allowed
+    assert_eq!(
+        Compatibility::full_check().incompatibilities(&public_module, &friend_module),
+        vec![Incompatibility::FunctionSignatureChanged(fn_name)]
+    );
+    assert!(Compatibility::new(true, true, false)
+        .incompatibilities(&friend_module, &private_module)
+        .is_empty());
+    assert!(Compatibility::full_check()
+        .incompatibilities(&public_module, &public_module)
+        .is_empty());
+}
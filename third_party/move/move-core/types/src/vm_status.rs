@@ -834,11 +834,21 @@ pub enum StatusCode {
     TOO_MANY_DELAYED_FIELDS = 4036,
     // Dynamic function call errors.
     RUNTIME_DISPATCH_ERROR = 4037,
+    // The transaction emitted more events than currently allowed.
+    TOO_MANY_EVENTS = 4038,
+    // The transaction grew a resource group past the currently allowed size.
+    RESOURCE_GROUP_SIZE_LIMIT_REACHED = 4039,
+    // The arguments of the entry function or script are larger than currently allowed.
+    TXN_ARGS_SIZE_LIMIT_REACHED = 4040,
+    // The transaction made more dynamic dispatches than currently allowed.
+    TOO_MANY_DYNAMIC_DISPATCHES = 4041,
+    // The transaction created more new state slots than currently allowed.
+    RESOURCE_CREATION_LIMIT_REACHED = 4042,
     // Reserved error code for future use. Always keep this buffer of well-defined new codes.
-    RESERVED_RUNTIME_ERROR_1 = 4038,
-    RESERVED_RUNTIME_ERROR_2 = 4039,
-    RESERVED_RUNTIME_ERROR_3 = 4040,
-    RESERVED_RUNTIME_ERROR_4 = 4041,
+    RESERVED_RUNTIME_ERROR_6 = 4043,
+    RESERVED_RUNTIME_ERROR_7 = 4044,
+    RESERVED_RUNTIME_ERROR_8 = 4045,
+    RESERVED_RUNTIME_ERROR_9 = 4046,
 
     // A reserved status to represent an unknown vm status.
     // this is std::u64::MAX, but we can't pattern match on that, so put the hardcoded value in
@@ -27,6 +27,9 @@ pub struct VMConfig {
     pub type_base_cost: u64,
     pub type_byte_cost: u64,
     pub aggregator_v2_type_tagging: bool,
+    /// Maximum number of dynamic dispatches (calls through native dispatch functions) allowed
+    /// in a single function execution, e.g., a transaction payload
+    pub max_dynamic_dispatches: Option<u64>,
 }
 
 impl Default for VMConfig {
@@ -42,6 +45,7 @@ impl Default for VMConfig {
             type_base_cost: 0,
             type_byte_cost: 0,
             aggregator_v2_type_tagging: false,
+            max_dynamic_dispatches: None,
         }
     }
 }
@@ -68,6 +68,10 @@ pub(crate) struct Interpreter {
     access_control: AccessControlState,
     /// Set of modules that exists on call stack.
     active_modules: HashSet<ModuleId>,
+    /// Maximum number of dynamic dispatches allowed, if limited.
+    max_dynamic_dispatches: Option<u64>,
+    /// Number of dynamic dispatches performed so far.
+    num_dynamic_dispatches: u64,
 }
 
 struct TypeWithLoader<'a, 'b> {
@@ -101,6 +105,8 @@ impl Interpreter {
             paranoid_type_checks: loader.vm_config().paranoid_type_checks,
             access_control: AccessControlState::default(),
             active_modules: HashSet::new(),
+            max_dynamic_dispatches: loader.vm_config().max_dynamic_dispatches,
+            num_dynamic_dispatches: 0,
         }
         .execute_main(
             loader,
@@ -351,7 +357,10 @@ impl Interpreter {
 
         std::mem::swap(current_frame, &mut frame);
         self.call_stack.push(frame).map_err(|frame| {
-            let err = PartialVMError::new(StatusCode::CALL_STACK_OVERFLOW);
+            let err = PartialVMError::new(StatusCode::CALL_STACK_OVERFLOW).with_message(format!(
+                "Call stack size limit of {} frames reached",
+                CALL_STACK_SIZE_LIMIT
+            ));
             let err = set_err_info!(frame, err);
             self.attach_state_if_invariant_violation(err, &frame)
         })?;
@@ -599,6 +608,17 @@ impl Interpreter {
             } => {
                 gas_meter.charge_native_function(cost, Option::<std::iter::Empty<&Value>>::None)?;
 
+                self.num_dynamic_dispatches += 1;
+                if let Some(max_dynamic_dispatches) = self.max_dynamic_dispatches {
+                    if self.num_dynamic_dispatches > max_dynamic_dispatches {
+                        return Err(PartialVMError::new(StatusCode::TOO_MANY_DYNAMIC_DISPATCHES)
+                            .with_message(format!(
+                                "Number of dynamic dispatches exceeds the limit of {}",
+                                max_dynamic_dispatches
+                            )));
+                    }
+                }
+
                 // Load the module that contains this function regardless of the traversal context.
                 //
                 // This is just a precautionary step to make sure that caching status of the VM will not alter execution
@@ -1150,10 +1170,21 @@ impl Interpreter {
 }
 
 // TODO Determine stack size limits based on gas limit
-const OPERAND_STACK_SIZE_LIMIT: usize = 1024;
-const CALL_STACK_SIZE_LIMIT: usize = 1024;
+/// Maximum number of values (and types, if type checks are enabled) on the operand stack.
+/// Exceeding it results in an `EXECUTION_STACK_OVERFLOW` error.
+pub const OPERAND_STACK_SIZE_LIMIT: usize = 1024;
+/// Maximum number of frames on the call stack, i.e., the maximum call depth. Exceeding it results
+/// in a `CALL_STACK_OVERFLOW` error.
+pub const CALL_STACK_SIZE_LIMIT: usize = 1024;
 pub(crate) const ACCESS_STACK_SIZE_LIMIT: usize = 256;
 
+fn operand_stack_overflow_error() -> PartialVMError {
+    PartialVMError::new(StatusCode::EXECUTION_STACK_OVERFLOW).with_message(format!(
+        "Operand stack size limit of {} values reached",
+        OPERAND_STACK_SIZE_LIMIT
+    ))
+}
+
 /// The operand stack.
 struct Stack {
     value: Vec<Value>,
@@ -1176,7 +1207,7 @@ impl Stack {
             self.value.push(value);
             Ok(())
         } else {
-            Err(PartialVMError::new(StatusCode::EXECUTION_STACK_OVERFLOW))
+            Err(operand_stack_overflow_error())
         }
     }
 
@@ -1222,7 +1253,7 @@ impl Stack {
             self.types.push(ty);
             Ok(())
         } else {
-            Err(PartialVMError::new(StatusCode::EXECUTION_STACK_OVERFLOW))
+            Err(operand_stack_overflow_error())
         }
     }
 
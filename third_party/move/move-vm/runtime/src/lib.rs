@@ -29,3 +29,5 @@ pub mod module_traversal;
 mod debug;
 
 mod access_control;
+
+pub use interpreter::{CALL_STACK_SIZE_LIMIT, OPERAND_STACK_SIZE_LIMIT};
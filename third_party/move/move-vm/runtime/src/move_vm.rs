@@ -145,6 +145,10 @@ impl MoveVM {
         self.runtime.loader().is_invalidated()
     }
 
+    pub fn get_vm_config(&self) -> &VMConfig {
+        self.runtime.loader().vm_config()
+    }
+
     /// If the loader cache has been invalidated (either by the above call or by internal logic)
     /// flush it so it is valid again. Notice that should only be called if there are no
     /// outstanding sessions created from this VM.
@@ -67,7 +67,7 @@ impl VMRuntime {
         sender: AccountAddress,
         data_store: &mut TransactionDataCache,
         module_store: &ModuleStorageAdapter,
-        _gas_meter: &mut impl GasMeter,
+        gas_meter: &mut impl GasMeter,
         compat: Compatibility,
     ) -> VMResult<()> {
         // deserialize the modules. Perform bounds check. After this indexes can be
@@ -93,10 +93,47 @@ impl VMRuntime {
             },
         };
 
+        self.publish_compiled_module_bundle(
+            modules,
+            &compiled_modules,
+            sender,
+            data_store,
+            module_store,
+            gas_meter,
+            compat,
+        )
+    }
+
+    /// Same as `publish_module_bundle`, but takes modules which have already been deserialized
+    /// by the caller, so that the bundle does not need to be deserialized twice. The caller must
+    /// ensure `compiled_modules[i]` is the deserialization of `modules[i]` under this VM's
+    /// deserializer config.
+    pub(crate) fn publish_compiled_module_bundle(
+        &self,
+        modules: Vec<Vec<u8>>,
+        compiled_modules: &[CompiledModule],
+        sender: AccountAddress,
+        data_store: &mut TransactionDataCache,
+        module_store: &ModuleStorageAdapter,
+        _gas_meter: &mut impl GasMeter,
+        compat: Compatibility,
+    ) -> VMResult<()> {
+        if modules.len() != compiled_modules.len() {
+            return Err(
+                PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                    .with_message(format!(
+                        "module bundle has {} blobs but {} compiled modules",
+                        modules.len(),
+                        compiled_modules.len()
+                    ))
+                    .finish(Location::Undefined),
+            );
+        }
+
         // Make sure all modules' self addresses matches the transaction sender. The self address is
         // where the module will actually be published. If we did not check this, the sender could
         // publish a module under anyone's account.
-        for module in &compiled_modules {
+        for module in compiled_modules {
             if module.address() != &sender {
                 return Err(verification_error(
                     StatusCode::MODULE_ADDRESS_DOES_NOT_MATCH_SENDER,
@@ -115,7 +152,7 @@ impl VMRuntime {
         //
         // TODO: in the future, we may want to add restrictions on module republishing, possibly by
         // changing the bytecode format to include an `is_upgradable` flag in the CompiledModule.
-        for module in &compiled_modules {
+        for module in compiled_modules {
             let module_id = module.self_id();
 
             if data_store.exists_module(&module_id)? && compat.need_check_compat() {
@@ -137,7 +174,7 @@ impl VMRuntime {
 
         // Perform bytecode and loading verification. Modules must be sorted in topological order.
         self.loader.verify_module_bundle_for_publication(
-            &compiled_modules,
+            compiled_modules,
             data_store,
             module_store,
         )?;
@@ -196,7 +233,7 @@ impl VMRuntime {
         // none of the module can be published/updated.
 
         // All modules verified, publish them to data cache
-        for (module, blob) in compiled_modules.into_iter().zip(modules.into_iter()) {
+        for (module, blob) in compiled_modules.iter().zip(modules.into_iter()) {
             let is_republishing = data_store.exists_module(&module.self_id())?;
             if is_republishing {
                 // This is an upgrade, so invalidate the loader cache, which still contains the
@@ -15,6 +15,7 @@ use move_binary_format::{
     compatibility::Compatibility,
     errors::*,
     file_format::{AbilitySet, LocalIndex},
+    CompiledModule,
 };
 use move_core_types::{
     account_address::AccountAddress,
@@ -253,6 +254,28 @@ impl<'r, 'l> Session<'r, 'l> {
         )
     }
 
+    /// Same like `publish_module_bundle_with_compat_config` but takes modules which have already
+    /// been deserialized, avoiding deserializing the bundle a second time. `compiled_modules[i]`
+    /// must be the deserialization of `modules[i]` under the VM's deserializer config.
+    pub fn publish_compiled_module_bundle_with_compat_config(
+        &mut self,
+        modules: Vec<Vec<u8>>,
+        compiled_modules: &[CompiledModule],
+        sender: AccountAddress,
+        gas_meter: &mut impl GasMeter,
+        compat_config: Compatibility,
+    ) -> VMResult<()> {
+        self.move_vm.runtime.publish_compiled_module_bundle(
+            modules,
+            compiled_modules,
+            sender,
+            &mut self.data_cache,
+            &self.module_store,
+            gas_meter,
+            compat_config,
+        )
+    }
+
     pub fn publish_module_bundle_relax_compatibility(
         &mut self,
         modules: Vec<Vec<u8>>,
@@ -3,6 +3,10 @@
 
 use crate::on_chain_config::BlockGasLimitType;
 use serde::{Deserialize, Serialize};
+use std::{
+    sync::{atomic::AtomicBool, Arc},
+    time::Instant,
+};
 
 /// Local, per-node configuration.
 #[derive(Clone, Debug)]
@@ -14,6 +18,22 @@ pub struct BlockExecutorLocalConfig {
     // If true, we will discard the failed blocks and continue with the next block.
     // (allow_fallback needs to be set)
     pub discard_failed_blocks: bool,
+    // If set, block execution is aborted once the deadline passes, and the block is rejected
+    // (even if discard_failed_blocks is set).
+    pub deadline: Option<Instant>,
+    // If set, block execution is aborted once the flag is raised (e.g. from another thread),
+    // and the block is rejected the same way as when the deadline passes.
+    pub cancelled: Option<Arc<AtomicBool>>,
+    // If true, the final read set (state keys read) of each committed transaction is
+    // recorded in the block output, e.g. for offline conflict analysis. Not meant for production.
+    pub record_read_sets: bool,
+    // If specified, blocks whose speculative aborts in parallel execution exceed this percentage
+    // of the committed transactions are counted and logged, to help identify contended blocks.
+    pub abort_rate_alert_threshold_pct: Option<u64>,
+    // If true, user transactions continue in the prologue session instead of flushing the data
+    // cache into a new session after the prologue, so gas usage may differ from normal execution.
+    // Only meant for trusted re-execution, see `AptosVM::set_skip_prologue_resession`.
+    pub skip_prologue_resession: bool,
 }
 
 /// Configuration from on-chain configuration, that is
@@ -73,6 +93,11 @@ impl BlockExecutorConfig {
                 concurrency_level,
                 allow_fallback: true,
                 discard_failed_blocks: false,
+                deadline: None,
+                cancelled: None,
+                record_read_sets: false,
+                abort_rate_alert_threshold_pct: None,
+                skip_prologue_resession: false,
             },
             onchain: BlockExecutorConfigFromOnchain::new_no_block_limit(),
         }
@@ -87,6 +112,11 @@ impl BlockExecutorConfig {
                 concurrency_level,
                 allow_fallback: true,
                 discard_failed_blocks: false,
+                deadline: None,
+                cancelled: None,
+                record_read_sets: false,
+                abort_rate_alert_threshold_pct: None,
+                skip_prologue_resession: false,
             },
             onchain: BlockExecutorConfigFromOnchain::new_maybe_block_limit(maybe_block_gas_limit),
         }
@@ -442,6 +442,16 @@ impl ExecutableBlock {
             transactions,
         }
     }
+
+    /// Creates an unsharded block from raw transactions, treating all of them as signature
+    /// verified.
+    #[cfg(any(test, feature = "fuzzing"))]
+    pub fn for_test(block_id: HashValue, transactions: Vec<Transaction>) -> Self {
+        Self::new(
+            block_id,
+            ExecutableTransactions::Unsharded(into_signature_verified_block(transactions)),
+        )
+    }
 }
 
 impl From<(HashValue, Vec<SignatureVerifiedTransaction>)> for ExecutableBlock {
@@ -14,7 +14,7 @@ use anyhow::{bail, Error, Result};
 use aptos_crypto_derive::{BCSCryptoHash, CryptoHasher};
 use move_core_types::{
     ident_str,
-    language_storage::{StructTag, TypeTag, CORE_CODE_ADDRESS},
+    language_storage::{ModuleId, StructTag, TypeTag, CORE_CODE_ADDRESS},
     move_resource::MoveStructType,
 };
 use once_cell::sync::Lazy;
@@ -155,6 +155,17 @@ impl ContractEvent {
         Ok(None)
     }
 
+    /// Returns whether the event type is a struct declared in `module`.
+    pub fn is_from_module(&self, module: &ModuleId) -> bool {
+        match self.type_tag() {
+            TypeTag::Struct(struct_tag) => {
+                struct_tag.address == *module.address()
+                    && struct_tag.module.as_ident_str() == module.name()
+            },
+            _ => false,
+        }
+    }
+
     pub fn is_new_epoch_event(&self) -> bool {
         match self {
             ContractEvent::V1(event) => *event.key() == new_epoch_event_key(),
@@ -75,6 +75,8 @@ pub enum FeatureFlag {
     PRIMARY_APT_FUNGIBLE_STORE_AT_USER_ADDRESS = 61,
     OBJECT_NATIVE_DERIVED_ADDRESS = 62,
     DISPATCHABLE_FUNGIBLE_ASSET = 63,
+    SCRIPT_EVENT_EMISSION = 64,
+    MULTI_SIGNER_WRITE_SET_SCRIPT = 65,
 }
 
 impl FeatureFlag {
@@ -0,0 +1,46 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::state_store::{
+    state_key::StateKey, state_storage_usage::StateStorageUsage, state_value::StateValue, Result,
+    StateView, StateViewId, TStateView,
+};
+use std::{cell::RefCell, collections::BTreeSet};
+
+/// A state view that records the keys of all the values read through it, e.g. to find out which
+/// part of the state the result of a view function depends on.
+pub struct ReadRecordingStateView<'a, S> {
+    base_view: &'a S,
+    read_set: RefCell<BTreeSet<StateKey>>,
+}
+
+impl<'a, S: StateView> ReadRecordingStateView<'a, S> {
+    pub fn new(base_view: &'a S) -> Self {
+        Self {
+            base_view,
+            read_set: RefCell::new(BTreeSet::new()),
+        }
+    }
+
+    /// Returns the keys read so far, including the keys of values that do not exist.
+    pub fn into_read_set(self) -> BTreeSet<StateKey> {
+        self.read_set.into_inner()
+    }
+}
+
+impl<'a, S: StateView> TStateView for ReadRecordingStateView<'a, S> {
+    type Key = StateKey;
+
+    fn id(&self) -> StateViewId {
+        self.base_view.id()
+    }
+
+    fn get_state_value(&self, state_key: &StateKey) -> Result<Option<StateValue>> {
+        self.read_set.borrow_mut().insert(state_key.clone());
+        self.base_view.get_state_value(state_key)
+    }
+
+    fn get_usage(&self) -> Result<StateStorageUsage> {
+        self.base_view.get_usage()
+    }
+}
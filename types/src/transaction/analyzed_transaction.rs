@@ -104,6 +104,17 @@ impl AnalyzedTransaction {
         self.transaction.sender()
     }
 
+    /// Returns the read and write hints of the transaction, or `None` if the analysis does not
+    /// support it (in which case [`AnalyzedTransaction::new`] would panic).
+    pub fn try_read_write_hints(
+        transaction: &SignatureVerifiedTransaction,
+    ) -> Option<(Vec<StorageLocation>, Vec<StorageLocation>)> {
+        match transaction {
+            SignatureVerifiedTransaction::Valid(txn) => try_get_read_write_hints(txn),
+            SignatureVerifiedTransaction::Invalid(_) => Some(empty_rw_set()),
+        }
+    }
+
     pub fn expect_p_txn(self) -> (SignatureVerifiedTransaction, Vec<StateKey>, Vec<StateKey>) {
         assert!(self.predictable_transaction());
         (
@@ -235,49 +246,56 @@ trait AnalyzedTransactionProvider {
     fn get_read_write_hints(&self) -> (Vec<StorageLocation>, Vec<StorageLocation>);
 }
 
+/// Returns the read and write hints of the transaction, or `None` if the transaction is not
+/// supported by the analysis yet.
+fn try_get_read_write_hints(
+    txn: &Transaction,
+) -> Option<(Vec<StorageLocation>, Vec<StorageLocation>)> {
+    match txn {
+        Transaction::UserTransaction(signed_txn) => match signed_txn.payload() {
+            TransactionPayload::EntryFunction(func) => {
+                match (
+                    *func.module().address(),
+                    func.module().name().as_str(),
+                    func.function().as_str(),
+                ) {
+                    (AccountAddress::ONE, "coin", "transfer") => {
+                        let sender_address = signed_txn.sender();
+                        let receiver_address = bcs::from_bytes(func.args().first()?).ok()?;
+                        Some(rw_set_for_coin_transfer(
+                            sender_address,
+                            receiver_address,
+                            true,
+                        ))
+                    },
+                    (AccountAddress::ONE, "aptos_account", "transfer") => {
+                        let sender_address = signed_txn.sender();
+                        let receiver_address = bcs::from_bytes(func.args().first()?).ok()?;
+                        Some(rw_set_for_coin_transfer(
+                            sender_address,
+                            receiver_address,
+                            false,
+                        ))
+                    },
+                    (AccountAddress::ONE, "aptos_account", "create_account") => {
+                        let sender_address = signed_txn.sender();
+                        let receiver_address = bcs::from_bytes(func.args().first()?).ok()?;
+                        Some(rw_set_for_create_account(sender_address, receiver_address))
+                    },
+                    _ => None,
+                }
+            },
+            _ => None,
+        },
+        _ => Some(empty_rw_set()),
+    }
+}
+
 impl AnalyzedTransactionProvider for Transaction {
     fn get_read_write_hints(&self) -> (Vec<StorageLocation>, Vec<StorageLocation>) {
-        match self {
-            Transaction::UserTransaction(signed_txn) => match signed_txn.payload() {
-                TransactionPayload::EntryFunction(func) => {
-                    match (
-                        *func.module().address(),
-                        func.module().name().as_str(),
-                        func.function().as_str(),
-                    ) {
-                        (AccountAddress::ONE, "coin", "transfer") => {
-                            let sender_address = signed_txn.sender();
-                            let receiver_address = bcs::from_bytes(&func.args()[0]).unwrap();
-                            rw_set_for_coin_transfer(
-                                sender_address,
-                                receiver_address,
-                                true,
-                            )
-                        },
-                        (AccountAddress::ONE, "aptos_account", "transfer") => {
-                            let sender_address = signed_txn.sender();
-                            let receiver_address = bcs::from_bytes(&func.args()[0]).unwrap();
-                            rw_set_for_coin_transfer(
-                                sender_address,
-                                receiver_address,
-                                false,
-                            )
-                        },
-                        (AccountAddress::ONE, "aptos_account", "create_account") => {
-                            let sender_address = signed_txn.sender();
-                            let receiver_address = bcs::from_bytes(&func.args()[0]).unwrap();
-                            rw_set_for_create_account(
-                                sender_address,
-                                receiver_address,
-                            )
-                        },
-                        _ => todo!("Only coin transfer and create account transactions are supported for now")
-                    }
-                },
-                _ => todo!("Only entry function transactions are supported for now"),
-            },
-            _ => empty_rw_set(),
-        }
+        try_get_read_write_hints(self).unwrap_or_else(|| {
+            todo!("Only coin transfer and create account entry function transactions are supported for now")
+        })
     }
 }
 
@@ -1,21 +1,64 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::{state_store::state_key::StateKey, transaction::TransactionOutput};
+use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
+/// Why the block executor stopped executing the transactions of a block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BlockEndReason {
+    /// The accumulated effective block gas reached the per-block gas limit, the remaining
+    /// transactions are retried.
+    GasLimitReached,
+    /// The accumulated approximate output size reached the per-block output limit, the
+    /// remaining transactions are retried.
+    BlockFull,
+    /// No block limit was reached. Transactions after a reconfiguration are still retried.
+    AllTransactionsExecuted,
+}
+
 #[derive(Debug)]
-pub struct BlockOutput<Output: Debug> {
+pub struct BlockOutput<Output: Debug, Key: Debug = StateKey> {
     transaction_outputs: Vec<Output>,
-    // TODO add block_limit_info
+    /// Final read sets of the committed transactions (in order), only populated when
+    /// read set recording is enabled in the block executor config.
+    read_sets: Option<Vec<Vec<Key>>>,
+    /// Set by the block executor when the block was executed to completion, i.e. not discarded.
+    block_end_reason: Option<BlockEndReason>,
 }
 
-impl<Output: Debug> BlockOutput<Output> {
+impl<Output: Debug, Key: Debug> BlockOutput<Output, Key> {
     pub fn new(transaction_outputs: Vec<Output>) -> Self {
         Self {
             transaction_outputs,
+            read_sets: None,
+            block_end_reason: None,
         }
     }
 
+    pub fn with_read_sets(mut self, read_sets: Option<Vec<Vec<Key>>>) -> Self {
+        self.read_sets = read_sets;
+        self
+    }
+
+    pub fn read_sets(&self) -> Option<&[Vec<Key>]> {
+        self.read_sets.as_deref()
+    }
+
+    pub fn take_read_sets(&mut self) -> Option<Vec<Vec<Key>>> {
+        self.read_sets.take()
+    }
+
+    pub fn with_block_end_reason(mut self, block_end_reason: Option<BlockEndReason>) -> Self {
+        self.block_end_reason = block_end_reason;
+        self
+    }
+
+    pub fn block_end_reason(&self) -> Option<BlockEndReason> {
+        self.block_end_reason
+    }
+
     /// If block limit is not set (i.e. in tests), we can safely unwrap here
     pub fn into_transaction_outputs_forced(self) -> Vec<Output> {
         // TODO assert there is no block limit info?
@@ -34,3 +77,13 @@ impl<Output: Debug> BlockOutput<Output> {
         &self.transaction_outputs
     }
 }
+
+impl<Key: Debug> BlockOutput<TransactionOutput, Key> {
+    /// Size in bytes of the serialized write set and events of each transaction output, in order.
+    pub fn output_sizes(&self) -> Vec<usize> {
+        self.transaction_outputs
+            .iter()
+            .map(TransactionOutput::output_size)
+            .collect()
+    }
+}
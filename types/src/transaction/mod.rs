@@ -13,7 +13,7 @@ use crate::{
     ledger_info::LedgerInfo,
     on_chain_config::{FeatureFlag, Features},
     proof::{TransactionInfoListWithProof, TransactionInfoWithProof},
-    state_store::ShardedStateUpdates,
+    state_store::{state_key::StateKey, ShardedStateUpdates},
     transaction::authenticator::{
         AccountAuthenticator, AnyPublicKey, AnySignature, SingleKeyAuthenticator,
         TransactionAuthenticator,
@@ -58,7 +58,7 @@ use crate::{
     fee_statement::FeeStatement, proof::accumulator::InMemoryEventAccumulator,
     validator_txn::ValidatorTransaction, write_set::TransactionWrite,
 };
-pub use block_output::BlockOutput;
+pub use block_output::{BlockEndReason, BlockOutput};
 pub use change_set::ChangeSet;
 pub use module::{Module, ModuleBundle};
 pub use move_core_types::transaction_argument::TransactionArgument;
@@ -428,7 +428,7 @@ impl TransactionPayload {
     }
 }
 
-/// Two different kinds of WriteSet transactions.
+/// Different kinds of WriteSet transactions.
 #[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub enum WriteSetPayload {
     /// Directly passing in the WriteSet.
@@ -440,13 +440,20 @@ pub enum WriteSetPayload {
         /// Script body that gets executed.
         script: Script,
     },
+    /// Generate the WriteSet by running a script which takes any number of signers.
+    MultiSignerScript {
+        /// Signers passed to the script, in the order of its signer parameters.
+        signers: Vec<AccountAddress>,
+        /// Script body that gets executed.
+        script: Script,
+    },
 }
 
 impl WriteSetPayload {
     pub fn should_trigger_reconfiguration_by_default(&self) -> bool {
         match self {
             Self::Direct(_) => true,
-            Self::Script { .. } => false,
+            Self::Script { .. } | Self::MultiSignerScript { .. } => false,
         }
     }
 }
@@ -1054,6 +1061,12 @@ pub struct VMValidatorResult {
     /// Score for ranking the transaction priority (e.g., based on the gas price).
     /// Only used when the status is `None`. Higher values indicate a higher priority.
     score: u64,
+
+    /// Coarse estimate of the execution gas units the transaction uses, if known.
+    estimated_execution_gas: Option<u64>,
+
+    /// Coarse estimate of the storage gas units the transaction uses, if known.
+    estimated_storage_gas: Option<u64>,
 }
 
 impl VMValidatorResult {
@@ -1072,6 +1085,8 @@ impl VMValidatorResult {
         Self {
             status: vm_status,
             score,
+            estimated_execution_gas: None,
+            estimated_storage_gas: None,
         }
     }
 
@@ -1079,9 +1094,18 @@ impl VMValidatorResult {
         Self {
             status: Some(vm_status),
             score: 0,
+            estimated_execution_gas: None,
+            estimated_storage_gas: None,
         }
     }
 
+    /// Attaches the estimated split between execution and storage gas units.
+    pub fn with_gas_estimate(mut self, execution_gas: u64, storage_gas: u64) -> Self {
+        self.estimated_execution_gas = Some(execution_gas);
+        self.estimated_storage_gas = Some(storage_gas);
+        self
+    }
+
     pub fn status(&self) -> Option<DiscardedVMStatus> {
         self.status
     }
@@ -1089,6 +1113,14 @@ impl VMValidatorResult {
     pub fn score(&self) -> u64 {
         self.score
     }
+
+    pub fn estimated_execution_gas(&self) -> Option<u64> {
+        self.estimated_execution_gas
+    }
+
+    pub fn estimated_storage_gas(&self) -> Option<u64> {
+        self.estimated_storage_gas
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -1232,6 +1264,12 @@ impl TransactionOutput {
         &self.events
     }
 
+    /// Size in bytes of the serialized write set and events of this output.
+    pub fn output_size(&self) -> usize {
+        bcs::serialized_size(&self.write_set).expect("Unable to serialize WriteSet")
+            + bcs::serialized_size(&self.events).expect("Unable to serialize events")
+    }
+
     pub fn gas_used(&self) -> u64 {
         self.gas_used
     }
@@ -2110,10 +2148,29 @@ pub trait BlockExecutableTransaction: Sync + Send + Clone + 'static {
 pub struct ViewFunctionOutput {
     pub values: Result<Vec<Vec<u8>>>,
     pub gas_used: u64,
+    /// The gas feature version the view function was executed with, if known.
+    pub gas_feature_version: Option<u64>,
+    /// The state keys read during the execution, if they were recorded.
+    pub read_set: Option<BTreeSet<StateKey>>,
 }
 
 impl ViewFunctionOutput {
     pub fn new(values: Result<Vec<Vec<u8>>>, gas_used: u64) -> Self {
-        Self { values, gas_used }
+        Self {
+            values,
+            gas_used,
+            gas_feature_version: None,
+            read_set: None,
+        }
+    }
+
+    pub fn with_gas_feature_version(mut self, gas_feature_version: u64) -> Self {
+        self.gas_feature_version = Some(gas_feature_version);
+        self
+    }
+
+    pub fn with_read_set(mut self, read_set: BTreeSet<StateKey>) -> Self {
+        self.read_set = Some(read_set);
+        self
     }
 }
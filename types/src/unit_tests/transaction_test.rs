@@ -5,17 +5,22 @@
 use crate::{
     account_address::AccountAddress,
     chain_id::ChainId,
+    contract_event::ContractEvent,
+    state_store::state_key::StateKey,
     transaction::{
-        AccountTransactionsWithProof, RawTransaction, Script, SignedTransaction, Transaction,
-        TransactionInfo, TransactionListWithProof, TransactionPayload, TransactionWithProof,
+        AccountTransactionsWithProof, BlockOutput, ExecutionStatus, RawTransaction, Script,
+        SignedTransaction, Transaction, TransactionAuxiliaryData, TransactionInfo,
+        TransactionListWithProof, TransactionOutput, TransactionPayload, TransactionStatus,
+        TransactionWithProof, WriteSetPayload,
     },
+    write_set::{WriteOp, WriteSet, WriteSetMut},
 };
 use aptos_crypto::{
     ed25519::{self, Ed25519PrivateKey, Ed25519Signature},
     PrivateKey, Uniform,
 };
 use bcs::test_helpers::assert_canonical_encode_decode;
-use proptest::prelude::*;
+use proptest::{collection::vec, prelude::*};
 use std::convert::TryFrom;
 
 #[test]
@@ -39,6 +44,55 @@ fn test_invalid_signature() {
     )
 }
 
+#[test]
+fn test_multi_signer_write_set_payload_encoding() {
+    let signers = vec![AccountAddress::ZERO, AccountAddress::ONE];
+    let script = Script::new(vec![1, 2, 3], vec![], vec![]);
+    let payload = WriteSetPayload::MultiSignerScript {
+        signers: signers.clone(),
+        script: script.clone(),
+    };
+    // The variant follows `Direct` and `Script` on the wire, followed by its fields in order.
+    let mut expected = vec![2];
+    expected.extend(bcs::to_bytes(&signers).unwrap());
+    expected.extend(bcs::to_bytes(&script).unwrap());
+    assert_eq!(bcs::to_bytes(&payload).unwrap(), expected);
+    assert_canonical_encode_decode(payload);
+}
+
+#[test]
+fn test_block_output_sizes() {
+    let output = |write_set: WriteSet, events: Vec<ContractEvent>| {
+        TransactionOutput::new(
+            write_set,
+            events,
+            0,
+            TransactionStatus::Keep(ExecutionStatus::Success),
+            TransactionAuxiliaryData::default(),
+        )
+    };
+    let write_set = WriteSetMut::new(vec![(
+        StateKey::raw(b"key"),
+        WriteOp::legacy_modification(vec![0; 100].into()),
+    )])
+    .freeze()
+    .unwrap();
+    let events = vec![ContractEvent::new_v2_with_type_tag_str(
+        "0x1::event::Event",
+        vec![0; 50],
+    )];
+
+    let block_output = BlockOutput::new(vec![
+        output(WriteSet::default(), vec![]),
+        output(write_set.clone(), events.clone()),
+    ]);
+    assert_eq!(block_output.output_sizes(), vec![
+        bcs::to_bytes(&WriteSet::default()).unwrap().len()
+            + bcs::to_bytes(&Vec::<ContractEvent>::new()).unwrap().len(),
+        bcs::to_bytes(&write_set).unwrap().len() + bcs::to_bytes(&events).unwrap().len(),
+    ]);
+}
+
 proptest! {
     #[test]
     fn test_sign_raw_transaction(raw_txn in any::<RawTransaction>(), keypair in ed25519::keypair_strategy()) {
@@ -66,6 +120,14 @@ proptest! {
     fn transaction_info_bcs_roundtrip(txn_info in any::<TransactionInfo>()) {
         assert_canonical_encode_decode(txn_info);
     }
+
+    #[test]
+    fn multi_signer_write_set_payload_bcs_roundtrip(
+        signers in vec(any::<AccountAddress>(), 0..5),
+        script in any::<Script>(),
+    ) {
+        assert_canonical_encode_decode(WriteSetPayload::MultiSignerScript { signers, script });
+    }
 }
 
 proptest! {
@@ -92,6 +92,66 @@ fn test_validate_transaction() {
     assert_eq!(ret.status(), None);
 }
 
+#[test]
+fn test_validate_transaction_with_validation_cache() {
+    let TestValidator {
+        vm_validator,
+        _db_path,
+    } = TestValidator::new();
+    let mut vm_validator = vm_validator.with_validation_cache(10);
+
+    let address = account_config::aptos_test_root_address();
+    let program = aptos_stdlib::aptos_coin_mint(address, 100);
+    let transaction = transaction_test_helpers::get_test_signed_txn(
+        address,
+        1,
+        &aptos_vm_genesis::GENESIS_KEYPAIR.0,
+        aptos_vm_genesis::GENESIS_KEYPAIR.1.clone(),
+        Some(program),
+    );
+    let ret = vm_validator
+        .validate_transaction(transaction.clone())
+        .unwrap();
+    assert_eq!(ret.status(), None);
+    let cache_key = (transaction.committed_hash(), vm_validator.state_version);
+    assert!(vm_validator
+        .validation_cache
+        .as_ref()
+        .unwrap()
+        .lock()
+        .unwrap()
+        .contains(&cache_key));
+
+    // Re-validating against the same state returns the cached result.
+    let cached_ret = vm_validator
+        .validate_transaction(transaction.clone())
+        .unwrap();
+    assert_eq!(cached_ret, ret);
+
+    // Nothing was committed, so the cached result is still valid.
+    vm_validator.notify_commit();
+    assert_eq!(
+        vm_validator
+            .validation_cache
+            .as_ref()
+            .unwrap()
+            .lock()
+            .unwrap()
+            .len(),
+        1
+    );
+
+    // Restarting drops the cached results.
+    vm_validator.restart().unwrap();
+    assert!(vm_validator
+        .validation_cache
+        .as_ref()
+        .unwrap()
+        .lock()
+        .unwrap()
+        .is_empty());
+}
+
 #[test]
 fn test_validate_invalid_signature() {
     let vm_validator = TestValidator::new();
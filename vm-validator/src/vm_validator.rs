@@ -3,6 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use anyhow::Result;
+use aptos_crypto::HashValue;
 use aptos_logger::info;
 use aptos_storage_interface::{
     cached_state_view::CachedDbStateView,
@@ -13,11 +14,12 @@ use aptos_types::{
     account_address::AccountAddress,
     account_config::AccountResource,
     state_store::{MoveResourceExt, StateView},
-    transaction::{SignedTransaction, VMValidatorResult},
+    transaction::{SignedTransaction, VMValidatorResult, Version},
 };
 use aptos_vm::{data_cache::AsMoveResolver, AptosVM};
 use aptos_vm_logging::log_schema::AdapterLogSchema;
 use fail::fail_point;
+use lru::LruCache;
 use rand::{thread_rng, Rng};
 use std::sync::{Arc, Mutex};
 
@@ -38,15 +40,26 @@ pub trait TransactionValidation: Send + Sync + Clone {
     fn notify_commit(&mut self);
 }
 
+/// Caches validation results keyed by the transaction hash and the version of the state the
+/// transaction was validated against. Any commit moves the state to a new version, so a result is
+/// never reused once the sender's account (or anything else) may have changed.
+type ValidationCache = LruCache<(HashValue, Option<Version>), VMValidatorResult>;
+
 pub struct VMValidator {
     db_reader: Arc<dyn DbReader>,
     state_view: CachedDbStateView,
+    state_version: Option<Version>,
     vm: AptosVM,
+    validation_cache: Option<Mutex<ValidationCache>>,
 }
 
 impl Clone for VMValidator {
     fn clone(&self) -> Self {
-        Self::new(self.db_reader.clone())
+        let validator = Self::new(self.db_reader.clone());
+        match &self.validation_cache {
+            Some(cache) => validator.with_validation_cache(cache.lock().unwrap().cap()),
+            None => validator,
+        }
     }
 }
 
@@ -70,10 +83,20 @@ impl VMValidator {
         let vm = Self::new_vm_for_validation(&db_state_view);
         VMValidator {
             db_reader,
+            state_version: db_state_view.version(),
             state_view: db_state_view.into(),
             vm,
+            validation_cache: None,
         }
     }
+
+    /// Caches up to `capacity` validation results, so that re-validating an unchanged
+    /// transaction against an unchanged state returns the previous result. A capacity of zero
+    /// disables the cache.
+    pub fn with_validation_cache(mut self, capacity: usize) -> Self {
+        self.validation_cache = (capacity > 0).then(|| Mutex::new(LruCache::new(capacity)));
+        self
+    }
 }
 
 impl TransactionValidation for VMValidator {
@@ -87,22 +110,43 @@ impl TransactionValidation for VMValidator {
         });
         use aptos_vm::VMValidator;
 
-        Ok(self.vm.validate_transaction(txn, &self.state_view))
+        let cache = match &self.validation_cache {
+            Some(cache) => cache,
+            None => return Ok(self.vm.validate_transaction(txn, &self.state_view)),
+        };
+        let key = (txn.committed_hash(), self.state_version);
+        if let Some(result) = cache.lock().unwrap().get(&key) {
+            return Ok(result.clone());
+        }
+        let result = self.vm.validate_transaction(txn, &self.state_view);
+        cache.lock().unwrap().put(key, result.clone());
+        Ok(result)
     }
 
     fn restart(&mut self) -> Result<()> {
         self.notify_commit();
+        // The new VM may validate differently (e.g., after a reconfiguration).
+        if let Some(cache) = &self.validation_cache {
+            cache.lock().unwrap().clear();
+        }
 
         self.vm = Self::new_vm_for_validation(&self.state_view);
         Ok(())
     }
 
     fn notify_commit(&mut self) {
-        self.state_view = self
+        let db_state_view = self
             .db_reader
             .latest_state_checkpoint_view()
-            .expect("Get db view cannot fail")
-            .into();
+            .expect("Get db view cannot fail");
+        if db_state_view.version() != self.state_version {
+            // Results cached for the previous version can never be hit again.
+            if let Some(cache) = &self.validation_cache {
+                cache.lock().unwrap().clear();
+            }
+        }
+        self.state_version = db_state_view.version();
+        self.state_view = db_state_view.into();
     }
 }
 
@@ -132,9 +176,22 @@ pub struct PooledVMValidator {
 
 impl PooledVMValidator {
     pub fn new(db_reader: Arc<dyn DbReader>, pool_size: usize) -> Self {
+        Self::new_with_validation_cache(db_reader, pool_size, 0)
+    }
+
+    /// Same as `new`, but every validator in the pool caches up to `validation_cache_capacity`
+    /// validation results. See `VMValidator::with_validation_cache`.
+    pub fn new_with_validation_cache(
+        db_reader: Arc<dyn DbReader>,
+        pool_size: usize,
+        validation_cache_capacity: usize,
+    ) -> Self {
         let mut vm_validators = Vec::new();
         for _ in 0..pool_size {
-            vm_validators.push(Arc::new(Mutex::new(VMValidator::new(db_reader.clone()))));
+            vm_validators.push(Arc::new(Mutex::new(
+                VMValidator::new(db_reader.clone())
+                    .with_validation_cache(validation_cache_capacity),
+            )));
         }
         PooledVMValidator { vm_validators }
     }